@@ -4,7 +4,7 @@ use strum::EnumIter;
 use super::{
     AssemblerConfig, AssemblyMode, DirectoryMergeOutput, cargo_resource_assign,
     cargo_workspace_merge, composer_resource_assign, conda_rootfs_merge, file_ref_resolve,
-    hackage_merge, npm_resource_assign, npm_workspace_merge, nuget_cpm_resolve,
+    go_workspace_link, hackage_merge, npm_resource_assign, npm_workspace_merge, nuget_cpm_resolve,
     python_requirements_assign, ruby_resource_assign, swift_merge,
 };
 
@@ -24,6 +24,7 @@ pub(super) enum PostAssemblyPassKind {
     RpmYumdbMerge,
     NpmWorkspaceMerge,
     CargoWorkspaceMerge,
+    GoWorkspaceLink,
     NugetCpmResolve,
     CargoResourceAssign,
     ComposerResourceAssign,
@@ -49,6 +50,7 @@ pub(super) static POST_ASSEMBLY_PASSES: &[PostAssemblyPassKind] = &[
     PostAssemblyPassKind::RpmYumdbMerge,
     PostAssemblyPassKind::NpmWorkspaceMerge,
     PostAssemblyPassKind::CargoWorkspaceMerge,
+    PostAssemblyPassKind::GoWorkspaceLink,
     PostAssemblyPassKind::NugetCpmResolve,
     PostAssemblyPassKind::CargoResourceAssign,
     PostAssemblyPassKind::ComposerResourceAssign,
@@ -110,6 +112,9 @@ impl PostAssemblyPassKind {
             Self::CargoWorkspaceMerge => {
                 cargo_workspace_merge::assemble_cargo_workspaces(files, packages, dependencies)
             }
+            Self::GoWorkspaceLink => {
+                go_workspace_link::link_go_workspaces(files, packages, dependencies)
+            }
             Self::NugetCpmResolve => {
                 nuget_cpm_resolve::resolve_nuget_cpm_versions(files, dependencies)
             }
@@ -226,6 +231,12 @@ pub static ASSEMBLERS: &[AssemblerConfig] = &[
         sibling_file_patterns: &["default.nix"],
         mode: AssemblyMode::OnePerPackageData,
     },
+    // Hex/Elixir ecosystem
+    AssemblerConfig {
+        datasource_ids: &[DatasourceId::HexMixExs, DatasourceId::HexMixLock],
+        sibling_file_patterns: &["mix.exs", "mix.lock"],
+        mode: AssemblyMode::SiblingMerge,
+    },
     // Helm chart ecosystem
     AssemblerConfig {
         datasource_ids: &[DatasourceId::HelmChartYaml, DatasourceId::HelmChartLock],
@@ -392,8 +403,17 @@ pub static ASSEMBLERS: &[AssemblerConfig] = &[
     },
     // Gradle/Android ecosystem
     AssemblerConfig {
-        datasource_ids: &[DatasourceId::BuildGradle, DatasourceId::GradleLockfile],
-        sibling_file_patterns: &["build.gradle", "build.gradle.kts", "gradle.lockfile"],
+        datasource_ids: &[
+            DatasourceId::BuildGradle,
+            DatasourceId::GradleLockfile,
+            DatasourceId::GradleDependenciesLock,
+        ],
+        sibling_file_patterns: &[
+            "build.gradle",
+            "build.gradle.kts",
+            "gradle.lockfile",
+            "dependencies.lock",
+        ],
         mode: AssemblyMode::SiblingMerge,
     },
     AssemblerConfig {
@@ -512,6 +532,12 @@ pub static ASSEMBLERS: &[AssemblerConfig] = &[
         sibling_file_patterns: &["**/var/lib/yum/yumdb/*/*/from_repo"],
         mode: AssemblyMode::OnePerPackageData,
     },
+    // Apple framework bundle Info.plist
+    AssemblerConfig {
+        datasource_ids: &[DatasourceId::AppleInfoPlist],
+        sibling_file_patterns: &["Info.plist"],
+        mode: AssemblyMode::SiblingMerge,
+    },
     // Microsoft Update Manifest
     AssemblerConfig {
         datasource_ids: &[DatasourceId::MicrosoftUpdateManifestMum],
@@ -643,7 +669,6 @@ pub static UNASSEMBLED_DATASOURCE_IDS: &[DatasourceId] = &[
     DatasourceId::DebianInstalledMd5Sums,
     DatasourceId::DebianSourceControlDsc,
     DatasourceId::Dockerfile,
-    DatasourceId::HexMixLock,
     DatasourceId::JavaEarApplicationXml,
     DatasourceId::JavaWarWebXml,
     DatasourceId::JbossServiceXml,
@@ -653,6 +678,8 @@ pub static UNASSEMBLED_DATASOURCE_IDS: &[DatasourceId] = &[
     DatasourceId::RpmPackageLicenses,
     DatasourceId::SbtBuildSbt,
     DatasourceId::VcpkgJson,
+    // Assembled only by assemblers registered at runtime with a custom parser
+    DatasourceId::Custom,
 ];
 
 #[cfg(test)]
@@ -143,6 +143,7 @@ mod tests {
                 is_generated: None,
                 facets: vec![],
                 tallies: None,
+                ..Default::default()
             };
 
             file_infos.push(file_info);
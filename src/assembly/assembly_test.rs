@@ -60,6 +60,7 @@ mod tests {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         }
     }
 
@@ -1552,6 +1553,75 @@ mod tests {
         assert!(package.datasource_ids.contains(&DatasourceId::CargoLock));
     }
 
+    #[test]
+    fn test_assemble_merges_custom_lockfile_pattern_with_manifest() {
+        use super::super::{SiblingPatterns, assemble_with_patterns};
+
+        let build_files = || {
+            vec![
+                create_test_file_info(
+                    "project/Cargo.toml",
+                    DatasourceId::CargoToml,
+                    Some("pkg:cargo/my-crate@0.1.0"),
+                    Some("my-crate"),
+                    Some("0.1.0"),
+                    vec![],
+                ),
+                create_test_file_info(
+                    "project/deps.vendor.lock",
+                    DatasourceId::CargoLock,
+                    Some("pkg:cargo/my-crate@0.1.0"),
+                    Some("my-crate"),
+                    Some("0.1.0"),
+                    vec![create_test_dependency("pkg:cargo/serde@1.0.0", None, None)],
+                ),
+            ]
+        };
+
+        let mut files = build_files();
+        let result = assemble(&mut files);
+        assert_eq!(
+            result.packages[0].datafile_paths,
+            vec!["project/Cargo.toml".to_string()]
+        );
+
+        let mut patterns = SiblingPatterns::default();
+        patterns
+            .add_lockfile_pattern(DatasourceId::CargoLock, "*.vendor.lock")
+            .expect("cargo assembler should accept lockfile patterns");
+        let mut files = build_files();
+        let result = assemble_with_patterns(&mut files, &patterns);
+
+        assert_eq!(result.packages.len(), 1, "Expected exactly one package");
+        let package = &result.packages[0];
+        assert_eq!(
+            package.datafile_paths,
+            vec![
+                "project/Cargo.toml".to_string(),
+                "project/deps.vendor.lock".to_string()
+            ]
+        );
+        assert!(package.datasource_ids.contains(&DatasourceId::CargoLock));
+        assert_eq!(result.dependencies.len(), 1);
+        assert_eq!(
+            result.dependencies[0].for_package_uid.as_deref(),
+            Some(package.package_uid.as_str())
+        );
+        assert_eq!(files[1].for_packages, vec![package.package_uid.clone()]);
+    }
+
+    #[test]
+    fn test_sibling_patterns_reject_non_sibling_assemblers() {
+        use super::super::SiblingPatterns;
+
+        let mut patterns = SiblingPatterns::default();
+        assert!(
+            patterns
+                .add_manifest_pattern(DatasourceId::AlpineInstalledDb, "installed.custom")
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_assemble_python_pyproject_with_uv_lock() {
         let mut files = vec![
@@ -1618,6 +1688,108 @@ mod tests {
         assert_eq!(files[1].for_packages.len(), 1);
     }
 
+    #[test]
+    fn test_assemble_python_pyproject_with_poetry_lock() {
+        let mut files = vec![
+            create_test_file_info(
+                "project/pyproject.toml",
+                DatasourceId::PypiPyprojectToml,
+                Some("pkg:pypi/poetry-demo@0.1.0"),
+                Some("poetry-demo"),
+                Some("0.1.0"),
+                vec![],
+            ),
+            create_test_file_info(
+                "project/poetry.lock",
+                DatasourceId::PypiPoetryLock,
+                None,
+                None,
+                None,
+                vec![Dependency {
+                    purl: Some("pkg:pypi/requests@2.31.0".to_string()),
+                    extracted_requirement: None,
+                    scope: Some("main".to_string()),
+                    is_runtime: Some(true),
+                    is_optional: Some(false),
+                    is_pinned: Some(true),
+                    is_direct: Some(false),
+                    resolved_package: None,
+                    extra_data: None,
+                }],
+            ),
+        ];
+
+        let result = assemble(&mut files);
+
+        assert_eq!(result.packages.len(), 1);
+        let package = &result.packages[0];
+        assert_eq!(package.name, Some("poetry-demo".to_string()));
+        assert!(
+            package
+                .datasource_ids
+                .contains(&DatasourceId::PypiPyprojectToml)
+        );
+        assert!(
+            package
+                .datasource_ids
+                .contains(&DatasourceId::PypiPoetryLock)
+        );
+        assert_eq!(result.dependencies.len(), 1);
+        assert_eq!(
+            result.dependencies[0].for_package_uid.as_deref(),
+            Some(package.package_uid.as_str())
+        );
+        assert_eq!(
+            result.dependencies[0].datafile_path,
+            "project/poetry.lock".to_string()
+        );
+    }
+
+    #[test]
+    fn test_assemble_hex_mix_exs_with_mix_lock() {
+        let mut files = vec![
+            create_test_file_info(
+                "project/mix.exs",
+                DatasourceId::HexMixExs,
+                Some("pkg:hex/demo@0.4.2"),
+                Some("demo"),
+                Some("0.4.2"),
+                vec![],
+            ),
+            create_test_file_info(
+                "project/mix.lock",
+                DatasourceId::HexMixLock,
+                None,
+                None,
+                None,
+                vec![Dependency {
+                    purl: Some("pkg:hex/jason@1.4.1".to_string()),
+                    extracted_requirement: Some("1.4.1".to_string()),
+                    scope: Some("dependencies".to_string()),
+                    is_runtime: Some(true),
+                    is_optional: Some(false),
+                    is_pinned: Some(true),
+                    is_direct: Some(false),
+                    resolved_package: None,
+                    extra_data: None,
+                }],
+            ),
+        ];
+
+        let result = assemble(&mut files);
+
+        assert_eq!(result.packages.len(), 1);
+        let package = &result.packages[0];
+        assert_eq!(package.purl.as_deref(), Some("pkg:hex/demo@0.4.2"));
+        assert!(package.datasource_ids.contains(&DatasourceId::HexMixExs));
+        assert!(package.datasource_ids.contains(&DatasourceId::HexMixLock));
+        assert_eq!(result.dependencies.len(), 1);
+        assert_eq!(
+            result.dependencies[0].for_package_uid.as_deref(),
+            Some(package.package_uid.as_str())
+        );
+    }
+
     #[test]
     fn test_assemble_python_pyproject_with_uv_lock_backfills_version_and_refreshes_uids() {
         let mut files = vec![
@@ -2388,6 +2560,74 @@ mod tests {
         assert_eq!(result.dependencies.len(), 1);
     }
 
+    #[test]
+    fn test_assemble_go_work_links_member_modules() {
+        let use_dependency = |module: &str, use_path: &str| Dependency {
+            purl: Some(format!("pkg:golang/example.com/{module}")),
+            extracted_requirement: Some(use_path.to_string()),
+            scope: Some("use".to_string()),
+            is_runtime: Some(true),
+            is_optional: Some(false),
+            is_pinned: Some(false),
+            is_direct: Some(true),
+            resolved_package: None,
+            extra_data: None,
+        };
+        let mut files = vec![
+            create_test_file_info(
+                "workspace/go.work",
+                DatasourceId::GoWork,
+                None,
+                None,
+                None,
+                vec![
+                    use_dependency("api", "./api"),
+                    use_dependency("worker", "services/worker"),
+                ],
+            ),
+            create_test_file_info(
+                "workspace/api/go.mod",
+                DatasourceId::GoMod,
+                Some("pkg:golang/example.com/api"),
+                Some("api"),
+                None,
+                vec![],
+            ),
+            create_test_file_info(
+                "workspace/services/worker/go.mod",
+                DatasourceId::GoMod,
+                Some("pkg:golang/example.com/worker"),
+                Some("worker"),
+                None,
+                vec![],
+            ),
+        ];
+        files[0].package_data[0].extra_data = Some(std::collections::HashMap::from([(
+            "use_paths".to_string(),
+            serde_json::json!(["./api", "services/worker"]),
+        )]));
+
+        let result = assemble(&mut files);
+
+        assert_eq!(result.packages.len(), 2);
+        assert_eq!(result.dependencies.len(), 2);
+        for package in &result.packages {
+            let extra_data = package.extra_data.as_ref().expect("member extra_data");
+            assert_eq!(extra_data["go_workspace"], "workspace/go.work");
+
+            let use_dependency = result
+                .dependencies
+                .iter()
+                .find(|dependency| dependency.purl == package.purl)
+                .expect("use dependency for member");
+            assert_eq!(use_dependency.datafile_path, "workspace/go.work");
+            assert_eq!(
+                use_dependency.extra_data.as_ref().expect("extra_data")["workspace_package_uid"],
+                package.package_uid.as_str()
+            );
+        }
+    }
+
     #[test]
     fn test_assemble_no_matching_datasource() {
         let mut files = vec![create_test_file_info(
@@ -2545,6 +2785,60 @@ mod tests {
         assert!(uid2.starts_with("pkg:npm/test@1.0.0?arch=x64&uuid="));
     }
 
+    #[test]
+    fn test_assemble_npm_package_takes_root_integrity_from_lockfile() {
+        use crate::parsers::{NpmLockParser, PackageParser};
+
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let lock_path = temp_dir.path().join("package-lock.json");
+        std::fs::write(
+            &lock_path,
+            r#"{
+                "name": "my-app",
+                "version": "1.0.0",
+                "lockfileVersion": 3,
+                "packages": {
+                    "": {
+                        "name": "my-app",
+                        "version": "1.0.0",
+                        "integrity": "sha256-47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU="
+                    }
+                }
+            }"#,
+        )
+        .expect("Failed to write package-lock.json");
+
+        let mut lockfile = create_test_file_info(
+            "project/package-lock.json",
+            DatasourceId::NpmPackageLockJson,
+            None,
+            None,
+            None,
+            vec![],
+        );
+        lockfile.package_data = vec![NpmLockParser::extract_first_package(&lock_path)];
+
+        let mut files = vec![
+            create_test_file_info(
+                "project/package.json",
+                DatasourceId::NpmPackageJson,
+                Some("pkg:npm/my-app@1.0.0"),
+                Some("my-app"),
+                Some("1.0.0"),
+                vec![],
+            ),
+            lockfile,
+        ];
+
+        let result = assemble(&mut files);
+
+        assert_eq!(result.packages.len(), 1);
+        assert_eq!(
+            result.packages[0].sha256.as_deref(),
+            Some("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+        );
+    }
+
     #[test]
     fn test_package_update_merges_fields() {
         let initial_pkg_data = PackageData {
@@ -2597,6 +2891,20 @@ mod tests {
             Some("abc123".to_string()),
             "New sha256 should be filled"
         );
+
+        let conflicting_pkg_data = PackageData {
+            sha256: Some("def456".to_string()),
+            sha1: Some("0123abcd".to_string()),
+            ..update_pkg_data.clone()
+        };
+        package.update(&conflicting_pkg_data, "file3.json".to_string());
+
+        assert_eq!(
+            package.sha256,
+            Some("abc123".to_string()),
+            "Existing sha256 should not be clobbered"
+        );
+        assert_eq!(package.sha1, Some("0123abcd".to_string()));
     }
 
     #[test]
@@ -2733,6 +3041,7 @@ mod tests {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         }];
 
         let result = assemble(&mut files);
@@ -0,0 +1,152 @@
+//! Dependency depth and path annotation for resolved lockfile graphs.
+//!
+//! Lockfiles such as `uv.lock`, `pnpm-lock.yaml` or `yarn.lock` record the
+//! edges between resolved packages in each dependency's
+//! `resolved_package.dependencies`. When those edges are present, every
+//! top-level dependency of the same datafile is annotated with its distance
+//! from the owning package (`depth`, 1 for direct dependencies) and one
+//! example `dependency_path` of purls leading to it.
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::models::{Package, TopLevelDependency};
+
+/// Annotate dependencies with `depth` and `dependency_path` where a resolved
+/// graph is available.
+///
+/// Dependencies are grouped by datafile and owning package. Groups without any
+/// resolved edges are left untouched, as are dependencies unreachable from a
+/// direct dependency.
+pub fn annotate_dependency_graph(packages: &[Package], dependencies: &mut [TopLevelDependency]) {
+    let package_purls: HashMap<&str, &str> = packages
+        .iter()
+        .filter_map(|package| Some((package.package_uid.as_str(), package.purl.as_deref()?)))
+        .collect();
+
+    let mut groups: HashMap<(String, Option<String>), Vec<usize>> = HashMap::new();
+    for (index, dependency) in dependencies.iter().enumerate() {
+        groups
+            .entry((
+                dependency.datafile_path.clone(),
+                dependency.for_package_uid.clone(),
+            ))
+            .or_default()
+            .push(index);
+    }
+
+    for ((_, for_package_uid), indices) in groups {
+        let root_purl = for_package_uid
+            .as_deref()
+            .and_then(|uid| package_purls.get(uid).copied());
+        annotate_group(dependencies, &indices, root_purl);
+    }
+}
+
+fn annotate_group(
+    dependencies: &mut [TopLevelDependency],
+    indices: &[usize],
+    root_purl: Option<&str>,
+) {
+    let has_edges = indices.iter().any(|&index| {
+        dependencies[index]
+            .resolved_package
+            .as_ref()
+            .is_some_and(|resolved| !resolved.dependencies.is_empty())
+    });
+    if !has_edges {
+        return;
+    }
+
+    let mut by_purl: HashMap<&str, usize> = HashMap::new();
+    let mut by_base_purl: HashMap<&str, Vec<usize>> = HashMap::new();
+    for &index in indices {
+        if let Some(purl) = dependencies[index].purl.as_deref() {
+            by_purl.entry(purl).or_insert(index);
+            by_base_purl.entry(base_purl(purl)).or_default().push(index);
+        }
+    }
+
+    let lookup = |purl: &str| -> Option<usize> {
+        by_purl.get(purl).copied().or_else(|| {
+            match by_base_purl.get(base_purl(purl)).map(Vec::as_slice) {
+                Some([index]) => Some(*index),
+                _ => None,
+            }
+        })
+    };
+
+    let children: HashMap<usize, Vec<usize>> = indices
+        .iter()
+        .map(|&index| {
+            let targets = dependencies[index]
+                .resolved_package
+                .as_ref()
+                .map(|resolved| {
+                    resolved
+                        .dependencies
+                        .iter()
+                        .filter_map(|edge| edge.purl.as_deref())
+                        .filter_map(&lookup)
+                        .filter(|&target| target != index)
+                        .collect()
+                })
+                .unwrap_or_default();
+            (index, targets)
+        })
+        .collect();
+
+    let mut parents: HashMap<usize, Option<usize>> = HashMap::new();
+    let mut depths: HashMap<usize, usize> = HashMap::new();
+    let mut queue = VecDeque::new();
+    for &index in indices {
+        if dependencies[index].is_direct == Some(true) && dependencies[index].purl.is_some() {
+            parents.insert(index, None);
+            depths.insert(index, 1);
+            queue.push_back(index);
+        }
+    }
+
+    while let Some(index) = queue.pop_front() {
+        let depth = depths[&index];
+        for &child in &children[&index] {
+            if depths.contains_key(&child) {
+                continue;
+            }
+            depths.insert(child, depth + 1);
+            parents.insert(child, Some(index));
+            queue.push_back(child);
+        }
+    }
+
+    for (&index, &depth) in &depths {
+        let mut path = Vec::with_capacity(depth + 1);
+        let mut current = Some(index);
+        while let Some(node) = current {
+            if let Some(purl) = dependencies[node].purl.clone() {
+                path.push(purl);
+            }
+            current = parents.get(&node).copied().flatten();
+        }
+        if let Some(root_purl) = root_purl {
+            path.push(root_purl.to_string());
+        }
+        path.reverse();
+
+        dependencies[index].depth = Some(depth);
+        dependencies[index].dependency_path = path;
+    }
+}
+
+/// Strip the version, qualifiers and subpath from a purl.
+fn base_purl(purl: &str) -> &str {
+    let purl = purl.split(['?', '#']).next().unwrap_or(purl);
+    let name_start = purl.rfind('/').map_or(0, |index| index + 1);
+    match purl[name_start..].find('@') {
+        Some(at) => &purl[..name_start + at],
+        None => purl,
+    }
+}
+
+#[cfg(test)]
+#[path = "dependency_graph_test.rs"]
+mod tests;
@@ -0,0 +1,109 @@
+use super::*;
+use crate::models::DatasourceId;
+use crate::parsers::{PackageParser, UvLockParser};
+
+const CHAIN_UV_LOCK: &str = r#"version = 1
+requires-python = ">=3.12"
+
+[[package]]
+name = "app"
+version = "1.0.0"
+source = { editable = "." }
+dependencies = [
+    { name = "alpha" },
+]
+
+[[package]]
+name = "alpha"
+version = "1.1.0"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "beta" },
+]
+
+[[package]]
+name = "beta"
+version = "2.0.0"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "gamma" },
+]
+
+[[package]]
+name = "gamma"
+version = "3.0.0"
+source = { registry = "https://pypi.org/simple" }
+"#;
+
+fn dependency<'a>(dependencies: &'a [TopLevelDependency], purl: &str) -> &'a TopLevelDependency {
+    dependencies
+        .iter()
+        .find(|dependency| dependency.purl.as_deref() == Some(purl))
+        .unwrap_or_else(|| panic!("missing dependency {purl}"))
+}
+
+#[test]
+fn test_annotate_dependency_graph_reports_depth_and_path_for_chain() {
+    let temp_dir = tempfile::tempdir().expect("create temp dir");
+    let lock_path = temp_dir.path().join("uv.lock");
+    std::fs::write(&lock_path, CHAIN_UV_LOCK).expect("write uv.lock");
+
+    let package_data = UvLockParser::extract_first_package(&lock_path);
+    let package = Package::from_package_data(&package_data, "uv.lock".to_string());
+    let mut dependencies: Vec<TopLevelDependency> = package_data
+        .dependencies
+        .iter()
+        .map(|dependency| {
+            TopLevelDependency::from_dependency(
+                dependency,
+                "uv.lock".to_string(),
+                DatasourceId::PypiUvLock,
+                Some(package.package_uid.clone()),
+            )
+        })
+        .collect();
+
+    annotate_dependency_graph(std::slice::from_ref(&package), &mut dependencies);
+
+    let alpha = dependency(&dependencies, "pkg:pypi/alpha@1.1.0");
+    assert_eq!(alpha.depth, Some(1));
+
+    let gamma = dependency(&dependencies, "pkg:pypi/gamma@3.0.0");
+    assert_eq!(gamma.depth, Some(3));
+    assert_eq!(
+        gamma.dependency_path,
+        vec![
+            "pkg:pypi/app@1.0.0".to_string(),
+            "pkg:pypi/alpha@1.1.0".to_string(),
+            "pkg:pypi/beta@2.0.0".to_string(),
+            "pkg:pypi/gamma@3.0.0".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_annotate_dependency_graph_skips_groups_without_edges() {
+    let mut dependencies = vec![TopLevelDependency {
+        purl: Some("pkg:cargo/serde@1.0.0".to_string()),
+        extracted_requirement: None,
+        scope: None,
+        is_runtime: Some(true),
+        is_optional: None,
+        is_pinned: None,
+        is_direct: Some(true),
+        resolved_package: None,
+        extra_data: None,
+        dependency_uid: "pkg:cargo/serde@1.0.0?uuid=dep-uuid".to_string(),
+        for_package_uid: None,
+        datafile_path: "Cargo.lock".to_string(),
+        datasource_id: DatasourceId::CargoLock,
+        namespace: None,
+        depth: None,
+        dependency_path: Vec::new(),
+    }];
+
+    annotate_dependency_graph(&[], &mut dependencies);
+
+    assert_eq!(dependencies[0].depth, None);
+    assert!(dependencies[0].dependency_path.is_empty());
+}
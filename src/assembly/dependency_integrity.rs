@@ -0,0 +1,136 @@
+//! Referential integrity of `for_package_uid` after assembly.
+//!
+//! Every top-level dependency that names an owning package must point at a
+//! package in the assembly result. Passes that replace or drop packages
+//! (nested merges, workspace merges) are expected to re-point or remove the
+//! dependencies they orphan; a dangling reference left behind is an assembly
+//! bug.
+
+use std::collections::HashSet;
+
+use log::warn;
+
+use crate::models::{Package, TopLevelDependency};
+
+/// Indices of dependencies whose `for_package_uid` names no package.
+pub(super) fn dangling_dependency_indices(
+    packages: &[Package],
+    dependencies: &[TopLevelDependency],
+) -> Vec<usize> {
+    let package_uids: HashSet<&str> = packages
+        .iter()
+        .map(|package| package.package_uid.as_str())
+        .collect();
+
+    dependencies
+        .iter()
+        .enumerate()
+        .filter(|(_, dependency)| {
+            dependency
+                .for_package_uid
+                .as_deref()
+                .is_some_and(|uid| !package_uids.contains(uid))
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Log and detach dangling `for_package_uid` references.
+///
+/// The stale uid is kept in `extra_data["dangling_for_package_uid"]` so the
+/// problem stays visible in the output. Returns the number of dependencies
+/// that were detached.
+pub(super) fn detach_dangling_dependencies(
+    packages: &[Package],
+    dependencies: &mut [TopLevelDependency],
+) -> usize {
+    let dangling = dangling_dependency_indices(packages, dependencies);
+    for &index in &dangling {
+        let dependency = &mut dependencies[index];
+        let Some(stale_uid) = dependency.for_package_uid.take() else {
+            continue;
+        };
+        warn!(
+            "Assembly left dependency {} from {} pointing at missing package {}",
+            dependency.purl.as_deref().unwrap_or("<no purl>"),
+            dependency.datafile_path,
+            stale_uid
+        );
+        dependency
+            .extra_data
+            .get_or_insert_with(Default::default)
+            .insert(
+                "dangling_for_package_uid".to_string(),
+                serde_json::Value::String(stale_uid),
+            );
+    }
+    dangling.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{DatasourceId, PackageData};
+
+    fn dependency(purl: &str, for_package_uid: Option<&str>) -> TopLevelDependency {
+        TopLevelDependency {
+            purl: Some(purl.to_string()),
+            extracted_requirement: None,
+            scope: None,
+            is_runtime: None,
+            is_optional: None,
+            is_pinned: None,
+            is_direct: None,
+            resolved_package: None,
+            extra_data: None,
+            dependency_uid: format!("{purl}?uuid=test"),
+            for_package_uid: for_package_uid.map(str::to_string),
+            datafile_path: "project/pom.xml".to_string(),
+            datasource_id: DatasourceId::MavenPom,
+            namespace: None,
+            depth: None,
+            dependency_path: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_detach_dangling_dependencies_keeps_stale_uid_in_extra_data() {
+        let package = Package::from_package_data(
+            &PackageData {
+                purl: Some("pkg:maven/demo/app@1.0".to_string()),
+                datasource_id: Some(DatasourceId::MavenPom),
+                ..Default::default()
+            },
+            "project/pom.xml".to_string(),
+        );
+        let mut dependencies = vec![
+            dependency("pkg:maven/demo/kept@1.0", Some(&package.package_uid)),
+            dependency(
+                "pkg:maven/demo/orphan@1.0",
+                Some("pkg:maven/demo/app@1.0?uuid=gone"),
+            ),
+            dependency("pkg:maven/demo/unowned@1.0", None),
+        ];
+
+        let detached =
+            detach_dangling_dependencies(std::slice::from_ref(&package), &mut dependencies);
+
+        assert_eq!(detached, 1);
+        assert!(
+            dangling_dependency_indices(std::slice::from_ref(&package), &dependencies).is_empty()
+        );
+        assert_eq!(
+            dependencies[0].for_package_uid.as_deref(),
+            Some(package.package_uid.as_str())
+        );
+        assert_eq!(dependencies[1].for_package_uid, None);
+        assert_eq!(
+            dependencies[1]
+                .extra_data
+                .as_ref()
+                .and_then(|extra| extra.get("dangling_for_package_uid")),
+            Some(&serde_json::json!("pkg:maven/demo/app@1.0?uuid=gone"))
+        );
+        assert!(dependencies[2].extra_data.is_none());
+    }
+}
@@ -0,0 +1,117 @@
+//! Runtime vs development dependency rollup per assembled package.
+//!
+//! SBOM consumers often drop test and dev dependencies. This pass counts the
+//! top-level dependencies owned by each package by their `is_runtime` flag so
+//! that filtering can happen without walking the dependency list.
+
+use std::collections::HashMap;
+
+use crate::models::{DependencyRollup, Package, TopLevelDependency};
+
+/// Attach a [`DependencyRollup`] to every package, counting the dependencies
+/// whose `for_package_uid` points at it.
+pub fn annotate_dependency_rollup(packages: &mut [Package], dependencies: &[TopLevelDependency]) {
+    let mut rollups: HashMap<&str, DependencyRollup> = HashMap::new();
+    for dependency in dependencies {
+        let Some(for_package_uid) = dependency.for_package_uid.as_deref() else {
+            continue;
+        };
+        let rollup = rollups.entry(for_package_uid).or_default();
+        match dependency.is_runtime {
+            Some(true) => rollup.runtime += 1,
+            Some(false) => rollup.development += 1,
+            None => rollup.unclassified += 1,
+        }
+    }
+
+    for package in packages {
+        let mut rollup = rollups
+            .remove(package.package_uid.as_str())
+            .unwrap_or_default();
+        rollup.has_only_dev_dependencies =
+            rollup.development > 0 && rollup.runtime == 0 && rollup.unclassified == 0;
+        package.dependency_rollup = Some(rollup);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::assembly::assemble;
+    use crate::models::{DatasourceId, Dependency, FileInfo, FileType, PackageData, PackageType};
+
+    fn npm_dependency(name: &str, scope: &str, is_runtime: bool) -> Dependency {
+        Dependency {
+            purl: Some(format!("pkg:npm/{name}")),
+            extracted_requirement: Some("^1.0.0".to_string()),
+            scope: Some(scope.to_string()),
+            is_runtime: Some(is_runtime),
+            is_optional: Some(false),
+            is_pinned: Some(false),
+            is_direct: Some(true),
+            resolved_package: None,
+            extra_data: None,
+        }
+    }
+
+    fn package_json(dependencies: Vec<Dependency>) -> FileInfo {
+        FileInfo {
+            name: "package.json".to_string(),
+            base_name: "package".to_string(),
+            extension: ".json".to_string(),
+            path: "project/package.json".to_string(),
+            file_type: FileType::File,
+            package_data: vec![PackageData {
+                package_type: Some(PackageType::Npm),
+                name: Some("demo".to_string()),
+                version: Some("1.0.0".to_string()),
+                purl: Some("pkg:npm/demo@1.0.0".to_string()),
+                datasource_id: Some(DatasourceId::NpmPackageJson),
+                dependencies,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_rollup_counts_runtime_and_dev_dependencies() {
+        let mut files = vec![package_json(vec![
+            npm_dependency("express", "dependencies", true),
+            npm_dependency("lodash", "dependencies", true),
+            npm_dependency("jest", "devDependencies", false),
+        ])];
+        let mut result = assemble(&mut files);
+
+        annotate_dependency_rollup(&mut result.packages, &result.dependencies);
+
+        assert_eq!(
+            result.packages[0].dependency_rollup,
+            Some(DependencyRollup {
+                runtime: 2,
+                development: 1,
+                unclassified: 0,
+                has_only_dev_dependencies: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_rollup_flags_packages_with_only_dev_dependencies() {
+        let mut files = vec![package_json(vec![
+            npm_dependency("jest", "devDependencies", false),
+            npm_dependency("eslint", "devDependencies", false),
+        ])];
+        let mut result = assemble(&mut files);
+
+        annotate_dependency_rollup(&mut result.packages, &result.dependencies);
+
+        let rollup = result.packages[0]
+            .dependency_rollup
+            .as_ref()
+            .expect("rollup should be attached");
+        assert_eq!(rollup.development, 2);
+        assert!(rollup.has_only_dev_dependencies);
+    }
+}
@@ -0,0 +1,203 @@
+//! Reproducible `package_uid` and `dependency_uid` values.
+//!
+//! Assembly gives every package and dependency a random `uuid=` qualifier so
+//! uids stay unique even when two packages share a purl. That makes output
+//! differ between runs of the same scan. This pass replaces those UUIDs with
+//! ones derived from a SHA-256 of the purl and the datafiles the item came
+//! from, then rewrites every `for_packages` and `for_package_uid` reference to
+//! match.
+
+use std::collections::HashMap;
+
+use sha2::{Digest, Sha256};
+use uuid::{Builder, Uuid};
+
+use crate::models::FileInfo;
+
+use super::AssemblyResult;
+
+/// Replace the random uids in `result` with deterministic ones and update the
+/// references held by `files`.
+///
+/// Items that derive the same seed get an occurrence index mixed in, so uids
+/// stay unique as long as assembly emits them in a stable order.
+pub fn make_uids_deterministic(result: &mut AssemblyResult, files: &mut [FileInfo]) {
+    let mut seeds = SeedCounter::default();
+    let mut renamed: HashMap<String, String> = HashMap::new();
+
+    for package in &mut result.packages {
+        let Some(purl) = package.purl.as_deref() else {
+            continue;
+        };
+        if package.package_uid.is_empty() {
+            continue;
+        }
+
+        let mut datafile_paths: Vec<&str> =
+            package.datafile_paths.iter().map(String::as_str).collect();
+        datafile_paths.sort_unstable();
+        let mut parts = vec!["package", purl];
+        parts.extend(datafile_paths);
+
+        let package_uid = uid_with_uuid(purl, seeds.uuid_for(&parts));
+        renamed.insert(
+            std::mem::replace(&mut package.package_uid, package_uid.clone()),
+            package_uid,
+        );
+    }
+
+    for dependency in &mut result.dependencies {
+        if let Some(for_package_uid) = dependency.for_package_uid.as_mut()
+            && let Some(package_uid) = renamed.get(for_package_uid)
+        {
+            *for_package_uid = package_uid.clone();
+        }
+
+        let Some(purl) = dependency.purl.as_deref() else {
+            continue;
+        };
+        if dependency.dependency_uid.is_empty() {
+            continue;
+        }
+
+        let parts = [
+            "dependency",
+            purl,
+            dependency.datafile_path.as_str(),
+            dependency.for_package_uid.as_deref().unwrap_or_default(),
+        ];
+        dependency.dependency_uid = uid_with_uuid(purl, seeds.uuid_for(&parts));
+    }
+
+    for file in files {
+        for package_uid in &mut file.for_packages {
+            if let Some(renamed_uid) = renamed.get(package_uid) {
+                *package_uid = renamed_uid.clone();
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct SeedCounter {
+    seen: HashMap<Vec<u8>, usize>,
+}
+
+impl SeedCounter {
+    fn uuid_for(&mut self, parts: &[&str]) -> Uuid {
+        let mut seed = Vec::new();
+        for part in parts {
+            seed.extend_from_slice(part.as_bytes());
+            seed.push(0);
+        }
+
+        let occurrence = self.seen.entry(seed.clone()).or_insert(0);
+        if *occurrence > 0 {
+            seed.extend_from_slice(occurrence.to_string().as_bytes());
+        }
+        *occurrence += 1;
+
+        let digest = Sha256::digest(&seed);
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&digest[..16]);
+        Builder::from_custom_bytes(bytes).into_uuid()
+    }
+}
+
+fn uid_with_uuid(purl: &str, uuid: Uuid) -> String {
+    if purl.contains('?') {
+        format!("{}&uuid={}", purl, uuid)
+    } else {
+        format!("{}?uuid={}", purl, uuid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::assembly::assemble;
+    use crate::models::{DatasourceId, Dependency, FileType, PackageData, PackageType};
+
+    fn manifest_file(path: &str) -> FileInfo {
+        let package_data = PackageData {
+            package_type: Some(PackageType::Npm),
+            name: Some("demo".to_string()),
+            version: Some("1.0.0".to_string()),
+            purl: Some("pkg:npm/demo@1.0.0".to_string()),
+            datasource_id: Some(DatasourceId::NpmPackageJson),
+            dependencies: vec![Dependency {
+                purl: Some("pkg:npm/left-pad".to_string()),
+                extracted_requirement: Some("^1.3.0".to_string()),
+                scope: Some("dependencies".to_string()),
+                is_runtime: Some(true),
+                is_optional: Some(false),
+                is_pinned: Some(false),
+                is_direct: Some(true),
+                resolved_package: None,
+                extra_data: None,
+            }],
+            ..Default::default()
+        };
+
+        FileInfo {
+            name: "package.json".to_string(),
+            base_name: "package".to_string(),
+            extension: ".json".to_string(),
+            path: path.to_string(),
+            file_type: FileType::File,
+            package_data: vec![package_data],
+            ..Default::default()
+        }
+    }
+
+    fn deterministic_run() -> (AssemblyResult, Vec<FileInfo>) {
+        let mut files = vec![manifest_file("project/package.json")];
+        let mut result = assemble(&mut files);
+        make_uids_deterministic(&mut result, &mut files);
+        (result, files)
+    }
+
+    #[test]
+    fn test_deterministic_runs_produce_identical_uids() {
+        let (first, first_files) = deterministic_run();
+        let (second, second_files) = deterministic_run();
+
+        assert_eq!(first.packages.len(), 1);
+        assert_eq!(
+            first.packages[0].package_uid,
+            second.packages[0].package_uid
+        );
+        assert!(
+            first.packages[0]
+                .package_uid
+                .starts_with("pkg:npm/demo@1.0.0?uuid=")
+        );
+        assert_eq!(
+            first.dependencies[0].dependency_uid,
+            second.dependencies[0].dependency_uid
+        );
+        assert_eq!(first_files[0].for_packages, second_files[0].for_packages);
+    }
+
+    #[test]
+    fn test_references_follow_rewritten_package_uid() {
+        let (result, files) = deterministic_run();
+        let package_uid = &result.packages[0].package_uid;
+
+        assert_eq!(files[0].for_packages, vec![package_uid.clone()]);
+        assert_eq!(
+            result.dependencies[0].for_package_uid.as_ref(),
+            Some(package_uid)
+        );
+    }
+
+    #[test]
+    fn test_duplicate_seeds_get_distinct_uuids() {
+        let mut seeds = SeedCounter::default();
+        let first = seeds.uuid_for(&["package", "pkg:npm/demo@1.0.0"]);
+        let second = seeds.uuid_for(&["package", "pkg:npm/demo@1.0.0"]);
+
+        assert_ne!(first, second);
+    }
+}
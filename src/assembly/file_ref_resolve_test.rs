@@ -134,6 +134,7 @@ fn test_resolve_basic_alpine() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "libc.so".to_string(),
@@ -171,6 +172,7 @@ fn test_resolve_basic_alpine() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "ldconfig".to_string(),
@@ -208,6 +210,7 @@ fn test_resolve_basic_alpine() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
     ];
 
@@ -254,6 +257,7 @@ fn test_resolve_basic_alpine() {
         package_uid: "pkg:alpine/musl@1.2.3?uuid=test-uuid".to_string(),
         datafile_paths: vec!["lib/apk/db/installed".to_string()],
         datasource_ids: vec![DatasourceId::AlpineInstalledDb],
+        dependency_rollup: None,
     }];
 
     let mut dependencies = vec![];
@@ -335,6 +339,7 @@ fn test_resolve_missing_refs() {
         is_generated: None,
         facets: vec![],
         tallies: None,
+        ..Default::default()
     }];
 
     let mut packages = vec![Package {
@@ -380,6 +385,7 @@ fn test_resolve_missing_refs() {
         package_uid: "pkg:alpine/test@1.0?uuid=test-uuid".to_string(),
         datafile_paths: vec!["lib/apk/db/installed".to_string()],
         datasource_ids: vec![DatasourceId::AlpineInstalledDb],
+        dependency_rollup: None,
     }];
 
     let mut dependencies = vec![];
@@ -443,6 +449,7 @@ fn test_resolve_rpm_namespace() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "os-release".to_string(),
@@ -485,6 +492,7 @@ fn test_resolve_rpm_namespace() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
     ];
 
@@ -531,6 +539,7 @@ fn test_resolve_rpm_namespace() {
         package_uid: "pkg:rpm/bash@5.0?uuid=test-uuid".to_string(),
         datafile_paths: vec!["rootfs/var/lib/rpm/Packages".to_string()],
         datasource_ids: vec![DatasourceId::RpmInstalledDatabaseBdb],
+        dependency_rollup: None,
     }];
 
     let mut dependencies = vec![TopLevelDependency {
@@ -548,6 +557,8 @@ fn test_resolve_rpm_namespace() {
         datafile_path: "rootfs/var/lib/rpm/Packages".to_string(),
         datasource_id: DatasourceId::RpmInstalledDatabaseBdb,
         namespace: None,
+        depth: None,
+        dependency_path: Vec::new(),
     }];
 
     resolve_file_references(&mut files, &mut packages, &mut dependencies);
@@ -609,6 +620,7 @@ fn test_merge_rpm_yumdb_metadata() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "from_repo".to_string(),
@@ -646,6 +658,7 @@ fn test_merge_rpm_yumdb_metadata() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
     ];
 
@@ -693,6 +706,7 @@ fn test_merge_rpm_yumdb_metadata() {
             package_uid: "pkg:rpm/bash@5.0-1.el8?uuid=rpm-uuid".to_string(),
             datafile_paths: vec!["rootfs/var/lib/rpm/Packages".to_string()],
             datasource_ids: vec![DatasourceId::RpmInstalledDatabaseBdb],
+            dependency_rollup: None,
         },
         Package {
             package_type: Some(PackageType::Rpm),
@@ -752,6 +766,7 @@ fn test_merge_rpm_yumdb_metadata() {
                 "rootfs/var/lib/yum/yumdb/p/abc123-bash-5.0-1.el8.x86_64/from_repo".to_string(),
             ],
             datasource_ids: vec![DatasourceId::RpmYumdb],
+            dependency_rollup: None,
         },
     ];
 
@@ -832,6 +847,7 @@ fn test_strip_leading_slash() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "test.so".to_string(),
@@ -869,6 +885,7 @@ fn test_strip_leading_slash() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
     ];
 
@@ -915,6 +932,7 @@ fn test_strip_leading_slash() {
         package_uid: "pkg:alpine/test@1.0?uuid=test-uuid".to_string(),
         datafile_paths: vec!["lib/apk/db/installed".to_string()],
         datasource_ids: vec![DatasourceId::AlpineInstalledDb],
+        dependency_rollup: None,
     }];
 
     let mut dependencies = vec![];
@@ -1002,6 +1020,7 @@ fn test_resolve_python_metadata_file_references() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "__init__.py".to_string(),
@@ -1039,6 +1058,7 @@ fn test_resolve_python_metadata_file_references() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "core.py".to_string(),
@@ -1076,6 +1096,7 @@ fn test_resolve_python_metadata_file_references() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "LICENSE.rst".to_string(),
@@ -1113,6 +1134,7 @@ fn test_resolve_python_metadata_file_references() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
     ];
 
@@ -1161,6 +1183,7 @@ fn test_resolve_python_metadata_file_references() {
             "venv/lib/python3.11/site-packages/click-8.0.4.dist-info/METADATA".to_string(),
         ],
         datasource_ids: vec![DatasourceId::PypiWheelMetadata],
+        dependency_rollup: None,
     }];
 
     let mut dependencies = vec![];
@@ -1230,6 +1253,7 @@ fn test_resolve_python_pkg_info_installed_files_references() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "core.py".to_string(),
@@ -1267,6 +1291,7 @@ fn test_resolve_python_pkg_info_installed_files_references() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
     ];
 
@@ -1315,6 +1340,7 @@ fn test_resolve_python_pkg_info_installed_files_references() {
             "venv/lib/python3.11/site-packages/examplepkg.egg-info/PKG-INFO".to_string(),
         ],
         datasource_ids: vec![DatasourceId::PypiSdistPkginfo],
+        dependency_rollup: None,
     }];
 
     let mut dependencies = vec![];
@@ -1381,6 +1407,7 @@ fn test_resolve_python_metadata_file_references_in_dist_packages() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "core.py".to_string(),
@@ -1418,6 +1445,7 @@ fn test_resolve_python_metadata_file_references_in_dist_packages() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
     ];
 
@@ -1466,6 +1494,7 @@ fn test_resolve_python_metadata_file_references_in_dist_packages() {
             "usr/lib/python3/dist-packages/click-8.0.4.dist-info/METADATA".to_string(),
         ],
         datasource_ids: vec![DatasourceId::PypiWheelMetadata],
+        dependency_rollup: None,
     }];
 
     let mut dependencies = vec![];
@@ -1532,6 +1561,7 @@ fn test_python_metadata_file_references_do_not_assign_outside_packages_dirs() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "core.py".to_string(),
@@ -1569,6 +1599,7 @@ fn test_python_metadata_file_references_do_not_assign_outside_packages_dirs() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
     ];
 
@@ -1615,6 +1646,7 @@ fn test_python_metadata_file_references_do_not_assign_outside_packages_dirs() {
         package_uid: "pkg:pypi/examplepkg@1.0.0?uuid=test-uuid".to_string(),
         datafile_paths: vec!["project/metadata/METADATA".to_string()],
         datasource_ids: vec![DatasourceId::PypiWheelMetadata],
+        dependency_rollup: None,
     }];
 
     let mut dependencies = vec![];
@@ -1678,6 +1710,7 @@ fn test_python_sources_file_references_do_not_escape_project_root() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "outside.py".to_string(),
@@ -1715,6 +1748,7 @@ fn test_python_sources_file_references_do_not_escape_project_root() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
     ];
 
@@ -1761,6 +1795,7 @@ fn test_python_sources_file_references_do_not_escape_project_root() {
         package_uid: "pkg:pypi/PyJPString@0.0.3?uuid=test-uuid".to_string(),
         datafile_paths: vec!["project/PyJPString.egg-info/PKG-INFO".to_string()],
         datasource_ids: vec![DatasourceId::PypiSdistPkginfo],
+        dependency_rollup: None,
     }];
 
     let mut dependencies = vec![];
@@ -1824,6 +1859,7 @@ fn test_resolve_debian_installed_file_references_from_status_db() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "bash.list".to_string(),
@@ -1888,6 +1924,7 @@ fn test_resolve_debian_installed_file_references_from_status_db() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "bash.md5sums".to_string(),
@@ -1941,6 +1978,7 @@ fn test_resolve_debian_installed_file_references_from_status_db() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "bash".to_string(),
@@ -1978,6 +2016,7 @@ fn test_resolve_debian_installed_file_references_from_status_db() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "copyright".to_string(),
@@ -2015,6 +2054,7 @@ fn test_resolve_debian_installed_file_references_from_status_db() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
     ];
 
@@ -2061,6 +2101,7 @@ fn test_resolve_debian_installed_file_references_from_status_db() {
         package_uid: "pkg:deb/debian/bash@5.2-1?arch=amd64&uuid=test-uuid".to_string(),
         datafile_paths: vec!["rootfs/var/lib/dpkg/status".to_string()],
         datasource_ids: vec![DatasourceId::DebianInstalledStatusDb],
+        dependency_rollup: None,
     }];
 
     let mut dependencies = vec![];
@@ -2123,6 +2164,7 @@ fn test_resolve_debian_installed_file_references_matches_ubuntu_package_namespac
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "bash.list".to_string(),
@@ -2176,6 +2218,7 @@ fn test_resolve_debian_installed_file_references_matches_ubuntu_package_namespac
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "bash".to_string(),
@@ -2213,6 +2256,7 @@ fn test_resolve_debian_installed_file_references_matches_ubuntu_package_namespac
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
     ];
 
@@ -2259,6 +2303,7 @@ fn test_resolve_debian_installed_file_references_matches_ubuntu_package_namespac
         package_uid: "pkg:deb/ubuntu/bash@5.2-1ubuntu1?arch=amd64&uuid=test-uuid".to_string(),
         datafile_paths: vec!["rootfs/var/lib/dpkg/status".to_string()],
         datasource_ids: vec![DatasourceId::DebianInstalledStatusDb],
+        dependency_rollup: None,
     }];
 
     let mut dependencies = vec![];
@@ -2318,6 +2363,7 @@ fn test_resolve_debian_installed_file_references_respects_arch_qualifier() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "libc6:amd64.list".to_string(),
@@ -2372,6 +2418,7 @@ fn test_resolve_debian_installed_file_references_respects_arch_qualifier() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "libc6:i386.list".to_string(),
@@ -2426,6 +2473,7 @@ fn test_resolve_debian_installed_file_references_respects_arch_qualifier() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "libc.so.6".to_string(),
@@ -2463,6 +2511,7 @@ fn test_resolve_debian_installed_file_references_respects_arch_qualifier() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
         FileInfo {
             name: "libc.so.6".to_string(),
@@ -2500,6 +2549,7 @@ fn test_resolve_debian_installed_file_references_respects_arch_qualifier() {
             is_generated: None,
             facets: vec![],
             tallies: None,
+            ..Default::default()
         },
     ];
 
@@ -2546,6 +2596,7 @@ fn test_resolve_debian_installed_file_references_respects_arch_qualifier() {
         package_uid: "pkg:deb/debian/libc6@2.36-1?arch=amd64&uuid=test-uuid".to_string(),
         datafile_paths: vec!["rootfs/var/lib/dpkg/status".to_string()],
         datasource_ids: vec![DatasourceId::DebianInstalledStatusDb],
+        dependency_rollup: None,
     }];
 
     let mut dependencies = vec![];
@@ -0,0 +1,81 @@
+//! Go workspace (`go.work`) linking.
+//!
+//! A `go.work` file lists the directories of the modules that make up a
+//! multi-module workspace. Each member's `go.mod` is assembled into its own
+//! package by the per-directory sibling merge, so this pass links them back:
+//! member packages record the `go.work` they belong to, and the workspace's
+//! `use` dependencies record the `package_uid` of the member they resolve to.
+
+use std::path::{Component, Path, PathBuf};
+
+use crate::models::{DatasourceId, FileInfo, Package, TopLevelDependency};
+
+pub fn link_go_workspaces(
+    files: &[FileInfo],
+    packages: &mut [Package],
+    dependencies: &mut [TopLevelDependency],
+) {
+    for file in files {
+        let Some(use_paths) = file
+            .package_data
+            .iter()
+            .filter(|pkg_data| pkg_data.datasource_id == Some(DatasourceId::GoWork))
+            .find_map(|pkg_data| pkg_data.extra_data.as_ref()?.get("use_paths")?.as_array())
+        else {
+            continue;
+        };
+        let work_dir = Path::new(&file.path).parent().unwrap_or(Path::new(""));
+
+        for use_path in use_paths.iter().filter_map(|value| value.as_str()) {
+            let member_go_mod = normalize_lexically(&work_dir.join(use_path).join("go.mod"));
+            let Some(member) = packages.iter_mut().find(|package| {
+                package.datasource_ids.contains(&DatasourceId::GoMod)
+                    && package
+                        .datafile_paths
+                        .iter()
+                        .any(|path| Path::new(path) == member_go_mod)
+                    && !package.datafile_paths.contains(&file.path)
+            }) else {
+                continue;
+            };
+
+            member
+                .extra_data
+                .get_or_insert_with(Default::default)
+                .insert(
+                    "go_workspace".to_string(),
+                    serde_json::Value::String(file.path.clone()),
+                );
+
+            for dependency in dependencies.iter_mut().filter(|dependency| {
+                dependency.datafile_path == file.path
+                    && dependency.datasource_id == DatasourceId::GoWork
+                    && dependency.extracted_requirement.as_deref() == Some(use_path)
+            }) {
+                dependency
+                    .extra_data
+                    .get_or_insert_with(Default::default)
+                    .insert(
+                        "workspace_package_uid".to_string(),
+                        serde_json::Value::String(member.package_uid.clone()),
+                    );
+            }
+        }
+    }
+}
+
+/// Resolve `.` and `..` components without touching the filesystem, since
+/// assembly works on scanned paths that may be relative to the scan root.
+fn normalize_lexically(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
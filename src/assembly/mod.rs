@@ -7,7 +7,12 @@ mod cargo_resource_assign;
 mod cargo_workspace_merge;
 mod composer_resource_assign;
 mod conda_rootfs_merge;
+mod dependency_graph;
+mod dependency_integrity;
+mod dependency_rollup;
+mod deterministic_uids;
 pub mod file_ref_resolve;
+mod go_workspace_link;
 mod hackage_merge;
 mod nested_merge;
 mod npm_resource_assign;
@@ -17,14 +22,19 @@ mod python_requirements_assign;
 mod ruby_resource_assign;
 mod sibling_merge;
 mod swift_merge;
+mod version_constraints;
 
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
-use std::sync::LazyLock;
+use std::sync::{LazyLock, RwLock};
 
 use crate::models::{DatasourceId, FileInfo, Package, TopLevelDependency};
 
 pub use assemblers::ASSEMBLERS;
+pub use dependency_graph::annotate_dependency_graph;
+pub use dependency_rollup::annotate_dependency_rollup;
+pub use deterministic_uids::make_uids_deterministic;
+pub use version_constraints::annotate_version_constraints;
 
 type DirectoryMergeOutput = (Option<Package>, Vec<TopLevelDependency>, Vec<usize>);
 
@@ -57,6 +67,55 @@ static ASSEMBLER_CONFIG_LOOKUP: LazyLock<HashMap<DatasourceId, &'static Assemble
         lookup
     });
 
+/// Assemblers registered at runtime alongside custom parsers.
+static REGISTERED_ASSEMBLERS: RwLock<Vec<&'static AssemblerConfig>> = RwLock::new(Vec::new());
+
+/// Register an assembler for datasources that no built-in assembler handles.
+///
+/// Registering the same datasource list again is a no-op, so several parsers
+/// of one ecosystem can share an assembler.
+pub(crate) fn register_assembler(config: AssemblerConfig) -> Result<(), String> {
+    if config.datasource_ids.is_empty() {
+        return Err("Assembler must handle at least one datasource".to_string());
+    }
+    if let Some(dsid) = config
+        .datasource_ids
+        .iter()
+        .find(|dsid| ASSEMBLER_LOOKUP.contains_key(dsid))
+    {
+        return Err(format!(
+            "Datasource {dsid} is already handled by a built-in assembler"
+        ));
+    }
+
+    let mut registered = REGISTERED_ASSEMBLERS
+        .write()
+        .map_err(|_| "Assembler registry is poisoned".to_string())?;
+    if let Some(existing) = registered.iter().find(|existing| {
+        existing
+            .datasource_ids
+            .iter()
+            .any(|dsid| config.datasource_ids.contains(dsid))
+    }) {
+        if existing.datasource_ids == config.datasource_ids {
+            return Ok(());
+        }
+        return Err(format!(
+            "Datasources {:?} overlap an assembler registered for {:?}",
+            config.datasource_ids, existing.datasource_ids
+        ));
+    }
+    registered.push(Box::leak(Box::new(config)));
+    Ok(())
+}
+
+fn registered_assemblers() -> Vec<&'static AssemblerConfig> {
+    REGISTERED_ASSEMBLERS
+        .read()
+        .map(|registered| registered.clone())
+        .unwrap_or_default()
+}
+
 /// Result of the assembly phase: top-level packages and dependencies,
 /// plus updated file-to-package associations.
 #[derive(serde::Serialize)]
@@ -81,14 +140,94 @@ pub struct AssemblerConfig {
     pub mode: AssemblyMode,
 }
 
+/// Additional sibling filename patterns registered at runtime.
+///
+/// Lets proprietary build systems whose manifests or lockfiles use custom
+/// names take part in sibling merging without editing [`ASSEMBLERS`]. Each
+/// pattern is attached to the assembler handling a given datasource, so the
+/// matched files must still carry package data from that datasource.
+#[derive(Debug, Clone, Default)]
+pub struct SiblingPatterns {
+    manifests: HashMap<DatasourceId, Vec<String>>,
+    lockfiles: HashMap<DatasourceId, Vec<String>>,
+}
+
+impl SiblingPatterns {
+    /// Register a manifest filename pattern, tried before the built-in patterns.
+    pub fn add_manifest_pattern(
+        &mut self,
+        datasource_id: DatasourceId,
+        pattern: &str,
+    ) -> Result<(), String> {
+        let key = Self::assembler_key(datasource_id)?;
+        let patterns = self.manifests.entry(key).or_default();
+        if !patterns.iter().any(|existing| existing == pattern) {
+            patterns.push(pattern.to_string());
+        }
+        Ok(())
+    }
+
+    /// Register a lockfile filename pattern, tried after the built-in patterns.
+    pub fn add_lockfile_pattern(
+        &mut self,
+        datasource_id: DatasourceId,
+        pattern: &str,
+    ) -> Result<(), String> {
+        let key = Self::assembler_key(datasource_id)?;
+        let patterns = self.lockfiles.entry(key).or_default();
+        if !patterns.iter().any(|existing| existing == pattern) {
+            patterns.push(pattern.to_string());
+        }
+        Ok(())
+    }
+
+    fn assembler_key(datasource_id: DatasourceId) -> Result<DatasourceId, String> {
+        let key = ASSEMBLER_LOOKUP
+            .get(&datasource_id)
+            .copied()
+            .ok_or_else(|| format!("No assembler handles datasource {datasource_id}"))?;
+        match ASSEMBLER_CONFIG_LOOKUP.get(&key).map(|config| config.mode) {
+            Some(AssemblyMode::SiblingMerge) => Ok(key),
+            _ => Err(format!(
+                "Assembler for datasource {datasource_id} does not merge sibling files"
+            )),
+        }
+    }
+
+    fn for_assembler(&self, key: DatasourceId) -> (&[String], &[String]) {
+        (
+            self.manifests.get(&key).map_or(&[], Vec::as_slice),
+            self.lockfiles.get(&key).map_or(&[], Vec::as_slice),
+        )
+    }
+}
+
 /// Run the assembly phase over all scanned files.
 ///
 /// Groups files by parent directory, finds related manifests/lockfiles,
 /// merges them into top-level `Package` objects, and hoists dependencies.
 /// Updates each `FileInfo.for_packages` with the UIDs of packages it belongs to.
 pub fn assemble(files: &mut [FileInfo]) -> AssemblyResult {
-    let assembler_lookup = &*ASSEMBLER_LOOKUP;
-    let assembler_config_lookup = &*ASSEMBLER_CONFIG_LOOKUP;
+    assemble_with_patterns(files, &SiblingPatterns::default())
+}
+
+/// Like [`assemble`], with additional runtime sibling filename patterns.
+pub fn assemble_with_patterns(
+    files: &mut [FileInfo],
+    sibling_patterns: &SiblingPatterns,
+) -> AssemblyResult {
+    let registered_assemblers = registered_assemblers();
+    let assembler_for = |dsid: DatasourceId| -> Option<(DatasourceId, &'static AssemblerConfig)> {
+        if let Some(&key) = ASSEMBLER_LOOKUP.get(&dsid) {
+            return ASSEMBLER_CONFIG_LOOKUP
+                .get(&key)
+                .map(|&config| (key, config));
+        }
+        registered_assemblers
+            .iter()
+            .find(|config| config.datasource_ids.contains(&dsid))
+            .map(|&config| (config.datasource_ids[0], config))
+    };
     let mut packages = Vec::new();
     let mut dependencies = Vec::new();
 
@@ -100,7 +239,7 @@ pub fn assemble(files: &mut [FileInfo]) -> AssemblyResult {
         for &idx in file_indices {
             for pkg_data in &files[idx].package_data {
                 if let Some(dsid) = pkg_data.datasource_id
-                    && let Some(&config_key) = assembler_lookup.get(&dsid)
+                    && let Some((config_key, _)) = assembler_for(dsid)
                 {
                     groups.insert(config_key);
                 }
@@ -108,10 +247,7 @@ pub fn assemble(files: &mut [FileInfo]) -> AssemblyResult {
         }
 
         for &config_key in &groups {
-            let config = assembler_config_lookup
-                .get(&config_key)
-                .copied()
-                .expect("assembler config must exist");
+            let (_, config) = assembler_for(config_key).expect("assembler config must exist");
 
             if let Some(special_merger) = assemblers::special_directory_merger_for(config_key) {
                 let results = special_merger.run(files, file_indices);
@@ -121,9 +257,17 @@ pub fn assemble(files: &mut [FileInfo]) -> AssemblyResult {
 
             match config.mode {
                 AssemblyMode::SiblingMerge => {
-                    let results = sibling_merge::assemble_siblings(config, files, file_indices)
-                        .into_iter()
-                        .collect();
+                    let (extra_manifests, extra_lockfiles) =
+                        sibling_patterns.for_assembler(config_key);
+                    let results = sibling_merge::assemble_siblings(
+                        config,
+                        extra_manifests,
+                        extra_lockfiles,
+                        files,
+                        file_indices,
+                    )
+                    .into_iter()
+                    .collect();
                     apply_directory_merge_results(files, &mut packages, &mut dependencies, results);
                 }
                 AssemblyMode::OnePerPackageData => {
@@ -137,7 +281,10 @@ pub fn assemble(files: &mut [FileInfo]) -> AssemblyResult {
         }
     }
 
-    for config in ASSEMBLERS {
+    for config in ASSEMBLERS
+        .iter()
+        .chain(registered_assemblers.iter().copied())
+    {
         if config.mode != AssemblyMode::SiblingMerge {
             continue;
         }
@@ -172,6 +319,12 @@ pub fn assemble(files: &mut [FileInfo]) -> AssemblyResult {
 
     assemblers::run_post_assembly_passes(files, &mut packages, &mut dependencies);
     hoist_unassembled_file_dependencies(files, &mut dependencies);
+    let detached_dependencies =
+        dependency_integrity::detach_dangling_dependencies(&packages, &mut dependencies);
+    debug_assert_eq!(
+        detached_dependencies, 0,
+        "assembled dependencies must reference assembled packages"
+    );
 
     for package in &mut packages {
         package.datafile_paths.sort();
@@ -268,3 +268,58 @@ fn test_maven_nested_merge_skips_multiple_nested_poms() {
 
     assert!(assembled.is_none());
 }
+
+#[test]
+fn test_nested_merge_leaves_no_dangling_for_package_uid() {
+    let maven_data = |datasource_id: DatasourceId, dependencies: Vec<Dependency>| PackageData {
+        datasource_id: Some(datasource_id),
+        package_type: Some(crate::models::PackageType::Maven),
+        primary_language: Some("Java".to_string()),
+        purl: Some("pkg:maven/com.example/my-lib@1.0.0".to_string()),
+        name: Some("my-lib".to_string()),
+        namespace: Some("com.example".to_string()),
+        version: Some("1.0.0".to_string()),
+        dependencies,
+        ..Default::default()
+    };
+    let mut files = vec![
+        test_file(
+            "my-lib/META-INF/MANIFEST.MF",
+            vec![maven_data(DatasourceId::JavaJarManifest, vec![])],
+        ),
+        test_file(
+            "my-lib/META-INF/maven/com.example/my-lib/pom.xml",
+            vec![maven_data(
+                DatasourceId::MavenPom,
+                vec![Dependency {
+                    purl: Some("pkg:maven/org.slf4j/slf4j-api@2.0.9".to_string()),
+                    extracted_requirement: Some("2.0.9".to_string()),
+                    scope: Some("compile".to_string()),
+                    is_runtime: Some(true),
+                    is_optional: Some(false),
+                    is_pinned: Some(true),
+                    is_direct: Some(true),
+                    resolved_package: None,
+                    extra_data: None,
+                }],
+            )],
+        ),
+    ];
+
+    let result = crate::assembly::assemble(&mut files);
+
+    assert!(!result.packages.is_empty());
+    assert!(!result.dependencies.is_empty());
+    let package_uids: HashSet<&str> = result
+        .packages
+        .iter()
+        .map(|package| package.package_uid.as_str())
+        .collect();
+    for dependency in &result.dependencies {
+        let for_package_uid = dependency
+            .for_package_uid
+            .as_deref()
+            .expect("pom dependency should be owned by the merged package");
+        assert!(package_uids.contains(for_package_uid));
+    }
+}
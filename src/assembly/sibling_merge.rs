@@ -18,9 +18,14 @@ struct PendingDependency {
 /// `file_indices`, and merges their package data into a single `Package`.
 /// Dependencies from all matched files are hoisted to the top level.
 ///
+/// Runtime `extra_manifests` patterns are tried before the built-in ones so
+/// they can establish the package identity; `extra_lockfiles` are tried after.
+///
 /// Returns `None` if no files with valid package data are found.
 pub fn assemble_siblings(
     config: &AssemblerConfig,
+    extra_manifests: &[String],
+    extra_lockfiles: &[String],
     files: &[FileInfo],
     file_indices: &[usize],
 ) -> Option<(Option<Package>, Vec<TopLevelDependency>, Vec<usize>)> {
@@ -29,7 +34,26 @@ pub fn assemble_siblings(
     let mut affected_indices = Vec::new();
     let mut saw_unpackageable_npm_manifest = false;
 
-    for &pattern in config.sibling_file_patterns {
+    // Runtime patterns may overlap the built-in ones; files already merged are
+    // not merged a second time through them.
+    let patterns = extra_manifests
+        .iter()
+        .map(|pattern| (pattern.as_str(), true))
+        .chain(
+            config
+                .sibling_file_patterns
+                .iter()
+                .map(|&pattern| (pattern, false)),
+        )
+        .chain(
+            extra_lockfiles
+                .iter()
+                .map(|pattern| (pattern.as_str(), true)),
+        );
+
+    let mut extra_matched = Vec::new();
+
+    for (pattern, is_extra) in patterns {
         for &idx in file_indices {
             let file = &files[idx];
             let file_name = Path::new(&file.path)
@@ -41,6 +65,10 @@ pub fn assemble_siblings(
                 continue;
             }
 
+            if affected_indices.contains(&idx) && (is_extra || extra_matched.contains(&idx)) {
+                continue;
+            }
+
             if file.package_data.is_empty() {
                 continue;
             }
@@ -98,6 +126,9 @@ pub fn assemble_siblings(
 
             if file_used {
                 affected_indices.push(idx);
+                if is_extra {
+                    extra_matched.push(idx);
+                }
             }
         }
     }
@@ -297,6 +297,7 @@ fn build_package_from_resolved_dependency(
         package_uid: build_package_uid(purl),
         datafile_paths: vec![datafile_path.to_string()],
         datasource_ids: vec![DatasourceId::SwiftPackageResolved],
+        dependency_rollup: None,
     })
 }
 
@@ -0,0 +1,118 @@
+//! Structured version constraints for top-level dependencies.
+//!
+//! Records the parsed form of each dependency's `extracted_requirement` as
+//! `extra_data["version_constraints"]` for ecosystems whose requirement syntax
+//! is known (see [`crate::utils::version_req`]). The raw requirement is left
+//! untouched.
+
+use crate::models::TopLevelDependency;
+use crate::utils::version_req::{VersionReqSyntax, parse_version_req};
+
+/// Annotate every dependency whose purl type has a known requirement syntax
+/// and whose requirement parses in it.
+pub fn annotate_version_constraints(dependencies: &mut [TopLevelDependency]) {
+    for dependency in dependencies {
+        let Some(syntax) = dependency
+            .purl
+            .as_deref()
+            .and_then(purl_type)
+            .and_then(VersionReqSyntax::for_purl_type)
+        else {
+            continue;
+        };
+        let Some(constraints) = dependency
+            .extracted_requirement
+            .as_deref()
+            .and_then(|requirement| parse_version_req(requirement, syntax))
+        else {
+            continue;
+        };
+        let Ok(value) = serde_json::to_value(constraints) else {
+            continue;
+        };
+        dependency
+            .extra_data
+            .get_or_insert_with(Default::default)
+            .insert("version_constraints".to_string(), value);
+    }
+}
+
+fn purl_type(purl: &str) -> Option<&str> {
+    purl.strip_prefix("pkg:")?.split('/').next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assembly::assemble;
+    use crate::models::{DatasourceId, Dependency, FileInfo, FileType, PackageData, PackageType};
+
+    fn npm_dependency(name: &str, requirement: &str) -> Dependency {
+        Dependency {
+            purl: Some(format!("pkg:npm/{name}")),
+            extracted_requirement: Some(requirement.to_string()),
+            scope: Some("dependencies".to_string()),
+            is_runtime: Some(true),
+            is_optional: Some(false),
+            is_pinned: Some(false),
+            is_direct: Some(true),
+            resolved_package: None,
+            extra_data: None,
+        }
+    }
+
+    #[test]
+    fn test_annotate_version_constraints_for_npm_ranges() {
+        let mut files = vec![FileInfo {
+            name: "package.json".to_string(),
+            base_name: "package".to_string(),
+            extension: ".json".to_string(),
+            path: "project/package.json".to_string(),
+            file_type: FileType::File,
+            package_data: vec![PackageData {
+                package_type: Some(PackageType::Npm),
+                name: Some("demo".to_string()),
+                version: Some("1.0.0".to_string()),
+                purl: Some("pkg:npm/demo@1.0.0".to_string()),
+                datasource_id: Some(DatasourceId::NpmPackageJson),
+                dependencies: vec![
+                    npm_dependency("express", ">=4.18.0 <5"),
+                    npm_dependency("local", "file:../local"),
+                ],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }];
+        let mut result = assemble(&mut files);
+
+        annotate_version_constraints(&mut result.dependencies);
+
+        let express = result
+            .dependencies
+            .iter()
+            .find(|dep| dep.purl.as_deref() == Some("pkg:npm/express"))
+            .expect("express dependency");
+        assert_eq!(
+            express
+                .extra_data
+                .as_ref()
+                .and_then(|extra| extra.get("version_constraints")),
+            Some(&serde_json::json!([[
+                {"operator": ">=", "version": "4.18.0"},
+                {"operator": "<", "version": "5"}
+            ]]))
+        );
+
+        let local = result
+            .dependencies
+            .iter()
+            .find(|dep| dep.purl.as_deref() == Some("pkg:npm/local"))
+            .expect("local dependency");
+        assert!(
+            local
+                .extra_data
+                .as_ref()
+                .is_none_or(|extra| !extra.contains_key("version_constraints"))
+        );
+    }
+}
@@ -28,6 +28,8 @@ pub struct CachedScanFindings {
     pub emails: Vec<OutputEmail>,
     pub urls: Vec<OutputURL>,
     pub programming_language: Option<String>,
+    #[serde(default)]
+    pub scan_notes: Vec<String>,
 }
 
 impl CachedScanFindings {
@@ -44,6 +46,7 @@ impl CachedScanFindings {
             emails: file_info.emails.clone(),
             urls: file_info.urls.clone(),
             programming_language: file_info.programming_language.clone(),
+            scan_notes: file_info.scan_notes.clone(),
         }
     }
 }
@@ -122,6 +125,7 @@ mod tests {
             emails: Vec::new(),
             urls: Vec::new(),
             programming_language: Some("Rust".to_string()),
+            scan_notes: vec![],
         };
 
         write_cached_findings(
@@ -174,6 +178,7 @@ mod tests {
                 matched_text_diagnostics: Some(
                     "This product currently only contains code developed by [authors]".to_string(),
                 ),
+                ..Default::default()
             }],
             percentage_of_license_text: Some(42.0),
             copyrights: Vec::new(),
@@ -182,6 +187,7 @@ mod tests {
             emails: Vec::new(),
             urls: Vec::new(),
             programming_language: None,
+            scan_notes: vec![],
         };
 
         write_cached_findings(
@@ -219,6 +225,7 @@ mod tests {
             emails: Vec::new(),
             urls: Vec::new(),
             programming_language: Some("Rust".to_string()),
+            scan_notes: vec![],
         };
 
         write_cached_findings(
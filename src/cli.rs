@@ -1,6 +1,8 @@
-use clap::{ArgGroup, Parser};
+use clap::{ArgGroup, Args, Parser, Subcommand};
 
 use crate::output::OutputFormat;
+use crate::scanner::MatchedTextSource;
+use crate::utils::hash::HashAlgorithm;
 
 #[derive(Parser, Debug)]
 #[command(
@@ -13,6 +15,8 @@ use crate::output::OutputFormat;
     ),
     about,
     long_about = None,
+    subcommand_negates_reqs = true,
+    args_conflicts_with_subcommands = true,
     group(
         ArgGroup::new("output")
             .required(true)
@@ -22,10 +26,14 @@ use crate::output::OutputFormat;
                 "output_json_lines",
                 "output_yaml",
                 "output_csv",
+                "output_files_csv",
+                "output_packages_csv",
+                "output_flat_json",
                 "output_html",
                 "output_html_app",
                 "output_spdx_tv",
                 "output_spdx_rdf",
+                "output_spdx_json",
                 "output_cyclonedx",
                 "output_cyclonedx_xml",
                 "custom_output",
@@ -34,6 +42,9 @@ use crate::output::OutputFormat;
     )
 )]
 pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Directory path to scan
     #[arg(required = false)]
     pub dir_path: Vec<String>,
@@ -58,6 +69,18 @@ pub struct Cli {
     #[arg(long = "csv", value_name = "FILE", allow_hyphen_values = true)]
     pub output_csv: Option<String>,
 
+    /// Write one CSV row per file (path, size, language, license, holders) to FILE
+    #[arg(long = "files-csv", value_name = "FILE", allow_hyphen_values = true)]
+    pub output_files_csv: Option<String>,
+
+    /// Write one CSV row per assembled package to FILE
+    #[arg(long = "packages-csv", value_name = "FILE", allow_hyphen_values = true)]
+    pub output_packages_csv: Option<String>,
+
+    /// Write the scanned files as a single top-level JSON array to FILE
+    #[arg(long = "flat-json", value_name = "FILE", allow_hyphen_values = true)]
+    pub output_flat_json: Option<String>,
+
     /// Write scan output as HTML report to FILE
     #[arg(long = "html", value_name = "FILE", allow_hyphen_values = true)]
     pub output_html: Option<String>,
@@ -79,6 +102,10 @@ pub struct Cli {
     #[arg(long = "spdx-rdf", value_name = "FILE", allow_hyphen_values = true)]
     pub output_spdx_rdf: Option<String>,
 
+    /// Write scan output as an SPDX 2.3 JSON document to FILE
+    #[arg(long = "spdx-json", value_name = "FILE", allow_hyphen_values = true)]
+    pub output_spdx_json: Option<String>,
+
     /// Write scan output as CycloneDX JSON to FILE
     #[arg(long = "cyclonedx", value_name = "FILE", allow_hyphen_values = true)]
     pub output_cyclonedx: Option<String>,
@@ -108,6 +135,20 @@ pub struct Cli {
     )]
     pub custom_template: Option<String>,
 
+    /// Split JSON output into numbered part files of at most N file entries,
+    /// writing a manifest listing the parts to the JSON output FILE
+    #[arg(
+        long,
+        value_name = "N",
+        value_parser = clap::value_parser!(usize).range(1..)
+    )]
+    pub split_output: Option<usize>,
+
+    /// Run the full scan but write only the headers, summary and aggregate
+    /// counts (license histogram, package and file counts) to the JSON output
+    #[arg(long = "stats-only", conflicts_with = "split_output")]
+    pub stats_only: bool,
+
     /// Maximum recursion depth (0 means no depth limit)
     #[arg(short, long, default_value = "0")]
     pub max_depth: usize,
@@ -115,6 +156,14 @@ pub struct Cli {
     #[arg(short = 'n', long, default_value_t = default_processes(), allow_hyphen_values = true)]
     pub processes: i32,
 
+    /// Cap the number of threads scanning files at N, whatever --processes asks for
+    #[arg(
+        long,
+        value_name = "N",
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    pub jobs: Option<usize>,
+
     #[arg(long, default_value_t = 120.0)]
     pub timeout: f64,
 
@@ -130,6 +179,10 @@ pub struct Cli {
     #[arg(long, conflicts_with = "strip_root")]
     pub full_root: bool,
 
+    /// Omit host operating system and CPU details from the output header
+    #[arg(long)]
+    pub no_system_env: bool,
+
     /// Exclude patterns (ScanCode-compatible alias: --ignore)
     #[arg(long = "exclude", visible_alias = "ignore", value_delimiter = ',')]
     pub exclude: Vec<String>,
@@ -137,9 +190,37 @@ pub struct Cli {
     #[arg(long, value_delimiter = ',')]
     pub include: Vec<String>,
 
+    /// Only scan files tracked by git (as listed by `git ls-files`), skipping
+    /// untracked build artifacts and other local files
+    #[arg(long = "tracked-only", conflicts_with = "from_json")]
+    pub tracked_only: bool,
+
+    /// Skip paths ignored by `.gitignore` files in the scanned tree or by a
+    /// top-level `.scancodeignore`, following git's matching rules
+    #[arg(long = "respect-gitignore", conflicts_with = "from_json")]
+    pub respect_gitignore: bool,
+
+    /// Scan what symbolic links point to instead of reporting them as
+    /// `symlink` resources; links back into an already visited directory are
+    /// still reported rather than followed
+    #[arg(long = "follow-symlinks", conflicts_with = "from_json")]
+    pub follow_symlinks: bool,
+
+    /// Always treat files with these extensions as text (e.g. --text-ext dat,ts)
+    #[arg(long = "text-ext", value_name = "EXT", value_delimiter = ',')]
+    pub text_ext: Vec<String>,
+
+    /// Always treat files with these extensions as binary (e.g. --binary-ext ts)
+    #[arg(long = "binary-ext", value_name = "EXT", value_delimiter = ',')]
+    pub binary_ext: Vec<String>,
+
     #[arg(long = "cache-dir", value_name = "PATH")]
     pub cache_dir: Option<String>,
 
+    /// Neither read nor write the scan result and license index caches
+    #[arg(long = "no-cache", conflicts_with_all = ["cache_dir", "cache_clear"])]
+    pub no_cache: bool,
+
     #[arg(long = "cache-clear")]
     pub cache_clear: bool,
 
@@ -149,9 +230,30 @@ pub struct Cli {
     #[arg(short = 'i', long)]
     pub info: bool,
 
+    /// Digests to compute for each file (default: sha1,md5,sha256)
+    #[arg(long, value_enum, value_name = "ALGORITHM", value_delimiter = ',')]
+    pub hash: Vec<HashAlgorithm>,
+
+    /// Skip computing file digests
+    #[arg(long, conflicts_with = "hash")]
+    pub no_hash: bool,
+
     #[arg(long)]
     pub from_json: bool,
 
+    /// Scan the layers of an extracted OCI image layout DIR (index.json + blobs)
+    #[arg(
+        long,
+        value_name = "DIR",
+        conflicts_with_all = ["from_json", "dir_path", "strip_root", "full_root"]
+    )]
+    pub oci_image: Option<String>,
+
+    /// Extract zip, jar and tar archives to a temporary directory and scan their
+    /// contents, reported as ARCHIVE!/ENTRY paths
+    #[arg(long, conflicts_with = "from_json")]
+    pub extract_archives: bool,
+
     /// Scan input for application package and dependency manifests, lockfiles and related data
     #[arg(short = 'p', long)]
     pub package: bool,
@@ -160,11 +262,78 @@ pub struct Cli {
     #[arg(long)]
     pub no_assemble: bool,
 
+    /// Parse files matching GLOB with PARSER and merge them with their package as a manifest
+    #[arg(long, value_name = "PARSER=GLOB", requires = "package")]
+    pub manifest_pattern: Vec<String>,
+
+    /// Parse files matching GLOB with PARSER and merge them with their package as a lockfile
+    #[arg(long, value_name = "PARSER=GLOB", requires = "package")]
+    pub lockfile_pattern: Vec<String>,
+
+    /// Record the per-file license and copyright holders of each `Files:` paragraph of
+    /// DEP-5 debian/copyright files in the package's extra_data
+    #[arg(long, requires = "package")]
+    pub debian_copyright_files: bool,
+
+    /// Map the License: tag of RPM .spec files to a declared license expression
+    #[arg(long, requires = "package")]
+    pub rpm_specfile_licenses: bool,
+
+    /// Annotate dependencies with their depth and an example path from the owning package
+    #[arg(long, requires = "package", conflicts_with = "no_assemble")]
+    pub dependency_graph: bool,
+
+    /// Derive package and dependency uids from their purl and datafiles instead of random UUIDs
+    #[arg(long, requires = "package", conflicts_with = "no_assemble")]
+    pub deterministic_uids: bool,
+
+    /// Count each package's runtime and development dependencies
+    #[arg(long, requires = "package", conflicts_with = "no_assemble")]
+    pub dependency_rollup: bool,
+
+    /// Record each npm, PyPI and Cargo dependency requirement as structured
+    /// `version_constraints` (operator and version) in its `extra_data`
+    #[arg(long, requires = "package", conflicts_with = "no_assemble")]
+    pub version_constraints: bool,
+
+    /// Report packages and licenses that differ from an approved SPDX or
+    /// CycloneDX JSON SBOM
+    #[arg(
+        long = "compare-sbom",
+        value_name = "FILE",
+        conflicts_with = "no_assemble"
+    )]
+    pub compare_sbom: Option<String>,
+
     /// Path to license rules directory containing .LICENSE and .RULE files.
-    /// If not specified, uses the built-in embedded license index.
+    /// If not specified, uses `PROVENANT_LICENSE_RULES` or else the built-in
+    /// embedded license index.
     #[arg(long, value_name = "PATH", requires = "license")]
     pub license_rules_path: Option<String>,
 
+    /// Path to a license data directory holding `rules` and `licenses`.
+    /// If not specified, uses `LICENSE_DATA_PATH`, then
+    /// `PROVENANT_LICENSE_RULES`, or else the built-in embedded license index.
+    #[arg(
+        long,
+        value_name = "PATH",
+        requires = "license",
+        conflicts_with = "license_rules_path"
+    )]
+    pub license_data_path: Option<String>,
+
+    /// Merge translated license rules from a directory, tagged with a language (LANG=PATH)
+    #[arg(
+        long = "translated-license-rules",
+        value_name = "LANG=PATH",
+        requires = "license"
+    )]
+    pub translated_license_rules: Vec<String>,
+
+    /// Merge additional .RULE and .LICENSE files from a directory into the license index
+    #[arg(long, value_name = "DIR", requires = "license")]
+    pub additional_rules: Vec<String>,
+
     /// Include matched text in license detection output
     #[arg(long = "license-text", alias = "include-text", requires = "license")]
     pub license_text: bool,
@@ -172,12 +341,112 @@ pub struct Cli {
     #[arg(long = "license-text-diagnostics", requires = "license_text")]
     pub license_text_diagnostics: bool,
 
+    /// Report matched text as found in the file (source) or as the matched rule's text (rule)
+    #[arg(
+        long = "license-text-from",
+        value_enum,
+        value_name = "SOURCE",
+        requires = "license_text"
+    )]
+    pub license_text_from: Option<MatchedTextSource>,
+
+    /// Omit matched license text from license matches loaded with --from-json
+    #[arg(
+        long = "strip-license-text",
+        requires = "from_json",
+        conflicts_with = "license_text"
+    )]
+    pub strip_license_text: bool,
+
+    /// Round license match scores and coverage to N decimal places in the output
+    #[arg(long = "score-precision", value_name = "N")]
+    pub score_precision: Option<u32>,
+
     #[arg(long = "license-diagnostics", requires = "license")]
     pub license_diagnostics: bool,
 
     #[arg(long = "unknown-licenses", requires = "license")]
     pub unknown_licenses: bool,
 
+    /// Report the rule category (text, notice, reference, tag, ...) of each license match
+    #[arg(long = "license-match-type", requires = "license")]
+    pub license_match_type: bool,
+
+    /// Flag license detections whose licenses are all public-domain dedications
+    #[arg(long = "license-public-domain", requires = "license")]
+    pub license_public_domain: bool,
+
+    /// Tag license detections as declared, concluded, referenced or incidental
+    #[arg(long = "license-facets", requires = "license")]
+    pub license_facets: bool,
+
+    /// Detect licenses per section in files that concatenate third-party
+    /// licenses under `--- component: NAME ---` headers
+    #[arg(long = "license-components", requires = "license")]
+    pub license_components: bool,
+
+    /// Flag whether each license detection in a source file is in the file's
+    /// leading comment header
+    #[arg(long = "license-in-header", requires = "license")]
+    pub license_in_header: bool,
+
+    /// Report license detections with imperfect match coverage under
+    /// `license_clues` instead of `license_detections`
+    #[arg(long = "imperfect-matches-as-clues", requires = "license")]
+    pub imperfect_matches_as_clues: bool,
+
+    /// Ignore matches from license rules with a relevance below this value (0-100)
+    #[arg(
+        long = "min-rule-relevance",
+        value_name = "INT",
+        default_value_t = 0,
+        value_parser = clap::value_parser!(u8).range(0..=100),
+        requires = "license"
+    )]
+    pub min_rule_relevance: u8,
+
+    /// Drop license detections whose longest match is shorter than N tokens,
+    /// unless it exactly matches a whole rule (0 keeps every detection)
+    #[arg(
+        long = "min-matched-length",
+        value_name = "N",
+        default_value_t = 0,
+        requires = "license"
+    )]
+    pub min_matched_length: usize,
+
+    /// Drop license matches scoring below N (0-100) before building detections;
+    /// files left without matches are reported as unlicensed (0 keeps every match)
+    #[arg(
+        long = "min-license-score",
+        value_name = "INT",
+        default_value_t = 0,
+        value_parser = clap::value_parser!(u8).range(0..=100),
+        requires = "license"
+    )]
+    pub min_license_score: u8,
+
+    /// Number of candidate rules the sequence matcher aligns per query run;
+    /// lower values are faster but may miss licenses in long mixed files
+    #[arg(
+        long = "max-sequence-candidates",
+        value_name = "N",
+        default_value_t = crate::license_detection::DEFAULT_MAX_SEQUENCE_CANDIDATES,
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..),
+        requires = "license"
+    )]
+    pub max_sequence_candidates: usize,
+
+    /// Build each license detection query from at most N tokens, bounding
+    /// memory on huge files; 0 reads the whole file
+    #[arg(
+        long = "max-query-tokens",
+        value_name = "N",
+        default_value_t = 0,
+        requires = "license"
+    )]
+    pub max_query_tokens: usize,
+
     #[arg(long)]
     pub filter_clues: bool,
 
@@ -207,12 +476,51 @@ pub struct Cli {
     #[arg(long, requires = "classify")]
     pub summary: bool,
 
+    /// Add license match coverage histogram and matcher counts to the summary
+    #[arg(long = "summary-match-stats", requires = "summary")]
+    pub summary_match_stats: bool,
+
+    /// Add the concluded license of the scanned project to the summary, taken
+    /// from the root package or the top-level legal files
+    #[arg(long = "primary-license", requires = "summary")]
+    pub primary_license: bool,
+
+    /// Add a single risk label to the summary: the most restrictive category
+    /// of the licenses found (Strong Copyleft, Weak Copyleft, Permissive or Unknown)
+    #[arg(long = "risk-label", requires_all = ["summary", "license"])]
+    pub risk_label: bool,
+
     #[arg(long = "license-clarity-score", requires = "classify")]
     pub license_clarity_score: bool,
 
     #[arg(long = "license-references", requires = "license")]
     pub license_references: bool,
 
+    /// Record the license of sibling files that detections refer to (such as
+    /// "see LICENSE") as `referenced_license_expression`, without changing the
+    /// file's own detections
+    #[arg(long = "license-reference-clues", requires = "license")]
+    pub license_reference_clues: bool,
+
+    /// Add an `unknown_license_texts` section with the text of each unknown or
+    /// custom `LicenseRef-` license detection, deduplicated across files
+    #[arg(
+        long = "unknown-license-texts",
+        requires = "license",
+        conflicts_with = "from_json"
+    )]
+    pub unknown_license_texts: bool,
+
+    /// Write to FILE a JSON report of how many times each license rule
+    /// matched across the scan, including the rules that never matched
+    #[arg(
+        long = "rule-stats",
+        value_name = "FILE",
+        requires = "license",
+        conflicts_with = "from_json"
+    )]
+    pub rule_stats: Option<String>,
+
     #[arg(long)]
     pub tallies: bool,
 
@@ -238,6 +546,27 @@ pub struct Cli {
     #[arg(short = 'c', long)]
     pub copyright: bool,
 
+    /// Flag files that have copyright notices but no detected license
+    #[arg(long = "mark-copyright-only", requires_all = ["copyright", "license"])]
+    pub mark_copyright_only: bool,
+
+    /// Add a canonical `Copyright (c) <years> <holder>` form next to each detected copyright
+    #[arg(long, requires = "copyright")]
+    pub canonicalize_copyrights: bool,
+
+    /// Also report each person listed one per line in AUTHORS and CONTRIBUTORS files
+    #[arg(long = "listed-authors", requires = "copyright")]
+    pub listed_authors: bool,
+
+    /// Drop year-less copyrights detected on `copyright(...)` function calls in code
+    #[arg(long = "skip-code-copyrights", requires = "copyright")]
+    pub skip_code_copyrights: bool,
+
+    /// Skip license and copyright detection for files that look like tabular
+    /// data (CSV, TSV and similar), recording a scan note instead
+    #[arg(long = "skip-data-files")]
+    pub skip_data_files: bool,
+
     /// Scan input for email addresses
     #[arg(short = 'e', long)]
     pub email: bool,
@@ -254,11 +583,36 @@ pub struct Cli {
     #[arg(long, default_value_t = 50, requires = "url")]
     pub max_url: usize,
 
+    /// Skip XML namespace and schema URLs and links to well-known license texts
+    #[arg(long, requires = "url")]
+    pub exclude_common_urls: bool,
+
     /// Show attribution notices for embedded license detection data
     #[arg(long)]
     pub show_attribution: bool,
 }
 
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Run an HTTP server answering `POST /detect` with license detections
+    Serve(ServeArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct ServeArgs {
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:8080")]
+    pub addr: String,
+
+    /// Path to license rules directory (defaults to the embedded index)
+    #[arg(long, value_name = "PATH")]
+    pub license_rules_path: Option<String>,
+
+    /// Path to a license data directory holding `rules` and `licenses`
+    #[arg(long, value_name = "PATH", conflicts_with = "license_rules_path")]
+    pub license_data_path: Option<String>,
+}
+
 fn default_processes() -> i32 {
     let cpus = std::thread::available_parallelism().map_or(1, |n| n.get());
     if cpus > 1 { (cpus - 1) as i32 } else { 1 }
@@ -315,6 +669,30 @@ impl Cli {
             });
         }
 
+        if let Some(file) = &self.output_files_csv {
+            targets.push(OutputTarget {
+                format: OutputFormat::FilesCsv,
+                file: file.clone(),
+                custom_template: None,
+            });
+        }
+
+        if let Some(file) = &self.output_packages_csv {
+            targets.push(OutputTarget {
+                format: OutputFormat::PackagesCsv,
+                file: file.clone(),
+                custom_template: None,
+            });
+        }
+
+        if let Some(file) = &self.output_flat_json {
+            targets.push(OutputTarget {
+                format: OutputFormat::FlatJson,
+                file: file.clone(),
+                custom_template: None,
+            });
+        }
+
         if let Some(file) = &self.output_html {
             targets.push(OutputTarget {
                 format: OutputFormat::Html,
@@ -347,6 +725,14 @@ impl Cli {
             });
         }
 
+        if let Some(file) = &self.output_spdx_json {
+            targets.push(OutputTarget {
+                format: OutputFormat::SpdxJson,
+                file: file.clone(),
+                custom_template: None,
+            });
+        }
+
         if let Some(file) = &self.output_cyclonedx {
             targets.push(OutputTarget {
                 format: OutputFormat::CycloneDxJson,
@@ -403,6 +789,30 @@ mod tests {
         assert_eq!(parsed.output_json_pp.as_deref(), Some("-"));
     }
 
+    #[test]
+    fn test_split_output_requires_positive_part_size() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json",
+            "scan.json",
+            "--split-output",
+            "100",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert_eq!(parsed.split_output, Some(100));
+
+        let zero = Cli::try_parse_from([
+            "provenant",
+            "--json",
+            "scan.json",
+            "--split-output",
+            "0",
+            "samples",
+        ]);
+        assert!(zero.is_err());
+    }
+
     #[test]
     fn test_custom_template_and_output_must_be_paired() {
         let missing_template =
@@ -432,6 +842,66 @@ mod tests {
         assert_eq!(parsed.timeout, 30.0);
     }
 
+    #[test]
+    fn test_jobs_must_be_positive() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--jobs",
+            "2",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert_eq!(parsed.jobs, Some(2));
+
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--jobs",
+            "0",
+            "samples",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_extract_archives_conflicts_with_from_json() {
+        let parsed =
+            Cli::try_parse_from(["provenant", "--json", "-", "--extract-archives", "samples"])
+                .expect("cli parse should succeed");
+        assert!(parsed.extract_archives);
+
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json",
+            "-",
+            "--from-json",
+            "--extract-archives",
+            "scan.json",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_oci_image_replaces_directory_path() {
+        let parsed = Cli::try_parse_from(["provenant", "--json", "-", "--oci-image", "image"])
+            .expect("cli parse should succeed");
+        assert_eq!(parsed.oci_image.as_deref(), Some("image"));
+        assert!(parsed.dir_path.is_empty());
+
+        let with_path = Cli::try_parse_from([
+            "provenant",
+            "--json",
+            "-",
+            "--oci-image",
+            "image",
+            "samples",
+        ]);
+        assert!(with_path.is_err());
+    }
+
     #[test]
     fn test_strip_root_conflicts_with_full_root() {
         let parsed = Cli::try_parse_from([
@@ -499,6 +969,26 @@ mod tests {
         assert_eq!(parsed.exclude, vec!["*.git*", "target/*"]);
     }
 
+    #[test]
+    fn test_parses_text_and_binary_extension_overrides() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--text-ext",
+            "dat,.ts",
+            "--binary-ext",
+            "bin",
+            "--binary-ext",
+            "img",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+
+        assert_eq!(parsed.text_ext, vec!["dat", ".ts"]);
+        assert_eq!(parsed.binary_ext, vec!["bin", "img"]);
+    }
+
     #[test]
     fn test_quiet_conflicts_with_verbose() {
         let parsed = Cli::try_parse_from([
@@ -531,6 +1021,34 @@ mod tests {
         assert!(parsed.mark_source);
     }
 
+    #[test]
+    fn test_hash_accepts_algorithm_list_and_conflicts_with_no_hash() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--hash",
+            "sha1,sha512",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert_eq!(
+            parsed.hash,
+            vec![HashAlgorithm::Sha1, HashAlgorithm::Sha512]
+        );
+
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--hash",
+            "sha1",
+            "--no-hash",
+            "samples",
+        ]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_mark_source_requires_info() {
         let parsed = Cli::try_parse_from([
@@ -594,155 +1112,946 @@ mod tests {
     }
 
     #[test]
-    fn test_tallies_key_files_requires_tallies_and_classify() {
-        let parsed = Cli::try_parse_from([
+    fn test_summary_match_stats_requires_summary() {
+        let result = Cli::try_parse_from([
             "provenant",
             "--json-pp",
             "scan.json",
-            "--tallies-key-files",
+            "--classify",
+            "--summary-match-stats",
             "samples",
         ]);
+        assert!(result.is_err());
 
-        assert!(parsed.is_err());
-    }
-
-    #[test]
-    fn test_parses_summary_tallies_and_generated_flags() {
         let parsed = Cli::try_parse_from([
             "provenant",
             "--json-pp",
             "scan.json",
             "--classify",
             "--summary",
-            "--license-clarity-score",
-            "--tallies",
-            "--tallies-key-files",
-            "--tallies-with-details",
-            "--generated",
+            "--summary-match-stats",
             "samples",
         ])
         .expect("cli parse should succeed");
-
-        assert!(parsed.classify);
-        assert!(parsed.summary);
-        assert!(parsed.license_clarity_score);
-        assert!(parsed.tallies);
-        assert!(parsed.tallies_key_files);
-        assert!(parsed.tallies_with_details);
-        assert!(parsed.generated);
+        assert!(parsed.summary_match_stats);
     }
 
     #[test]
-    fn test_parses_copyright_flag() {
-        let parsed = Cli::try_parse_from([
+    fn test_primary_license_requires_summary() {
+        let result = Cli::try_parse_from([
             "provenant",
             "--json-pp",
             "scan.json",
-            "--copyright",
+            "--classify",
+            "--primary-license",
             "samples",
-        ])
-        .expect("cli parse should succeed");
-
-        assert!(parsed.copyright);
-    }
-
-    #[test]
-    fn test_package_flag_defaults_to_disabled() {
-        let parsed = Cli::try_parse_from(["provenant", "--json-pp", "scan.json", "samples"])
-            .expect("cli parse should succeed");
-
-        assert!(!parsed.package);
-    }
+        ]);
+        assert!(result.is_err());
 
-    #[test]
-    fn test_parses_package_flag() {
         let parsed = Cli::try_parse_from([
             "provenant",
             "--json-pp",
             "scan.json",
-            "--package",
+            "--classify",
+            "--summary",
+            "--primary-license",
             "samples",
         ])
         .expect("cli parse should succeed");
-
-        assert!(parsed.package);
+        assert!(parsed.primary_license);
     }
 
     #[test]
-    fn test_package_short_flag() {
-        let parsed = Cli::try_parse_from(["provenant", "--json-pp", "scan.json", "-p", "samples"])
-            .expect("cli parse should succeed");
-
+    fn test_risk_label_requires_summary_and_license() {
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--classify",
+            "--summary",
+            "--risk-label",
+            "samples",
+        ]);
+        assert!(result.is_err());
+
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--classify",
+            "--summary",
+            "--risk-label",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert!(parsed.risk_label);
+    }
+
+    #[test]
+    fn test_tallies_key_files_requires_tallies_and_classify() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--tallies-key-files",
+            "samples",
+        ]);
+
+        assert!(parsed.is_err());
+    }
+
+    #[test]
+    fn test_parses_summary_tallies_and_generated_flags() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--classify",
+            "--summary",
+            "--license-clarity-score",
+            "--tallies",
+            "--tallies-key-files",
+            "--tallies-with-details",
+            "--generated",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+
+        assert!(parsed.classify);
+        assert!(parsed.summary);
+        assert!(parsed.license_clarity_score);
+        assert!(parsed.tallies);
+        assert!(parsed.tallies_key_files);
+        assert!(parsed.tallies_with_details);
+        assert!(parsed.generated);
+    }
+
+    #[test]
+    fn test_parses_copyright_flag() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--copyright",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+
+        assert!(parsed.copyright);
+    }
+
+    #[test]
+    fn test_package_flag_defaults_to_disabled() {
+        let parsed = Cli::try_parse_from(["provenant", "--json-pp", "scan.json", "samples"])
+            .expect("cli parse should succeed");
+
+        assert!(!parsed.package);
+    }
+
+    #[test]
+    fn test_parses_package_flag() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--package",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+
         assert!(parsed.package);
     }
 
     #[test]
-    fn test_parses_license_flag() {
+    fn test_package_short_flag() {
+        let parsed = Cli::try_parse_from(["provenant", "--json-pp", "scan.json", "-p", "samples"])
+            .expect("cli parse should succeed");
+
+        assert!(parsed.package);
+    }
+
+    #[test]
+    fn test_parses_license_flag() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+
+        assert!(parsed.license);
+    }
+
+    #[test]
+    fn test_license_short_flag() {
+        let parsed = Cli::try_parse_from(["provenant", "--json-pp", "scan.json", "-l", "samples"])
+            .expect("cli parse should succeed");
+
+        assert!(parsed.license);
+    }
+
+    #[test]
+    fn test_license_text_requires_license() {
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license-text",
+            "samples",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_license_text_diagnostics_requires_license_text() {
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--license-text-diagnostics",
+            "samples",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_license_text_from_parses_rule_and_requires_license_text() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--license-text",
+            "--license-text-from",
+            "rule",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert_eq!(parsed.license_text_from, Some(MatchedTextSource::Rule));
+
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--license-text-from",
+            "source",
+            "samples",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_stats_only_conflicts_with_split_output() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json",
+            "stats.json",
+            "--stats-only",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert!(parsed.stats_only);
+
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json",
+            "stats.json",
+            "--stats-only",
+            "--split-output",
+            "10",
+            "samples",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_strip_license_text_conflicts_with_license_text() {
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--license-text",
+            "--strip-license-text",
+            "samples",
+        ]);
+        assert!(result.is_err());
+
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "stripped.json",
+            "--from-json",
+            "--strip-license-text",
+            "scan.json",
+        ])
+        .expect("cli parse should succeed");
+        assert!(parsed.strip_license_text);
+    }
+
+    #[test]
+    fn test_strip_license_text_requires_from_json() {
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--strip-license-text",
+            "samples",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_mark_copyright_only_requires_copyright_and_license() {
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--copyright",
+            "--mark-copyright-only",
+            "samples",
+        ]);
+        assert!(result.is_err());
+
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--copyright",
+            "--license",
+            "--mark-copyright-only",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert!(parsed.mark_copyright_only);
+    }
+
+    #[test]
+    fn test_parses_license_text_and_diagnostics_flags() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--license-text",
+            "--license-text-diagnostics",
+            "--license-diagnostics",
+            "--unknown-licenses",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+
+        assert!(parsed.license_text);
+        assert!(parsed.license_text_diagnostics);
+        assert!(parsed.license_diagnostics);
+        assert!(parsed.unknown_licenses);
+    }
+
+    #[test]
+    fn test_parses_min_rule_relevance() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--min-rule-relevance",
+            "80",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+
+        assert_eq!(parsed.min_rule_relevance, 80);
+    }
+
+    #[test]
+    fn test_max_query_tokens_requires_license() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--max-query-tokens",
+            "5000",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert_eq!(parsed.max_query_tokens, 5000);
+
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--max-query-tokens",
+            "5000",
+            "samples",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_max_sequence_candidates_defaults_and_rejects_zero() {
+        let parsed = Cli::try_parse_from(["provenant", "--json-pp", "scan.json", "samples"])
+            .expect("cli parse should succeed");
+        assert_eq!(
+            parsed.max_sequence_candidates,
+            crate::license_detection::DEFAULT_MAX_SEQUENCE_CANDIDATES
+        );
+
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--max-sequence-candidates",
+            "10",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert_eq!(parsed.max_sequence_candidates, 10);
+
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--max-sequence-candidates",
+            "0",
+            "samples",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_listed_authors_requires_copyright() {
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--listed-authors",
+            "samples",
+        ]);
+        assert!(result.is_err());
+
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--copyright",
+            "--listed-authors",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert!(parsed.listed_authors);
+    }
+
+    #[test]
+    fn test_skip_code_copyrights_requires_copyright() {
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--skip-code-copyrights",
+            "samples",
+        ]);
+        assert!(result.is_err());
+
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--copyright",
+            "--skip-code-copyrights",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert!(parsed.skip_code_copyrights);
+    }
+
+    #[test]
+    fn test_debian_copyright_files_requires_package() {
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--debian-copyright-files",
+            "samples",
+        ]);
+        assert!(result.is_err());
+
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--package",
+            "--debian-copyright-files",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert!(parsed.debian_copyright_files);
+    }
+
+    #[test]
+    fn test_rpm_specfile_licenses_requires_package() {
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--rpm-specfile-licenses",
+            "samples",
+        ]);
+        assert!(result.is_err());
+
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--package",
+            "--rpm-specfile-licenses",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert!(parsed.rpm_specfile_licenses);
+    }
+
+    #[test]
+    fn test_deterministic_uids_requires_package() {
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--deterministic-uids",
+            "samples",
+        ]);
+        assert!(result.is_err());
+
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--package",
+            "--deterministic-uids",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert!(parsed.deterministic_uids);
+    }
+
+    #[test]
+    fn test_dependency_rollup_conflicts_with_no_assemble() {
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--package",
+            "--no-assemble",
+            "--dependency-rollup",
+            "samples",
+        ]);
+        assert!(result.is_err());
+
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--package",
+            "--dependency-rollup",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert!(parsed.dependency_rollup);
+    }
+
+    #[test]
+    fn test_license_public_domain_requires_license() {
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license-public-domain",
+            "samples",
+        ]);
+        assert!(result.is_err());
+
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--license-public-domain",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert!(parsed.license_public_domain);
+    }
+
+    #[test]
+    fn test_min_rule_relevance_defaults_to_zero_and_rejects_out_of_range() {
+        let parsed = Cli::try_parse_from(["provenant", "--json-pp", "scan.json", "samples"])
+            .expect("cli parse should succeed");
+        assert_eq!(parsed.min_rule_relevance, 0);
+
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--min-rule-relevance",
+            "101",
+            "samples",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_license_facets_requires_license() {
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license-facets",
+            "samples",
+        ]);
+        assert!(result.is_err());
+
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--license-facets",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert!(parsed.license_facets);
+    }
+
+    #[test]
+    fn test_license_components_requires_license() {
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license-components",
+            "samples",
+        ]);
+        assert!(result.is_err());
+
         let parsed = Cli::try_parse_from([
             "provenant",
             "--json-pp",
             "scan.json",
             "--license",
+            "--license-components",
             "samples",
         ])
         .expect("cli parse should succeed");
+        assert!(parsed.license_components);
+    }
 
-        assert!(parsed.license);
+    #[test]
+    fn test_exclude_common_urls_requires_url() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--url",
+            "--exclude-common-urls",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert!(parsed.exclude_common_urls);
+
+        let without_url = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--exclude-common-urls",
+            "samples",
+        ]);
+        assert!(without_url.is_err());
     }
 
     #[test]
-    fn test_license_short_flag() {
-        let parsed = Cli::try_parse_from(["provenant", "--json-pp", "scan.json", "-l", "samples"])
-            .expect("cli parse should succeed");
+    fn test_parses_imperfect_matches_as_clues() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--imperfect-matches-as-clues",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert!(parsed.imperfect_matches_as_clues);
 
-        assert!(parsed.license);
+        let without_license = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--imperfect-matches-as-clues",
+            "samples",
+        ]);
+        assert!(without_license.is_err());
     }
 
     #[test]
-    fn test_license_text_requires_license() {
+    fn test_license_in_header_requires_license() {
         let result = Cli::try_parse_from([
             "provenant",
             "--json-pp",
             "scan.json",
-            "--license-text",
+            "--license-in-header",
             "samples",
         ]);
         assert!(result.is_err());
+
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--license-in-header",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert!(parsed.license_in_header);
     }
 
     #[test]
-    fn test_license_text_diagnostics_requires_license_text() {
+    fn test_tracked_only_conflicts_with_from_json() {
         let result = Cli::try_parse_from([
             "provenant",
             "--json-pp",
             "scan.json",
-            "--license",
-            "--license-text-diagnostics",
+            "--from-json",
+            "--tracked-only",
+            "scan-input.json",
+        ]);
+        assert!(result.is_err());
+
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--tracked-only",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert!(parsed.tracked_only);
+    }
+
+    #[test]
+    fn test_follow_symlinks_is_off_by_default() {
+        let parsed = Cli::try_parse_from(["provenant", "--json-pp", "scan.json", "samples"])
+            .expect("cli parse should succeed");
+        assert!(!parsed.follow_symlinks);
+
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--follow-symlinks",
             "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert!(parsed.follow_symlinks);
+    }
+
+    #[test]
+    fn test_respect_gitignore_conflicts_with_from_json() {
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--from-json",
+            "--respect-gitignore",
+            "scan-input.json",
         ]);
+        assert!(result.is_err());
+
+        let parsed = Cli::try_parse_from(["provenant", "--json-pp", "scan.json", "samples"])
+            .expect("cli parse should succeed");
+        assert!(!parsed.respect_gitignore);
+    }
 
+    #[test]
+    fn test_unknown_license_texts_requires_license() {
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--unknown-license-texts",
+            "samples",
+        ]);
         assert!(result.is_err());
+
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--unknown-license-texts",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert!(parsed.unknown_license_texts);
     }
 
     #[test]
-    fn test_parses_license_text_and_diagnostics_flags() {
+    fn test_license_reference_clues_requires_license() {
         let parsed = Cli::try_parse_from([
             "provenant",
             "--json-pp",
             "scan.json",
             "--license",
-            "--license-text",
-            "--license-text-diagnostics",
-            "--license-diagnostics",
-            "--unknown-licenses",
+            "--license-reference-clues",
             "samples",
         ])
         .expect("cli parse should succeed");
+        assert!(parsed.license_reference_clues);
 
-        assert!(parsed.license_text);
-        assert!(parsed.license_text_diagnostics);
-        assert!(parsed.license_diagnostics);
-        assert!(parsed.unknown_licenses);
+        let without_license = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license-reference-clues",
+            "samples",
+        ]);
+        assert!(without_license.is_err());
+    }
+
+    #[test]
+    fn test_rule_stats_requires_license() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--rule-stats",
+            "rules.json",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert_eq!(parsed.rule_stats.as_deref(), Some("rules.json"));
+
+        let without_license = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--rule-stats",
+            "rules.json",
+            "samples",
+        ]);
+        assert!(without_license.is_err());
+    }
+
+    #[test]
+    fn test_parses_min_matched_length() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--min-matched-length",
+            "5",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert_eq!(parsed.min_matched_length, 5);
+
+        let without_license = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--min-matched-length",
+            "5",
+            "samples",
+        ]);
+        assert!(without_license.is_err());
+    }
+
+    #[test]
+    fn test_parses_repeated_additional_rules() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--additional-rules",
+            "acme-rules",
+            "--additional-rules",
+            "vendor-rules",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert_eq!(parsed.additional_rules, vec!["acme-rules", "vendor-rules"]);
+
+        let without_license = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--additional-rules",
+            "acme-rules",
+            "samples",
+        ]);
+        assert!(without_license.is_err());
+    }
+
+    #[test]
+    fn test_parses_min_license_score() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--min-license-score",
+            "80",
+            "samples",
+        ])
+        .expect("cli parse should succeed");
+        assert_eq!(parsed.min_license_score, 80);
+
+        let out_of_range = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--min-license-score",
+            "101",
+            "samples",
+        ]);
+        assert!(out_of_range.is_err());
+
+        let without_license = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--min-license-score",
+            "80",
+            "samples",
+        ]);
+        assert!(without_license.is_err());
+    }
+
+    #[test]
+    fn test_min_rule_relevance_requires_license() {
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--min-rule-relevance",
+            "80",
+            "samples",
+        ]);
+
+        assert!(result.is_err());
     }
 
     #[test]
@@ -839,6 +2148,30 @@ mod tests {
         assert_eq!(parsed.max_in_memory, Some(5000));
     }
 
+    #[test]
+    fn test_no_cache_conflicts_with_cache_dir() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--no-cache",
+            "samples",
+        ])
+        .expect("cli parse should accept --no-cache");
+        assert!(parsed.no_cache);
+
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--no-cache",
+            "--cache-dir",
+            "/tmp/sc-cache",
+            "samples",
+        ]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_max_depth_default_matches_reference_behavior() {
         let parsed = Cli::try_parse_from(["provenant", "--json-pp", "scan.json", "samples"])
@@ -846,4 +2179,45 @@ mod tests {
 
         assert_eq!(parsed.max_depth, 0);
     }
+
+    #[test]
+    fn test_parses_serve_subcommand_without_output_option() {
+        let parsed = Cli::try_parse_from(["provenant", "serve", "--addr", "0.0.0.0:9000"])
+            .expect("cli parse should accept serve subcommand");
+
+        let Some(Command::Serve(args)) = parsed.command else {
+            panic!("expected serve subcommand");
+        };
+        assert_eq!(args.addr, "0.0.0.0:9000");
+        assert!(args.license_rules_path.is_none());
+        assert!(args.license_data_path.is_none());
+    }
+
+    #[test]
+    fn test_license_data_path_conflicts_with_license_rules_path() {
+        let parsed = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--license-data-path",
+            "/data",
+            "samples",
+        ])
+        .expect("cli parse should accept --license-data-path");
+        assert_eq!(parsed.license_data_path.as_deref(), Some("/data"));
+
+        let result = Cli::try_parse_from([
+            "provenant",
+            "--json-pp",
+            "scan.json",
+            "--license",
+            "--license-data-path",
+            "/data",
+            "--license-rules-path",
+            "/rules",
+            "samples",
+        ]);
+        assert!(result.is_err());
+    }
 }
@@ -7,12 +7,15 @@
 //!   W: http://www.randombit.net/
 //!   P: (PGP key - ignored)
 //!   B: (bitcoin - ignored)
+//!
+//! Files without that structure are usually plain lists with one person per
+//! line (`Jane Doe <jane@example.com>`), which [`detect_listed_authors`] reads.
 
 use std::path::Path;
 
 use super::types::AuthorDetection;
 
-/// Filenames recognized as CREDITS/AUTHORS files (case-insensitive)
+/// Filenames recognized as CREDITS/AUTHORS/CONTRIBUTORS files (case-insensitive)
 const CREDITS_FILENAMES: &[&str] = &[
     "credit",
     "credits",
@@ -24,9 +27,18 @@ const CREDITS_FILENAMES: &[&str] = &[
     "authors.rst",
     "authors.txt",
     "authors.md",
+    "contributors",
+    "contributors.rst",
+    "contributors.txt",
+    "contributors.md",
+];
+
+/// Lowercase name particles allowed inside a listed person's name.
+const NAME_PARTICLES: &[&str] = &[
+    "van", "von", "der", "den", "de", "del", "della", "da", "di", "du", "la", "le", "bin", "al",
 ];
 
-/// Check if a file path is a CREDITS/AUTHORS file by its filename.
+/// Check if a file path is a CREDITS/AUTHORS/CONTRIBUTORS file by its filename.
 pub fn is_credits_file(path: &Path) -> bool {
     path.file_name()
         .and_then(|name| name.to_str())
@@ -79,6 +91,76 @@ pub fn detect_credits_authors(content: &str) -> Vec<AuthorDetection> {
     results
 }
 
+/// Detect authors from a plain one-person-per-line AUTHORS/CONTRIBUTORS list.
+///
+/// Each line that looks like a person, a capitalized name of two to six
+/// words optionally followed by an email or URL, or a bare email, becomes one
+/// author. Bullets are stripped; headings, comments and prose are skipped.
+pub fn detect_listed_authors(content: &str) -> Vec<AuthorDetection> {
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(idx, line)| {
+            let entry = listed_person(line)?;
+            Some(AuthorDetection {
+                author: entry.to_string(),
+                start_line: idx + 1,
+                end_line: idx + 1,
+            })
+        })
+        .collect()
+}
+
+/// Return the person entry on `line`, without list bullets, if it is one.
+fn listed_person(line: &str) -> Option<&str> {
+    let entry = line.trim().trim_start_matches(['*', '-', '+']).trim_start();
+    if entry.is_empty()
+        || entry.len() > 120
+        || entry.starts_with('#')
+        || entry.ends_with(':')
+        || entry.to_lowercase().contains("copyright")
+    {
+        return None;
+    }
+
+    // Split off a trailing contact such as `<jane@example.com>` or `(https://...)`.
+    let name = match entry.find(['<', '(']) {
+        Some(index) => {
+            let contact = &entry[index..];
+            if !(contact.contains('@') || contact.contains("://")) {
+                return None;
+            }
+            entry[..index].trim()
+        }
+        None if is_bare_email(entry) => return Some(entry),
+        None => entry,
+    };
+
+    is_person_name(name).then_some(entry)
+}
+
+fn is_bare_email(entry: &str) -> bool {
+    !entry.contains(char::is_whitespace)
+        && entry
+            .split_once('@')
+            .is_some_and(|(user, domain)| !user.is_empty() && domain.contains('.'))
+}
+
+fn is_person_name(name: &str) -> bool {
+    let words: Vec<&str> = name.split_whitespace().collect();
+    if !(2..=6).contains(&words.len()) {
+        return false;
+    }
+    let first_capitalized = words[0].chars().next().is_some_and(char::is_uppercase);
+    first_capitalized
+        && words.iter().all(|word| {
+            word.chars()
+                .all(|c| c.is_alphabetic() || matches!(c, '.' | '\'' | '-'))
+                && (word.chars().next().is_some_and(char::is_uppercase)
+                    || NAME_PARTICLES.contains(word))
+        })
+}
+
 /// Process a single group of N:/E:/W: lines into an AuthorDetection.
 fn process_credit_group(group: &[(usize, &str)]) -> Option<AuthorDetection> {
     let mut names = Vec::new();
@@ -210,6 +292,46 @@ W: https://bob.example.com
         assert!(authors.is_empty());
     }
 
+    #[test]
+    fn test_is_credits_file_recognizes_contributors() {
+        assert!(is_credits_file(&PathBuf::from("CONTRIBUTORS")));
+        assert!(is_credits_file(&PathBuf::from("contributors.md")));
+    }
+
+    #[test]
+    fn test_detect_listed_authors_one_per_line() {
+        let content = "\
+# This is the list of project authors.
+The following people have contributed:
+
+* Alice Smith <alice@example.com>
+- Bob van der Berg (https://bob.example.com)
+carol@example.org
+Thanks to everyone who reported bugs and sent patches over the years.
+";
+        let authors = detect_listed_authors(content);
+        let found: Vec<_> = authors
+            .iter()
+            .map(|a| (a.author.as_str(), a.start_line))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("Alice Smith <alice@example.com>", 4),
+                ("Bob van der Berg (https://bob.example.com)", 5),
+                ("carol@example.org", 6),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detect_listed_authors_skips_copyright_lines() {
+        let content = "Copyright 2020 Example Corp\nJane Doe\n";
+        let authors = detect_listed_authors(content);
+        assert_eq!(authors.len(), 1);
+        assert_eq!(authors[0].author, "Jane Doe");
+    }
+
     #[test]
     fn test_detect_credits_ignores_pgp_and_bitcoin() {
         let content = "\
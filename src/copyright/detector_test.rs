@@ -356,6 +356,34 @@ fn test_detect_copyright_or_copr_without_year() {
     assert!(hs.iter().any(|s| s == "CNRS"), "holders: {hs:#?}");
 }
 
+#[test]
+fn test_detect_year_before_copyright_keyword() {
+    let content = "2020 Copyright Foo Inc.\n";
+    let (copyrights, holders, _authors) = detect_copyrights_from_text(content);
+    let cr: Vec<String> = copyrights.into_iter().map(|c| c.copyright).collect();
+    let hs: Vec<String> = holders.into_iter().map(|h| h.holder).collect();
+
+    assert!(
+        cr.iter().any(|s| s == "2020 Copyright Foo Inc."),
+        "copyrights: {cr:#?}"
+    );
+    assert_eq!(hs, vec!["Foo Inc.".to_string()], "holders: {hs:#?}");
+}
+
+#[test]
+fn test_detect_copyright_with_month_name_date() {
+    let content = "Copyright January 2020 Foo\n";
+    let (copyrights, holders, _authors) = detect_copyrights_from_text(content);
+    let cr: Vec<String> = copyrights.into_iter().map(|c| c.copyright).collect();
+    let hs: Vec<String> = holders.into_iter().map(|h| h.holder).collect();
+
+    assert!(
+        cr.iter().any(|s| s == "Copyright January 2020 Foo"),
+        "copyrights: {cr:#?}"
+    );
+    assert_eq!(hs, vec!["Foo".to_string()], "holders: {hs:#?}");
+}
+
 #[test]
 fn test_detect_copr_with_multiple_dash_segments_not_truncated() {
     let content = "Copyright  or Copr. 2006 INRIA - CIRAD - INRA\n";
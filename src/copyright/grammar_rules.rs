@@ -70,6 +70,21 @@ pub(crate) static GRAMMAR_RULES: &[GrammarRule] = &[
         pattern: &[AnyTag(&[Cd, Cds, BareYr]), Tag(Yr), Tag(BareYr)],
     },
     //
+    // Rust-specific: month-name dates such as "January 2020" or "Jan 5, 2020".
+    // The lexer only tags MONTH before a year on a copyright line.
+    GrammarRule {
+        label: YrRange,
+        pattern: &[Tag(Month), Label(YrRange)],
+    },
+    GrammarRule {
+        label: YrRange,
+        pattern: &[Tag(Month), AnyTag(&[Cd, Cds]), Label(YrRange)],
+    },
+    GrammarRule {
+        label: YrRange,
+        pattern: &[Tag(Month), AnyTag(&[Cd, Cds]), Tag(Cc), Label(YrRange)],
+    },
+    //
     // #50  YR-RANGE: {<YR>+ <BARE-YR>?}
     // Expanded: YR (already covered by #40), YR YR, YR BARE-YR (covered), YR YR BARE-YR
     GrammarRule {
@@ -5530,6 +5545,12 @@ pub(crate) static GRAMMAR_RULES: &[GrammarRule] = &[
         label: Copyright,
         pattern: &[Tag(Copy), AnyLabel(&[Copyright, Copyright2])],
     },
+    // Rust-specific: year written before the copyright keyword on the same
+    // line, as in "2020 Copyright Foo Inc."
+    GrammarRule {
+        label: Copyright,
+        pattern: &[Label(YrRange), AnyLabel(&[Copyright, Copyright2])],
+    },
     // #999990
     GrammarRule {
         label: Copyright,
@@ -100,10 +100,75 @@ pub fn get_tokens(numbered_lines: &[(usize, String)]) -> Vec<Token> {
     }
 
     retag_camel_case_junk_before_company_suffix_in_copyright_context(&mut tokens);
+    retag_month_names_before_years_in_copyright_context(&mut tokens);
 
     tokens
 }
 
+const MONTH_NAMES: &[&str] = &[
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+/// Tag month names that start a date on a copyright line ("January 2020",
+/// "Jan 5, 2020") as `Month`, so the grammar folds them into the year range.
+///
+/// Full month names and the abbreviations that double as first names (`Jan`,
+/// `May`, `Jun`) are otherwise tagged as nouns, so this only fires when a year
+/// follows and a copyright keyword is on the same line.
+fn retag_month_names_before_years_in_copyright_context(tokens: &mut [Token]) {
+    for i in 0..tokens.len() {
+        if tokens[i].tag == PosTag::Month || !is_month_name(&tokens[i].value) {
+            continue;
+        }
+        let line = tokens[i].start_line;
+        let on_line = |index: usize| tokens.get(index).filter(|t| t.start_line == line);
+
+        let mut next = i + 1;
+        if on_line(next).is_some_and(|t| is_day_of_month(&t.value)) {
+            next += 1;
+        }
+        if on_line(next).is_some_and(|t| t.tag == PosTag::Cc && t.value == ",") {
+            next += 1;
+        }
+        if !on_line(next).is_some_and(|t| t.tag == PosTag::Yr) {
+            continue;
+        }
+
+        let has_copy_on_line = tokens
+            .iter()
+            .any(|t| t.start_line == line && t.tag == PosTag::Copy);
+        if has_copy_on_line {
+            tokens[i].tag = PosTag::Month;
+        }
+    }
+}
+
+fn is_month_name(value: &str) -> bool {
+    let value = value.trim_end_matches('.').to_ascii_lowercase();
+    value.len() >= 3
+        && MONTH_NAMES
+            .iter()
+            .any(|month| *month == value || (value.len() <= 4 && month.starts_with(&value)))
+}
+
+fn is_day_of_month(value: &str) -> bool {
+    value
+        .trim_end_matches(['.', ','])
+        .parse::<u8>()
+        .is_ok_and(|day| (1..=31).contains(&day))
+}
+
 fn retag_camel_case_junk_before_company_suffix_in_copyright_context(tokens: &mut [Token]) {
     if tokens.len() < 2 {
         return;
@@ -180,3 +180,26 @@ fn test_does_not_retag_all_caps_token_before_company_suffix() {
         "All-caps AS token should not be retagged as a name"
     );
 }
+
+#[test]
+fn test_retags_month_name_before_year_in_copyright_context() {
+    let lines = vec![(1, "Copyright January 5, 2020 Foo Inc.".to_string())];
+    let tokens = get_tokens(&lines);
+    let month = tokens
+        .iter()
+        .find(|t| t.value == "January")
+        .expect("January token should exist");
+    assert_eq!(month.tag, PosTag::Month);
+
+    let lines = vec![(1, "Meet Jan 2020 in Berlin".to_string())];
+    let tokens = get_tokens(&lines);
+    let jan = tokens
+        .iter()
+        .find(|t| t.value == "Jan")
+        .expect("Jan token should exist");
+    assert_ne!(
+        jan.tag,
+        PosTag::Month,
+        "Jan should stay a name outside copyright context"
+    );
+}
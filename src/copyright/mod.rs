@@ -27,7 +27,8 @@ mod types;
 #[cfg(all(test, feature = "golden-tests"))]
 mod golden_test;
 
-pub use credits::{detect_credits_authors, is_credits_file};
+pub use credits::{detect_credits_authors, detect_listed_authors, is_credits_file};
+pub use refiner::canonicalize_copyright;
 pub use types::{AuthorDetection, CopyrightDetection, HolderDetection};
 
 #[derive(Debug, Clone)]
@@ -36,6 +37,10 @@ pub struct CopyrightDetectionOptions {
     pub include_holders: bool,
     pub include_authors: bool,
     pub max_runtime: Option<Duration>,
+    /// Drop year-less, single-line copyrights found on lines where `copyright`
+    /// is a function call, such as `license_copyright(c, d)`. Off by default,
+    /// as ScanCode reports these.
+    pub suppress_code_like_copyrights: bool,
 }
 
 impl Default for CopyrightDetectionOptions {
@@ -45,6 +50,7 @@ impl Default for CopyrightDetectionOptions {
             include_holders: true,
             include_authors: true,
             max_runtime: None,
+            suppress_code_like_copyrights: false,
         }
     }
 }
@@ -78,6 +84,10 @@ pub fn detect_copyrights_with_options(
         detector::detect_copyrights_from_text(content)
     };
 
+    if options.suppress_code_like_copyrights {
+        suppress_code_like_copyrights(content, &mut copyrights, &mut holders);
+    }
+
     if !options.include_copyrights {
         copyrights.clear();
     }
@@ -91,9 +101,88 @@ pub fn detect_copyrights_with_options(
     (copyrights, holders, authors)
 }
 
+/// Remove single-line copyrights that [`refiner::is_code_like_copyright`]
+/// flags, along with the holders found on their lines. A notice continuing on
+/// the next line, such as `Copyright(c)` followed by the holder, is kept.
+fn suppress_code_like_copyrights(
+    content: &str,
+    copyrights: &mut Vec<CopyrightDetection>,
+    holders: &mut Vec<HolderDetection>,
+) {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut suppressed_lines = Vec::new();
+    copyrights.retain(|copyright| {
+        if copyright.start_line != copyright.end_line {
+            return true;
+        }
+        let source_line = lines
+            .get(copyright.start_line.saturating_sub(1))
+            .copied()
+            .unwrap_or_default();
+        let code_like = refiner::is_code_like_copyright(&copyright.copyright, source_line);
+        if code_like {
+            suppressed_lines.push((copyright.start_line, copyright.end_line));
+        }
+        !code_like
+    });
+    holders.retain(|holder| {
+        !suppressed_lines
+            .iter()
+            .any(|&(start, end)| start <= holder.start_line && holder.end_line <= end)
+    });
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{CopyrightDetectionOptions, detect_copyrights_with_options};
+    use super::{CopyrightDetectionOptions, detect_copyrights, detect_copyrights_with_options};
+
+    fn suppressing_code_calls() -> CopyrightDetectionOptions {
+        CopyrightDetectionOptions {
+            suppress_code_like_copyrights: true,
+            ..CopyrightDetectionOptions::default()
+        }
+    }
+
+    #[test]
+    fn test_code_like_copyright_call_is_suppressed() {
+        let content = "int ok = license_copyright(c, d);\nif (copyright(c)) {\n";
+        let (copyrights, holders, _authors) =
+            detect_copyrights_with_options(content, &suppressing_code_calls());
+        assert!(copyrights.is_empty(), "unexpected: {copyrights:?}");
+        assert!(holders.is_empty(), "unexpected: {holders:?}");
+
+        let (copyrights, holders, _authors) = detect_copyrights_with_options(
+            "/* Copyright (c) 2020 Foo */\n",
+            &suppressing_code_calls(),
+        );
+        assert_eq!(copyrights.len(), 1);
+        assert_eq!(copyrights[0].copyright, "Copyright (c) 2020 Foo");
+        assert_eq!(holders[0].holder, "Foo");
+    }
+
+    #[test]
+    fn test_code_like_copyright_suppression_is_off_by_default() {
+        let content = "int ok = license_copyright(c, d);\nif (copyright(c)) {\n";
+        assert_eq!(
+            detect_copyrights(content),
+            detect_copyrights_with_options(
+                content,
+                &CopyrightDetectionOptions {
+                    suppress_code_like_copyrights: false,
+                    ..CopyrightDetectionOptions::default()
+                }
+            )
+        );
+        assert!(!CopyrightDetectionOptions::default().suppress_code_like_copyrights);
+    }
+
+    #[test]
+    fn test_code_like_copyright_suppression_keeps_multi_line_notice() {
+        let content = "/* Copyright(c)\n * Acme Widgets Incorporated */\n";
+        let (default_copyrights, _, _) = detect_copyrights(content);
+        let (copyrights, _, _) = detect_copyrights_with_options(content, &suppressing_code_calls());
+        assert_eq!(copyrights, default_copyrights);
+    }
 
     #[test]
     fn test_options_can_disable_all_outputs() {
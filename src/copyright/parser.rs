@@ -148,6 +148,17 @@ fn matches_at(rule: &GrammarRule, nodes: &[ParseNode], start: usize) -> bool {
         return false;
     }
 
+    if rule.label == crate::copyright::types::TreeLabel::Copyright
+        && rule.pattern.len() == 2
+        && matches!(
+            rule.pattern[0],
+            TagMatcher::Label(crate::copyright::types::TreeLabel::YrRange)
+        )
+        && last_line(&nodes[start]) != first_line(&nodes[start + 1])
+    {
+        return false;
+    }
+
     for (i, matcher) in rule.pattern.iter().enumerate() {
         if !matcher_matches(matcher, &nodes[start + i]) {
             return false;
@@ -9,6 +9,7 @@ use std::collections::HashSet;
 use std::sync::LazyLock;
 
 use regex::Regex;
+use unicode_normalization::UnicodeNormalization;
 
 use super::candidates::strip_balanced_edge_parens;
 
@@ -1968,6 +1969,24 @@ pub fn is_junk_copyright(s: &str) -> bool {
     COPYRIGHTS_JUNK_PATTERNS.iter().any(|re| re.is_match(s))
 }
 
+/// Return true if a copyright without a year was detected on `source_line`
+/// where the `copyright` word is really code: an identifier called with
+/// identifier arguments, such as `license_copyright(c, d)` or `copyright(c);`.
+///
+/// The call must be glued to its parenthesis and followed only by code
+/// punctuation, so prose like `Copyright(c) Foo Inc.` is kept.
+pub fn is_code_like_copyright(copyright: &str, source_line: &str) -> bool {
+    static YEAR_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b(?:19|20)\d{2}\b").unwrap());
+    static CODE_CALL_RE: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(
+            r"(?i)\b[a-z0-9_]*copyright[a-z0-9_]*\(\s*[a-z_][a-z0-9_]*(?:\s*,\s*[a-z_][a-z0-9_]*)*\s*\)\s*(?:[;,{)&|+=:?]|$)",
+        )
+        .unwrap()
+    });
+
+    !YEAR_RE.is_match(copyright) && CODE_CALL_RE.is_match(source_line.trim_end())
+}
+
 /// Return true if `s` matches any known junk holder pattern.
 fn is_junk_holder(s: &str) -> bool {
     HOLDERS_JUNK_PATTERNS.iter().any(|re| re.is_match(s))
@@ -3034,10 +3053,17 @@ fn strip_parenthesized_emails(s: &str) -> String {
     normalize_whitespace(&PAREN_EMAIL_RE.replace_all(s, " "))
 }
 
+/// Compose `s` to Unicode NFC so that the same name written with precomposed
+/// or combining accents compares, dedupes and is reported identically.
+fn to_nfc(s: &str) -> String {
+    s.nfc().collect()
+}
+
 fn refine_holder_impl(s: &str, in_copyright_context: bool) -> Option<String> {
     if s.is_empty() {
         return None;
     }
+    let s = &to_nfc(s);
 
     let had_paren_email =
         in_copyright_context && s.contains('@') && s.contains('(') && s.contains(')');
@@ -3457,6 +3483,33 @@ fn strip_trailing_single_digit_token(s: &str) -> String {
     prefix.to_string()
 }
 
+/// Canonicalize a refined copyright statement to `Copyright (c) <years> <holder>`.
+///
+/// Leading copyright markers in any spelling (`©`, `(C)`, `Copr.`,
+/// `Copyright (c)`, `&copy;`, ...) are collapsed into a single
+/// `Copyright (c)` prefix and spaced year ranges are tightened. Statements
+/// that do not start with a copyright marker are returned with whitespace
+/// normalized only.
+pub fn canonicalize_copyright(s: &str) -> String {
+    static LEADING_MARKERS_RE: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"(?i)^(?:(?:(?:copyright(?:ed)?|copr)\b\.?|\(c\)|©|&copy;?|&#169;)[\s,:]*)+")
+            .unwrap()
+    });
+    static YEAR_RANGE_RE: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"\b((?:19|20)\d{2})\s*-\s*((?:19|20)\d{2})\b").unwrap());
+
+    let normalized = normalize_whitespace(s);
+    let Some(markers) = LEADING_MARKERS_RE.find(&normalized) else {
+        return normalized;
+    };
+    let rest = normalized[markers.end()..].trim();
+    let rest = YEAR_RANGE_RE.replace_all(rest, "$1-$2");
+    if rest.is_empty() {
+        return "Copyright (c)".to_string();
+    }
+    format!("Copyright (c) {rest}")
+}
+
 #[path = "refiner_author.rs"]
 mod author;
 #[path = "refiner_utils.rs"]
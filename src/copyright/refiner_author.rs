@@ -5,7 +5,7 @@ pub fn refine_author(s: &str) -> Option<String> {
     if s.is_empty() {
         return None;
     }
-    let mut a = remove_some_extra_words_and_punct(s);
+    let mut a = remove_some_extra_words_and_punct(&to_nfc(s));
     a = strip_trailing_javadoc_tags(&a);
     a = strip_trailing_paren_years(&a);
     a = strip_trailing_bare_c_copyright_clause(&a);
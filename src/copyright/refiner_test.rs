@@ -2,6 +2,26 @@ use super::*;
 
 // ── debug tests ──────────────────────────────────────────────────
 
+#[test]
+fn test_is_code_like_copyright() {
+    assert!(is_code_like_copyright(
+        "copyright(c, d)",
+        "    return license_copyright(c, d);"
+    ));
+    assert!(is_code_like_copyright(
+        "copyright(c)",
+        "if (copyright(c)) {"
+    ));
+    assert!(!is_code_like_copyright(
+        "Copyright(c) Foo Inc.",
+        "Copyright(c) Foo Inc."
+    ));
+    assert!(!is_code_like_copyright(
+        "Copyright (c) 2020 Foo",
+        "x = copyright(c); // Copyright (c) 2020 Foo"
+    ));
+}
+
 #[test]
 fn test_strip_trailing_original_authors() {
     assert_eq!(
@@ -789,6 +809,19 @@ fn test_refine_holder_basic() {
     assert_eq!(result, Some("Acme Inc.".to_string()));
 }
 
+#[test]
+fn test_refine_holder_dedupes_nfc_and_nfd_forms_to_nfc() {
+    let nfc = "Jos\u{e9} Mu\u{f1}oz";
+    let nfd = "Jose\u{301} Mun\u{303}oz";
+    assert_ne!(nfc, nfd);
+
+    let holders: HashSet<String> = [nfc, nfd].into_iter().filter_map(refine_holder).collect();
+
+    assert_eq!(holders.len(), 1);
+    assert!(holders.contains(nfc));
+    assert_eq!(refine_author(nfd), Some(nfc.to_string()));
+}
+
 #[test]
 fn test_refine_holder_removes_embedded_url_token() {
     let result = refine_holder("the http://wtforms.simplecodes.com WTForms Team");
@@ -1265,3 +1298,25 @@ fn test_refine_author_drops_d3_transition_markdown_link_fragment() {
         None
     );
 }
+
+#[test]
+fn test_canonicalize_copyright_unifies_marker_spellings() {
+    let expected = "Copyright (c) 2020 Foo";
+    assert_eq!(canonicalize_copyright("© 2020 Foo"), expected);
+    assert_eq!(canonicalize_copyright("(C) 2020 Foo"), expected);
+    assert_eq!(canonicalize_copyright("Copr. 2020 Foo"), expected);
+    assert_eq!(canonicalize_copyright("Copyright (c) 2020 Foo"), expected);
+    assert_eq!(canonicalize_copyright("COPYRIGHT © 2020 Foo"), expected);
+}
+
+#[test]
+fn test_canonicalize_copyright_tightens_year_ranges_and_keeps_other_text() {
+    assert_eq!(
+        canonicalize_copyright("Copyright 2018 - 2020   Foo Inc."),
+        "Copyright (c) 2018-2020 Foo Inc."
+    );
+    assert_eq!(
+        canonicalize_copyright("Portions (c) 2020 Foo"),
+        "Portions (c) 2020 Foo"
+    );
+}
@@ -82,6 +82,7 @@ mod tests {
             max_emails,
             max_urls: 50,
             unique: true,
+            exclude_common_urls: false,
         };
 
         for file in expected.files {
@@ -102,6 +103,7 @@ mod tests {
             max_emails: 50,
             max_urls,
             unique: true,
+            exclude_common_urls: false,
         };
 
         for file in expected.files {
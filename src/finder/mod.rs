@@ -6,13 +6,15 @@ mod junk_data;
 mod urls;
 
 pub use emails::find_emails;
-pub use urls::find_urls;
+pub use urls::{UrlDetection, detect_urls, find_urls};
 
 #[derive(Debug, Clone)]
 pub struct DetectionConfig {
     pub max_emails: usize,
     pub max_urls: usize,
     pub unique: bool,
+    /// Skip XML namespace and schema URLs and links to well-known license texts.
+    pub exclude_common_urls: bool,
 }
 
 impl Default for DetectionConfig {
@@ -21,13 +23,14 @@ impl Default for DetectionConfig {
             max_emails: 50,
             max_urls: 50,
             unique: true,
+            exclude_common_urls: false,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{DetectionConfig, find_emails, find_urls};
+    use super::{DetectionConfig, detect_urls, find_emails, find_urls};
 
     #[test]
     fn test_find_emails_threshold() {
@@ -54,4 +57,35 @@ mod tests {
         assert_eq!(urls[0].url, "http://a.com/");
         assert_eq!(urls[1].url, "http://b.com/");
     }
+
+    #[test]
+    fn test_find_urls_excludes_common_urls_when_enabled() {
+        let text = "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">\n\
+                    Licensed under http://www.apache.org/licenses/LICENSE-2.0\n\
+                    See https://github.com/acme/project for details\n";
+        let all = find_urls(text, &DetectionConfig::default());
+        assert_eq!(all.len(), 3);
+
+        let config = DetectionConfig {
+            exclude_common_urls: true,
+            ..Default::default()
+        };
+        let urls = find_urls(text, &config);
+        assert_eq!(urls.len(), 1);
+        assert_eq!(urls[0].url, "https://github.com/acme/project");
+        assert_eq!(urls[0].start_line, 3);
+    }
+
+    #[test]
+    fn test_detect_urls_deduplicates_without_limit() {
+        let text = (1..=60)
+            .map(|n| format!("https://host{n}.acme.io/\n"))
+            .chain(["https://host1.acme.io/\ngit@github.com:org/repo.git\n".to_string()])
+            .collect::<String>();
+        let urls = detect_urls(&text, false);
+        assert_eq!(urls.len(), 61);
+        assert_eq!(urls[60].url, "git@github.com:org/repo.git");
+        assert_eq!(urls[60].start_line, 62);
+        assert_eq!(urls[60].end_line, 62);
+    }
 }
@@ -36,6 +36,24 @@ static INVALID_URLS_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
 
 const EMPTY_URLS: &[&str] = &["https", "http", "ftp", "www"];
 
+/// Host and path prefixes, without `www.`, of URLs skipped by
+/// `DetectionConfig::exclude_common_urls`.
+const COMMON_URL_PREFIXES: &[&str] = &[
+    "w3.org/",
+    "schemas.xmlsoap.org/",
+    "schemas.microsoft.com/",
+    "json-schema.org/",
+    "purl.org/dc/",
+    "apache.org/licenses/",
+    "gnu.org/licenses/",
+    "gnu.org/copyleft/",
+    "fsf.org/",
+    "opensource.org/licenses/",
+    "creativecommons.org/licenses/",
+    "creativecommons.org/publicdomain/",
+    "spdx.org/licenses/",
+];
+
 fn is_filterable(url: &str) -> bool {
     !url.starts_with("git@")
 }
@@ -88,6 +106,20 @@ fn remove_user_password(url: &str) -> Option<String> {
     Some(parsed.to_string())
 }
 
+fn is_common_url(url: &str) -> bool {
+    let Ok(parsed) = Url::parse(url) else {
+        return false;
+    };
+    let Some(host) = parsed.host_str() else {
+        return false;
+    };
+    let host = host.strip_prefix("www.").unwrap_or(host);
+    let location = format!("{host}{}", parsed.path()).to_ascii_lowercase();
+    COMMON_URL_PREFIXES
+        .iter()
+        .any(|prefix| location.starts_with(prefix))
+}
+
 fn canonical_url(url: &str) -> Option<String> {
     if !is_filterable(url) {
         return Some(url.to_string());
@@ -131,6 +163,9 @@ pub fn find_urls(text: &str, config: &DetectionConfig) -> Vec<UrlDetection> {
             if !classify_url(&candidate.to_ascii_lowercase()) {
                 continue;
             }
+            if config.exclude_common_urls && is_common_url(&candidate) {
+                continue;
+            }
 
             detections.push(UrlDetection {
                 url: candidate,
@@ -156,3 +191,14 @@ pub fn find_urls(text: &str, config: &DetectionConfig) -> Vec<UrlDetection> {
 
     detections
 }
+
+/// Find every distinct URL in `content`, with the line it was found on,
+/// optionally skipping common URLs.
+pub fn detect_urls(content: &str, exclude_common_urls: bool) -> Vec<UrlDetection> {
+    let config = DetectionConfig {
+        max_urls: 0,
+        exclude_common_urls,
+        ..DetectionConfig::default()
+    };
+    find_urls(content, &config)
+}
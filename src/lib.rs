@@ -8,6 +8,7 @@
 //!
 //! - [`collect_paths`] to discover files in a directory tree
 //! - [`process_collected`] to scan collected files in parallel
+//! - [`scan_text`] to scan in-memory content without a directory walk
 //! - [`OutputFormat`], [`OutputWriter`], and [`write_output_file`] to serialize scan results
 //! - [`parsers`] and [`models`] for lower-level package parsing and result inspection
 //!
@@ -37,14 +38,16 @@ pub mod output;
 pub mod parsers;
 pub mod progress;
 pub mod scanner;
+pub mod serve;
 pub mod utils;
 
 pub use models::{ExtraData, FileInfo, FileType, Header, Output, SystemEnvironment};
 pub use output::{
     OutputFormat, OutputWriteConfig, OutputWriter, write_output_file, writer_for_format,
 };
-pub use parsers::{NpmParser, PackageParser};
+pub use parsers::{DynPackageParser, NpmParser, PackageParser, register_parser};
 pub use progress::{ProgressMode, ScanProgress};
 pub use scanner::{
-    CollectedPaths, ProcessResult, TextDetectionOptions, collect_paths, process_collected,
+    CollectedPaths, ProcessResult, TextDetectionOptions, collect_paths,
+    collect_paths_with_symlinks, process_collected, scan_text, scan_text_with_options,
 };
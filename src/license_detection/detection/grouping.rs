@@ -46,6 +46,9 @@ pub(super) fn group_matches_by_region_with_threshold(
             }
             groups.push(DetectionGroup::new(vec![match_item.clone()]));
             current_group = Vec::new();
+        } else if is_separate_full_license_text(previous_match, match_item) {
+            groups.push(DetectionGroup::new(current_group.clone()));
+            current_group = vec![match_item.clone()];
         } else if should_group_together(previous_match, match_item, proximity_threshold) {
             current_group.push(match_item.clone());
         } else {
@@ -63,6 +66,19 @@ pub(super) fn group_matches_by_region_with_threshold(
     groups
 }
 
+/// Check if `cur` starts a different complete license text right after `prev`.
+///
+/// Files such as `COPYING` often concatenate several full license texts with
+/// only a separator line between them. Each complete text is its own region,
+/// even when the line gap is within the grouping threshold.
+fn is_separate_full_license_text(prev: &LicenseMatch, cur: &LicenseMatch) -> bool {
+    let is_complete_text = |m: &LicenseMatch| m.is_license_text() && m.match_coverage >= 99.0;
+    is_complete_text(prev)
+        && is_complete_text(cur)
+        && prev.license_expression != cur.license_expression
+        && cur.start_line > prev.end_line
+}
+
 /// Check if two matches should be in the same group based on line proximity.
 ///
 /// Matches are grouped together when line gap is within threshold.
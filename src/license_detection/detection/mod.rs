@@ -316,6 +316,33 @@ pub fn filter_detections_by_score(
         .collect()
 }
 
+/// Drop detections whose longest match spans fewer than `min_matched_length`
+/// tokens, unless that match is an exact match of the whole rule.
+///
+/// A threshold of `0` keeps every detection.
+pub fn filter_detections_by_matched_length(
+    detections: Vec<LicenseDetection>,
+    min_matched_length: usize,
+) -> Vec<LicenseDetection> {
+    if min_matched_length == 0 {
+        return detections;
+    }
+
+    detections
+        .into_iter()
+        .filter(|detection| {
+            detection
+                .matches
+                .iter()
+                .max_by_key(|m| m.matched_length)
+                .is_none_or(|best| {
+                    best.matched_length >= min_matched_length
+                        || (best.matched_length == best.rule_length && best.match_coverage >= 100.0)
+                })
+        })
+        .collect()
+}
+
 /// Remove duplicate detections (same identifier).
 ///
 /// Groups detections by their identifier (license expression + content hash).
@@ -356,6 +383,11 @@ pub fn remove_duplicate_detections(detections: Vec<LicenseDetection>) -> Vec<Lic
 /// 1. Detection score (higher is better)
 /// 2. Detection coverage (higher is better)
 ///
+/// Remaining ties go to the shorter total rule length, then the smaller
+/// license expression, rule identifiers and detection identifier.
+/// [`sort_detections_by_line`] is stable, so this ranking decides the output
+/// order of detections that start on the same line.
+///
 /// Based on Python: sort_unique_detections() at detection.py:1003
 pub fn rank_detections(mut detections: Vec<LicenseDetection>) -> Vec<LicenseDetection> {
     detections.sort_by(|a, b| {
@@ -364,27 +396,47 @@ pub fn rank_detections(mut detections: Vec<LicenseDetection>) -> Vec<LicenseDete
         let coverage_a = compute_detection_coverage(&a.matches);
         let coverage_b = compute_detection_coverage(&b.matches);
 
+        // Ties on score and coverage (e.g. a notice matching two close license
+        // variants) are broken on stable rule properties so the winner does not
+        // depend on the order candidates were produced in.
         score_b
             .partial_cmp(&score_a)
             .unwrap()
             .then_with(|| coverage_b.partial_cmp(&coverage_a).unwrap())
+            .then_with(|| detection_rule_length(a).cmp(&detection_rule_length(b)))
+            .then_with(|| a.license_expression.cmp(&b.license_expression))
+            .then_with(|| detection_rule_identifiers(a).cmp(&detection_rule_identifiers(b)))
             .then_with(|| a.identifier.cmp(&b.identifier))
     });
 
     detections
 }
 
+fn detection_rule_length(detection: &LicenseDetection) -> usize {
+    detection.matches.iter().map(|m| m.rule_length).sum()
+}
+
+fn detection_rule_identifiers(detection: &LicenseDetection) -> Vec<&str> {
+    detection
+        .matches
+        .iter()
+        .map(|m| m.rule_identifier.as_str())
+        .collect()
+}
+
 /// Sort detections by minimum line number (earliest match first).
 ///
 /// This matches Python's qstart ordering, ensuring detections
-/// earlier in the file come first in the results.
+/// earlier in the file come first in the results. The sort is stable:
+/// detections starting on the same line keep their incoming (ranked) order.
 pub fn sort_detections_by_line(mut detections: Vec<LicenseDetection>) -> Vec<LicenseDetection> {
-    detections.sort_by(|a, b| {
-        let min_line_a = a.matches.iter().map(|m| m.start_line).min().unwrap_or(0);
-        let min_line_b = b.matches.iter().map(|m| m.start_line).min().unwrap_or(0);
-        min_line_a
-            .cmp(&min_line_b)
-            .then_with(|| a.identifier.cmp(&b.identifier))
+    detections.sort_by_key(|detection| {
+        detection
+            .matches
+            .iter()
+            .map(|m| m.start_line)
+            .min()
+            .unwrap_or(0)
     });
     detections
 }
@@ -408,6 +460,8 @@ pub fn apply_detection_preferences(detections: Vec<LicenseDetection>) -> Vec<Lic
 ///
 /// * `detections` - Raw detections from detection grouping
 /// * `min_score` - Minimum score threshold (default: 90.0)
+/// * `min_matched_length` - Minimum token length of a detection's longest
+///   match, exact full-rule matches excepted (`0` disables the filter)
 ///
 /// # Returns
 ///
@@ -415,8 +469,12 @@ pub fn apply_detection_preferences(detections: Vec<LicenseDetection>) -> Vec<Lic
 pub fn post_process_detections(
     detections: Vec<LicenseDetection>,
     min_score: f32,
+    min_matched_length: usize,
 ) -> Vec<LicenseDetection> {
-    let filtered = filter_detections_by_score(detections, min_score);
+    let filtered = filter_detections_by_matched_length(
+        filter_detections_by_score(detections, min_score),
+        min_matched_length,
+    );
     let promoted = promote_non_clue_no_expression_detections(filtered);
     // NOTE: We do NOT call remove_duplicate_detections here.
     //
@@ -577,6 +635,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_group_matches_by_region_splits_adjacent_full_license_texts() {
+        let mut mit = create_perfect_match(1, 21);
+        mit.rule_kind = crate::license_detection::models::RuleKind::Text;
+        let mut gpl = create_perfect_match(23, 696);
+        gpl.rule_kind = crate::license_detection::models::RuleKind::Text;
+        gpl.license_expression = "gpl-3.0".to_string();
+        gpl.rule_identifier = "gpl-3.0.LICENSE".to_string();
+
+        let groups = group_matches_by_region(&[mit.clone(), gpl.clone()]);
+        assert_eq!(groups.len(), 2);
+
+        // A second copy of the same text stays in one region, as before.
+        let mut mit_again = mit.clone();
+        mit_again.start_line = 23;
+        mit_again.end_line = 43;
+        assert_eq!(group_matches_by_region(&[mit, mit_again]).len(), 1);
+
+        // Partial texts keep the plain line-proximity grouping.
+        let mut partial_gpl = gpl;
+        partial_gpl.match_coverage = 60.0;
+        let mut mit = create_perfect_match(1, 21);
+        mit.rule_kind = crate::license_detection::models::RuleKind::Text;
+        assert_eq!(group_matches_by_region(&[mit, partial_gpl]).len(), 1);
+    }
+
     #[test]
     fn test_create_detection_from_group_empty() {
         let group = DetectionGroup::new(Vec::new());
@@ -934,6 +1018,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_rank_detections_breaks_ties_deterministically() {
+        let tied = |expression: &str, rule_identifier: &str, rule_length: usize| {
+            let mut m = create_test_match(1, 3, "3-seq", rule_identifier);
+            m.license_expression = expression.to_string();
+            m.rule_length = rule_length;
+            LicenseDetection {
+                license_expression: Some(expression.to_string()),
+                license_expression_spdx: None,
+                matches: vec![m],
+                detection_log: vec![],
+                identifier: None,
+                file_regions: Vec::new(),
+            }
+        };
+        let candidates = || {
+            vec![
+                tied("bsd-new", "bsd-new_12.RULE", 40),
+                tied("bsd-modified", "bsd-modified_3.RULE", 40),
+                tied("bsd-new", "bsd-new_7.RULE", 40),
+                tied("bsd-original", "bsd-original_1.RULE", 38),
+            ]
+        };
+        let ranked_rules = |detections: Vec<LicenseDetection>| -> Vec<String> {
+            rank_detections(detections)
+                .into_iter()
+                .map(|d| d.matches[0].rule_identifier.clone())
+                .collect()
+        };
+
+        let expected = vec![
+            "bsd-original_1.RULE".to_string(),
+            "bsd-modified_3.RULE".to_string(),
+            "bsd-new_12.RULE".to_string(),
+            "bsd-new_7.RULE".to_string(),
+        ];
+        for _ in 0..5 {
+            assert_eq!(ranked_rules(candidates()), expected);
+            let mut reversed = candidates();
+            reversed.reverse();
+            assert_eq!(ranked_rules(reversed), expected);
+        }
+    }
+
     #[test]
     fn test_rank_detections_empty() {
         let result = rank_detections(vec![]);
@@ -1058,7 +1186,7 @@ mod tests {
             file_regions: Vec::new(),
         };
         d.identifier = Some(compute_detection_identifier(&d));
-        let result = post_process_detections(vec![d], 0.0);
+        let result = post_process_detections(vec![d], 0.0, 0);
         assert_eq!(result.len(), 1);
     }
 
@@ -1077,13 +1205,86 @@ mod tests {
             file_regions: Vec::new(),
         };
         d.identifier = Some(compute_detection_identifier(&d));
-        let result = post_process_detections(vec![d], 50.0);
+        let result = post_process_detections(vec![d], 50.0, 0);
         assert!(result.is_empty());
     }
 
+    #[test]
+    fn test_post_process_detections_drops_short_partial_matches() {
+        let mut fragment = create_test_match(1, 1, "3-seq", "mit_fragment.RULE");
+        fragment.matched_length = 2;
+        fragment.rule_length = 12;
+        fragment.match_coverage = 16.67;
+        let mut short = LicenseDetection {
+            license_expression: Some("mit".to_string()),
+            license_expression_spdx: Some("MIT".to_string()),
+            matches: vec![fragment],
+            detection_log: vec![],
+            identifier: None,
+            file_regions: Vec::new(),
+        };
+        short.identifier = Some(compute_detection_identifier(&short));
+
+        let mut full = LicenseDetection {
+            license_expression: Some("mit".to_string()),
+            license_expression_spdx: Some("MIT".to_string()),
+            matches: vec![create_perfect_match(5, 25)],
+            detection_log: vec![],
+            identifier: None,
+            file_regions: Vec::new(),
+        };
+        full.identifier = Some(compute_detection_identifier(&full));
+
+        let result = post_process_detections(vec![short, full], 0.0, 5);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].matches[0].start_line, 5);
+    }
+
+    #[test]
+    fn test_post_process_detections_orders_same_line_ties_by_rank() {
+        let tied = |expression: &str, rule_identifier: &str, rule_length: usize| {
+            let mut m = create_test_match(1, 3, "3-seq", rule_identifier);
+            m.license_expression = expression.to_string();
+            m.rule_length = rule_length;
+            let mut detection = LicenseDetection {
+                license_expression: Some(expression.to_string()),
+                license_expression_spdx: None,
+                matches: vec![m],
+                detection_log: vec![],
+                identifier: None,
+                file_regions: Vec::new(),
+            };
+            detection.identifier = Some(compute_detection_identifier(&detection));
+            detection
+        };
+        let processed_expressions = |detections: Vec<LicenseDetection>| -> Vec<String> {
+            post_process_detections(detections, 0.0, 0)
+                .into_iter()
+                .filter_map(|d| d.license_expression)
+                .collect()
+        };
+
+        // By identifier alone "bsd-new" would come first; the shorter rule wins.
+        let expected = vec!["mit".to_string(), "bsd-new".to_string()];
+        assert_eq!(
+            processed_expressions(vec![
+                tied("bsd-new", "bsd-new_7.RULE", 40),
+                tied("mit", "mit_5.RULE", 20),
+            ]),
+            expected
+        );
+        assert_eq!(
+            processed_expressions(vec![
+                tied("mit", "mit_5.RULE", 20),
+                tied("bsd-new", "bsd-new_7.RULE", 40),
+            ]),
+            expected
+        );
+    }
+
     #[test]
     fn test_post_process_detections_empty() {
-        let result = post_process_detections(vec![], 0.0);
+        let result = post_process_detections(vec![], 0.0, 0);
         assert!(result.is_empty());
     }
 
@@ -1116,7 +1317,7 @@ mod tests {
             file_regions: Vec::new(),
         };
 
-        let result = post_process_detections(vec![proper, low_quality], 0.0);
+        let result = post_process_detections(vec![proper, low_quality], 0.0, 0);
         let promoted = result
             .iter()
             .find(|detection| {
@@ -1160,7 +1361,7 @@ mod tests {
             file_regions: Vec::new(),
         };
 
-        let result = post_process_detections(vec![proper, clue], 0.0);
+        let result = post_process_detections(vec![proper, clue], 0.0, 0);
         let preserved_clue = result
             .iter()
             .find(|detection| {
@@ -1420,7 +1621,7 @@ mod tests {
             }],
         };
 
-        let processed = post_process_detections(vec![first, second], 0.0);
+        let processed = post_process_detections(vec![first, second], 0.0, 0);
 
         assert_eq!(processed.len(), 2);
         assert_eq!(processed[0].file_regions.len(), 2);
@@ -453,6 +453,55 @@ fn combine_expressions_with(
     }
 }
 
+/// Normalize a license expression into a canonical string.
+///
+/// Duplicate licenses are removed, nested `AND`/`OR` chains of the same
+/// operator are flattened, absorbed operands are dropped (`(A OR B) AND A`
+/// becomes `A`), and the operands of every chain are sorted so equivalent
+/// expressions render identically. `WITH` clauses keep their license and
+/// exception in place. Keys are lowercased as by [`parse_expression`];
+/// expressions that fail to parse are returned trimmed but otherwise as is.
+///
+/// [`parse_expression`]: super::parse_expression
+pub fn normalize_expression(expr: &str) -> String {
+    match super::parse::parse_expression(expr) {
+        Ok(parsed) => render_flat(&sort_operands(&simplify_expression(&parsed))),
+        Err(_) => expr.trim().to_string(),
+    }
+}
+
+fn sort_operands(expr: &LicenseExpression) -> LicenseExpression {
+    match expr {
+        LicenseExpression::And { .. } | LicenseExpression::Or { .. } => {
+            let mut operands: Vec<LicenseExpression> =
+                get_flat_args(expr).iter().map(sort_operands).collect();
+            operands.sort_by_cached_key(render_flat);
+            build_expression_from_list(&operands, matches!(expr, LicenseExpression::And { .. }))
+        }
+        _ => expr.clone(),
+    }
+}
+
+/// Render chains of one operator without inner parentheses (`a AND b AND c`).
+fn render_flat(expr: &LicenseExpression) -> String {
+    let separator = match expr {
+        LicenseExpression::And { .. } => " AND ",
+        LicenseExpression::Or { .. } => " OR ",
+        _ => return expression_to_string(expr),
+    };
+    // Flattened operands are never chains of the same operator.
+    get_flat_args(expr)
+        .iter()
+        .map(|operand| match operand {
+            LicenseExpression::And { .. } | LicenseExpression::Or { .. } => {
+                format!("({})", render_flat(operand))
+            }
+            _ => render_flat(operand),
+        })
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
 /// Combine multiple license expressions with `AND`.
 ///
 /// This function parses each expression string, combines them with `AND`, and
@@ -857,6 +906,42 @@ mod tests {
         assert!(result.contains("gpl-2.0"));
     }
 
+    #[test]
+    fn test_normalize_expression_sorts_and_deduplicates() {
+        assert_eq!(
+            normalize_expression("MIT AND Apache-2.0 AND mit"),
+            "apache-2.0 AND mit"
+        );
+        assert_eq!(
+            normalize_expression("bsd-new OR (mit OR apache-2.0)"),
+            "apache-2.0 OR bsd-new OR mit"
+        );
+        assert_eq!(normalize_expression("mit OR mit"), "mit");
+        assert_eq!(
+            normalize_expression("zlib AND (mit OR apache-2.0)"),
+            normalize_expression("(apache-2.0 OR mit) AND zlib")
+        );
+    }
+
+    #[test]
+    fn test_normalize_expression_absorbs_subsumed_operands() {
+        assert_eq!(normalize_expression("(mit OR apache-2.0) AND mit"), "mit");
+        assert_eq!(normalize_expression("(mit AND apache-2.0) OR mit"), "mit");
+    }
+
+    #[test]
+    fn test_normalize_expression_keeps_with_clauses_in_place() {
+        assert_eq!(
+            normalize_expression("mit OR gpl-2.0 WITH classpath-exception-2.0"),
+            "gpl-2.0 WITH classpath-exception-2.0 OR mit"
+        );
+    }
+
+    #[test]
+    fn test_normalize_expression_returns_unparsable_input_trimmed() {
+        assert_eq!(normalize_expression("  mit AND ( "), "mit AND (");
+    }
+
     #[test]
     fn test_expression_to_string_with_no_outer_parens() {
         let with_expr = LicenseExpression::With {
@@ -0,0 +1,117 @@
+//! On-disk cache of a license index built from a rules directory.
+//!
+//! The index itself is stored in the same compressed archive format as the
+//! embedded artifact, wrapped in an envelope that records the fingerprint of
+//! the license data it was built from so stale caches can be detected.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::license_detection::embedded::index::SCHEMA_VERSION as EMBEDDED_SCHEMA_VERSION;
+
+/// Bumped whenever the envelope layout changes.
+const INDEX_CACHE_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct IndexCacheFile {
+    schema_version: u32,
+    embedded_schema_version: u32,
+    engine_version: String,
+    pub(crate) rules_fingerprint: Option<String>,
+    pub(crate) license_list_version: String,
+    pub(crate) load_errors: Vec<String>,
+    /// Index serialized with `EmbeddedLicenseIndex::serialize_to_bytes`.
+    pub(crate) index: Vec<u8>,
+}
+
+impl IndexCacheFile {
+    pub(crate) fn new(
+        rules_fingerprint: Option<String>,
+        license_list_version: String,
+        load_errors: Vec<String>,
+        index: Vec<u8>,
+    ) -> Self {
+        Self {
+            schema_version: INDEX_CACHE_SCHEMA_VERSION,
+            embedded_schema_version: EMBEDDED_SCHEMA_VERSION,
+            engine_version: env!("CARGO_PKG_VERSION").to_string(),
+            rules_fingerprint,
+            license_list_version,
+            load_errors,
+            index,
+        }
+    }
+}
+
+pub(crate) fn read_index_cache(path: &Path) -> Result<IndexCacheFile> {
+    let bytes = fs::read(path)
+        .with_context(|| format!("Failed to read license index cache: {}", path.display()))?;
+    let cache: IndexCacheFile = rmp_serde::from_slice(&bytes)
+        .with_context(|| format!("Invalid license index cache: {}", path.display()))?;
+    if cache.schema_version != INDEX_CACHE_SCHEMA_VERSION
+        || cache.embedded_schema_version != EMBEDDED_SCHEMA_VERSION
+        || cache.engine_version != env!("CARGO_PKG_VERSION")
+    {
+        return Err(anyhow!(
+            "License index cache was written by an incompatible version: {}",
+            path.display()
+        ));
+    }
+    Ok(cache)
+}
+
+/// Write `cache` to `path` through a temporary file so concurrent readers never
+/// see a partial cache.
+pub(crate) fn write_index_cache(path: &Path, cache: &IndexCacheFile) -> Result<()> {
+    let parent = path
+        .parent()
+        .ok_or_else(|| anyhow!("License index cache path has no parent: {}", path.display()))?;
+    fs::create_dir_all(parent)?;
+
+    let bytes = rmp_serde::to_vec(cache)?;
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("license-index");
+    let temp_path = parent.join(format!(".tmp-{file_name}-{}", Uuid::new_v4()));
+    let result = fs::write(&temp_path, bytes).and_then(|()| fs::rename(&temp_path, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result.with_context(|| format!("Failed to write license index cache: {}", path.display()))
+}
+
+/// SHA-256 over the names and contents of the files directly inside `dirs`,
+/// plus the SPDX license list `version`, in a stable order.
+pub(crate) fn license_data_fingerprint(dirs: &[&Path], version: Option<&str>) -> Result<String> {
+    let mut files: Vec<PathBuf> = Vec::new();
+    for dir in dirs {
+        let entries = fs::read_dir(dir)
+            .with_context(|| format!("Failed to read license data directory: {}", dir.display()))?;
+        for entry in entries {
+            let path = entry?.path();
+            if path.is_file() {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    files.dedup();
+
+    let mut hasher = Sha256::new();
+    hasher.update(version.unwrap_or_default().as_bytes());
+    for path in &files {
+        let content = fs::read(path)
+            .with_context(|| format!("Failed to read license data file: {}", path.display()))?;
+        let name = path.file_name().unwrap_or_default();
+        hasher.update(name.to_string_lossy().as_bytes());
+        hasher.update((content.len() as u64).to_le_bytes());
+        hasher.update(&content);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
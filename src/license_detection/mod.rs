@@ -12,8 +12,10 @@ pub mod expression;
 mod golden_test;
 pub mod hash_match;
 pub mod index;
+mod index_cache;
 mod match_refine;
 pub mod models;
+pub mod multi_component;
 pub mod query;
 pub mod rules;
 pub mod seq_match;
@@ -27,18 +29,24 @@ pub mod unknown_match;
 
 use bit_set::BitSet;
 use std::collections::HashSet;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::Result;
 
 use crate::license_detection::embedded::index::{
-    embedded_index_artifact_setup_hint, load_license_index_from_bytes,
+    EmbeddedLicenseIndex, embedded_index_artifact_setup_hint, load_license_index_from_bytes,
+};
+use crate::license_detection::expression::parse_expression;
+use crate::license_detection::index::{
+    build_index, build_index_from_loaded, loaded_license_to_license, loaded_rule_to_rule,
+};
+use crate::license_detection::index_cache::{
+    IndexCacheFile, license_data_fingerprint, read_index_cache, write_index_cache,
 };
-use crate::license_detection::index::build_index_from_loaded;
 use crate::license_detection::query::Query;
 use crate::license_detection::rules::{
-    load_loaded_licenses_from_directory, load_loaded_rules_from_directory,
+    load_loaded_licenses_from_directory_with_errors, load_loaded_rules_from_directory_with_errors,
 };
 use crate::license_detection::spdx_mapping::{SpdxMapping, build_spdx_mapping};
 use crate::utils::text::strip_utf8_bom_str;
@@ -46,7 +54,7 @@ use crate::utils::text::strip_utf8_bom_str;
 use crate::license_detection::detection::{
     attach_source_path_to_detections, empty_detection, populate_detection_from_group_with_spdx,
 };
-use crate::license_detection::models::MatcherKind;
+use crate::license_detection::models::{License, MatcherKind, Rule};
 
 /// Path to the license rules directory in the reference scancode-toolkit submodule.
 /// Used by test code and the xtask generate-license-loader-artifact binary.
@@ -65,6 +73,16 @@ pub const SCANCODE_LICENSES_LICENSES_PATH: &str =
 #[allow(dead_code)]
 pub const SCANCODE_LICENSES_DATA_PATH: &str = "reference/scancode-toolkit/src/licensedcode/data";
 
+/// SPDX license list version the embedded license data was generated from.
+pub const DEFAULT_LICENSE_LIST_VERSION: &str = "3.27";
+
+/// Oldest SPDX license list version of license data the scanner is known to
+/// work with. Data from [`DEFAULT_LICENSE_LIST_VERSION`] is the newest.
+pub const MIN_SUPPORTED_LICENSE_LIST_VERSION: &str = "3.20";
+
+/// File in a license data directory recording the SPDX license list version.
+const LICENSE_LIST_VERSION_FILE: &str = "spdx_license_list_version";
+
 pub(crate) use detection::{
     LicenseDetection, group_matches_by_region, post_process_detections, sort_matches_by_line,
 };
@@ -91,13 +109,73 @@ pub use unknown_match::unknown_match;
 pub struct LicenseDetectionEngine {
     index: Arc<index::LicenseIndex>,
     spdx_mapping: SpdxMapping,
+    min_rule_relevance: u8,
+    min_matched_length: usize,
+    min_license_score: u8,
+    max_sequence_candidates: usize,
+    max_query_tokens: usize,
+    license_list_version: String,
+    /// Translated rule directories merged into the index, with their language.
+    translated_rules: Vec<(PathBuf, String)>,
+    /// Identifiers of the rules merged from `translated_rules`.
+    translated_rule_identifiers: HashSet<String>,
+    /// Directories of additional rules and licenses merged into the index.
+    additional_rules: Vec<PathBuf>,
+    /// Rule and license files skipped because they failed to parse.
+    load_errors: Vec<String>,
+    /// Fingerprint of the license data directory the index was built from.
+    rules_fingerprint: Option<String>,
+}
+
+// Counts detections that went past the hash phase, so tests can assert that
+// exact whole-file matches short-circuit the approximate matchers.
+#[cfg(test)]
+thread_local! {
+    static APPROXIMATE_PHASE_RUNS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
 }
 
 const MAX_DETECTION_SIZE: usize = 10 * 1024 * 1024; // 10MB
-const MAX_REGULAR_SEQ_CANDIDATES: usize = 70;
+/// Default number of candidate rules the sequence matcher aligns per query run.
+pub const DEFAULT_MAX_SEQUENCE_CANDIDATES: usize = 70;
 const MAX_REDUNDANT_SEQ_CONTAINER_BOUNDARY_GAP: usize = 8;
 const MAX_REDUNDANT_SEQ_CONTAINER_UNMATCHED_GAP: usize = 2;
 
+/// Read the SPDX license list version recorded in a license data directory.
+///
+/// Returns `None` when the directory carries no version file or it is empty.
+pub fn load_license_list_version(data_dir: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(data_dir.join(LICENSE_LIST_VERSION_FILE)).ok()?;
+    let version = content.trim();
+    (!version.is_empty()).then(|| version.to_string())
+}
+
+/// Explain why license data of SPDX license list `version` may not match what
+/// the scanner expects, or `None` when it is in the supported range.
+///
+/// Rules and licenses added or reworked in other list versions silently change
+/// what can be detected, so loading such data is worth surfacing in the scan
+/// header rather than failing.
+pub fn license_list_version_warning(version: &str) -> Option<String> {
+    fn parse(version: &str) -> Option<(u32, u32)> {
+        let mut parts = version.trim().split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next().unwrap_or("0").parse().ok()?;
+        Some((major, minor))
+    }
+
+    let min = parse(MIN_SUPPORTED_LICENSE_LIST_VERSION)?;
+    let max = parse(DEFAULT_LICENSE_LIST_VERSION)?;
+    match parse(version) {
+        Some(parsed) if (min..=max).contains(&parsed) => None,
+        _ => Some(format!(
+            "License data is for SPDX license list version {}, but this scanner expects {} to {}; license detection results may be incomplete",
+            version.trim(),
+            MIN_SUPPORTED_LICENSE_LIST_VERSION,
+            DEFAULT_LICENSE_LIST_VERSION
+        )),
+    }
+}
+
 fn truncate_detection_text(clean_text: &str) -> &str {
     if clean_text.len() <= MAX_DETECTION_SIZE {
         return clean_text;
@@ -113,6 +191,34 @@ fn truncate_detection_text(clean_text: &str) -> &str {
     &clean_text[..boundary]
 }
 
+fn filter_low_relevance_matches(
+    matches: Vec<LicenseMatch>,
+    min_rule_relevance: u8,
+) -> Vec<LicenseMatch> {
+    if min_rule_relevance == 0 {
+        return matches;
+    }
+
+    matches
+        .into_iter()
+        .filter(|m| m.rule_relevance >= min_rule_relevance)
+        .collect()
+}
+
+fn filter_low_score_matches(
+    matches: Vec<LicenseMatch>,
+    min_license_score: u8,
+) -> Vec<LicenseMatch> {
+    if min_license_score == 0 {
+        return matches;
+    }
+
+    matches
+        .into_iter()
+        .filter(|m| m.score >= f32::from(min_license_score))
+        .collect()
+}
+
 fn query_span_for_match(m: &LicenseMatch) -> Option<query::PositionSpan> {
     (m.end_token > m.start_token).then(|| query::PositionSpan::new(m.start_token, m.end_token - 1))
 }
@@ -418,6 +524,7 @@ fn collect_regular_seq_matches(
     query: &Query<'_>,
     matched_qspans: &[query::PositionSpan],
     candidate_contained_matches: &[LicenseMatch],
+    max_candidates: usize,
 ) -> Vec<LicenseMatch> {
     let mut seq_all_matches = Vec::new();
 
@@ -426,8 +533,7 @@ fn collect_regular_seq_matches(
             continue;
         }
 
-        let candidates =
-            compute_candidates_with_msets(index, &query_run, false, MAX_REGULAR_SEQ_CANDIDATES);
+        let candidates = compute_candidates_with_msets(index, &query_run, false, max_candidates);
         if !candidates.is_empty() {
             let matches = seq_match_with_candidates(index, &query_run, &candidates);
             seq_all_matches.extend(matches);
@@ -443,6 +549,43 @@ fn collect_regular_seq_matches(
     )
 }
 
+/// Resolve `rules_path` into the license data directory and the directories
+/// holding `.RULE` and `.LICENSE` files.
+fn license_data_dirs(rules_path: &Path) -> Result<(PathBuf, PathBuf, PathBuf)> {
+    let data_dir = if rules_path.ends_with("data") {
+        rules_path.to_path_buf()
+    } else if rules_path.ends_with("rules") {
+        rules_path
+            .parent()
+            .ok_or_else(|| anyhow::anyhow!("Cannot determine parent directory for rules path"))?
+            .to_path_buf()
+    } else {
+        rules_path.to_path_buf()
+    };
+    let (rules_dir, licenses_dir) = if rules_path.ends_with("data") || rules_path.ends_with("rules")
+    {
+        (data_dir.join("rules"), data_dir.join("licenses"))
+    } else {
+        (rules_path.to_path_buf(), rules_path.to_path_buf())
+    };
+    Ok((data_dir, rules_dir, licenses_dir))
+}
+
+/// Rule and license directories of an additional rules directory, see
+/// [`LicenseDetectionEngine::with_additional_rules`].
+fn additional_rule_dirs(rules_dir: &Path) -> (Vec<PathBuf>, Vec<PathBuf>) {
+    let rules = rules_dir.join("rules");
+    let licenses = rules_dir.join("licenses");
+    if rules.is_dir() || licenses.is_dir() {
+        (
+            rules.is_dir().then_some(rules).into_iter().collect(),
+            licenses.is_dir().then_some(licenses).into_iter().collect(),
+        )
+    } else {
+        (vec![rules_dir.to_path_buf()], vec![rules_dir.to_path_buf()])
+    }
+}
+
 impl LicenseDetectionEngine {
     /// Create a new license detection engine from a pre-built license index.
     ///
@@ -456,9 +599,117 @@ impl LicenseDetectionEngine {
         Ok(Self {
             index: Arc::new(index),
             spdx_mapping,
+            min_rule_relevance: 0,
+            min_matched_length: 0,
+            min_license_score: 0,
+            max_sequence_candidates: DEFAULT_MAX_SEQUENCE_CANDIDATES,
+            max_query_tokens: 0,
+            license_list_version: DEFAULT_LICENSE_LIST_VERSION.to_string(),
+            translated_rules: Vec::new(),
+            translated_rule_identifiers: HashSet::new(),
+            additional_rules: Vec::new(),
+            load_errors: Vec::new(),
+            rules_fingerprint: None,
         })
     }
 
+    /// Exclude matches from rules whose relevance is below `min_rule_relevance`.
+    ///
+    /// A floor of `0` (the default) keeps every rule. Raising it drops short,
+    /// low-confidence rules (bare names, generic references) from the results.
+    pub fn with_min_rule_relevance(mut self, min_rule_relevance: u8) -> Self {
+        self.min_rule_relevance = min_rule_relevance;
+        self
+    }
+
+    /// Minimum rule relevance a match needs to be reported.
+    pub fn min_rule_relevance(&self) -> u8 {
+        self.min_rule_relevance
+    }
+
+    /// SPDX license list version of the loaded license data.
+    pub fn license_list_version(&self) -> &str {
+        &self.license_list_version
+    }
+
+    /// Warning about loaded license data from an unexpected SPDX license list
+    /// version, see [`license_list_version_warning`].
+    pub fn license_data_warning(&self) -> Option<String> {
+        license_list_version_warning(&self.license_list_version)
+    }
+
+    /// Rule and license files skipped while loading the license data because
+    /// they failed to parse.
+    pub fn load_errors(&self) -> &[String] {
+        &self.load_errors
+    }
+
+    /// Drop detections whose longest match spans fewer than
+    /// `min_matched_length` tokens, unless it matches its whole rule exactly.
+    ///
+    /// A length of `0` (the default) keeps every detection.
+    pub fn with_min_matched_length(mut self, min_matched_length: usize) -> Self {
+        self.min_matched_length = min_matched_length;
+        self
+    }
+
+    /// Minimum token length of a detection's longest match.
+    pub fn min_matched_length(&self) -> usize {
+        self.min_matched_length
+    }
+
+    /// Drop matches scoring below `min_license_score` (0-100) before they are
+    /// grouped into detections, so detections and license expressions only
+    /// reflect the matches that pass.
+    ///
+    /// A score of `0` (the default) keeps every match.
+    pub fn with_min_license_score(mut self, min_license_score: u8) -> Self {
+        self.min_license_score = min_license_score.min(100);
+        self
+    }
+
+    /// Minimum score a match needs to be reported.
+    pub fn min_license_score(&self) -> u8 {
+        self.min_license_score
+    }
+
+    /// Cap the number of candidate rules the sequence matcher aligns against
+    /// each query run.
+    ///
+    /// Lower caps trade recall on long, mixed-license files for speed. A cap of
+    /// `0` is treated as `1`.
+    pub fn with_max_sequence_candidates(mut self, max_sequence_candidates: usize) -> Self {
+        self.max_sequence_candidates = max_sequence_candidates.max(1);
+        self
+    }
+
+    /// Maximum number of sequence-matching candidates per query run.
+    pub fn max_sequence_candidates(&self) -> usize {
+        self.max_sequence_candidates
+    }
+
+    /// Only build the detection query from the first `max_query_tokens` tokens
+    /// of a text.
+    ///
+    /// Query structures grow with the text, so this bounds memory on huge
+    /// files at the cost of missing licenses past the cap. A cap of `0` (the
+    /// default) keeps the whole text.
+    pub fn with_max_query_tokens(mut self, max_query_tokens: usize) -> Self {
+        self.max_query_tokens = max_query_tokens;
+        self
+    }
+
+    /// Maximum number of tokens used to build a detection query.
+    pub fn max_query_tokens(&self) -> usize {
+        self.max_query_tokens
+    }
+
+    /// The prefix of `text` that detection reads under
+    /// [`Self::with_max_query_tokens`], or `None` when the text fits the cap.
+    pub fn truncate_query_text<'t>(&self, text: &'t str) -> Option<&'t str> {
+        tokenize::truncate_to_token_count(text, self.max_query_tokens)
+    }
+
     /// Create a new license detection engine from the embedded license index.
     ///
     /// This method loads the build-time embedded license artifact and constructs
@@ -506,22 +757,242 @@ impl LicenseDetectionEngine {
     /// # Returns
     /// A Result containing the engine or an error
     pub fn from_directory(rules_path: &Path) -> Result<Self> {
-        let (rules_dir, licenses_dir) = if rules_path.ends_with("data") {
-            (rules_path.join("rules"), rules_path.join("licenses"))
-        } else if rules_path.ends_with("rules") {
-            let parent = rules_path.parent().ok_or_else(|| {
-                anyhow::anyhow!("Cannot determine parent directory for rules path")
-            })?;
-            (rules_path.to_path_buf(), parent.join("licenses"))
-        } else {
-            (rules_path.to_path_buf(), rules_path.to_path_buf())
-        };
+        let (data_dir, rules_dir, licenses_dir) = license_data_dirs(rules_path)?;
 
-        let loaded_rules = load_loaded_rules_from_directory(&rules_dir)?;
-        let loaded_licenses = load_loaded_licenses_from_directory(&licenses_dir)?;
+        let mut load_errors = Vec::new();
+        let loaded_rules =
+            load_loaded_rules_from_directory_with_errors(&rules_dir, &mut load_errors)?;
+        let loaded_licenses =
+            load_loaded_licenses_from_directory_with_errors(&licenses_dir, &mut load_errors)?;
         let index = build_index_from_loaded(loaded_rules, loaded_licenses, false);
 
-        Self::from_index(index)
+        let mut engine = Self::from_index(index)?;
+        engine.load_errors = load_errors;
+        if let Some(version) = load_license_list_version(&data_dir) {
+            if let Some(warning) = license_list_version_warning(&version) {
+                log::warn!("{}", warning);
+            }
+            engine.license_list_version = version;
+        }
+        Ok(engine)
+    }
+
+    /// Create an engine from `rules_path`, reusing the index cached at
+    /// `cache_path` when it was built from identical license data.
+    ///
+    /// The rules and licenses are fingerprinted on every call; on a mismatch or
+    /// an unreadable cache the index is rebuilt and the cache rewritten. Failing
+    /// to write the cache is logged and otherwise ignored.
+    pub fn from_directory_cached(rules_path: &Path, cache_path: &Path) -> Result<Self> {
+        let (data_dir, rules_dir, licenses_dir) = license_data_dirs(rules_path)?;
+        let version = load_license_list_version(&data_dir);
+        let fingerprint =
+            license_data_fingerprint(&[&rules_dir, &licenses_dir], version.as_deref())?;
+
+        match Self::from_cache(cache_path) {
+            Ok(engine) if engine.rules_fingerprint.as_deref() == Some(fingerprint.as_str()) => {
+                return Ok(engine);
+            }
+            Ok(_) => log::info!("License index cache is stale: {}", cache_path.display()),
+            Err(e) => log::debug!("{e:#}"),
+        }
+
+        let mut engine = Self::from_directory(rules_path)?;
+        engine.rules_fingerprint = Some(fingerprint);
+        if let Err(e) = engine.save_cache(cache_path) {
+            log::warn!("{e:#}");
+        }
+        Ok(engine)
+    }
+
+    /// Load an engine from an index cache written by [`Self::save_cache`].
+    ///
+    /// Detection thresholds are not cached and start at their defaults.
+    pub fn from_cache(cache_path: &Path) -> Result<Self> {
+        let cache = read_index_cache(cache_path)?;
+        let index = load_license_index_from_bytes(&cache.index)?;
+        let mut engine = Self::from_index(index)?;
+        engine.rules_fingerprint = cache.rules_fingerprint;
+        engine.license_list_version = cache.license_list_version;
+        engine.load_errors = cache.load_errors;
+        Ok(engine)
+    }
+
+    /// Write the current index to `cache_path` for [`Self::from_cache`].
+    pub fn save_cache(&self, cache_path: &Path) -> Result<()> {
+        let index = EmbeddedLicenseIndex::from(self.index.as_ref()).serialize_to_bytes()?;
+        write_index_cache(
+            cache_path,
+            &IndexCacheFile::new(
+                self.rules_fingerprint.clone(),
+                self.license_list_version.clone(),
+                self.load_errors.clone(),
+                index,
+            ),
+        )
+    }
+
+    /// Fingerprint of the license data the index was built from, when it was
+    /// created by [`Self::from_directory_cached`].
+    pub fn rules_fingerprint(&self) -> Option<&str> {
+        self.rules_fingerprint.as_deref()
+    }
+
+    /// Rebuild the index from `rules_path` and swap it in, keeping the relevance floor
+    /// and any rules merged with [`Self::with_translated_rules`] or
+    /// [`Self::with_additional_rules`].
+    ///
+    /// Indexes previously handed out by [`Self::shared_index`] stay valid until their last
+    /// `Arc` is dropped. On error the current index is left in place.
+    pub fn reload_rules(&mut self, rules_path: &Path) -> Result<()> {
+        let mut reloaded = Self::from_directory(rules_path)?;
+        for (rules_dir, language) in &self.translated_rules {
+            reloaded = reloaded.with_translated_rules(rules_dir, language)?;
+        }
+        for rules_dir in &self.additional_rules {
+            reloaded = reloaded.with_additional_rules(rules_dir)?;
+        }
+        self.index = reloaded.index;
+        self.spdx_mapping = reloaded.spdx_mapping;
+        self.license_list_version = reloaded.license_list_version;
+        self.load_errors = reloaded.load_errors;
+        self.rules_fingerprint = reloaded.rules_fingerprint;
+        Ok(())
+    }
+
+    /// Merge translated rules from `rules_dir` into the index, tagging them with `language`.
+    ///
+    /// Rules that already declare a `language` keep it. The whole index is rebuilt, so this
+    /// is meant to run once when the engine is created.
+    pub fn with_translated_rules(self, rules_dir: &Path, language: &str) -> Result<Self> {
+        let mut load_errors = self.load_errors.clone();
+        let translated: Vec<_> =
+            load_loaded_rules_from_directory_with_errors(rules_dir, &mut load_errors)?
+                .into_iter()
+                .map(|mut rule| {
+                    rule.language.get_or_insert_with(|| language.to_string());
+                    loaded_rule_to_rule(rule)
+                })
+                .collect();
+        let identifiers: Vec<_> = translated
+            .iter()
+            .map(|rule| rule.identifier.clone())
+            .collect();
+
+        let mut engine = self.rebuild_with(translated, Vec::new(), load_errors)?;
+        engine.translated_rule_identifiers.extend(identifiers);
+        engine
+            .translated_rules
+            .push((rules_dir.to_path_buf(), language.to_string()));
+        Ok(engine)
+    }
+
+    /// Merge the `.RULE` and `.LICENSE` files of `rules_dir` into the index,
+    /// alongside the license data the engine was created from.
+    ///
+    /// Files are read from the `rules` and `licenses` subdirectories when
+    /// either exists, and from `rules_dir` itself otherwise. A license whose
+    /// key is already known replaces the existing one. Deprecated entries are
+    /// skipped, as when loading a license data directory.
+    pub fn with_additional_rules(self, rules_dir: &Path) -> Result<Self> {
+        let (rule_dirs, license_dirs) = additional_rule_dirs(rules_dir);
+        let mut load_errors = self.load_errors.clone();
+        let mut rules = Vec::new();
+        for dir in rule_dirs {
+            rules.extend(
+                load_loaded_rules_from_directory_with_errors(&dir, &mut load_errors)?
+                    .into_iter()
+                    .filter(|rule| !rule.is_deprecated)
+                    .map(loaded_rule_to_rule),
+            );
+        }
+        let mut licenses = Vec::new();
+        for dir in license_dirs {
+            licenses.extend(
+                load_loaded_licenses_from_directory_with_errors(&dir, &mut load_errors)?
+                    .into_iter()
+                    .filter(|license| !license.is_deprecated)
+                    .map(loaded_license_to_license),
+            );
+        }
+
+        let mut engine = self.rebuild_with(rules, licenses, load_errors)?;
+        engine.additional_rules.push(rules_dir.to_path_buf());
+        Ok(engine)
+    }
+
+    /// Directories of additional rules merged with [`Self::with_additional_rules`].
+    pub fn additional_rules(&self) -> &[PathBuf] {
+        &self.additional_rules
+    }
+
+    /// Rebuild the index with `rules` and `licenses` added to the current ones,
+    /// keeping the engine settings and merged rule directories.
+    fn rebuild_with(
+        self,
+        rules: Vec<Rule>,
+        licenses: Vec<License>,
+        load_errors: Vec<String>,
+    ) -> Result<Self> {
+        // Rules generated from license texts are rebuilt from the licenses.
+        let rules: Vec<_> = self
+            .index
+            .rules_by_rid
+            .iter()
+            .filter(|rule| !rule.is_from_license)
+            .cloned()
+            .chain(rules)
+            .collect();
+        let mut licenses_by_key = self.index.licenses_by_key.clone();
+        licenses_by_key.extend(
+            licenses
+                .into_iter()
+                .map(|license| (license.key.clone(), license)),
+        );
+
+        let mut engine =
+            Self::from_index(build_index(rules, licenses_by_key.into_values().collect()))?
+                .with_min_rule_relevance(self.min_rule_relevance)
+                .with_min_matched_length(self.min_matched_length)
+                .with_min_license_score(self.min_license_score)
+                .with_max_sequence_candidates(self.max_sequence_candidates)
+                .with_max_query_tokens(self.max_query_tokens);
+        engine.license_list_version = self.license_list_version;
+        engine.translated_rules = self.translated_rules;
+        engine.translated_rule_identifiers = self.translated_rule_identifiers;
+        engine.additional_rules = self.additional_rules;
+        engine.load_errors = load_errors;
+        Ok(engine)
+    }
+
+    /// Translated rule directories merged into the index, with their language.
+    pub fn translated_rules(&self) -> &[(PathBuf, String)] {
+        &self.translated_rules
+    }
+
+    /// Canonical text of the rule behind `license_match`.
+    pub fn rule_text(&self, license_match: &LicenseMatch) -> Option<&str> {
+        self.index
+            .rules_by_rid
+            .get(license_match.rid)
+            .map(|rule| rule.text.as_str())
+    }
+
+    /// Language of the translated rule behind `license_match`, if any.
+    ///
+    /// Only rules merged with [`Self::with_translated_rules`] are reported, so the
+    /// `language` metadata of stock ScanCode rules stays out of the output.
+    pub fn match_language(&self, license_match: &LicenseMatch) -> Option<&str> {
+        if !self
+            .translated_rule_identifiers
+            .contains(&license_match.rule_identifier)
+        {
+            return None;
+        }
+        self.index
+            .rules_by_rid
+            .get(license_match.rid)
+            .and_then(|rule| rule.language.as_deref())
     }
 
     pub fn detect_with_kind(
@@ -533,6 +1004,7 @@ impl LicenseDetectionEngine {
         let clean_text = strip_utf8_bom_str(text);
 
         let content = truncate_detection_text(clean_text);
+        let content = self.truncate_query_text(content).unwrap_or(content);
 
         let mut query = Query::from_extracted_text(content, &self.index, binary_derived)?;
         let whole_query_run = query.whole_query_run();
@@ -543,9 +1015,17 @@ impl LicenseDetectionEngine {
         let mut matched_qspans: Vec<query::PositionSpan> = Vec::new();
 
         // Phase 1a: Hash matching
-        // Python returns immediately if hash matches found (index.py:987-991)
+        // Python returns immediately if hash matches found (index.py:987-991).
+        // A hash match always spans the whole query, so no other region is left
+        // for the approximate phases below.
         {
-            let hash_matches = hash_match(&self.index, &whole_query_run);
+            let hash_matches = filter_low_score_matches(
+                filter_low_relevance_matches(
+                    hash_match(&self.index, &whole_query_run),
+                    self.min_rule_relevance,
+                ),
+                self.min_license_score,
+            );
 
             if !hash_matches.is_empty() {
                 let mut matches = hash_matches;
@@ -565,10 +1045,17 @@ impl LicenseDetectionEngine {
                     })
                     .collect();
 
-                return Ok(post_process_detections(detections, 0.0));
+                return Ok(post_process_detections(
+                    detections,
+                    0.0,
+                    self.min_matched_length,
+                ));
             }
         }
 
+        #[cfg(test)]
+        APPROXIMATE_PHASE_RUNS.with(|runs| runs.set(runs.get() + 1));
+
         // Phase 1b: SPDX-LID matching
         {
             let spdx_matches = spdx_lid_match(&self.index, &query);
@@ -619,6 +1106,7 @@ impl LicenseDetectionEngine {
                 &query,
                 &matched_qspans,
                 &candidate_contained_matches,
+                self.max_sequence_candidates,
             );
             all_matches.extend(merged_seq);
         }
@@ -650,7 +1138,13 @@ impl LicenseDetectionEngine {
         };
 
         // Step 5: Final refine WITH false positive filtering - Python: index.py:1130-1145
-        let refined = refine_matches(&self.index, refined_matches, &query);
+        let refined = filter_low_score_matches(
+            filter_low_relevance_matches(
+                refine_matches(&self.index, refined_matches, &query),
+                self.min_rule_relevance,
+            ),
+            self.min_license_score,
+        );
 
         let mut sorted = refined;
         sort_matches_by_line(&mut sorted);
@@ -666,7 +1160,7 @@ impl LicenseDetectionEngine {
             })
             .collect();
 
-        let detections = post_process_detections(detections, 0.0);
+        let detections = post_process_detections(detections, 0.0, self.min_matched_length);
 
         Ok(detections)
     }
@@ -696,6 +1190,7 @@ impl LicenseDetectionEngine {
         let clean_text = strip_utf8_bom_str(text);
 
         let content = truncate_detection_text(clean_text);
+        let content = self.truncate_query_text(content).unwrap_or(content);
 
         let mut query = Query::from_extracted_text(content, &self.index, binary_derived)?;
         let whole_query_run = query.whole_query_run();
@@ -707,7 +1202,13 @@ impl LicenseDetectionEngine {
 
         // Phase 1a: Hash matching
         {
-            let hash_matches = hash_match(&self.index, &whole_query_run);
+            let hash_matches = filter_low_score_matches(
+                filter_low_relevance_matches(
+                    hash_match(&self.index, &whole_query_run),
+                    self.min_rule_relevance,
+                ),
+                self.min_license_score,
+            );
 
             if !hash_matches.is_empty() {
                 let mut matches = hash_matches;
@@ -763,6 +1264,7 @@ impl LicenseDetectionEngine {
                 &query,
                 &matched_qspans,
                 &candidate_contained_matches,
+                self.max_sequence_candidates,
             );
             all_matches.extend(merged_seq);
         }
@@ -787,7 +1289,13 @@ impl LicenseDetectionEngine {
         };
 
         // Step 3: Final refine WITH false positive filtering - Python: index.py:1130-1145
-        let refined = refine_matches(&self.index, refined_matches, &query);
+        let refined = filter_low_score_matches(
+            filter_low_relevance_matches(
+                refine_matches(&self.index, refined_matches, &query),
+                self.min_rule_relevance,
+            ),
+            self.min_license_score,
+        );
 
         let mut sorted = refined;
         sort_matches_by_line(&mut sorted);
@@ -801,6 +1309,31 @@ impl LicenseDetectionEngine {
         &self.index
     }
 
+    /// Get a shared handle to the license index that outlives a later [`Self::reload_rules`].
+    pub fn shared_index(&self) -> Arc<index::LicenseIndex> {
+        Arc::clone(&self.index)
+    }
+
+    /// Check whether every license in `license_expression` is a public-domain dedication.
+    ///
+    /// Relies on the ScanCode `Public Domain` license category, which covers the Unlicense,
+    /// CC0 and the generic `public-domain` key.
+    pub fn is_public_domain_expression(&self, license_expression: &str) -> bool {
+        let Ok(expression) = parse_expression(license_expression) else {
+            return false;
+        };
+        let keys = expression.license_keys();
+
+        !keys.is_empty()
+            && keys.iter().all(|key| {
+                self.index
+                    .licenses_by_key
+                    .get(key)
+                    .and_then(|license| license.category.as_deref())
+                    == Some("Public Domain")
+            })
+    }
+
     /// Get a reference to the SPDX mapping.
     #[cfg(test)]
     pub fn spdx_mapping(&self) -> &SpdxMapping {
@@ -462,6 +462,16 @@ impl LicenseMatch {
         self.rule_kind.is_license_clue()
     }
 
+    /// Category of the rule behind this match: `text`, `notice`, `reference`,
+    /// `tag`, `intro` or `clue`. Rules built from license files count as `text`.
+    pub const fn match_type(&self) -> Option<&'static str> {
+        if self.is_from_license {
+            Some("text")
+        } else {
+            self.rule_kind.as_str()
+        }
+    }
+
     pub fn hilen(&self) -> usize {
         self.hilen
     }
@@ -158,6 +158,19 @@ impl RuleKind {
     pub const fn is_license_clue(self) -> bool {
         matches!(self, Self::Clue)
     }
+
+    /// Lowercase name of the kind as reported in `match_type`, if any.
+    pub const fn as_str(self) -> Option<&'static str> {
+        match self {
+            Self::None => None,
+            Self::Text => Some("text"),
+            Self::Notice => Some("notice"),
+            Self::Reference => Some("reference"),
+            Self::Tag => Some("tag"),
+            Self::Intro => Some("intro"),
+            Self::Clue => Some("clue"),
+        }
+    }
 }
 
 /// Rule metadata loaded from .LICENSE and .RULE files.
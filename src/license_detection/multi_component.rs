@@ -0,0 +1,105 @@
+//! Multi-component license files.
+//!
+//! Large projects (Chromium, Android, Google SDKs) ship a single `LICENSE`
+//! that concatenates the licenses of dozens of bundled third-party components,
+//! each introduced by a delimiter line such as
+//! `-------- component: zlib --------`. Detecting the file as a whole merges
+//! neighbouring licenses into one detection; splitting it first lets the
+//! scanner run detection per section and attribute each detection to the
+//! component it belongs to (`--license-components`).
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Matches a component delimiter line, e.g. `----- component: foo -----` or
+/// `==== Component: foo ====`.
+static COMPONENT_HEADER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^\s*[-=]{3,}\s*component\s*:\s*(?P<name>\S.*?)\s*[-=]{3,}\s*$")
+        .expect("Invalid component header pattern")
+});
+
+/// One component's section of a multi-component license file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentSection<'a> {
+    /// Component name taken from the delimiter line.
+    pub name: String,
+    /// Section text, excluding the delimiter line.
+    pub text: &'a str,
+    /// 1-based file line of the first line of `text`.
+    pub start_line: usize,
+}
+
+/// Split `text` at component delimiter lines.
+///
+/// Returns `None` unless the text has at least two component sections, so a
+/// lone delimiter in an ordinary license file does not change detection. Text
+/// before the first delimiter is not part of any section.
+pub fn split_components(text: &str) -> Option<Vec<ComponentSection<'_>>> {
+    // (name, header start offset, body start offset, body start line)
+    let mut headers = Vec::new();
+    let mut offset = 0;
+    for (index, line) in text.split_inclusive('\n').enumerate() {
+        if let Some(captures) = COMPONENT_HEADER.captures(line.trim_end_matches(['\r', '\n'])) {
+            headers.push((
+                captures["name"].to_string(),
+                offset,
+                offset + line.len(),
+                index + 2,
+            ));
+        }
+        offset += line.len();
+    }
+    if headers.len() < 2 {
+        return None;
+    }
+
+    let section_ends: Vec<usize> = headers
+        .iter()
+        .skip(1)
+        .map(|(_, header_start, _, _)| *header_start)
+        .chain(std::iter::once(text.len()))
+        .collect();
+
+    Some(
+        headers
+            .into_iter()
+            .zip(section_ends)
+            .map(|((name, _, start, start_line), end)| ComponentSection {
+                name,
+                text: &text[start..end],
+                start_line,
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_components_finds_each_section() {
+        let text = "Third-party notices\n\
+                    -------- component: zlib --------\n\
+                    zlib license text\n\
+                    ======== Component: libpng ========\n\
+                    libpng license text\n\
+                    more libpng text\n";
+
+        let sections = split_components(text).expect("two sections");
+
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].name, "zlib");
+        assert_eq!(sections[0].text, "zlib license text\n");
+        assert_eq!(sections[0].start_line, 3);
+        assert_eq!(sections[1].name, "libpng");
+        assert_eq!(sections[1].text, "libpng license text\nmore libpng text\n");
+        assert_eq!(sections[1].start_line, 5);
+    }
+
+    #[test]
+    fn test_split_components_needs_two_sections() {
+        assert!(split_components("-------- component: zlib --------\nzlib\n").is_none());
+        assert!(split_components("MIT License\n----------\nPermission\n").is_none());
+    }
+}
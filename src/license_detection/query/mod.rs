@@ -2,6 +2,7 @@
 
 use crate::license_detection::index::LicenseIndex;
 use crate::license_detection::index::dictionary::{KnownToken, QueryToken, TokenId, TokenKind};
+use crate::license_detection::spdx_lid::split_spdx_lid;
 use crate::license_detection::tokenize::STOPWORDS;
 use crate::license_detection::tokenize::tokenize_as_ids;
 use bit_set::BitSet;
@@ -467,6 +468,25 @@ impl<'a> Query<'a> {
         None
     }
 
+    /// Whether an SPDX line stops mid-expression, on a dangling `AND`/`OR`/`WITH`
+    /// or an unclosed parenthesis, so the next comment line continues it.
+    fn is_wrapped_spdx_line(spdx_text: &str) -> bool {
+        let (_, expression) = split_spdx_lid(spdx_text);
+        let expression = expression
+            .trim_end_matches(|c: char| c.is_whitespace() || matches!(c, '*' | '/' | '>' | '-'));
+        let ends_with_operator = expression.split_whitespace().last().is_some_and(|word| {
+            ["and", "or", "with"].contains(&word.to_ascii_lowercase().as_str())
+        });
+        ends_with_operator || expression.matches('(').count() > expression.matches(')').count()
+    }
+
+    /// Strip leading comment markers from an SPDX continuation line.
+    fn strip_spdx_continuation_prefix(line: &str) -> &str {
+        line.trim_start_matches(|c: char| {
+            c.is_whitespace() || matches!(c, '/' | '*' | '#' | '-' | ';' | '!' | '%')
+        })
+    }
+
     pub fn from_extracted_text(
         text: &str,
         index: &'a LicenseIndex,
@@ -515,6 +535,7 @@ impl<'a> Query<'a> {
         let mut current_line = 1usize;
 
         let mut tokens_by_line: Vec<Vec<Option<KnownToken>>> = Vec::new();
+        let mut wrapped_spdx_line: Option<usize> = None;
 
         for line in text.lines() {
             let line_trimmed = line.trim();
@@ -563,6 +584,7 @@ impl<'a> Query<'a> {
             let spdx_start_offset =
                 Self::compute_spdx_offset(&line_query_tokens, &index.dictionary);
 
+            let continued_spdx_line = wrapped_spdx_line.take();
             if let Some(offset) = spdx_start_offset
                 && let Some(line_first_known_pos) = line_first_known_pos
             {
@@ -571,6 +593,19 @@ impl<'a> Query<'a> {
                     let spdx_start = spdx_start_known_pos as usize;
                     let spdx_end = (line_last_known_pos + 1) as usize;
                     spdx_lines.push((line_trimmed.to_string(), spdx_start, spdx_end));
+                    if Self::is_wrapped_spdx_line(line_trimmed) {
+                        wrapped_spdx_line = Some(spdx_lines.len() - 1);
+                    }
+                }
+            } else if let Some(spdx_index) = continued_spdx_line
+                && line_first_known_pos.is_some()
+            {
+                let (spdx_text, _, spdx_end) = &mut spdx_lines[spdx_index];
+                spdx_text.push(' ');
+                spdx_text.push_str(Self::strip_spdx_continuation_prefix(line_trimmed));
+                *spdx_end = (line_last_known_pos + 1) as usize;
+                if Self::is_wrapped_spdx_line(spdx_text) {
+                    wrapped_spdx_line = Some(spdx_index);
                 }
             }
 
@@ -548,6 +548,15 @@ fn parse_license_file_content(content: &str, path: &Path) -> Result<ParsedLicens
 /// * `Ok(Vec<LoadedRule>)` - All loaded rules (including deprecated)
 /// * `Err(...)` - Directory read error
 pub fn load_loaded_rules_from_directory(dir: &Path) -> Result<Vec<LoadedRule>> {
+    load_loaded_rules_from_directory_with_errors(dir, &mut Vec::new())
+}
+
+/// Like [`load_loaded_rules_from_directory`], also recording each .RULE file
+/// that failed to parse in `errors`.
+pub fn load_loaded_rules_from_directory_with_errors(
+    dir: &Path,
+    errors: &mut Vec<String>,
+) -> Result<Vec<LoadedRule>> {
     let mut rules = Vec::new();
 
     let entries = fs::read_dir(dir)
@@ -562,7 +571,9 @@ pub fn load_loaded_rules_from_directory(dir: &Path) -> Result<Vec<LoadedRule>> {
             match parse_rule_to_loaded(&path) {
                 Ok(rule) => rules.push(rule),
                 Err(e) => {
-                    warn!("Failed to parse rule file {}: {}", path.display(), e);
+                    let error = format!("Failed to parse rule file {}: {}", path.display(), e);
+                    warn!("{}", error);
+                    errors.push(error);
                 }
             }
         }
@@ -583,6 +594,15 @@ pub fn load_loaded_rules_from_directory(dir: &Path) -> Result<Vec<LoadedRule>> {
 /// * `Ok(Vec<LoadedLicense>)` - All loaded licenses (including deprecated)
 /// * `Err(...)` - Directory read error
 pub fn load_loaded_licenses_from_directory(dir: &Path) -> Result<Vec<LoadedLicense>> {
+    load_loaded_licenses_from_directory_with_errors(dir, &mut Vec::new())
+}
+
+/// Like [`load_loaded_licenses_from_directory`], also recording each .LICENSE
+/// file that failed to parse in `errors`.
+pub fn load_loaded_licenses_from_directory_with_errors(
+    dir: &Path,
+    errors: &mut Vec<String>,
+) -> Result<Vec<LoadedLicense>> {
     let mut licenses = Vec::new();
 
     let entries = fs::read_dir(dir)
@@ -597,7 +617,9 @@ pub fn load_loaded_licenses_from_directory(dir: &Path) -> Result<Vec<LoadedLicen
             match parse_license_to_loaded(&path) {
                 Ok(license) => licenses.push(license),
                 Err(e) => {
-                    warn!("Failed to parse license file {}: {}", path.display(), e);
+                    let error = format!("Failed to parse license file {}: {}", path.display(), e);
+                    warn!("{}", error);
+                    errors.push(error);
                 }
             }
         }
@@ -806,3 +806,26 @@ fn test_ibmpl_detection() {
         "Single low-quality fragment should remain expressionless"
     );
 }
+
+#[test]
+fn test_load_rules_from_directory_reports_unparsable_rule_files() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    std::fs::write(
+        dir.path().join("mit_1.RULE"),
+        "---\nlicense_expression: mit\nis_license_notice: yes\n---\nLicensed under the MIT license.",
+    )
+    .expect("write rule");
+    std::fs::write(
+        dir.path().join("broken_1.RULE"),
+        "---\nlicense_expression: [mit\n---\nBroken frontmatter.",
+    )
+    .expect("write rule");
+
+    let mut errors = Vec::new();
+    let rules = load_loaded_rules_from_directory_with_errors(dir.path(), &mut errors)
+        .expect("directory should load");
+
+    assert_eq!(rules.len(), 1);
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("broken_1.RULE"), "{errors:?}");
+}
@@ -369,6 +369,71 @@ mod tests {
         assert_eq!(matches.len(), 3);
     }
 
+    fn create_wrapped_spdx_index() -> crate::license_detection::index::LicenseIndex {
+        let mut index = create_spdx_lookup_index(&[
+            ("mit", "mit"),
+            ("apache-2.0", "apache-2.0"),
+            ("gpl-2.0-only", "gpl-2.0"),
+        ]);
+        for token in [
+            "spdx",
+            "license",
+            "identifier",
+            "mit",
+            "apache",
+            "gpl",
+            "only",
+        ] {
+            let _ = index.dictionary.get_or_assign(token);
+        }
+        index
+    }
+
+    #[test]
+    fn test_spdx_lid_match_reassembles_wrapped_expression() {
+        let index = create_wrapped_spdx_index();
+
+        let text =
+            "// SPDX-License-Identifier: MIT OR\n//     Apache-2.0 OR\n//     GPL-2.0-only\nint x;";
+        let query = Query::from_extracted_text(text, &index, false).unwrap();
+        let matches = spdx_lid_match(&index, &query);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(
+            matches[0].license_expression,
+            "mit OR apache-2.0 OR gpl-2.0"
+        );
+        assert_eq!(
+            matches[0].license_expression_spdx.as_deref(),
+            Some("MIT OR Apache-2.0 OR GPL-2.0-only")
+        );
+        assert_eq!(matches[0].start_line, 1);
+    }
+
+    #[test]
+    fn test_spdx_lid_match_reassembles_unclosed_parenthesis() {
+        let index = create_wrapped_spdx_index();
+
+        let text = "# SPDX-License-Identifier: (MIT OR\n#   Apache-2.0)\n";
+        let query = Query::from_extracted_text(text, &index, false).unwrap();
+        let matches = spdx_lid_match(&index, &query);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].license_expression, "mit OR apache-2.0");
+    }
+
+    #[test]
+    fn test_spdx_lid_match_does_not_join_complete_expression() {
+        let index = create_wrapped_spdx_index();
+
+        let text = "// SPDX-License-Identifier: MIT\n// Apache-2.0 is mentioned here\n";
+        let query = Query::from_extracted_text(text, &index, false).unwrap();
+        let matches = spdx_lid_match(&index, &query);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].license_expression, "mit");
+    }
+
     #[test]
     fn test_spdx_lid_match_no_match() {
         let index = create_test_index(&[("mit", 0)], 1);
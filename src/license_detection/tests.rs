@@ -473,6 +473,56 @@ fn test_engine_matched_text_populated() {
     }
 }
 
+#[test]
+fn test_detect_multiline_notice_reports_source_line_range() {
+    let engine = get_engine();
+
+    let text = r#"[package]
+name = "demo"
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+fn main() {}
+"#;
+
+    let detections = engine
+        .detect_with_kind(text, false, false)
+        .expect("Detection should succeed");
+
+    let mit = detections
+        .iter()
+        .find(|d| d.license_expression.as_deref() == Some("mit"))
+        .expect("Should detect the MIT notice");
+
+    let start_line = mit.matches.iter().map(|m| m.start_line).min();
+    let end_line = mit.matches.iter().map(|m| m.end_line).max();
+    assert_eq!(
+        (start_line, end_line),
+        (Some(4), Some(20)),
+        "Detection should span exactly the notice lines, got matches: {:?}",
+        mit.matches
+            .iter()
+            .map(|m| (m.rule_identifier.as_str(), m.start_line, m.end_line))
+            .collect::<Vec<_>>()
+    );
+}
+
 #[test]
 fn test_detect_multiple_licenses_in_text() {
     let engine = get_engine();
@@ -610,6 +660,57 @@ fn test_spdx_with_plus() {
     );
 }
 
+#[test]
+fn test_spdx_or_later_forms_emit_scancode_and_spdx_expressions() {
+    let engine = get_engine();
+
+    for text in [
+        "SPDX-License-Identifier: GPL-2.0+",
+        "SPDX-License-Identifier: GPL-2.0-or-later",
+    ] {
+        let detections = engine
+            .detect_with_kind(text, false, false)
+            .expect("Detection should succeed");
+
+        assert_eq!(detections.len(), 1, "{text}: {detections:?}");
+        let detection = &detections[0];
+        assert_eq!(
+            detection.license_expression.as_deref(),
+            Some("gpl-2.0-plus"),
+            "{text}"
+        );
+        assert_eq!(
+            detection.license_expression_spdx.as_deref(),
+            Some("GPL-2.0-or-later"),
+            "{text}"
+        );
+        assert!(detection.matches.iter().all(|m| {
+            m.license_expression == "gpl-2.0-plus"
+                && m.license_expression_spdx.as_deref() == Some("GPL-2.0-or-later")
+        }));
+    }
+}
+
+#[test]
+fn test_spdx_plus_with_exception_round_trips() {
+    let engine = get_engine();
+
+    let text = "SPDX-License-Identifier: GPL-2.0+ WITH Classpath-exception-2.0";
+    let detections = engine
+        .detect_with_kind(text, false, false)
+        .expect("Detection should succeed");
+
+    assert_eq!(detections.len(), 1, "{detections:?}");
+    assert_eq!(
+        detections[0].license_expression.as_deref(),
+        Some("gpl-2.0-plus WITH classpath-exception-2.0")
+    );
+    assert_eq!(
+        detections[0].license_expression_spdx.as_deref(),
+        Some("GPL-2.0-or-later WITH Classpath-exception-2.0")
+    );
+}
+
 #[test]
 fn test_spdx_in_comment() {
     let engine = get_engine();
@@ -1133,3 +1234,757 @@ fn test_detect_with_kind_handles_multibyte_boundary_at_size_limit() {
 
     assert!(detections.is_empty());
 }
+
+fn write_relevance_fixture(dir: &std::path::Path) {
+    for key in ["foo", "bar"] {
+        std::fs::write(
+            dir.join(format!("{key}.LICENSE")),
+            format!(
+                "---\nkey: {key}\nshort_name: {key}\nname: {key}\ncategory: Permissive\n---\n\
+                 The {key} license grants everyone the right to copy and share this software freely."
+            ),
+        )
+        .expect("write license fixture");
+    }
+    std::fs::write(
+        dir.join("foo_1.RULE"),
+        "---\nlicense_expression: foo\nis_license_notice: yes\nrelevance: 50\n---\n\
+         This package is distributed under the terms of the foo license agreement.",
+    )
+    .expect("write low relevance rule");
+    std::fs::write(
+        dir.join("bar_1.RULE"),
+        "---\nlicense_expression: bar\nis_license_notice: yes\nrelevance: 100\n---\n\
+         This library is made available under the terms of the bar license agreement.",
+    )
+    .expect("write high relevance rule");
+}
+
+#[test]
+fn test_min_rule_relevance_drops_low_relevance_matches() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    write_relevance_fixture(dir.path());
+
+    let engine = LicenseDetectionEngine::from_directory(dir.path()).expect("engine from fixture");
+    assert_eq!(engine.min_rule_relevance(), 0);
+
+    let text = "This package is distributed under the terms of the foo license agreement.\n\n\n\n\
+                This library is made available under the terms of the bar license agreement.";
+    let expressions = |engine: &LicenseDetectionEngine| -> Vec<String> {
+        engine
+            .detect_with_kind(text, false, false)
+            .expect("detection should succeed")
+            .into_iter()
+            .filter_map(|d| d.license_expression)
+            .collect()
+    };
+
+    let unfiltered = expressions(&engine);
+    assert!(unfiltered.iter().any(|e| e == "foo"), "{unfiltered:?}");
+    assert!(unfiltered.iter().any(|e| e == "bar"), "{unfiltered:?}");
+
+    let floored = engine.with_min_rule_relevance(80);
+    let filtered = expressions(&floored);
+    assert!(!filtered.iter().any(|e| e == "foo"), "{filtered:?}");
+    assert!(filtered.iter().any(|e| e == "bar"), "{filtered:?}");
+}
+
+#[test]
+fn test_from_directory_cached_reuses_index_until_rules_change() {
+    let rules_dir = tempfile::tempdir().expect("create rules dir");
+    write_relevance_fixture(rules_dir.path());
+    let cache_dir = tempfile::tempdir().expect("create cache dir");
+    let cache_path = cache_dir.path().join("license_index.msgpack");
+    let text = "This library is made available under the terms of the bar license agreement.";
+    let expressions = |engine: &LicenseDetectionEngine| -> Vec<String> {
+        engine
+            .detect_with_kind(text, false, false)
+            .expect("detection should succeed")
+            .into_iter()
+            .filter_map(|d| d.license_expression)
+            .collect()
+    };
+
+    let built = LicenseDetectionEngine::from_directory_cached(rules_dir.path(), &cache_path)
+        .expect("engine from fixture");
+    assert!(cache_path.exists());
+    let fingerprint = built.rules_fingerprint().expect("fingerprint").to_string();
+
+    let cached = LicenseDetectionEngine::from_cache(&cache_path).expect("engine from cache");
+    assert_eq!(cached.rules_fingerprint(), Some(fingerprint.as_str()));
+    assert_eq!(
+        cached.index().rules_by_rid.len(),
+        built.index().rules_by_rid.len()
+    );
+    assert_eq!(expressions(&cached), expressions(&built));
+
+    std::fs::write(
+        rules_dir.path().join("baz_1.RULE"),
+        "---\nlicense_expression: baz\nis_license_notice: yes\nrelevance: 100\n---\n\
+         This tool is provided under the baz license agreement.",
+    )
+    .expect("write added rule");
+    let rebuilt = LicenseDetectionEngine::from_directory_cached(rules_dir.path(), &cache_path)
+        .expect("engine from changed fixture");
+    assert_ne!(rebuilt.rules_fingerprint(), Some(fingerprint.as_str()));
+    assert_eq!(
+        rebuilt.index().rules_by_rid.len(),
+        built.index().rules_by_rid.len() + 1
+    );
+    assert_eq!(
+        LicenseDetectionEngine::from_cache(&cache_path)
+            .expect("rewritten cache")
+            .rules_fingerprint(),
+        rebuilt.rules_fingerprint()
+    );
+}
+
+#[test]
+fn test_min_rule_relevance_filters_hash_matches() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    write_relevance_fixture(dir.path());
+
+    let engine = LicenseDetectionEngine::from_directory(dir.path())
+        .expect("engine from fixture")
+        .with_min_rule_relevance(80);
+
+    let detections = engine
+        .detect_with_kind(
+            "This package is distributed under the terms of the foo license agreement.",
+            false,
+            false,
+        )
+        .expect("detection should succeed");
+
+    assert!(
+        detections
+            .iter()
+            .all(|d| d.license_expression.as_deref() != Some("foo")),
+        "low relevance hash match should be dropped: {detections:?}"
+    );
+}
+
+#[test]
+fn test_max_sequence_candidates_caps_sequence_matching() {
+    let engine = get_engine().clone();
+    assert_eq!(
+        engine.max_sequence_candidates(),
+        DEFAULT_MAX_SEQUENCE_CANDIDATES
+    );
+    assert_eq!(
+        engine
+            .clone()
+            .with_max_sequence_candidates(0)
+            .max_sequence_candidates(),
+        1
+    );
+
+    let mit_text = engine
+        .index()
+        .licenses_by_key
+        .get("mit")
+        .map(|license| license.text.clone())
+        .expect("embedded index should contain the MIT license");
+    // Drop the warranty disclaimer so the text can only be found by sequence matching.
+    let truncated = mit_text
+        .split("THE SOFTWARE IS PROVIDED")
+        .next()
+        .expect("MIT text should have a disclaimer")
+        .replace("Permission is hereby granted", "Permission is granted");
+
+    let detections = engine
+        .with_max_sequence_candidates(1)
+        .detect_with_kind(&truncated, false, false)
+        .expect("Detection should succeed");
+
+    assert!(
+        detections
+            .iter()
+            .any(|d| d.license_expression.as_deref() == Some("mit")),
+        "the top sequence candidate should still be found: {detections:?}"
+    );
+}
+
+#[test]
+fn test_max_query_tokens_bounds_huge_repetitive_text() {
+    let engine = get_engine().clone().with_max_query_tokens(50);
+    assert_eq!(engine.max_query_tokens(), 50);
+
+    let text = format!(
+        "SPDX-License-Identifier: MIT\n{}",
+        "lorem ipsum dolor sit amet\n".repeat(200_000)
+    );
+    let truncated = engine
+        .truncate_query_text(&text)
+        .expect("text past the cap should be truncated");
+    assert!(truncated.len() < 1024);
+
+    let detections = engine
+        .detect_with_kind(&text, false, false)
+        .expect("Detection should succeed");
+    assert!(
+        detections
+            .iter()
+            .any(|d| d.license_expression.as_deref() == Some("mit")),
+        "licenses before the cap should still be found: {detections:?}"
+    );
+}
+
+#[test]
+fn test_min_matched_length_keeps_full_rule_matches() {
+    let engine = get_engine().clone().with_min_matched_length(5);
+    assert_eq!(engine.min_matched_length(), 5);
+
+    let mit_text = engine
+        .index()
+        .licenses_by_key
+        .get("mit")
+        .map(|license| license.text.clone())
+        .expect("embedded index should contain mit");
+    let detections = engine
+        .detect_with_kind(&mit_text, false, false)
+        .expect("Detection should succeed");
+    assert_eq!(detections.len(), 1, "{detections:?}");
+    assert_eq!(detections[0].license_expression.as_deref(), Some("mit"));
+
+    // Short rules matched in full are exempt from the length floor.
+    let detections = engine
+        .detect_with_kind("SPDX-License-Identifier: MIT", false, false)
+        .expect("Detection should succeed");
+    assert_eq!(detections.len(), 1, "{detections:?}");
+}
+
+#[test]
+fn test_min_license_score_drops_low_scoring_matches() {
+    let engine = get_engine().clone().with_min_license_score(100);
+    assert_eq!(engine.min_license_score(), 100);
+
+    let mit_text = engine
+        .index()
+        .licenses_by_key
+        .get("mit")
+        .map(|license| license.text.clone())
+        .expect("embedded index should contain mit");
+    let detections = engine
+        .detect_with_kind(&mit_text, false, false)
+        .expect("Detection should succeed");
+    assert_eq!(detections.len(), 1, "{detections:?}");
+    assert_eq!(detections[0].license_expression.as_deref(), Some("mit"));
+
+    // Half of the MIT text only matches partially, with a score below 100.
+    let words: Vec<&str> = mit_text.split_whitespace().collect();
+    let partial = words[..words.len() / 2].join(" ");
+    let unfiltered = get_engine()
+        .detect_with_kind(&partial, false, false)
+        .expect("Detection should succeed");
+    assert!(
+        unfiltered
+            .iter()
+            .flat_map(|d| &d.matches)
+            .any(|m| m.score < 100.0),
+        "{unfiltered:?}"
+    );
+
+    let filtered = engine
+        .detect_with_kind(&partial, false, false)
+        .expect("Detection should succeed");
+    assert!(
+        filtered
+            .iter()
+            .flat_map(|d| &d.matches)
+            .all(|m| m.score >= 100.0),
+        "{filtered:?}"
+    );
+}
+
+#[test]
+fn test_match_type_distinguishes_tag_from_full_text() {
+    let engine = get_engine();
+
+    let detections = engine
+        .detect_with_kind("SPDX-License-Identifier: MIT", false, false)
+        .expect("Detection should succeed");
+    assert_eq!(detections.len(), 1, "{detections:?}");
+    assert!(
+        detections[0]
+            .matches
+            .iter()
+            .all(|m| m.match_type() == Some("tag")),
+        "{detections:?}"
+    );
+
+    let mit_text = engine
+        .index()
+        .licenses_by_key
+        .get("mit")
+        .map(|license| license.text.clone())
+        .expect("embedded index should contain mit");
+    let detections = engine
+        .detect_with_kind(&mit_text, false, false)
+        .expect("Detection should succeed");
+    assert_eq!(detections.len(), 1, "{detections:?}");
+    assert!(
+        detections[0]
+            .matches
+            .iter()
+            .all(|m| m.match_type() == Some("text")),
+        "{detections:?}"
+    );
+}
+
+#[test]
+fn test_exact_license_text_short_circuits_after_hash_match() {
+    let engine = get_engine();
+    let mit_text = engine
+        .index()
+        .licenses_by_key
+        .get("mit")
+        .map(|license| license.text.clone())
+        .expect("embedded index should contain the MIT license");
+
+    APPROXIMATE_PHASE_RUNS.with(|runs| runs.set(0));
+    let detections = engine
+        .detect_with_kind(&mit_text, false, false)
+        .expect("Detection should succeed");
+
+    assert_eq!(detections.len(), 1);
+    assert_eq!(detections[0].matches.len(), 1);
+    assert_eq!(detections[0].matches[0].matcher, MatcherKind::Hash);
+    assert_eq!(detections[0].license_expression.as_deref(), Some("mit"));
+    assert_eq!(APPROXIMATE_PHASE_RUNS.with(|runs| runs.get()), 0);
+
+    engine
+        .detect_with_kind(
+            "Some unrelated text. SPDX-License-Identifier: MIT",
+            false,
+            false,
+        )
+        .expect("Detection should succeed");
+    assert_eq!(APPROXIMATE_PHASE_RUNS.with(|runs| runs.get()), 1);
+}
+
+#[test]
+fn test_concatenated_full_license_texts_are_separate_detections() {
+    let engine = get_engine();
+    let license_text = |key: &str| {
+        engine
+            .index()
+            .licenses_by_key
+            .get(key)
+            .map(|license| license.text.clone())
+            .unwrap_or_else(|| panic!("embedded index should contain {key}"))
+    };
+
+    // A COPYING file that bundles two licenses with a single separator line,
+    // well within LINES_THRESHOLD of each other.
+    let text = format!(
+        "{}\n{}\n{}",
+        license_text("mit").trim_end(),
+        "-".repeat(72),
+        license_text("gpl-3.0")
+    );
+
+    let detections = engine
+        .detect_with_kind(&text, false, false)
+        .expect("Detection should succeed");
+
+    let mit = detections
+        .iter()
+        .find(|d| d.license_expression.as_deref() == Some("mit"))
+        .expect("MIT text should be its own detection");
+    assert!(
+        mit.matches
+            .iter()
+            .all(|m| m.license_expression == "mit" && m.match_coverage >= 99.0),
+        "{mit:?}"
+    );
+
+    let gpl = detections
+        .iter()
+        .find(|d| {
+            d.license_expression
+                .as_deref()
+                .is_some_and(|e| e.starts_with("gpl-3.0"))
+        })
+        .expect("GPL text should be its own detection");
+    assert!(
+        gpl.matches.iter().any(|m| m.match_coverage >= 99.0),
+        "{gpl:?}"
+    );
+
+    assert!(
+        detections.iter().all(|d| {
+            let expression = d.license_expression.as_deref().unwrap_or_default();
+            !(expression.contains("mit") && expression.contains("gpl"))
+        }),
+        "MIT and GPL regions should not be merged: {detections:?}"
+    );
+}
+
+#[test]
+fn test_templated_headers_with_substituted_variables_detect_identically() {
+    let engine = get_engine();
+
+    // A header template rendered with `${year}` and `${owner}` filled in per file.
+    let template = "/*\n * Copyright ${year} ${owner}\n *\n * Licensed under the Apache License, Version 2.0 (the \"License\");\n * you may not use this file except in compliance with the License.\n * You may obtain a copy of the License at\n *\n *      http://www.apache.org/licenses/LICENSE-2.0\n *\n * Unless required by applicable law or agreed to in writing, software\n * distributed under the License is distributed on an \"AS IS\" BASIS,\n * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.\n * See the License for the specific language governing permissions and\n * limitations under the License.\n */\npackage org.example;\n";
+
+    let rendered: Vec<String> = [
+        ("2015", "Alice Example"),
+        ("2019-2021", "Example Corp. and contributors"),
+        ("2024", "The Widget Authors <widgets@example.org>"),
+    ]
+    .iter()
+    .map(|(year, owner)| template.replace("${year}", year).replace("${owner}", owner))
+    .collect();
+
+    let summaries: Vec<(Option<String>, Vec<String>)> = rendered
+        .iter()
+        .map(|text| {
+            let detections = engine
+                .detect_with_kind(text, false, false)
+                .expect("Detection should succeed");
+            assert_eq!(detections.len(), 1, "{text}: {detections:?}");
+            let detection = &detections[0];
+            assert!(
+                detection
+                    .matches
+                    .iter()
+                    .all(|m| m.license_expression == "apache-2.0" && m.match_coverage >= 95.0),
+                "{text}: {detection:?}"
+            );
+            (
+                detection.license_expression.clone(),
+                detection
+                    .matches
+                    .iter()
+                    .map(|m| m.rule_identifier.clone())
+                    .collect(),
+            )
+        })
+        .collect();
+
+    assert_eq!(summaries[0].0.as_deref(), Some("apache-2.0"));
+    assert!(
+        summaries.iter().all(|summary| summary == &summaries[0]),
+        "{summaries:?}"
+    );
+}
+
+#[test]
+fn test_public_domain_dedications_are_flagged() {
+    let engine = get_engine();
+    let license_text = |key: &str| {
+        engine
+            .index()
+            .licenses_by_key
+            .get(key)
+            .map(|license| license.text.clone())
+            .unwrap_or_else(|| panic!("embedded index should contain {key}"))
+    };
+
+    for key in ["unlicense", "cc0-1.0"] {
+        let detections = engine
+            .detect_with_kind(&license_text(key), false, false)
+            .expect("Detection should succeed");
+        assert_eq!(detections.len(), 1, "{key}: {detections:?}");
+
+        let expression = detections[0]
+            .license_expression
+            .as_deref()
+            .expect("detection should have an expression");
+        assert_eq!(expression, key);
+        assert!(engine.is_public_domain_expression(expression), "{key}");
+    }
+
+    let detections = engine
+        .detect_with_kind("This file is placed in the public domain.\n", false, false)
+        .expect("Detection should succeed");
+    let expression = detections
+        .iter()
+        .filter_map(|d| d.license_expression.as_deref())
+        .find(|e| e.contains("public-domain"))
+        .unwrap_or_else(|| panic!("public domain statement not detected: {detections:?}"));
+    assert!(
+        engine.is_public_domain_expression(expression),
+        "{expression}"
+    );
+}
+
+#[test]
+fn test_is_public_domain_expression_rejects_other_licenses() {
+    let engine = get_engine();
+
+    assert!(!engine.is_public_domain_expression("mit"));
+    assert!(!engine.is_public_domain_expression("unlicense AND mit"));
+    assert!(engine.is_public_domain_expression("unlicense OR cc0-1.0"));
+    assert!(!engine.is_public_domain_expression(""));
+}
+
+#[test]
+fn test_reload_rules_picks_up_new_rule_files() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    write_relevance_fixture(dir.path());
+
+    let mut engine =
+        LicenseDetectionEngine::from_directory(dir.path()).expect("engine from fixture");
+    let bar_text = "This library is made available under the terms of the bar license agreement.";
+    let baz_text = "Redistribution of this module is governed by the baz license agreement only.";
+    let expressions = |engine: &LicenseDetectionEngine, text: &str| -> Vec<String> {
+        engine
+            .detect_with_kind(text, false, false)
+            .expect("detection should succeed")
+            .into_iter()
+            .filter_map(|d| d.license_expression)
+            .collect()
+    };
+
+    assert!(!expressions(&engine, baz_text).iter().any(|e| e == "baz"));
+    let previous_index = engine.shared_index();
+
+    std::fs::write(
+        dir.path().join("baz.LICENSE"),
+        "---\nkey: baz\nshort_name: baz\nname: baz\ncategory: Permissive\n---\n\
+         The baz license grants everyone the right to copy and share this software freely.",
+    )
+    .expect("write license fixture");
+    std::fs::write(
+        dir.path().join("baz_1.RULE"),
+        format!("---\nlicense_expression: baz\nis_license_notice: yes\n---\n{baz_text}"),
+    )
+    .expect("write new rule");
+
+    engine
+        .reload_rules(dir.path())
+        .expect("reload should succeed");
+
+    assert_eq!(expressions(&engine, baz_text), vec!["baz".to_string()]);
+    assert_eq!(expressions(&engine, bar_text), vec!["bar".to_string()]);
+    assert!(!previous_index.licenses_by_key.contains_key("baz"));
+    assert!(engine.index().licenses_by_key.contains_key("baz"));
+}
+
+#[test]
+fn test_reload_rules_keeps_index_on_error() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    write_relevance_fixture(dir.path());
+
+    let mut engine = LicenseDetectionEngine::from_directory(dir.path())
+        .expect("engine from fixture")
+        .with_min_rule_relevance(40);
+
+    let missing = dir.path().join("missing");
+    assert!(engine.reload_rules(&missing).is_err());
+    assert!(engine.index().licenses_by_key.contains_key("bar"));
+    assert_eq!(engine.min_rule_relevance(), 40);
+}
+
+#[test]
+fn test_translated_rules_are_detected_with_language() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    write_relevance_fixture(dir.path());
+    let translated_dir = tempfile::tempdir().expect("create translated dir");
+    std::fs::write(
+        translated_dir.path().join("bar_fr_1.RULE"),
+        "---\nlicense_expression: bar\nis_license_notice: yes\n---\n\
+         Cette bibliothèque est distribuée selon les termes de la licence bar.",
+    )
+    .expect("write translated rule");
+
+    let engine = LicenseDetectionEngine::from_directory(dir.path())
+        .expect("engine from fixture")
+        .with_min_rule_relevance(30)
+        .with_translated_rules(translated_dir.path(), "fr")
+        .expect("merge translated rules");
+    assert_eq!(engine.min_rule_relevance(), 30);
+
+    let detections = engine
+        .detect_with_kind(
+            "Cette bibliothèque est distribuée selon les termes de la licence bar.",
+            false,
+            false,
+        )
+        .expect("detection should succeed");
+    assert_eq!(detections.len(), 1, "{detections:?}");
+    assert_eq!(detections[0].license_expression.as_deref(), Some("bar"));
+    let languages: Vec<_> = detections[0]
+        .matches
+        .iter()
+        .map(|license_match| engine.match_language(license_match))
+        .collect();
+    assert_eq!(languages, vec![Some("fr")]);
+
+    let english = engine
+        .detect_with_kind(
+            "This library is made available under the terms of the bar license agreement.",
+            false,
+            false,
+        )
+        .expect("detection should succeed");
+    assert_eq!(english[0].license_expression.as_deref(), Some("bar"));
+    assert!(
+        english[0]
+            .matches
+            .iter()
+            .all(|license_match| engine.match_language(license_match).is_none())
+    );
+}
+
+#[test]
+fn test_reload_rules_keeps_translated_rules() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    write_relevance_fixture(dir.path());
+    let translated_dir = tempfile::tempdir().expect("create translated dir");
+    let french_text = "Cette bibliothèque est distribuée selon les termes de la licence bar.";
+    std::fs::write(
+        translated_dir.path().join("bar_fr_1.RULE"),
+        format!("---\nlicense_expression: bar\nis_license_notice: yes\n---\n{french_text}"),
+    )
+    .expect("write translated rule");
+
+    let mut engine = LicenseDetectionEngine::from_directory(dir.path())
+        .expect("engine from fixture")
+        .with_translated_rules(translated_dir.path(), "fr")
+        .expect("merge translated rules");
+    engine
+        .reload_rules(dir.path())
+        .expect("reload should succeed");
+
+    let detections = engine
+        .detect_with_kind(french_text, false, false)
+        .expect("detection should succeed");
+    assert_eq!(detections.len(), 1, "{detections:?}");
+    assert_eq!(detections[0].license_expression.as_deref(), Some("bar"));
+    assert_eq!(engine.match_language(&detections[0].matches[0]), Some("fr"));
+    assert_eq!(engine.translated_rules().len(), 1);
+}
+
+#[test]
+fn test_additional_rules_add_licenses_and_rules_to_index() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    write_relevance_fixture(dir.path());
+    let additional_dir = tempfile::tempdir().expect("create additional dir");
+    let licenses_dir = additional_dir.path().join("licenses");
+    let rules_dir = additional_dir.path().join("rules");
+    std::fs::create_dir_all(&licenses_dir).expect("create licenses dir");
+    std::fs::create_dir_all(&rules_dir).expect("create rules dir");
+    std::fs::write(
+        licenses_dir.join("acme-internal-1.0.LICENSE"),
+        "---\nkey: acme-internal-1.0\nshort_name: ACME Internal 1.0\nname: ACME Internal License 1.0\n\
+         category: Proprietary Free\nspdx_license_key: LicenseRef-ACME-Internal-1.0\n---\n\
+         ACME Corporation grants its employees the right to use this software for internal projects only.",
+    )
+    .expect("write additional license");
+    let rule_text = "This component is licensed under the ACME Internal License version 1.0.";
+    std::fs::write(
+        rules_dir.join("acme-internal-1.0_1.RULE"),
+        format!(
+            "---\nlicense_expression: acme-internal-1.0\nis_license_notice: yes\n---\n{rule_text}"
+        ),
+    )
+    .expect("write additional rule");
+
+    let mut engine = LicenseDetectionEngine::from_directory(dir.path())
+        .expect("engine from fixture")
+        .with_min_rule_relevance(30)
+        .with_additional_rules(additional_dir.path())
+        .expect("merge additional rules");
+    assert_eq!(engine.min_rule_relevance(), 30);
+    assert_eq!(
+        engine.additional_rules(),
+        [additional_dir.path().to_path_buf()]
+    );
+    assert!(
+        engine
+            .index()
+            .licenses_by_key
+            .contains_key("acme-internal-1.0")
+    );
+    assert!(engine.index().licenses_by_key.contains_key("bar"));
+
+    let expressions = |engine: &LicenseDetectionEngine, text: &str| -> Vec<String> {
+        engine
+            .detect_with_kind(text, false, false)
+            .expect("detection should succeed")
+            .into_iter()
+            .filter_map(|d| d.license_expression)
+            .collect()
+    };
+    assert_eq!(
+        expressions(&engine, rule_text),
+        vec!["acme-internal-1.0".to_string()]
+    );
+    assert_eq!(
+        expressions(
+            &engine,
+            "This library is made available under the terms of the bar license agreement."
+        ),
+        vec!["bar".to_string()]
+    );
+
+    engine
+        .reload_rules(dir.path())
+        .expect("reload should succeed");
+    assert_eq!(
+        expressions(&engine, rule_text),
+        vec!["acme-internal-1.0".to_string()]
+    );
+}
+
+#[test]
+fn test_load_license_list_version_reads_data_directory_metadata() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    assert_eq!(load_license_list_version(dir.path()), None);
+
+    std::fs::write(dir.path().join(LICENSE_LIST_VERSION_FILE), "3.26\n").expect("write version");
+    assert_eq!(
+        load_license_list_version(dir.path()).as_deref(),
+        Some("3.26")
+    );
+}
+
+#[test]
+fn test_license_data_with_unexpected_version_warns_but_still_detects() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    write_relevance_fixture(dir.path());
+    std::fs::write(dir.path().join(LICENSE_LIST_VERSION_FILE), "2.6\n").expect("write version");
+
+    let engine = LicenseDetectionEngine::from_directory(dir.path()).expect("engine from fixture");
+
+    assert_eq!(engine.license_list_version(), "2.6");
+    let warning = engine
+        .license_data_warning()
+        .expect("unexpected version should warn");
+    assert!(warning.contains("2.6"), "{warning}");
+
+    let detections = engine
+        .detect_with_kind(
+            "This library is made available under the terms of the bar license agreement.",
+            false,
+            false,
+        )
+        .expect("detection should succeed");
+    assert_eq!(detections[0].license_expression.as_deref(), Some("bar"));
+}
+
+#[test]
+fn test_license_list_version_warning_accepts_supported_range() {
+    assert_eq!(
+        license_list_version_warning(DEFAULT_LICENSE_LIST_VERSION),
+        None
+    );
+    assert_eq!(
+        license_list_version_warning(MIN_SUPPORTED_LICENSE_LIST_VERSION),
+        None
+    );
+    assert!(get_engine().license_data_warning().is_none());
+    assert!(license_list_version_warning("4.0").is_some());
+    assert!(license_list_version_warning("not-a-version").is_some());
+}
+
+#[test]
+fn test_engine_defaults_license_list_version() {
+    assert_eq!(
+        get_engine().license_list_version(),
+        DEFAULT_LICENSE_LIST_VERSION
+    );
+}
@@ -220,6 +220,19 @@ pub fn count_tokens(text: &str) -> usize {
         .count()
 }
 
+/// Cut `text` right after its `max_tokens`-th query token.
+///
+/// Stopwords count towards the cap, like they take a position in the query.
+/// Returns `None` when the text already has at most `max_tokens` tokens.
+pub fn truncate_to_token_count(text: &str, max_tokens: usize) -> Option<&str> {
+    let last_kept = QUERY_PATTERN
+        .find_iter(text)
+        .filter(|m| !m.as_str().is_empty())
+        .nth(max_tokens.checked_sub(1)?)?;
+    let end = last_kept.end();
+    QUERY_PATTERN.is_match(&text[end..]).then(|| &text[..end])
+}
+
 /// Parse {{...}} required phrase markers from rule text.
 ///
 /// Returns list of token position ranges for required phrases.
@@ -419,6 +432,15 @@ mod tests {
         assert_eq!(result, vec!["hello", "world", "this", "is", "test"]);
     }
 
+    #[test]
+    fn test_truncate_to_token_count() {
+        let text = "one two, three\nfour five";
+        assert_eq!(truncate_to_token_count(text, 3), Some("one two, three"));
+        assert_eq!(truncate_to_token_count(text, 5), None);
+        assert_eq!(truncate_to_token_count(text, 0), None);
+        assert_eq!(truncate_to_token_count("one two ...", 2), None);
+    }
+
     #[test]
     fn test_tokenize_with_spaces() {
         let result = tokenize("some Text with   spAces!");
@@ -1,28 +1,44 @@
-use anyhow::{Result, anyhow};
+use anyhow::{Context, Result, anyhow};
 use chrono::Utc;
 use clap::Parser;
 use regex::Regex;
 use std::env;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use tempfile::TempDir;
 
 use crate::cache::{CACHE_DIR_ENV_VAR, CacheConfig, build_collection_exclude_patterns};
-use crate::cli::Cli;
-use crate::license_detection::LicenseDetectionEngine;
-use crate::output::{OutputWriteConfig, write_output_file};
+use crate::cli::{Cli, Command, ServeArgs};
+use crate::license_detection::{DEFAULT_MAX_SEQUENCE_CANDIDATES, LicenseDetectionEngine};
+use crate::models::{FileInfo, Package};
+use crate::output::{
+    OutputFormat, OutputWriteConfig, write_output_file, write_rule_stats_file,
+    write_split_json_output, write_stats_output_file,
+};
 use crate::post_processing::{
-    CreateOutputContext, CreateOutputOptions, apply_package_reference_following, build_facet_rules,
-    collect_top_level_license_detections, collect_top_level_license_references, create_output,
+    CreateOutputContext, CreateOutputOptions, annotate_local_license_references,
+    apply_package_reference_following, build_facet_rules, collect_custom_license_references,
+    collect_rule_stats, collect_top_level_license_detections, collect_top_level_license_references,
+    collect_unknown_license_texts, compute_scan_risk_label, create_output,
+    link_custom_license_files,
 };
 use crate::progress::{ProgressMode, ScanProgress};
 use crate::scan_result_shaping::{
-    apply_cli_path_selection_filter, apply_ignore_resource_filter, apply_mark_source,
-    apply_only_findings_filter, apply_user_path_filters_to_collected, filter_redundant_clues,
-    filter_redundant_clues_with_rules, load_and_merge_json_inputs, normalize_paths,
-    normalize_top_level_output_paths, prepare_filter_clue_rule_lookup, resolve_native_scan_inputs,
-    trim_preloaded_assembly_to_files,
+    apply_cli_path_selection_filter, apply_gitignore_filter_to_collected,
+    apply_ignore_resource_filter, apply_mark_source, apply_only_findings_filter,
+    apply_tracked_only_filter_to_collected, apply_user_path_filters_to_collected,
+    compare_with_sbom, filter_redundant_clues, filter_redundant_clues_with_rules,
+    load_and_merge_json_inputs, load_sbom_components, normalize_paths,
+    normalize_top_level_output_paths, normalize_unknown_license_text_paths,
+    prepare_filter_clue_rule_lookup, resolve_native_scan_inputs, round_match_scores,
+    strip_license_text, trim_preloaded_assembly_to_files,
+};
+use crate::scanner::{
+    LicenseScanOptions, OciLayer, ParserPattern, TextDetectionOptions, attribute_archive_paths,
+    attribute_oci_layers, collect_paths_with_symlinks, extract_archives, extract_oci_layers,
+    process_collected,
 };
-use crate::scanner::{LicenseScanOptions, TextDetectionOptions, collect_paths, process_collected};
+use crate::utils::hash::{DEFAULT_HASH_ALGORITHMS, HashAlgorithm};
 
 mod assembly;
 mod cache;
@@ -37,6 +53,7 @@ mod post_processing;
 mod progress;
 mod scan_result_shaping;
 mod scanner;
+mod serve;
 mod utils;
 
 fn main() -> std::io::Result<()> {
@@ -48,21 +65,36 @@ fn main() -> std::io::Result<()> {
 }
 
 fn run() -> Result<()> {
-    let cli = Cli::parse();
+    let mut cli = Cli::parse();
+    let license_data_path = resolve_license_data_path(
+        cli.license_data_path.take(),
+        cli.license_rules_path.take(),
+        |name| env::var_os(name),
+    );
+    cli.license_rules_path = license_data_path.as_ref().map(|data| data.path.clone());
 
     if cli.show_attribution {
         print!("{}", include_str!("../NOTICE"));
         return Ok(());
     }
 
+    if let Some(Command::Serve(args)) = &cli.command {
+        return run_serve(args);
+    }
+
     let start_time = Utc::now();
     let progress = Arc::new(ScanProgress::new(progress_mode_from_cli(&cli)));
-    progress.set_processes(resolve_thread_count(cli.processes));
+    progress.set_processes(resolve_thread_count(cli.processes, cli.jobs));
     progress.set_scan_names(configured_scan_names(&cli));
     progress.init_logging_bridge();
 
     validate_scan_option_compatibility(&cli)?;
     let facet_rules = build_facet_rules(&cli.facet)?;
+    let sbom_components = cli
+        .compare_sbom
+        .as_deref()
+        .map(load_sbom_components)
+        .transpose()?;
 
     let ignore_author_patterns = compile_regex_patterns("--ignore-author", &cli.ignore_author)?;
     let ignore_copyright_holder_patterns =
@@ -106,21 +138,53 @@ fn run() -> Result<()> {
             None,
         )
     } else {
-        let (scan_path, native_input_includes) = resolve_native_scan_inputs(&cli.dir_path)?;
+        let oci_extraction = cli
+            .oci_image
+            .as_deref()
+            .map(prepare_oci_image)
+            .transpose()?;
+        let (scan_path, native_input_includes) = match &oci_extraction {
+            Some((_, image_root, _)) => (image_root.to_string_lossy().into_owned(), Vec::new()),
+            None => resolve_native_scan_inputs(&cli.dir_path)?,
+        };
         let mut native_include_patterns = cli.include.clone();
         native_include_patterns.extend(native_input_includes);
 
         let cache_config = prepare_cache_for_scan(&scan_path, &cli)?;
-        let collection_exclude_patterns =
-            build_collection_exclude_patterns(Path::new(&scan_path), cache_config.root_dir());
+        let collection_exclude_patterns = cache_config.as_ref().map_or_else(Vec::new, |config| {
+            build_collection_exclude_patterns(Path::new(&scan_path), config.root_dir())
+        });
 
-        let mut collected = collect_paths(&scan_path, cli.max_depth, &collection_exclude_patterns);
-        let user_excluded_count = apply_user_path_filters_to_collected(
+        let mut collected = collect_paths_with_symlinks(
+            &scan_path,
+            cli.max_depth,
+            &collection_exclude_patterns,
+            cli.follow_symlinks,
+        );
+        let mut user_excluded_count = apply_user_path_filters_to_collected(
             &mut collected,
             Path::new(&scan_path),
             &native_include_patterns,
             &cli.exclude,
         );
+        if cli.tracked_only {
+            user_excluded_count +=
+                apply_tracked_only_filter_to_collected(&mut collected, Path::new(&scan_path))?;
+        }
+        if cli.respect_gitignore {
+            user_excluded_count +=
+                apply_gitignore_filter_to_collected(&mut collected, Path::new(&scan_path));
+        }
+        let archive_extraction = if cli.extract_archives {
+            let extraction_dir = tempfile::Builder::new()
+                .prefix("provenant-archives-")
+                .tempdir()
+                .context("Failed to create directory for extracted archives")?;
+            let archives = extract_archives(&mut collected, extraction_dir.path(), cli.max_depth);
+            Some((extraction_dir, archives))
+        } else {
+            None
+        };
         let total_files = collected.file_count();
         let total_dirs = collected.directory_count();
         let total_size = collected.total_file_bytes;
@@ -138,7 +202,17 @@ fn run() -> Result<()> {
 
         let license_engine = if cli.license {
             progress.start_license_detection_engine_creation();
-            let engine = init_license_engine(&cli.license_rules_path)?;
+            let engine = init_license_engine(
+                license_data_path.as_ref(),
+                &cli.translated_license_rules,
+                &cli.additional_rules,
+                cli.min_rule_relevance,
+                cli.min_matched_length,
+                cli.min_license_score,
+                cli.max_sequence_candidates,
+                cli.max_query_tokens,
+                cache_config.as_ref().map(CacheConfig::index_dir).as_deref(),
+            )?;
             progress.finish_license_detection_engine_creation();
             progress.output_written(&describe_license_engine_source(
                 &engine,
@@ -158,17 +232,36 @@ fn run() -> Result<()> {
             detect_urls: cli.url,
             max_emails: cli.max_email,
             max_urls: cli.max_url,
+            exclude_common_urls: cli.exclude_common_urls,
             timeout_seconds: cli.timeout,
-            scan_cache_dir: Some(cache_config.scan_results_dir()),
+            scan_cache_dir: cache_config.as_ref().map(CacheConfig::scan_results_dir),
+            text_extensions: cli.text_ext.clone(),
+            binary_extensions: cli.binary_ext.clone(),
+            mark_copyright_only: cli.mark_copyright_only,
+            canonicalize_copyrights: cli.canonicalize_copyrights,
+            skip_data_files: cli.skip_data_files,
+            listed_authors: cli.listed_authors,
+            skip_code_copyrights: cli.skip_code_copyrights,
+            parser_patterns: build_parser_patterns(&cli)?,
+            debian_copyright_files: cli.debian_copyright_files,
+            rpm_specfile_licenses: cli.rpm_specfile_licenses,
+            hash_algorithms: resolve_hash_algorithms(cli.hash.clone(), cli.no_hash),
         };
 
-        let thread_count = resolve_thread_count(cli.processes);
+        let thread_count = resolve_thread_count(cli.processes, cli.jobs);
         progress.start_scan(total_files);
         let license_options = LicenseScanOptions {
             include_text: cli.license_text,
             include_text_diagnostics: cli.license_text_diagnostics,
             include_diagnostics: cli.license_diagnostics,
             unknown_licenses: cli.unknown_licenses,
+            include_match_type: cli.license_match_type,
+            include_public_domain: cli.license_public_domain,
+            include_license_facets: cli.license_facets,
+            split_license_components: cli.license_components,
+            include_in_header: cli.license_in_header,
+            imperfect_matches_as_clues: cli.imperfect_matches_as_clues,
+            matched_text_source: cli.license_text_from.unwrap_or_default(),
         };
         let mut result = run_with_thread_pool(thread_count, || {
             Ok(process_collected(
@@ -183,6 +276,28 @@ fn run() -> Result<()> {
         result.excluded_count = excluded_count;
         progress.finish_scan();
 
+        if let Some((extraction_dir, archives)) = archive_extraction {
+            attribute_archive_paths(&mut result.files, &archives);
+            let extraction_path = extraction_dir.path().to_path_buf();
+            if let Err(err) = extraction_dir.close() {
+                progress.output_written(&format!(
+                    "Failed to remove extracted archives {:?}: {}",
+                    extraction_path, err
+                ));
+            }
+        }
+
+        if let Some((extraction_dir, _, layers)) = oci_extraction {
+            attribute_oci_layers(&mut result.files, &layers, extraction_dir.path());
+            let extraction_path = extraction_dir.path().to_path_buf();
+            if let Err(err) = extraction_dir.close() {
+                progress.output_written(&format!(
+                    "Failed to remove extracted image layers {:?}: {}",
+                    extraction_path, err
+                ));
+            }
+        }
+
         (
             result,
             total_dirs,
@@ -260,11 +375,41 @@ fn run() -> Result<()> {
         }
     } else {
         progress.start_assembly();
-        let assembled = assembly::assemble(&mut scan_result.files);
+        let sibling_patterns = build_sibling_patterns(&cli, &scan_result.files)?;
+        let mut assembled =
+            assembly::assemble_with_patterns(&mut scan_result.files, &sibling_patterns);
+        if cli.deterministic_uids {
+            assembly::make_uids_deterministic(&mut assembled, &mut scan_result.files);
+        }
+        if cli.dependency_graph {
+            assembly::annotate_dependency_graph(&assembled.packages, &mut assembled.dependencies);
+        }
+        if cli.dependency_rollup {
+            assembly::annotate_dependency_rollup(&mut assembled.packages, &assembled.dependencies);
+        }
+        if cli.version_constraints {
+            assembly::annotate_version_constraints(&mut assembled.dependencies);
+        }
         progress.finish_assembly(assembled.packages.len(), manifests_seen);
         assembled
     };
 
+    let scan_root = cli.dir_path.first().map(Path::new);
+    if let Some(scan_root) = scan_root.filter(|_| cli.license && !cli.from_json) {
+        link_custom_license_files(&mut scan_result.files, scan_root);
+    }
+    let custom_license_references = match scan_root {
+        Some(scan_root) if cli.license_references && !cli.from_json => {
+            collect_custom_license_references(&scan_result.files, scan_root)
+        }
+        _ => Vec::new(),
+    };
+    let mut unknown_license_texts = if cli.unknown_license_texts {
+        collect_unknown_license_texts(&scan_result.files)
+    } else {
+        Vec::new()
+    };
+
     if !cli.from_json && (cli.strip_root || cli.full_root) {
         let root_path = cli
             .dir_path
@@ -282,13 +427,23 @@ fn run() -> Result<()> {
             root_path,
             cli.strip_root,
         );
+        normalize_unknown_license_text_paths(
+            &mut unknown_license_texts,
+            root_path,
+            cli.strip_root,
+            cli.full_root,
+        );
     }
 
     for package in &mut assembly_result.packages {
         package.backfill_license_provenance();
     }
 
-    apply_package_reference_following(&mut scan_result.files, &mut assembly_result.packages);
+    resolve_license_references(
+        &mut scan_result.files,
+        &mut assembly_result.packages,
+        cli.license_reference_clues,
+    );
 
     let end_time = Utc::now();
 
@@ -305,10 +460,20 @@ fn run() -> Result<()> {
             || cli.license_references);
 
     if should_recompute_license_references && active_license_engine.is_none() {
-        active_license_engine = Some(init_license_engine(&cli.license_rules_path)?);
+        active_license_engine = Some(init_license_engine(
+            license_data_path.as_ref(),
+            &cli.translated_license_rules,
+            &cli.additional_rules,
+            cli.min_rule_relevance,
+            cli.min_matched_length,
+            cli.min_license_score,
+            cli.max_sequence_candidates,
+            cli.max_query_tokens,
+            None,
+        )?);
     }
 
-    let (license_references, license_rule_references) =
+    let (mut license_references, license_rule_references) =
         if cli.from_json && !should_recompute_license_references {
             (
                 preloaded_license_references,
@@ -327,8 +492,17 @@ fn run() -> Result<()> {
         } else {
             (Vec::new(), Vec::new())
         };
+    license_references.extend(custom_license_references);
 
-    let output = create_output(
+    if let (Some(rule_stats_file), Some(engine)) =
+        (cli.rule_stats.as_deref(), active_license_engine.as_deref())
+    {
+        let rule_stats = collect_rule_stats(&scan_result.files, engine.index());
+        write_rule_stats_file(rule_stats_file, &rule_stats)?;
+        progress.output_written(&format!("Rule statistics written to {rule_stats_file}"));
+    }
+
+    let mut output = create_output(
         start_time,
         end_time,
         scan_result,
@@ -348,10 +522,51 @@ fn run() -> Result<()> {
                 include_tallies_with_details: cli.tallies_with_details,
                 include_tallies_by_facet: cli.tallies_by_facet,
                 include_generated: cli.generated,
+                include_summary_match_stats: cli.summary_match_stats,
+                include_primary_license: cli.primary_license,
+                include_system_environment: !cli.no_system_env,
             },
         },
     );
 
+    if let (Some(engine), Some(header)) =
+        (active_license_engine.as_deref(), output.headers.first_mut())
+    {
+        header.extra_data.spdx_license_list_version =
+            Some(engine.license_list_version().to_string());
+        header.errors.extend(engine.license_data_warning());
+        header.errors.extend(engine.load_errors().iter().cloned());
+    }
+
+    if cli.risk_label
+        && let (Some(engine), Some(summary)) =
+            (active_license_engine.as_deref(), output.summary.as_mut())
+    {
+        summary.scan_risk_label = Some(compute_scan_risk_label(
+            &output.files,
+            &output.packages,
+            engine.index(),
+        ));
+    }
+
+    output.unknown_license_texts = unknown_license_texts;
+    if let (Some(sbom_path), Some(sbom_components)) =
+        (cli.compare_sbom.as_deref(), sbom_components.as_deref())
+    {
+        output.sbom_drift = Some(compare_with_sbom(
+            sbom_path,
+            &output.packages,
+            sbom_components,
+        ));
+    }
+
+    if cli.strip_license_text {
+        strip_license_text(&mut output);
+    }
+    if let Some(precision) = cli.score_precision {
+        round_match_scores(&mut output, precision);
+    }
+
     progress.start_output();
     for target in cli.output_targets() {
         let output_config = OutputWriteConfig {
@@ -364,11 +579,42 @@ fn run() -> Result<()> {
             },
         };
 
-        write_output_file(&target.file, &output, &output_config)?;
-        progress.output_written(&format!(
-            "{:?} output written to {}",
-            target.format, target.file
-        ));
+        if cli.stats_only {
+            write_stats_output_file(
+                &target.file,
+                &output,
+                target.format == OutputFormat::JsonPretty,
+            )?;
+            progress.output_written(&format!(
+                "{:?} statistics written to {}",
+                target.format, target.file
+            ));
+            continue;
+        }
+
+        match cli.split_output {
+            Some(files_per_part) if is_split_target(target.format, &target.file) => {
+                let parts = write_split_json_output(
+                    &target.file,
+                    &output,
+                    files_per_part,
+                    target.format == OutputFormat::JsonPretty,
+                )?;
+                progress.output_written(&format!(
+                    "{:?} output manifest written to {} ({} parts)",
+                    target.format,
+                    target.file,
+                    parts.len()
+                ));
+            }
+            _ => {
+                write_output_file(&target.file, &output, &output_config)?;
+                progress.output_written(&format!(
+                    "{:?} output written to {}",
+                    target.format, target.file
+                ));
+            }
+        }
     }
     progress.finish_output();
 
@@ -385,7 +631,7 @@ fn validate_scan_option_compatibility(cli: &Cli) -> Result<()> {
         ));
     }
 
-    if !cli.from_json && cli.dir_path.is_empty() {
+    if !cli.from_json && cli.dir_path.is_empty() && cli.oci_image.is_none() {
         return Err(anyhow!("Directory path is required for scan operations"));
     }
 
@@ -395,10 +641,60 @@ fn validate_scan_option_compatibility(cli: &Cli) -> Result<()> {
         ));
     }
 
+    if cli.stats_only
+        && cli
+            .output_targets()
+            .iter()
+            .any(|target| !matches!(target.format, OutputFormat::Json | OutputFormat::JsonPretty))
+    {
+        return Err(anyhow!(
+            "--stats-only only supports --json and --json-pp output"
+        ));
+    }
+
+    if cli.split_output.is_some()
+        && !cli
+            .output_targets()
+            .iter()
+            .any(|target| is_split_target(target.format, &target.file))
+    {
+        return Err(anyhow!(
+            "--split-output requires a --json or --json-pp output file (not stdout)"
+        ));
+    }
+
     Ok(())
 }
 
-fn prepare_cache_for_scan(scan_path: &str, cli: &Cli) -> Result<CacheConfig> {
+fn is_split_target(format: OutputFormat, file: &str) -> bool {
+    matches!(format, OutputFormat::Json | OutputFormat::JsonPretty) && file != "-"
+}
+
+/// Unpack the layers of the OCI image at `image_dir` into a fresh temporary
+/// directory, returning that directory, the image root to scan and the layers.
+/// The directory is removed when the returned [`TempDir`] is dropped, including
+/// on early error returns.
+fn prepare_oci_image(image_dir: &str) -> Result<(TempDir, PathBuf, Vec<OciLayer>)> {
+    let image_dir = Path::new(image_dir);
+    let image_name = image_dir
+        .canonicalize()
+        .ok()
+        .and_then(|path| path.file_name().map(|name| name.to_os_string()))
+        .unwrap_or_else(|| "image".into());
+    let extraction_dir = tempfile::Builder::new()
+        .prefix("provenant-oci-")
+        .tempdir()
+        .context("Failed to create directory for extracted image layers")?;
+    let image_root = extraction_dir.path().join(image_name);
+    let layers = extract_oci_layers(image_dir, &image_root)?;
+    Ok((extraction_dir, image_root, layers))
+}
+
+/// Resolve and create the cache directories, or `None` under `--no-cache`.
+fn prepare_cache_for_scan(scan_path: &str, cli: &Cli) -> Result<Option<CacheConfig>> {
+    if cli.no_cache {
+        return Ok(None);
+    }
     let env_cache_dir = env::var_os(CACHE_DIR_ENV_VAR).map(PathBuf::from);
     let config = CacheConfig::from_overrides(
         Path::new(scan_path),
@@ -411,7 +707,7 @@ fn prepare_cache_for_scan(scan_path: &str, cli: &Cli) -> Result<CacheConfig> {
     }
 
     config.ensure_dirs()?;
-    Ok(config)
+    Ok(Some(config))
 }
 
 fn compile_regex_patterns(option_name: &str, patterns: &[String]) -> Result<Vec<Regex>> {
@@ -425,14 +721,25 @@ fn compile_regex_patterns(option_name: &str, patterns: &[String]) -> Result<Vec<
         .collect()
 }
 
-fn resolve_thread_count(processes: i32) -> usize {
-    if processes > 0 {
+fn resolve_hash_algorithms(requested: Vec<HashAlgorithm>, no_hash: bool) -> Vec<HashAlgorithm> {
+    if no_hash {
+        Vec::new()
+    } else if requested.is_empty() {
+        DEFAULT_HASH_ALGORITHMS.to_vec()
+    } else {
+        requested
+    }
+}
+
+fn resolve_thread_count(processes: i32, jobs: Option<usize>) -> usize {
+    let threads = if processes > 0 {
         processes as usize
     } else if processes == 0 {
         default_parallel_threads()
     } else {
         1
-    }
+    };
+    jobs.map_or(threads, |jobs| threads.min(jobs))
 }
 
 fn default_parallel_threads() -> usize {
@@ -481,21 +788,246 @@ where
     pool.install(f)
 }
 
-fn init_license_engine(rules_path: &Option<String>) -> Result<Arc<LicenseDetectionEngine>> {
-    match rules_path {
-        Some(p) => {
-            let path = PathBuf::from(p);
+fn run_serve(args: &ServeArgs) -> Result<()> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn")).init();
+    let license_data_path = resolve_license_data_path(
+        args.license_data_path.clone(),
+        args.license_rules_path.clone(),
+        |name| env::var_os(name),
+    );
+    let engine = init_license_engine(
+        license_data_path.as_ref(),
+        &[],
+        &[],
+        0,
+        0,
+        0,
+        DEFAULT_MAX_SEQUENCE_CANDIDATES,
+        0,
+        None,
+    )?;
+    let server = serve::DetectServer::bind(args.addr.as_str(), engine)?;
+    eprintln!("Listening on http://{}/detect", server.local_addr()?);
+    server.serve()
+}
+
+/// File in the cache index directory holding the index built from
+/// `--license-rules-path`.
+const LICENSE_INDEX_CACHE_FILE: &str = "license_index.msgpack";
+
+/// Environment variable naming a license data directory, used when no path is
+/// given on the command line.
+const LICENSE_DATA_PATH_ENV_VAR: &str = "LICENSE_DATA_PATH";
+
+/// Environment variable naming a license rules directory, used when neither
+/// the command line nor [`LICENSE_DATA_PATH_ENV_VAR`] gives one.
+const LICENSE_RULES_PATH_ENV_VAR: &str = "PROVENANT_LICENSE_RULES";
+
+/// A license data directory and the option or variable it was taken from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LicenseDataPath {
+    path: String,
+    origin: String,
+}
+
+/// Pick the license data directory: `--license-data-path` or
+/// `--license-rules-path` first, then [`LICENSE_DATA_PATH_ENV_VAR`], then
+/// [`LICENSE_RULES_PATH_ENV_VAR`]. `None` selects the embedded index.
+fn resolve_license_data_path(
+    data_path: Option<String>,
+    rules_path: Option<String>,
+    env_var: impl Fn(&str) -> Option<std::ffi::OsString>,
+) -> Option<LicenseDataPath> {
+    let from_cli = [
+        ("--license-data-path", data_path),
+        ("--license-rules-path", rules_path),
+    ]
+    .into_iter()
+    .find_map(|(flag, path)| {
+        Some(LicenseDataPath {
+            path: path?,
+            origin: flag.to_string(),
+        })
+    });
+    from_cli.or_else(|| {
+        [LICENSE_DATA_PATH_ENV_VAR, LICENSE_RULES_PATH_ENV_VAR]
+            .into_iter()
+            .find_map(|name| {
+                let path = env_var(name).filter(|path| !path.is_empty())?;
+                Some(LicenseDataPath {
+                    path: path.to_string_lossy().into_owned(),
+                    origin: format!("the {name} environment variable"),
+                })
+            })
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn init_license_engine(
+    license_data_path: Option<&LicenseDataPath>,
+    translated_rules: &[String],
+    additional_rules: &[String],
+    min_rule_relevance: u8,
+    min_matched_length: usize,
+    min_license_score: u8,
+    max_sequence_candidates: usize,
+    max_query_tokens: usize,
+    index_cache_dir: Option<&Path>,
+) -> Result<Arc<LicenseDetectionEngine>> {
+    let mut engine = match license_data_path {
+        Some(data) => {
+            let path = PathBuf::from(&data.path);
             if !path.exists() {
-                return Err(anyhow!("License rules path does not exist: {:?}", path));
+                return Err(anyhow!(
+                    "License data path does not exist: {:?} (from {})",
+                    path,
+                    data.origin
+                ));
             }
-            let engine = LicenseDetectionEngine::from_directory(&path)?;
-            Ok(Arc::new(engine))
+            match index_cache_dir {
+                Some(cache_dir) => LicenseDetectionEngine::from_directory_cached(
+                    &path,
+                    &cache_dir.join(LICENSE_INDEX_CACHE_FILE),
+                )?,
+                None => LicenseDetectionEngine::from_directory(&path)?,
+            }
+        }
+        None => LicenseDetectionEngine::from_embedded()?,
+    };
+    for translated in translated_rules {
+        let (language, path) = parse_translated_rules_arg(translated)?;
+        engine = engine.with_translated_rules(&path, &language)?;
+    }
+    for rules_dir in additional_rules {
+        let path = PathBuf::from(rules_dir);
+        if !path.is_dir() {
+            return Err(anyhow!(
+                "Additional license rules path does not exist: {:?}",
+                path
+            ));
         }
-        None => {
-            let engine = LicenseDetectionEngine::from_embedded()?;
-            Ok(Arc::new(engine))
+        engine = engine.with_additional_rules(&path)?;
+    }
+    Ok(Arc::new(
+        engine
+            .with_min_rule_relevance(min_rule_relevance)
+            .with_min_matched_length(min_matched_length)
+            .with_min_license_score(min_license_score)
+            .with_max_sequence_candidates(max_sequence_candidates)
+            .with_max_query_tokens(max_query_tokens),
+    ))
+}
+
+fn parse_translated_rules_arg(value: &str) -> Result<(String, PathBuf)> {
+    let Some((language, path)) = value.split_once('=') else {
+        return Err(anyhow!(
+            "Invalid --translated-license-rules value \"{}\": expected LANG=PATH",
+            value
+        ));
+    };
+    let (language, path) = (language.trim(), PathBuf::from(path.trim()));
+    if language.is_empty() {
+        return Err(anyhow!(
+            "Invalid --translated-license-rules value \"{}\": missing language",
+            value
+        ));
+    }
+    if !path.is_dir() {
+        return Err(anyhow!(
+            "Translated license rules path does not exist: {:?}",
+            path
+        ));
+    }
+    Ok((language.to_string(), path))
+}
+
+/// Route files matching `--manifest-pattern`/`--lockfile-pattern` globs to their parser.
+fn build_parser_patterns(cli: &Cli) -> Result<Vec<ParserPattern>> {
+    cli.manifest_pattern
+        .iter()
+        .map(|value| parse_sibling_pattern_arg("--manifest-pattern", value))
+        .chain(
+            cli.lockfile_pattern
+                .iter()
+                .map(|value| parse_sibling_pattern_arg("--lockfile-pattern", value)),
+        )
+        .collect()
+}
+
+/// Attach each pattern to the assembler of the datasources its matched files were parsed as.
+fn build_sibling_patterns(cli: &Cli, files: &[FileInfo]) -> Result<assembly::SiblingPatterns> {
+    let mut patterns = assembly::SiblingPatterns::default();
+    for (option_name, values, is_lockfile) in [
+        ("--manifest-pattern", &cli.manifest_pattern, false),
+        ("--lockfile-pattern", &cli.lockfile_pattern, true),
+    ] {
+        for value in values {
+            let parser_pattern = parse_sibling_pattern_arg(option_name, value)?;
+            let mut datasource_ids = Vec::new();
+            for datasource_id in files
+                .iter()
+                .filter(|file| {
+                    Path::new(&file.path)
+                        .file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| parser_pattern.pattern.matches(name))
+                })
+                .flat_map(|file| file.package_data.iter())
+                .filter_map(|package_data| package_data.datasource_id)
+            {
+                if !datasource_ids.contains(&datasource_id) {
+                    datasource_ids.push(datasource_id);
+                }
+            }
+            for datasource_id in datasource_ids {
+                let registered = if is_lockfile {
+                    patterns.add_lockfile_pattern(datasource_id, parser_pattern.pattern.as_str())
+                } else {
+                    patterns.add_manifest_pattern(datasource_id, parser_pattern.pattern.as_str())
+                };
+                registered
+                    .map_err(|err| anyhow!("Invalid {option_name} value \"{}\": {}", value, err))?;
+            }
         }
     }
+    Ok(patterns)
+}
+
+/// Follow license references into the referenced files and packages, then,
+/// when requested, record the sibling-file clues on top of the followed result.
+fn resolve_license_references(
+    files: &mut [FileInfo],
+    packages: &mut [Package],
+    reference_clues: bool,
+) {
+    apply_package_reference_following(files, packages);
+    if reference_clues {
+        annotate_local_license_references(files);
+    }
+}
+
+fn parse_sibling_pattern_arg(option_name: &str, value: &str) -> Result<ParserPattern> {
+    let Some((parser, glob)) = value.split_once('=') else {
+        return Err(anyhow!(
+            "Invalid {option_name} value \"{value}\": expected PARSER=GLOB"
+        ));
+    };
+    let (parser, glob) = (parser.trim(), glob.trim());
+    let pattern = glob::Pattern::new(glob)
+        .ok()
+        .filter(|_| !glob.is_empty())
+        .ok_or_else(|| {
+            anyhow!("Invalid {option_name} value \"{value}\": invalid filename pattern")
+        })?;
+    if !crate::parsers::list_parser_types().contains(&parser) {
+        return Err(anyhow!(
+            "Invalid {option_name} value \"{value}\": unknown parser"
+        ));
+    }
+    Ok(ParserPattern {
+        parser: parser.to_string(),
+        pattern,
+    })
 }
 
 fn describe_license_engine_source(
@@ -5,7 +5,10 @@ use std::fs;
 use std::path::Path;
 
 use crate::cache::{DEFAULT_CACHE_DIR_NAME, build_collection_exclude_patterns};
+use crate::models::DatasourceId;
+use crate::models::{LicenseDetection, Match};
 use crate::post_processing::collect_top_level_license_detections;
+use crate::post_processing::test_utils::{dir, file};
 use crate::scan_result_shaping::json_input::{
     JsonScanInput, load_scan_from_json, normalize_loaded_json_scan,
 };
@@ -13,9 +16,30 @@ use crate::scanner::collect_paths;
 
 #[test]
 fn resolve_thread_count_supports_reference_compat_values() {
-    assert_eq!(resolve_thread_count(-1), 1);
-    assert_eq!(resolve_thread_count(0), default_parallel_threads());
-    assert_eq!(resolve_thread_count(4), 4);
+    assert_eq!(resolve_thread_count(-1, None), 1);
+    assert_eq!(resolve_thread_count(0, None), default_parallel_threads());
+    assert_eq!(resolve_thread_count(4, None), 4);
+}
+
+#[test]
+fn resolve_hash_algorithms_defaults_to_sha1_md5_sha256() {
+    assert_eq!(
+        resolve_hash_algorithms(Vec::new(), false),
+        DEFAULT_HASH_ALGORITHMS.to_vec()
+    );
+    assert_eq!(
+        resolve_hash_algorithms(vec![HashAlgorithm::Sha512], false),
+        vec![HashAlgorithm::Sha512]
+    );
+    assert!(resolve_hash_algorithms(Vec::new(), true).is_empty());
+}
+
+#[test]
+fn resolve_thread_count_caps_processes_at_jobs() {
+    assert_eq!(resolve_thread_count(8, Some(2)), 2);
+    assert_eq!(resolve_thread_count(2, Some(8)), 2);
+    assert_eq!(resolve_thread_count(-1, Some(4)), 1);
+    assert_eq!(resolve_thread_count(0, Some(1)), 1);
 }
 
 #[test]
@@ -282,6 +306,7 @@ fn from_json_loaded_manifest_detections_can_be_recomputed_into_top_level_uniques
             }],
             detection_log: vec![],
             identifier: None,
+            ..Default::default()
         }],
         ..Default::default()
     }];
@@ -345,6 +370,7 @@ fn from_json_recomputes_top_level_uniques_even_without_shaping_flags() {
             }],
             detection_log: vec![],
             identifier: None,
+            ..Default::default()
         }],
         ..Default::default()
     }];
@@ -446,7 +472,9 @@ fn prepare_cache_for_scan_defaults_to_scan_root_cache_directory() {
     let cli =
         crate::cli::Cli::try_parse_from(["provenant", "--json-pp", "scan.json", "sample-dir"])
             .unwrap();
-    let config = prepare_cache_for_scan(scan_root.to_str().unwrap(), &cli).unwrap();
+    let config = prepare_cache_for_scan(scan_root.to_str().unwrap(), &cli)
+        .unwrap()
+        .expect("cache should be enabled");
 
     assert_eq!(config.root_dir(), scan_root.join(DEFAULT_CACHE_DIR_NAME));
     assert!(config.index_dir().exists());
@@ -474,7 +502,9 @@ fn prepare_cache_for_scan_respects_cache_dir_and_cache_clear() {
         "sample-dir",
     ])
     .unwrap();
-    let config = prepare_cache_for_scan(scan_root.to_str().unwrap(), &cli).unwrap();
+    let config = prepare_cache_for_scan(scan_root.to_str().unwrap(), &cli)
+        .unwrap()
+        .expect("cache should be enabled");
 
     assert_eq!(config.root_dir(), explicit_cache_dir);
     assert!(!stale_file.exists());
@@ -482,6 +512,29 @@ fn prepare_cache_for_scan_respects_cache_dir_and_cache_clear() {
     assert!(config.scan_results_dir().exists());
 }
 
+#[test]
+fn prepare_cache_for_scan_is_disabled_by_no_cache() {
+    let temp_dir = tempfile::TempDir::new().expect("create temp dir");
+    let scan_root = temp_dir.path().join("scan");
+    fs::create_dir_all(&scan_root).expect("create scan root");
+
+    let cli = crate::cli::Cli::try_parse_from([
+        "provenant",
+        "--json-pp",
+        "scan.json",
+        "--no-cache",
+        "sample-dir",
+    ])
+    .unwrap();
+
+    assert!(
+        prepare_cache_for_scan(scan_root.to_str().unwrap(), &cli)
+            .unwrap()
+            .is_none()
+    );
+    assert!(!scan_root.join(DEFAULT_CACHE_DIR_NAME).exists());
+}
+
 #[test]
 fn build_collection_exclude_patterns_skips_default_cache_dir() {
     let temp_dir = tempfile::TempDir::new().expect("create temp dir");
@@ -554,3 +607,239 @@ fn build_collection_exclude_patterns_does_not_exclude_scan_root_when_cache_root_
     assert_eq!(collected.file_count(), 1);
     assert_eq!(collected.excluded_count, 0);
 }
+
+#[test]
+fn parse_translated_rules_arg_requires_language_and_existing_dir() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let value = format!("fr={}", dir.path().display());
+
+    let (language, path) = parse_translated_rules_arg(&value).expect("valid value");
+    assert_eq!(language, "fr");
+    assert_eq!(path, dir.path());
+
+    assert!(parse_translated_rules_arg(&dir.path().display().to_string()).is_err());
+    assert!(parse_translated_rules_arg(&format!("={}", dir.path().display())).is_err());
+    assert!(parse_translated_rules_arg("fr=/definitely/missing/rules").is_err());
+}
+
+fn env_lookup(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<std::ffi::OsString> {
+    let vars: Vec<(String, String)> = vars
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+    move |name| {
+        vars.iter()
+            .find(|(var, _)| var == name)
+            .map(|(_, value)| value.into())
+    }
+}
+
+#[test]
+fn resolve_license_data_path_prefers_cli_then_environment() {
+    let env = env_lookup(&[
+        (LICENSE_DATA_PATH_ENV_VAR, "/env/data"),
+        (LICENSE_RULES_PATH_ENV_VAR, "/env/rules"),
+    ]);
+
+    let resolved = resolve_license_data_path(Some("/cli/data".to_string()), None, &env)
+        .expect("cli path should resolve");
+    assert_eq!(resolved.path, "/cli/data");
+    assert_eq!(resolved.origin, "--license-data-path");
+
+    let resolved = resolve_license_data_path(None, Some("/cli/rules".to_string()), &env)
+        .expect("cli rules path should resolve");
+    assert_eq!(resolved.path, "/cli/rules");
+    assert_eq!(resolved.origin, "--license-rules-path");
+
+    let resolved = resolve_license_data_path(None, None, &env).expect("env path should resolve");
+    assert_eq!(resolved.path, "/env/data");
+    assert_eq!(
+        resolved.origin,
+        "the LICENSE_DATA_PATH environment variable"
+    );
+
+    let env = env_lookup(&[
+        (LICENSE_DATA_PATH_ENV_VAR, ""),
+        (LICENSE_RULES_PATH_ENV_VAR, "/env/rules"),
+    ]);
+    let resolved =
+        resolve_license_data_path(None, None, &env).expect("rules env path should resolve");
+    assert_eq!(resolved.path, "/env/rules");
+    assert_eq!(
+        resolved.origin,
+        "the PROVENANT_LICENSE_RULES environment variable"
+    );
+
+    assert_eq!(resolve_license_data_path(None, None, env_lookup(&[])), None);
+}
+
+#[test]
+fn init_license_engine_names_missing_data_path_and_origin() {
+    let data_path = LicenseDataPath {
+        path: "/definitely/missing/data".to_string(),
+        origin: "the LICENSE_DATA_PATH environment variable".to_string(),
+    };
+    let error = init_license_engine(
+        Some(&data_path),
+        &[],
+        &[],
+        0,
+        0,
+        0,
+        DEFAULT_MAX_SEQUENCE_CANDIDATES,
+        0,
+        None,
+    )
+    .err()
+    .expect("missing data path should fail");
+
+    let message = error.to_string();
+    assert!(message.contains("/definitely/missing/data"));
+    assert!(message.contains("LICENSE_DATA_PATH"));
+}
+
+#[test]
+fn parse_sibling_pattern_arg_requires_known_parser_and_glob() {
+    let parser_pattern =
+        parse_sibling_pattern_arg("--lockfile-pattern", "CargoLockParser=*.vendor.lock")
+            .expect("valid value");
+    assert_eq!(parser_pattern.parser, "CargoLockParser");
+    assert_eq!(parser_pattern.pattern.as_str(), "*.vendor.lock");
+
+    assert!(parse_sibling_pattern_arg("--lockfile-pattern", "*.vendor.lock").is_err());
+    assert!(parse_sibling_pattern_arg("--lockfile-pattern", "NotAParser=x.lock").is_err());
+    assert!(parse_sibling_pattern_arg("--lockfile-pattern", "CargoLockParser=").is_err());
+}
+
+#[test]
+fn custom_lockfile_pattern_is_parsed_and_merged_with_its_manifest() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let project = dir.path().join("project");
+    fs::create_dir_all(&project).expect("create project dir");
+    fs::write(
+        project.join("Cargo.toml"),
+        "[package]\nname = \"my-crate\"\nversion = \"0.1.0\"\n\n[dependencies]\nserde = \"1\"\n",
+    )
+    .expect("write manifest");
+    fs::write(
+        project.join("deps.vendor.lock"),
+        "version = 3\n\n[[package]]\nname = \"my-crate\"\nversion = \"0.1.0\"\ndependencies = [\n \"serde\",\n]\n\n[[package]]\nname = \"serde\"\nversion = \"1.0.200\"\nsource = \"registry+https://github.com/rust-lang/crates.io-index\"\n",
+    )
+    .expect("write custom lockfile");
+
+    let cli = Cli::try_parse_from([
+        "provenant",
+        "--json-pp",
+        "scan.json",
+        "--package",
+        "--lockfile-pattern",
+        "CargoLockParser=*.vendor.lock",
+        dir.path().to_str().expect("utf-8 temp path"),
+    ])
+    .expect("cli parse should succeed");
+
+    let collected = collect_paths(dir.path(), 0, &[]);
+    let mut files = process_collected(
+        &collected,
+        Arc::new(ScanProgress::new(ProgressMode::Quiet)),
+        None,
+        LicenseScanOptions::default(),
+        &TextDetectionOptions {
+            detect_packages: true,
+            parser_patterns: build_parser_patterns(&cli).expect("valid parser patterns"),
+            ..TextDetectionOptions::default()
+        },
+    )
+    .files;
+
+    let lockfile = files
+        .iter()
+        .find(|file| file.path.ends_with("deps.vendor.lock"))
+        .expect("custom lockfile should be scanned");
+    assert_eq!(
+        lockfile.package_data[0].datasource_id,
+        Some(DatasourceId::CargoLock)
+    );
+
+    let sibling_patterns =
+        build_sibling_patterns(&cli, &files).expect("sibling patterns should build");
+    let result = assembly::assemble_with_patterns(&mut files, &sibling_patterns);
+
+    assert_eq!(result.packages.len(), 1, "{:?}", result.packages);
+    let datafile_paths = &result.packages[0].datafile_paths;
+    assert_eq!(datafile_paths.len(), 2, "{datafile_paths:?}");
+    assert!(datafile_paths[1].ends_with("deps.vendor.lock"));
+}
+
+fn detection_with_match(
+    path: &str,
+    expression: &str,
+    spdx: &str,
+    referenced_filenames: Option<Vec<String>>,
+) -> LicenseDetection {
+    LicenseDetection {
+        license_expression: expression.to_string(),
+        license_expression_spdx: spdx.to_string(),
+        matches: vec![Match {
+            license_expression: expression.to_string(),
+            license_expression_spdx: spdx.to_string(),
+            from_file: Some(path.to_string()),
+            start_line: 1,
+            end_line: 1,
+            matcher: Some("2-aho".to_string()),
+            score: 100.0,
+            matched_length: Some(4),
+            match_coverage: Some(100.0),
+            rule_relevance: Some(100),
+            referenced_filenames,
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn license_reference_clues_are_recorded_after_reference_following() {
+    let mut source = file("project/main.c");
+    source.license_detections = vec![detection_with_match(
+        "project/main.c",
+        "unknown-license-reference",
+        "LicenseRef-scancode-unknown-license-reference",
+        Some(vec!["LICENSE".to_string()]),
+    )];
+    source.license_expression = Some("unknown-license-reference".to_string());
+    let mut license = file("project/LICENSE");
+    license.license_detections = vec![detection_with_match("project/LICENSE", "mit", "MIT", None)];
+    license.license_expression = Some("mit".to_string());
+
+    let mut files = vec![dir("project"), source, license];
+    resolve_license_references(&mut files, &mut [], true);
+
+    let source = &files[1];
+    assert_eq!(source.license_expression.as_deref(), Some("mit"));
+    assert_eq!(
+        source.license_detections[0].matches[1].from_file.as_deref(),
+        Some("project/LICENSE")
+    );
+    assert_eq!(source.referenced_license_expression.as_deref(), Some("mit"));
+    assert_eq!(files[2].referenced_license_expression, None);
+}
+
+#[test]
+fn license_reference_clues_stay_off_unless_requested() {
+    let mut source = file("project/main.c");
+    source.license_detections = vec![detection_with_match(
+        "project/main.c",
+        "unknown-license-reference",
+        "LicenseRef-scancode-unknown-license-reference",
+        Some(vec!["LICENSE".to_string()]),
+    )];
+    let mut license = file("project/LICENSE");
+    license.license_detections = vec![detection_with_match("project/LICENSE", "mit", "MIT", None)];
+
+    let mut files = vec![dir("project"), source, license];
+    resolve_license_references(&mut files, &mut [], false);
+
+    assert_eq!(files[1].license_detections[0].license_expression, "mit");
+    assert_eq!(files[1].referenced_license_expression, None);
+}
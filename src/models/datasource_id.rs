@@ -177,6 +177,7 @@ pub enum DatasourceId {
     // ── Gradle ──
     BuildGradle,
     GradleLockfile,
+    GradleDependenciesLock,
     GradleModule,
 
     // ── Haxe ──
@@ -187,6 +188,7 @@ pub enum DatasourceId {
     HelmChartYaml,
 
     // ── Hex/Elixir ──
+    HexMixExs,
     HexMixLock,
 
     // ── Java ──
@@ -214,6 +216,7 @@ pub enum DatasourceId {
 
     // ── Mobile/Browser ──
     AppleDmg,
+    AppleInfoPlist,
     ChromeCrx,
     IosIpa,
     MozillaXpi,
@@ -317,6 +320,9 @@ pub enum DatasourceId {
 
     // ── Git ──
     Gitmodules,
+
+    // ── Runtime-registered parsers ──
+    Custom,
 }
 
 impl DatasourceId {
@@ -440,6 +446,7 @@ impl DatasourceId {
             // Gradle
             Self::BuildGradle => "build_gradle",
             Self::GradleLockfile => "gradle_lockfile",
+            Self::GradleDependenciesLock => "gradle_dependencies_lock",
             Self::GradleModule => "gradle_module",
 
             // Haxe
@@ -450,6 +457,7 @@ impl DatasourceId {
             Self::HelmChartYaml => "helm_chart_yaml",
 
             // Hex/Elixir
+            Self::HexMixExs => "hex_mix_exs",
             Self::HexMixLock => "hex_mix_lock",
 
             // Java
@@ -476,6 +484,7 @@ impl DatasourceId {
 
             // Mobile/Browser
             Self::AppleDmg => "apple_dmg",
+            Self::AppleInfoPlist => "apple_info_plist",
             Self::ChromeCrx => "chrome_crx",
             Self::IosIpa => "ios_ipa",
             Self::MozillaXpi => "mozilla_xpi",
@@ -575,6 +584,9 @@ impl DatasourceId {
 
             // Git
             Self::Gitmodules => "gitmodules",
+
+            // Runtime-registered parsers
+            Self::Custom => "custom",
         }
     }
 }
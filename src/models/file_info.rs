@@ -12,7 +12,7 @@ use crate::license_detection::tokenize::tokenize_without_stopwords;
 use crate::models::output::Tallies;
 use crate::utils::spdx::combine_license_expressions;
 
-#[derive(Debug, Builder, Serialize, Deserialize)]
+#[derive(Debug, Default, Builder, Serialize, Deserialize)]
 #[builder(build_fn(skip))]
 /// File-level scan result containing metadata and detected findings.
 pub struct FileInfo {
@@ -33,6 +33,10 @@ pub struct FileInfo {
     pub md5: Option<String>,
     #[builder(default)]
     pub sha256: Option<String>,
+    /// Only computed when requested with `--hash sha512`.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub sha512: Option<String>,
     #[builder(default)]
     pub programming_language: Option<String>,
     #[builder(default)]
@@ -99,6 +103,32 @@ pub struct FileInfo {
     #[serde(skip_serializing_if = "is_false", default)]
     pub is_community: bool,
     #[builder(default)]
+    #[serde(skip_serializing_if = "is_false", default)]
+    pub has_copyright_no_license: bool,
+    /// Informational notes about how the file was scanned (e.g. lossy text decoding).
+    ///
+    /// Provenant extension with no ScanCode counterpart. The notes do not belong
+    /// in `scan_errors`, because the file was still scanned and ScanCode users
+    /// read a non-empty `scan_errors` as a failure. The field is omitted when
+    /// empty, so ScanCode-shaped output only gains it for files that were
+    /// decoded lossily, flagged as preserved comments or skipped as data files.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub scan_notes: Vec<String>,
+    /// Target of a symbolic link, as stored in the link (only for `symlink` resources).
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub link_target: Option<String>,
+    /// Digest of the container image layer the file was unpacked from (only with `--oci-image`).
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub layer_digest: Option<String>,
+    /// License of the sibling files this file's detections refer to, e.g. the
+    /// `LICENSE` named by "see LICENSE" (only with `--license-reference-clues`).
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub referenced_license_expression: Option<String>,
+    #[builder(default)]
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub facets: Vec<String>,
     #[builder(default)]
@@ -133,7 +163,9 @@ impl FileInfoBuilder {
             self.urls.clone().unwrap_or_default(),
             self.for_packages.clone().unwrap_or_default(),
             self.scan_errors.clone().unwrap_or_default(),
-        ))
+        )
+        .with_scan_notes(self.scan_notes.clone().unwrap_or_default())
+        .with_sha512(self.sha512.clone().flatten()))
     }
 }
 
@@ -205,6 +237,7 @@ impl FileInfo {
             sha1,
             md5,
             sha256,
+            sha512: None,
             programming_language,
             package_data,
             license_expression,
@@ -227,6 +260,11 @@ impl FileInfo {
             is_top_level: false,
             is_key_file: false,
             is_community: false,
+            has_copyright_no_license: false,
+            scan_notes: vec![],
+            link_target: None,
+            layer_digest: None,
+            referenced_license_expression: None,
             facets: vec![],
             tallies: None,
         };
@@ -234,6 +272,16 @@ impl FileInfo {
         file_info
     }
 
+    fn with_scan_notes(mut self, scan_notes: Vec<String>) -> Self {
+        self.scan_notes = scan_notes;
+        self
+    }
+
+    fn with_sha512(mut self, sha512: Option<String>) -> Self {
+        self.sha512 = sha512;
+        self
+    }
+
     pub fn backfill_license_provenance(&mut self) {
         for detection in &mut self.license_detections {
             enrich_license_detection_provenance(detection, &self.path);
@@ -243,6 +291,22 @@ impl FileInfo {
             enrich_package_data_license_provenance(package, &self.path);
         }
     }
+
+    /// Whether the scan recorded anything for this resource beyond its
+    /// file information.
+    pub fn has_findings(&self) -> bool {
+        self.license_expression.is_some()
+            || !self.license_detections.is_empty()
+            || !self.license_clues.is_empty()
+            || !self.copyrights.is_empty()
+            || !self.holders.is_empty()
+            || !self.authors.is_empty()
+            || !self.emails.is_empty()
+            || !self.urls.is_empty()
+            || !self.package_data.is_empty()
+            || !self.scan_errors.is_empty()
+            || self.is_generated == Some(true)
+    }
 }
 
 fn enrich_package_data_license_provenance(package_data: &mut PackageData, path: &str) {
@@ -475,7 +539,7 @@ impl PackageData {
 /// License detection result containing matched license expressions.
 ///
 /// Aggregates multiple license matches into a single SPDX license expression.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
 pub struct LicenseDetection {
     pub license_expression: String,
     pub license_expression_spdx: String,
@@ -484,12 +548,40 @@ pub struct LicenseDetection {
     pub detection_log: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub identifier: Option<String>,
+    /// Whether every license in the expression is a public-domain dedication.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub is_public_domain: Option<bool>,
+    /// Where the license finding comes from, reported with `--license-facets`.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub license_facet: Option<LicenseFacet>,
+    /// Component section of a multi-component license file the detection was
+    /// found in, reported with `--license-components`.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub component: Option<String>,
+    /// Whether the detection lies in the leading comment header of a source
+    /// file, reported with `--license-in-header`.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub in_header: Option<bool>,
+}
+
+/// Provenance of a license detection, used to weigh findings in reports.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LicenseFacet {
+    /// Declared in a package manifest.
+    Declared,
+    /// Detected in a legal file such as `LICENSE` or `COPYING`.
+    Concluded,
+    /// Only license references or tags, such as SPDX identifiers.
+    Referenced,
+    /// Detected anywhere else, typically a source file header.
+    Incidental,
 }
 
 /// Individual license text match with location and confidence score.
 ///
 /// Represents a specific region of text that matched a known license pattern.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
 pub struct Match {
     pub license_expression: String,
     pub license_expression_spdx: String,
@@ -515,13 +607,43 @@ pub struct Match {
     pub matched_text_diagnostics: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub referenced_filenames: Option<Vec<String>>,
+    /// Category of the matched rule: text, notice, reference, tag, intro or clue
+    /// (only with `--license-match-type`).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub match_type: Option<String>,
+    /// Language of the translated rule that produced this match.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub language: Option<String>,
+    /// REUSE `LICENSES/<id>.txt` files holding the texts of the custom `LicenseRef-`
+    /// licenses this match names, or of every license named by an SPDX tag.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub custom_license_files: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+impl Match {
+    /// Round `score` and `match_coverage` to `precision` decimal places so
+    /// serialized values do not carry floating point noise.
+    pub fn round_scores(&mut self, precision: u32) {
+        self.score = round_to_precision(self.score, precision);
+        self.match_coverage = self
+            .match_coverage
+            .map(|coverage| round_to_precision(coverage, precision));
+    }
+}
+
+fn round_to_precision(value: f64, precision: u32) -> f64 {
+    let factor = 10f64.powi(precision as i32);
+    (value * factor).round() / factor
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct Copyright {
     pub copyright: String,
     pub start_line: usize,
     pub end_line: usize,
+    /// Canonical `Copyright (c) <years> <holder>` form (only with `--canonicalize-copyrights`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub normalized_copyright: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -735,6 +857,22 @@ pub struct Package {
     pub datafile_paths: Vec<String>,
     /// Datasource identifiers for all parsers that contributed to this package.
     pub datasource_ids: Vec<DatasourceId>,
+    /// Runtime vs development dependency counts, filled in on request.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub dependency_rollup: Option<DependencyRollup>,
+}
+
+/// Per-package count of top-level dependencies by runtime classification.
+///
+/// Dependencies are classified from `is_runtime`; those that leave it unset
+/// are counted as `unclassified` rather than guessed from their scope.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct DependencyRollup {
+    pub runtime: usize,
+    pub development: usize,
+    pub unclassified: usize,
+    /// True when the package has dependencies and all of them are development-only.
+    pub has_only_dev_dependencies: bool,
 }
 
 impl Package {
@@ -799,6 +937,7 @@ impl Package {
             } else {
                 vec![]
             },
+            dependency_rollup: None,
         }
     }
 
@@ -1022,9 +1161,11 @@ mod tests {
                     matched_text: Some("MIT".to_string()),
                     referenced_filenames: None,
                     matched_text_diagnostics: None,
+                    ..Default::default()
                 }],
                 detection_log: vec![],
                 identifier: None,
+                ..Default::default()
             }],
             ..PackageData::default()
         };
@@ -1099,9 +1240,11 @@ mod tests {
                     matched_text: Some("MIT".to_string()),
                     referenced_filenames: None,
                     matched_text_diagnostics: None,
+                    ..Default::default()
                 }],
                 detection_log: vec![],
                 identifier: None,
+                ..Default::default()
             }],
             ..PackageData::default()
         };
@@ -1152,6 +1295,12 @@ pub struct TopLevelDependency {
     /// Namespace for the dependency (e.g., distribution name for RPM packages).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub namespace: Option<String>,
+    /// Distance from the owning package in the resolved dependency graph (1 for direct).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub depth: Option<usize>,
+    /// Example chain of purls from the owning package down to this dependency.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependency_path: Vec<String>,
 }
 
 impl TopLevelDependency {
@@ -1183,6 +1332,8 @@ impl TopLevelDependency {
             datafile_path,
             datasource_id,
             namespace: None,
+            depth: None,
+            dependency_path: Vec::new(),
         }
     }
 }
@@ -1213,10 +1364,12 @@ pub struct OutputURL {
     pub end_line: usize,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub enum FileType {
+    #[default]
     File,
     Directory,
+    Symlink,
 }
 
 impl Serialize for FileType {
@@ -1227,6 +1380,7 @@ impl Serialize for FileType {
         let value = match self {
             FileType::File => "file",
             FileType::Directory => "directory",
+            FileType::Symlink => "symlink",
         };
         serializer.serialize_str(value)
     }
@@ -1241,6 +1395,7 @@ impl<'de> Deserialize<'de> for FileType {
         match value.as_str() {
             "file" => Ok(FileType::File),
             "directory" => Ok(FileType::Directory),
+            "symlink" => Ok(FileType::Symlink),
             _ => Err(serde::de::Error::custom("invalid file type")),
         }
     }
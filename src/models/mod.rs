@@ -5,9 +5,9 @@ mod package_type;
 
 pub use datasource_id::DatasourceId;
 pub use file_info::{
-    Author, Copyright, Dependency, FileInfo, FileInfoBuilder, FileReference, FileType, Holder,
-    LicenseDetection, Match, OutputEmail, OutputURL, Package, PackageData, Party, ResolvedPackage,
-    TopLevelDependency,
+    Author, Copyright, Dependency, DependencyRollup, FileInfo, FileInfoBuilder, FileReference,
+    FileType, Holder, LicenseDetection, LicenseFacet, Match, OutputEmail, OutputURL, Package,
+    PackageData, Party, ResolvedPackage, TopLevelDependency,
 };
 pub use package_type::PackageType;
 
@@ -15,6 +15,7 @@ pub use package_type::PackageType;
 pub use file_info::build_package_uid;
 pub use output::{
     ExtraData, FacetTallies, Header, LicenseClarityScore, LicenseReference, LicenseRuleReference,
-    OUTPUT_FORMAT_VERSION, Output, Summary, SystemEnvironment, Tallies, TallyEntry,
-    TopLevelLicenseDetection,
+    OUTPUT_FORMAT_VERSION, Output, RuleHitCount, RuleStats, SbomComponent, SbomDrift,
+    SbomLicenseChange, Summary, SystemEnvironment, Tallies, TallyEntry, TopLevelLicenseDetection,
+    UnknownLicenseText,
 };
@@ -22,6 +22,11 @@ pub struct Output {
     pub files: Vec<FileInfo>,
     pub license_references: Vec<LicenseReference>,
     pub license_rule_references: Vec<LicenseRuleReference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unknown_license_texts: Vec<UnknownLicenseText>,
+    /// Differences from an approved SBOM (only with `--compare-sbom`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sbom_drift: Option<SbomDrift>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -51,6 +56,23 @@ pub struct Summary {
     pub other_holders: Vec<TallyEntry>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub other_languages: Vec<TallyEntry>,
+    /// License match counts bucketed by match coverage (only with `--summary-match-stats`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub match_coverage_histogram: Vec<TallyEntry>,
+    /// License match counts per matcher (only with `--summary-match-stats`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub matcher_counts: Vec<TallyEntry>,
+    /// Number of files flagged with `has_copyright_no_license` (only with `--mark-copyright-only`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub copyright_only_files: Option<usize>,
+    /// Concluded license of the scanned project as a whole (only with `--primary-license`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_license_expression: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_license_expression_spdx: Option<String>,
+    /// Most restrictive category of the licenses found in the scan (only with `--risk-label`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scan_risk_label: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -117,7 +139,12 @@ pub struct ExtraData {
     pub files_count: usize,
     pub directories_count: usize,
     pub excluded_count: usize,
-    pub system_environment: SystemEnvironment,
+    /// Host details, omitted with `--no-system-env`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system_environment: Option<SystemEnvironment>,
+    /// SPDX license list version of the license data used for detection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spdx_license_list_version: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -164,6 +191,56 @@ pub struct LicenseReference {
     pub text: String,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// Text of an unknown or custom `LicenseRef-` license detection, for review.
+///
+/// Entries are keyed by the SHA1 of the text, so a notice repeated across files
+/// is listed once with every file it was found in.
+pub struct UnknownLicenseText {
+    pub sha1: String,
+    pub license_expression: String,
+    pub text: String,
+    pub paths: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// Drift between the scanned packages and an approved SPDX or CycloneDX SBOM.
+pub struct SbomDrift {
+    pub sbom_path: String,
+    /// Scanned packages the SBOM does not list.
+    pub not_in_sbom: Vec<SbomComponent>,
+    /// SBOM components the scan did not find.
+    pub not_in_scan: Vec<SbomComponent>,
+    /// Components found on both sides whose license differs.
+    pub license_changes: Vec<SbomLicenseChange>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// A package or SBOM component as compared by `--compare-sbom`.
+pub struct SbomComponent {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purl: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license_expression: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// A component whose scanned license differs from the one in the SBOM.
+pub struct SbomLicenseChange {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purl: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    pub scan_license_expression: Option<String>,
+    pub sbom_license_expression: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 /// Reference metadata for a license detection rule.
 pub struct LicenseRuleReference {
@@ -206,3 +283,22 @@ pub struct LicenseRuleReference {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
 }
+
+/// Per-rule match counts across a scan, written by `--rule-stats`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RuleStats {
+    pub total_rules_count: usize,
+    pub fired_rules_count: usize,
+    pub never_fired_rules_count: usize,
+    /// Rules that matched at least once, most frequent first.
+    pub rules: Vec<RuleHitCount>,
+    /// Identifiers of rules that never matched, sorted.
+    pub never_fired_rules: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RuleHitCount {
+    pub identifier: String,
+    pub license_expression: String,
+    pub count: usize,
+}
@@ -65,6 +65,7 @@ pub enum PackageType {
     Ear,
     Freebsd,
     Gem,
+    Generic,
     Github,
     Golang,
     Hackage,
@@ -140,6 +141,7 @@ impl PackageType {
             Self::Ear => "ear",
             Self::Freebsd => "freebsd",
             Self::Gem => "gem",
+            Self::Generic => "generic",
             Self::Github => "github",
             Self::Golang => "golang",
             Self::Hackage => "hackage",
@@ -34,6 +34,83 @@ pub(crate) fn write_csv(output: &Output, writer: &mut dyn Write) -> io::Result<(
     csv_writer.flush().map_err(io_other)
 }
 
+/// Write one row per file with its size, language, detected license and a
+/// semicolon-joined list of copyright holders.
+pub(crate) fn write_files_csv(output: &Output, writer: &mut dyn Write) -> io::Result<()> {
+    let mut csv_writer = csv::Writer::from_writer(writer);
+    csv_writer
+        .write_record([
+            "path",
+            "size",
+            "programming_language",
+            "license_expression",
+            "holders",
+        ])
+        .map_err(io_other)?;
+
+    for file in sorted_files(&output.files) {
+        let holders = file
+            .holders
+            .iter()
+            .map(|holder| holder.holder.as_str())
+            .collect::<Vec<_>>()
+            .join(";");
+        csv_writer
+            .write_record([
+                normalize_csv_path(&file.path, file.file_type == FileType::Directory),
+                file.size.to_string(),
+                file.programming_language.clone().unwrap_or_default(),
+                file.license_expression.clone().unwrap_or_default(),
+                holders,
+            ])
+            .map_err(io_other)?;
+    }
+
+    csv_writer.flush().map_err(io_other)
+}
+
+/// Write one row per assembled package.
+pub(crate) fn write_packages_csv(output: &Output, writer: &mut dyn Write) -> io::Result<()> {
+    let mut csv_writer = csv::Writer::from_writer(writer);
+    csv_writer
+        .write_record([
+            "purl",
+            "type",
+            "namespace",
+            "name",
+            "version",
+            "declared_license_expression",
+            "holder",
+            "datafile_paths",
+        ])
+        .map_err(io_other)?;
+
+    let mut packages = output.packages.iter().collect::<Vec<_>>();
+    packages.sort_by(|a, b| a.package_uid.cmp(&b.package_uid));
+    for package in packages {
+        csv_writer
+            .write_record([
+                package.purl.clone().unwrap_or_default(),
+                package
+                    .package_type
+                    .map(|package_type| package_type.as_str().to_string())
+                    .unwrap_or_default(),
+                package.namespace.clone().unwrap_or_default(),
+                package.name.clone().unwrap_or_default(),
+                package.version.clone().unwrap_or_default(),
+                package
+                    .declared_license_expression
+                    .clone()
+                    .unwrap_or_default(),
+                package.holder.clone().unwrap_or_default(),
+                package.datafile_paths.join(";"),
+            ])
+            .map_err(io_other)?;
+    }
+
+    csv_writer.flush().map_err(io_other)
+}
+
 fn flatten_rows(output: &Output) -> (CsvRows, CsvHeadersByGroup) {
     let mut rows = Vec::new();
     let mut headers_by_group: CsvHeadersByGroup = BTreeMap::new();
@@ -50,6 +127,7 @@ fn flatten_rows(output: &Output) -> (CsvRows, CsvHeadersByGroup) {
             match file.file_type {
                 FileType::File => "file",
                 FileType::Directory => "directory",
+                FileType::Symlink => "symlink",
             }
             .to_string(),
         );
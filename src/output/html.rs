@@ -24,6 +24,7 @@ pub(crate) fn write_html_report(output: &Output, writer: &mut dyn Write) -> io::
             match file.file_type {
                 FileType::File => "file",
                 FileType::Directory => "directory",
+                FileType::Symlink => "symlink",
             }
             .to_string(),
         );
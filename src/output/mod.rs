@@ -10,8 +10,16 @@ mod html_app;
 mod jsonl;
 mod shared;
 mod spdx;
+mod split;
+mod stats;
 mod template;
 
+pub use split::write_split_json_output;
+pub use stats::{
+    LicenseExpressionCount, ScanStats, build_scan_stats, write_rule_stats_file, write_stats_json,
+    write_stats_output_file,
+};
+
 pub(crate) const EMPTY_SHA1: &str = "da39a3ee5e6b4b0d3255bfef95601890afd80709";
 pub(crate) const SPDX_DOCUMENT_NOTICE: &str = "Generated with Provenant and provided on an \"AS IS\" BASIS, WITHOUT WARRANTIES\nOR CONDITIONS OF ANY KIND, either express or implied. No content created from\nProvenant should be considered or used as legal advice. Consult an attorney\nfor legal advice.\nProvenant is a free software code scanning tool.\nVisit https://github.com/mstykow/provenant/ for support and download.\nSPDX License List: 3.27";
 
@@ -22,12 +30,16 @@ pub enum OutputFormat {
     JsonPretty,
     Yaml,
     Csv,
+    FilesCsv,
+    PackagesCsv,
+    FlatJson,
     JsonLines,
     Html,
     HtmlApp,
     CustomTemplate,
     SpdxTv,
     SpdxRdf,
+    SpdxJson,
     CycloneDxJson,
     CycloneDxXml,
 }
@@ -74,11 +86,18 @@ impl OutputWriter for FormatWriter {
             }
             OutputFormat::Yaml => write_yaml(output, writer),
             OutputFormat::Csv => csv::write_csv(output, writer),
+            OutputFormat::FilesCsv => csv::write_files_csv(output, writer),
+            OutputFormat::PackagesCsv => csv::write_packages_csv(output, writer),
+            OutputFormat::FlatJson => {
+                serde_json::to_writer(&mut *writer, &output.files).map_err(shared::io_other)?;
+                writer.write_all(b"\n")
+            }
             OutputFormat::JsonLines => jsonl::write_json_lines(output, writer),
             OutputFormat::Html => html::write_html_report(output, writer),
             OutputFormat::CustomTemplate => template::write_custom_template(output, writer, config),
             OutputFormat::SpdxTv => spdx::write_spdx_tag_value(output, writer, config),
             OutputFormat::SpdxRdf => spdx::write_spdx_rdf_xml(output, writer, config),
+            OutputFormat::SpdxJson => spdx::write_spdx_json(output, writer, config),
             OutputFormat::CycloneDxJson => cyclonedx::write_cyclonedx_json(output, writer),
             OutputFormat::CycloneDxXml => cyclonedx::write_cyclonedx_xml(output, writer),
             OutputFormat::HtmlApp => Err(io::Error::new(
@@ -123,14 +142,41 @@ fn write_yaml(output: &Output, writer: &mut dyn Write) -> io::Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use serde_json::Value;
+    use serde_json::{Value, json};
+    use std::collections::BTreeSet;
     use std::fs;
 
+    use crate::license_detection::DEFAULT_LICENSE_LIST_VERSION;
     use crate::models::{
         Author, Copyright, ExtraData, FileInfo, FileType, Header, Holder, LicenseDetection, Match,
-        OutputEmail, OutputURL, PackageData, SystemEnvironment,
+        OutputEmail, OutputURL, PackageData, SystemEnvironment, Tallies,
     };
 
+    #[test]
+    fn test_stats_json_includes_license_histogram_and_omits_files() {
+        let mut output = sample_output();
+        output.files.push(FileInfo {
+            path: "src".to_string(),
+            file_type: FileType::Directory,
+            ..output.files[0].clone()
+        });
+        let mut bytes = Vec::new();
+        write_stats_json(&output, &mut bytes, false).expect("stats write should succeed");
+
+        let value: Value = serde_json::from_slice(&bytes).expect("stats should be valid json");
+        assert!(value.get("files").is_none());
+        assert!(value.get("packages").is_none());
+        assert_eq!(value["headers"][0]["output_format_version"], "4.0.0");
+        assert_eq!(value["stats"]["files_count"], 1);
+        assert_eq!(value["stats"]["directories_count"], 1);
+        assert_eq!(value["stats"]["files_with_findings_count"], 1);
+        assert_eq!(value["stats"]["packages_count"], 0);
+        assert_eq!(
+            value["stats"]["license_histogram"],
+            serde_json::json!([{"license_expression": "mit", "count": 1}])
+        );
+    }
+
     #[test]
     fn test_yaml_writer_outputs_yaml() {
         let output = sample_output();
@@ -196,6 +242,72 @@ mod tests {
         assert!(rendered.contains("info"));
     }
 
+    #[test]
+    fn test_files_csv_writer_quotes_fields_and_joins_holders() {
+        let mut output = sample_output();
+        output.files[0].license_expression = Some("mit, apache-2.0".to_string());
+        output.files[0].holders.push(Holder {
+            holder: "The \"Quoted\" Group\nInc.".to_string(),
+            start_line: 2,
+            end_line: 3,
+        });
+        let mut bytes = Vec::new();
+        writer_for_format(OutputFormat::FilesCsv)
+            .write(&output, &mut bytes, &OutputWriteConfig::default())
+            .expect("files csv write should succeed");
+
+        let rendered = String::from_utf8(bytes).expect("csv should be utf-8");
+        assert_eq!(
+            rendered,
+            "path,size,programming_language,license_expression,holders\n\
+             src/main.rs,42,Rust,\"mit, apache-2.0\",\"Example Org;The \"\"Quoted\"\" Group\nInc.\"\n"
+        );
+    }
+
+    #[test]
+    fn test_packages_csv_writer_outputs_one_row_per_package() {
+        let mut output = sample_output();
+        output.packages = vec![crate::models::Package::from_package_data(
+            &PackageData {
+                package_type: Some(crate::models::PackageType::Npm),
+                name: Some("demo".to_string()),
+                version: Some("1.0.0".to_string()),
+                declared_license_expression: Some("mit".to_string()),
+                purl: Some("pkg:npm/demo@1.0.0".to_string()),
+                ..Default::default()
+            },
+            "package.json".to_string(),
+        )];
+        let mut bytes = Vec::new();
+        writer_for_format(OutputFormat::PackagesCsv)
+            .write(&output, &mut bytes, &OutputWriteConfig::default())
+            .expect("packages csv write should succeed");
+
+        let rendered = String::from_utf8(bytes).expect("csv should be utf-8");
+        let lines = rendered.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            vec![
+                "purl,type,namespace,name,version,declared_license_expression,holder,datafile_paths",
+                "pkg:npm/demo@1.0.0,npm,,demo,1.0.0,mit,,package.json",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_flat_json_writer_outputs_top_level_file_array() {
+        let output = sample_output();
+        let mut bytes = Vec::new();
+        writer_for_format(OutputFormat::FlatJson)
+            .write(&output, &mut bytes, &OutputWriteConfig::default())
+            .expect("flat json write should succeed");
+
+        let value: Value = serde_json::from_slice(&bytes).expect("valid json");
+        let files = value.as_array().expect("top level should be an array");
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0]["path"], "src/main.rs");
+    }
+
     #[test]
     fn test_spdx_tag_value_writer_contains_required_fields() {
         let output = sample_output();
@@ -238,6 +350,113 @@ mod tests {
         assert!(rendered.contains("<spdx:SpdxDocument"));
     }
 
+    #[test]
+    fn test_spdx_json_writer_emits_files_packages_and_relationships() {
+        let mut output = sample_output();
+        let package = crate::models::Package::from_package_data(
+            &PackageData {
+                package_type: Some(crate::models::PackageType::Cargo),
+                name: Some("demo".to_string()),
+                version: Some("0.1.0".to_string()),
+                download_url: Some("https://crates.io/api/v1/crates/demo/0.1.0/download".into()),
+                declared_license_expression_spdx: Some("MIT".to_string()),
+                purl: Some("pkg:cargo/demo@0.1.0".to_string()),
+                ..Default::default()
+            },
+            "Cargo.toml".to_string(),
+        );
+        output.files[0].for_packages = vec![package.package_uid.clone()];
+        output.files[0].license_expression = Some("MIT".to_string());
+        output.packages = vec![package];
+
+        let mut bytes = Vec::new();
+        writer_for_format(OutputFormat::SpdxJson)
+            .write(
+                &output,
+                &mut bytes,
+                &OutputWriteConfig {
+                    format: OutputFormat::SpdxJson,
+                    custom_template: None,
+                    scanned_path: Some("scan".to_string()),
+                },
+            )
+            .expect("spdx json write should succeed");
+        let value: Value = serde_json::from_slice(&bytes).expect("valid json");
+
+        assert_eq!(value["spdxVersion"], "SPDX-2.3");
+        assert_eq!(value["files"][0]["SPDXID"], "SPDXRef-1");
+        assert_eq!(value["files"][0]["fileName"], "./src/main.rs");
+        assert_eq!(value["files"][0]["licenseConcluded"], "MIT");
+        assert_eq!(value["files"][0]["licenseInfoInFiles"], json!(["MIT"]));
+        assert_eq!(value["files"][0]["copyrightText"], "Copyright (c) Example");
+
+        let package = &value["packages"][1];
+        assert_eq!(package["SPDXID"], "SPDXRef-package-1");
+        assert_eq!(package["versionInfo"], "0.1.0");
+        assert_eq!(package["licenseDeclared"], "MIT");
+        assert_eq!(
+            package["downloadLocation"],
+            "https://crates.io/api/v1/crates/demo/0.1.0/download"
+        );
+        assert_eq!(
+            package["externalRefs"][0]["referenceLocator"],
+            "pkg:cargo/demo@0.1.0"
+        );
+        assert!(
+            package["packageVerificationCode"]["packageVerificationCodeValue"]
+                .as_str()
+                .is_some_and(|code| code.len() == 40)
+        );
+        assert!(
+            value["relationships"]
+                .as_array()
+                .expect("relationships")
+                .contains(&json!({
+                    "spdxElementId": "SPDXRef-package-1",
+                    "relationshipType": "CONTAINS",
+                    "relatedSpdxElement": "SPDXRef-1",
+                }))
+        );
+    }
+
+    #[test]
+    fn test_spdx_writers_emit_license_list_version() {
+        let mut output = sample_output();
+        output.headers[0].extra_data.spdx_license_list_version = Some("3.26".to_string());
+
+        let render = |format| {
+            let mut bytes = Vec::new();
+            writer_for_format(format)
+                .write(
+                    &output,
+                    &mut bytes,
+                    &OutputWriteConfig {
+                        format,
+                        custom_template: None,
+                        scanned_path: Some("scan".to_string()),
+                    },
+                )
+                .expect("spdx write should succeed");
+            String::from_utf8(bytes).expect("spdx should be utf-8")
+        };
+
+        let tag_value = render(OutputFormat::SpdxTv);
+        let version = tag_value
+            .lines()
+            .find_map(|line| line.strip_prefix("LicenseListVersion: "))
+            .expect("tag-value should carry a license list version");
+        assert_eq!(version, "3.26");
+        assert!(
+            render(OutputFormat::SpdxRdf)
+                .contains("<spdx:licenseListVersion>3.26</spdx:licenseListVersion>")
+        );
+
+        output.headers[0].extra_data.spdx_license_list_version = None;
+        assert!(render(OutputFormat::SpdxTv).contains(&format!(
+            "LicenseListVersion: {DEFAULT_LICENSE_LIST_VERSION}"
+        )));
+    }
+
     #[test]
     fn test_spdx_writers_emit_real_file_and_package_license_info() {
         let output = sample_output();
@@ -310,9 +529,11 @@ mod tests {
                 matched_text: Some("Custom license text".to_string()),
                 referenced_filenames: Some(vec!["LICENSE".to_string()]),
                 matched_text_diagnostics: None,
+                ..Default::default()
             }],
             detection_log: vec![],
             identifier: Some("unknown-ref-id".to_string()),
+            ..Default::default()
         }];
         output.license_references = vec![crate::models::LicenseReference {
             key: Some("unknown-license-reference".to_string()),
@@ -433,6 +654,12 @@ mod tests {
                 value: Some("Python".to_string()),
                 count: 2,
             }],
+            match_coverage_histogram: vec![],
+            matcher_counts: vec![],
+            copyright_only_files: None,
+            primary_license_expression: None,
+            primary_license_expression_spdx: None,
+            scan_risk_label: None,
         });
         output.files[0].is_legal = true;
         output.files[0].is_top_level = true;
@@ -820,6 +1047,7 @@ mod tests {
             repository_download_url: None,
             api_data_url: None,
             datasource_ids: vec![],
+            dependency_rollup: None,
             purl: Some("pkg:maven/example/gradle-project@1.0.0".to_string()),
             package_uid: "pkg:maven/example/gradle-project@1.0.0?uuid=test".to_string(),
             datafile_paths: vec![],
@@ -853,6 +1081,8 @@ mod tests {
             files: vec![],
             license_references: vec![],
             license_rule_references: vec![],
+            unknown_license_texts: vec![],
+            sbom_drift: None,
         };
         let mut bytes = Vec::new();
         writer_for_format(OutputFormat::SpdxTv)
@@ -885,6 +1115,8 @@ mod tests {
             files: vec![],
             license_references: vec![],
             license_rule_references: vec![],
+            unknown_license_texts: vec![],
+            sbom_drift: None,
         };
         let mut bytes = Vec::new();
         writer_for_format(OutputFormat::SpdxRdf)
@@ -970,6 +1202,132 @@ mod tests {
         assert!(assets_dir.join("app.js").exists());
     }
 
+    #[test]
+    fn test_split_output_writes_parts_and_manifest() {
+        use super::split::OutputPart;
+
+        let mut output = sample_output();
+        let template = serde_json::to_value(&output.files[0]).expect("file should serialize");
+        output.files = (0..5)
+            .map(|index| {
+                let mut file = template.clone();
+                file["path"] = Value::String(format!("project/file{index}.txt"));
+                serde_json::from_value(file).expect("file should deserialize")
+            })
+            .collect();
+        let temp_dir = tempfile::tempdir().expect("tempdir should be created");
+        let output_path = temp_dir.path().join("scan.json");
+
+        let parts = write_split_json_output(
+            output_path
+                .to_str()
+                .expect("output path should be valid utf-8"),
+            &output,
+            2,
+            false,
+        )
+        .expect("split output write should succeed");
+
+        assert_eq!(
+            parts,
+            vec![
+                OutputPart {
+                    file: "scan.part1.json".to_string(),
+                    files_count: 2,
+                },
+                OutputPart {
+                    file: "scan.part2.json".to_string(),
+                    files_count: 2,
+                },
+                OutputPart {
+                    file: "scan.part3.json".to_string(),
+                    files_count: 1,
+                },
+            ]
+        );
+
+        let manifest: Value = serde_json::from_str(
+            &fs::read_to_string(&output_path).expect("manifest should be readable"),
+        )
+        .expect("manifest should be valid json");
+        assert_eq!(manifest["files_count"], 5);
+        assert!(manifest.get("files").is_none());
+        assert_eq!(manifest["headers"][0]["output_format_version"], "4.0.0");
+        assert_eq!(manifest["parts"].as_array().map(Vec::len), Some(3));
+
+        let mut total = 0;
+        for (index, part) in parts.iter().enumerate() {
+            let part_json: Value = serde_json::from_str(
+                &fs::read_to_string(temp_dir.path().join(&part.file))
+                    .expect("part should be readable"),
+            )
+            .expect("part should be valid json");
+            assert_eq!(part_json["part"], index + 1);
+            assert_eq!(part_json["total_parts"], 3);
+            total += part_json["files"].as_array().map_or(0, Vec::len);
+        }
+        assert_eq!(total, 5);
+    }
+
+    #[test]
+    fn test_split_manifest_has_every_output_field_but_files() {
+        let mut output = sample_output();
+        output.summary = Some(serde_json::from_value(json!({})).expect("summary"));
+        output.tallies = Some(Tallies::default());
+        output.tallies_of_key_files = Some(Tallies::default());
+        output.tallies_by_facet = Some(vec![]);
+        output.license_detections = vec![
+            serde_json::from_value(json!({
+                "identifier": "mit-0001",
+                "license_expression": "mit",
+                "license_expression_spdx": "MIT",
+                "detection_count": 1,
+                "reference_matches": [],
+            }))
+            .expect("license detection"),
+        ];
+        let temp_dir = tempfile::tempdir().expect("tempdir should be created");
+        let output_path = temp_dir.path().join("scan.json");
+
+        write_split_json_output(
+            output_path
+                .to_str()
+                .expect("output path should be valid utf-8"),
+            &output,
+            2,
+            false,
+        )
+        .expect("split output write should succeed");
+
+        let manifest: Value = serde_json::from_str(
+            &fs::read_to_string(&output_path).expect("manifest should be readable"),
+        )
+        .expect("manifest should be valid json");
+        let manifest_keys: BTreeSet<&str> = manifest
+            .as_object()
+            .expect("manifest object")
+            .keys()
+            .map(String::as_str)
+            .filter(|key| !matches!(*key, "files_count" | "parts"))
+            .collect();
+        let output_value = serde_json::to_value(&output).expect("output should serialize");
+        let output_keys: BTreeSet<&str> = output_value
+            .as_object()
+            .expect("output object")
+            .keys()
+            .map(String::as_str)
+            .filter(|key| *key != "files")
+            .collect();
+        assert_eq!(manifest_keys, output_keys);
+    }
+
+    #[test]
+    fn test_split_output_rejects_stdout() {
+        let error = write_split_json_output("-", &sample_output(), 2, false)
+            .expect_err("stdout should be rejected");
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+
     fn sample_output() -> Output {
         Output {
             summary: None,
@@ -984,12 +1342,13 @@ mod tests {
                     files_count: 1,
                     directories_count: 1,
                     excluded_count: 0,
-                    system_environment: SystemEnvironment {
+                    system_environment: Some(SystemEnvironment {
                         operating_system: Some("darwin".to_string()),
                         cpu_architecture: "aarch64".to_string(),
                         platform: "darwin".to_string(),
                         rust_version: "1.93.0".to_string(),
-                    },
+                    }),
+                    spdx_license_list_version: None,
                 },
                 errors: vec![],
                 output_format_version: "4.0.0".to_string(),
@@ -1031,15 +1390,18 @@ mod tests {
                         matched_text: None,
                         referenced_filenames: None,
                         matched_text_diagnostics: None,
+                        ..Default::default()
                     }],
                     detection_log: vec![],
                     identifier: None,
+                    ..Default::default()
                 }],
                 vec![],
                 vec![Copyright {
                     copyright: "Copyright (c) Example".to_string(),
                     start_line: 1,
                     end_line: 1,
+                    ..Default::default()
                 }],
                 vec![Holder {
                     holder: "Example Org".to_string(),
@@ -1066,6 +1428,8 @@ mod tests {
             )],
             license_references: vec![],
             license_rule_references: vec![],
+            unknown_license_texts: vec![],
+            sbom_drift: None,
         }
     }
 }
@@ -2,11 +2,13 @@ use std::collections::{BTreeSet, HashMap, HashSet};
 use std::io::{self, Write};
 use std::path::PathBuf;
 
+use serde_json::{Value, json};
 use sha1::{Digest, Sha1};
 
-use crate::models::{FileInfo, FileType, Match, Output};
+use crate::license_detection::DEFAULT_LICENSE_LIST_VERSION;
+use crate::models::{FileInfo, FileType, Match, Output, Package};
 
-use super::shared::{sorted_files, xml_escape};
+use super::shared::{io_other, sorted_files, xml_escape};
 use super::{EMPTY_SHA1, OutputWriteConfig, SPDX_DOCUMENT_NOTICE};
 
 struct ExtractedLicenseInfo {
@@ -47,6 +49,11 @@ pub(crate) fn write_spdx_tag_value(
         SPDX_DOCUMENT_NOTICE
     )?;
     writeln!(writer, "## Creation Information")?;
+    writeln!(
+        writer,
+        "LicenseListVersion: {}",
+        spdx_license_list_version(output)
+    )?;
     writeln!(writer, "## Package Information")?;
 
     writeln!(writer, "PackageName: {}", package_name)?;
@@ -116,6 +123,197 @@ pub(crate) fn write_spdx_tag_value(
     Ok(())
 }
 
+/// Write an SPDX 2.3 JSON document.
+///
+/// Besides the scanned codebase package and its files, every assembled
+/// package becomes an SPDX package carrying its purl as an external reference
+/// and `CONTAINS` relationships to the files that belong to it.
+pub(crate) fn write_spdx_json(
+    output: &Output,
+    writer: &mut dyn Write,
+    config: &OutputWriteConfig,
+) -> io::Result<()> {
+    let document = build_spdx_json(output, config);
+    serde_json::to_writer_pretty(&mut *writer, &document).map_err(io_other)?;
+    writer.write_all(b"\n")
+}
+
+fn build_spdx_json(output: &Output, config: &OutputWriteConfig) -> Value {
+    let package_name = primary_package_name(output, config);
+    let files = spdx_files(output);
+    let created = output
+        .headers
+        .first()
+        .map(|h| h.start_timestamp.as_str())
+        .unwrap_or("1970-01-01T00:00:00Z");
+
+    let file_ids: HashMap<&str, String> = files
+        .iter()
+        .enumerate()
+        .map(|(idx, file)| (file.path.as_str(), format!("SPDXRef-{}", idx + 1)))
+        .collect();
+
+    let mut relationships = vec![spdx_relationship(
+        "SPDXRef-DOCUMENT",
+        "DESCRIBES",
+        "SPDXRef-001",
+    )];
+
+    let spdx_files_json = files
+        .iter()
+        .map(|file| {
+            let file_id = &file_ids[file.path.as_str()];
+            relationships.push(spdx_relationship("SPDXRef-001", "CONTAINS", file_id));
+            let license_info: BTreeSet<String> = spdx_file_license_info(file).into_iter().collect();
+            json!({
+                "SPDXID": file_id,
+                "fileName": format!("./{}", file.path),
+                "checksums": [{
+                    "algorithm": "SHA1",
+                    "checksumValue": file.sha1.as_deref().unwrap_or(EMPTY_SHA1),
+                }],
+                "licenseConcluded": file
+                    .license_expression
+                    .as_deref()
+                    .filter(|expression| !expression.is_empty())
+                    .unwrap_or("NOASSERTION"),
+                "licenseInfoInFiles": if license_info.is_empty() {
+                    vec!["NONE".to_string()]
+                } else {
+                    license_info.into_iter().collect()
+                },
+                "copyrightText": spdx_file_copyright_text(file),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let package_license_info_from_files = spdx_package_license_info_from_files(&files);
+    let mut packages = vec![json!({
+        "SPDXID": "SPDXRef-001",
+        "name": package_name,
+        "downloadLocation": "NOASSERTION",
+        "filesAnalyzed": true,
+        "packageVerificationCode": {
+            "packageVerificationCodeValue": spdx_package_verification_code(&files),
+        },
+        "licenseConcluded": "NOASSERTION",
+        "licenseInfoFromFiles": if package_license_info_from_files.is_empty() {
+            vec!["NONE".to_string()]
+        } else {
+            package_license_info_from_files
+        },
+        "licenseDeclared": "NOASSERTION",
+        "copyrightText": spdx_package_copyright_text(&files),
+    })];
+
+    for (idx, package) in output.packages.iter().enumerate() {
+        let package_id = format!("SPDXRef-package-{}", idx + 1);
+        let package_files: Vec<&FileInfo> = files
+            .iter()
+            .copied()
+            .filter(|file| file.for_packages.contains(&package.package_uid))
+            .collect();
+        relationships.push(spdx_relationship("SPDXRef-001", "CONTAINS", &package_id));
+        for file in &package_files {
+            relationships.push(spdx_relationship(
+                &package_id,
+                "CONTAINS",
+                &file_ids[file.path.as_str()],
+            ));
+        }
+        packages.push(spdx_package_json(&package_id, package, &package_files));
+    }
+
+    let extracted_license_infos = spdx_extracted_license_infos(output, &files)
+        .into_iter()
+        .map(|info| {
+            json!({
+                "licenseId": info.license_id,
+                "extractedText": info.extracted_text,
+                "name": info.name,
+                "comment": info.comment,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut document = json!({
+        "spdxVersion": "SPDX-2.3",
+        "dataLicense": "CC0-1.0",
+        "SPDXID": "SPDXRef-DOCUMENT",
+        "name": "SPDX Document created by Provenant",
+        "documentNamespace": format!("http://spdx.org/spdxdocs/{}", package_name),
+        "comment": SPDX_DOCUMENT_NOTICE,
+        "creationInfo": {
+            "created": created,
+            "creators": [format!("Tool: Provenant-{}", env!("CARGO_PKG_VERSION"))],
+            "licenseListVersion": spdx_license_list_version(output),
+        },
+        "documentDescribes": ["SPDXRef-001"],
+        "packages": packages,
+        "files": spdx_files_json,
+        "relationships": relationships,
+    });
+    if !extracted_license_infos.is_empty() {
+        document["hasExtractedLicensingInfos"] = Value::Array(extracted_license_infos);
+    }
+    document
+}
+
+fn spdx_package_json(package_id: &str, package: &Package, files: &[&FileInfo]) -> Value {
+    let mut package_json = json!({
+        "SPDXID": package_id,
+        "name": package.name.as_deref().unwrap_or("unknown"),
+        "downloadLocation": package.download_url.as_deref().unwrap_or("NOASSERTION"),
+        "filesAnalyzed": !files.is_empty(),
+        "licenseConcluded": "NOASSERTION",
+        "licenseDeclared": package
+            .declared_license_expression_spdx
+            .as_deref()
+            .filter(|expression| !expression.is_empty())
+            .unwrap_or("NOASSERTION"),
+        "copyrightText": package.copyright.as_deref().unwrap_or("NOASSERTION"),
+    });
+    if let Some(version) = &package.version {
+        package_json["versionInfo"] = json!(version);
+    }
+    if let Some(homepage_url) = &package.homepage_url {
+        package_json["homepage"] = json!(homepage_url);
+    }
+    if !files.is_empty() {
+        package_json["packageVerificationCode"] = json!({
+            "packageVerificationCodeValue": spdx_package_verification_code(files),
+        });
+    }
+    if let Some(purl) = &package.purl {
+        package_json["externalRefs"] = json!([{
+            "referenceCategory": "PACKAGE-MANAGER",
+            "referenceType": "purl",
+            "referenceLocator": purl,
+        }]);
+    }
+    package_json
+}
+
+fn spdx_relationship(element: &str, relationship_type: &str, related: &str) -> Value {
+    json!({
+        "spdxElementId": element,
+        "relationshipType": relationship_type,
+        "relatedSpdxElement": related,
+    })
+}
+
+fn spdx_file_copyright_text(file: &FileInfo) -> String {
+    if file.copyrights.is_empty() {
+        "NONE".to_string()
+    } else {
+        file.copyrights
+            .iter()
+            .map(|c| c.copyright.clone())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
 pub(crate) fn write_spdx_rdf_xml(
     output: &Output,
     writer: &mut dyn Write,
@@ -260,13 +458,23 @@ pub(crate) fn write_spdx_rdf_xml(
     xml.push_str("    <spdx:specVersion>SPDX-2.2</spdx:specVersion>\n");
     xml.push_str("    <spdx:creationInfo><spdx:CreationInfo><spdx:created>");
     xml.push_str(&created);
-    xml.push_str("</spdx:created></spdx:CreationInfo></spdx:creationInfo>\n");
+    xml.push_str("</spdx:created><spdx:licenseListVersion>");
+    xml.push_str(&xml_escape(spdx_license_list_version(output)));
+    xml.push_str("</spdx:licenseListVersion></spdx:CreationInfo></spdx:creationInfo>\n");
     xml.push_str("  </spdx:SpdxDocument>\n");
 
     xml.push_str("</rdf:RDF>\n");
     writer.write_all(xml.as_bytes())
 }
 
+fn spdx_license_list_version(output: &Output) -> &str {
+    output
+        .headers
+        .first()
+        .and_then(|header| header.extra_data.spdx_license_list_version.as_deref())
+        .unwrap_or(DEFAULT_LICENSE_LIST_VERSION)
+}
+
 fn primary_package_name(output: &Output, config: &OutputWriteConfig) -> String {
     if let Some(scanned_path) = &config.scanned_path {
         let path = PathBuf::from(scanned_path);
@@ -0,0 +1,164 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::models::{
+    FacetTallies, FileInfo, Header, LicenseReference, LicenseRuleReference, Output, Package,
+    SbomDrift, Summary, Tallies, TopLevelDependency, TopLevelLicenseDetection, UnknownLicenseText,
+};
+
+use super::shared::io_other;
+
+/// One part file listed in a split-output manifest.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct OutputPart {
+    pub file: String,
+    pub files_count: usize,
+}
+
+/// Manifest written in place of the single JSON output when splitting.
+///
+/// Holds everything from [`Output`] except `files`, which are spread across
+/// the listed part files in path order. The fields mirror [`Output`]'s serde
+/// attributes; `write_split_json_output` destructures [`Output`] exhaustively
+/// so a new output field does not compile until it is handled here.
+#[derive(Serialize)]
+struct SplitManifest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: &'a Option<Summary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tallies: &'a Option<Tallies>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tallies_of_key_files: &'a Option<Tallies>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tallies_by_facet: &'a Option<Vec<FacetTallies>>,
+    headers: &'a [Header],
+    packages: &'a [Package],
+    dependencies: &'a [TopLevelDependency],
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    license_detections: &'a [TopLevelLicenseDetection],
+    license_references: &'a [LicenseReference],
+    license_rule_references: &'a [LicenseRuleReference],
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    unknown_license_texts: &'a [UnknownLicenseText],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sbom_drift: &'a Option<SbomDrift>,
+    files_count: usize,
+    parts: &'a [OutputPart],
+}
+
+#[derive(Serialize)]
+struct SplitPart<'a> {
+    part: usize,
+    total_parts: usize,
+    files: &'a [&'a FileInfo],
+}
+
+/// Write `output` as numbered JSON part files of at most `files_per_part`
+/// entries each, plus a manifest at `output_file`.
+///
+/// `scan.json` produces `scan.part1.json`, `scan.part2.json`, ... next to it.
+/// Returns the parts listed in the manifest.
+pub fn write_split_json_output(
+    output_file: &str,
+    output: &Output,
+    files_per_part: usize,
+    pretty: bool,
+) -> io::Result<Vec<OutputPart>> {
+    if output_file == "-" {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "split output cannot be written to stdout",
+        ));
+    }
+    if files_per_part == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "split output requires at least one file per part",
+        ));
+    }
+
+    let manifest_path = Path::new(output_file);
+    let mut files: Vec<&FileInfo> = output.files.iter().collect();
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    let chunks: Vec<&[&FileInfo]> = files.chunks(files_per_part).collect();
+    let total_parts = chunks.len();
+
+    let mut parts = Vec::with_capacity(total_parts);
+    for (index, chunk) in chunks.into_iter().enumerate() {
+        let part_path = part_file_path(manifest_path, index + 1);
+        let part = SplitPart {
+            part: index + 1,
+            total_parts,
+            files: chunk,
+        };
+        write_json(&part_path, &part, pretty)?;
+        parts.push(OutputPart {
+            file: part_path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            files_count: chunk.len(),
+        });
+    }
+
+    let Output {
+        summary,
+        tallies,
+        tallies_of_key_files,
+        tallies_by_facet,
+        headers,
+        packages,
+        dependencies,
+        license_detections,
+        files: _,
+        license_references,
+        license_rule_references,
+        unknown_license_texts,
+        sbom_drift,
+    } = output;
+    let manifest = SplitManifest {
+        summary,
+        tallies,
+        tallies_of_key_files,
+        tallies_by_facet,
+        headers,
+        packages,
+        dependencies,
+        license_detections,
+        license_references,
+        license_rule_references,
+        unknown_license_texts,
+        sbom_drift,
+        files_count: files.len(),
+        parts: &parts,
+    };
+    write_json(manifest_path, &manifest, pretty)?;
+
+    Ok(parts)
+}
+
+fn part_file_path(manifest_path: &Path, part: usize) -> std::path::PathBuf {
+    let stem = manifest_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "output".to_string());
+    let file_name = match manifest_path.extension() {
+        Some(extension) => format!("{stem}.part{part}.{}", extension.to_string_lossy()),
+        None => format!("{stem}.part{part}"),
+    };
+    manifest_path.with_file_name(file_name)
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T, pretty: bool) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    if pretty {
+        serde_json::to_writer_pretty(&mut writer, value).map_err(io_other)?;
+    } else {
+        serde_json::to_writer(&mut writer, value).map_err(io_other)?;
+    }
+    writer.write_all(b"\n")?;
+    writer.flush()
+}
@@ -0,0 +1,116 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+
+use serde::Serialize;
+
+use crate::models::{FileType, Header, Output, RuleStats, Summary};
+
+use super::shared::io_other;
+
+/// Aggregate counts written by `--stats-only` in place of the full output.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ScanStats {
+    pub files_count: usize,
+    pub directories_count: usize,
+    pub files_with_findings_count: usize,
+    pub packages_count: usize,
+    pub dependencies_count: usize,
+    /// Number of file-level license detections per license expression, most
+    /// frequent first.
+    pub license_histogram: Vec<LicenseExpressionCount>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct LicenseExpressionCount {
+    pub license_expression: String,
+    pub count: usize,
+}
+
+#[derive(Serialize)]
+struct StatsOutput<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: &'a Option<Summary>,
+    headers: &'a [Header],
+    stats: ScanStats,
+}
+
+/// Compute the `--stats-only` counts for `output`.
+pub fn build_scan_stats(output: &Output) -> ScanStats {
+    let mut histogram: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut files_count = 0;
+    let mut directories_count = 0;
+    let mut files_with_findings_count = 0;
+
+    for file in &output.files {
+        if file.file_type == FileType::Directory {
+            directories_count += 1;
+            continue;
+        }
+        files_count += 1;
+        if file.has_findings() {
+            files_with_findings_count += 1;
+        }
+        for detection in &file.license_detections {
+            *histogram
+                .entry(detection.license_expression.as_str())
+                .or_default() += 1;
+        }
+    }
+
+    let mut license_histogram: Vec<_> = histogram
+        .into_iter()
+        .map(|(license_expression, count)| LicenseExpressionCount {
+            license_expression: license_expression.to_string(),
+            count,
+        })
+        .collect();
+    license_histogram.sort_by(|a, b| b.count.cmp(&a.count));
+
+    ScanStats {
+        files_count,
+        directories_count,
+        files_with_findings_count,
+        packages_count: output.packages.len(),
+        dependencies_count: output.dependencies.len(),
+        license_histogram,
+    }
+}
+
+/// Write the headers, summary and [`ScanStats`] of `output` as JSON, leaving
+/// out the per-file `files` array and the other top-level listings.
+pub fn write_stats_json(output: &Output, writer: &mut dyn Write, pretty: bool) -> io::Result<()> {
+    let stats_output = StatsOutput {
+        summary: &output.summary,
+        headers: &output.headers,
+        stats: build_scan_stats(output),
+    };
+    if pretty {
+        serde_json::to_writer_pretty(&mut *writer, &stats_output).map_err(io_other)?;
+    } else {
+        serde_json::to_writer(&mut *writer, &stats_output).map_err(io_other)?;
+    }
+    writer.write_all(b"\n")
+}
+
+/// Write the `--stats-only` JSON for `output` to `output_file`, or to stdout
+/// when it is `-`.
+pub fn write_stats_output_file(output_file: &str, output: &Output, pretty: bool) -> io::Result<()> {
+    if output_file == "-" {
+        let stdout = io::stdout();
+        let mut handle = stdout.lock();
+        return write_stats_json(output, &mut handle, pretty);
+    }
+
+    let mut writer = BufWriter::new(File::create(output_file)?);
+    write_stats_json(output, &mut writer, pretty)?;
+    writer.flush()
+}
+
+/// Write the `--rule-stats` report to `output_file` as pretty-printed JSON.
+pub fn write_rule_stats_file(output_file: &str, rule_stats: &RuleStats) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(output_file)?);
+    serde_json::to_writer_pretty(&mut writer, rule_stats).map_err(io_other)?;
+    writer.write_all(b"\n")?;
+    writer.flush()
+}
@@ -0,0 +1,349 @@
+//! Parser for Apple framework bundle `Info.plist` files.
+//!
+//! Extracts bundle identity from the `Info.plist` shipped inside `.framework`
+//! and `.xcframework` bundles.
+//!
+//! # Supported Formats
+//! - `*.framework/Info.plist`, `*.framework/Resources/Info.plist` and
+//!   `*.framework/Versions/*/Resources/Info.plist`
+//! - `*.xcframework/Info.plist`
+//!
+//! # Key Features
+//! - XML (`<plist>`) and binary (`bplist00`) property lists
+//! - `CFBundleIdentifier` as the package name, `CFBundleShortVersionString`
+//!   (or `CFBundleVersion`) as the version
+//! - `NSHumanReadableCopyright` as copyright and any top-level key mentioning
+//!   a license as the extracted license statement
+//!
+//! # Implementation Notes
+//! - Only top-level string values are read; nested dictionaries and arrays
+//!   (such as `AvailableLibraries` in an xcframework) are skipped.
+//! - Bundles have no package registry, so packages use the `generic` purl type.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+use serde_json::Value;
+
+use crate::models::{DatasourceId, PackageData, PackageType};
+use crate::parser_warn as warn;
+use crate::utils::purl::build_purl;
+
+use super::PackageParser;
+
+const PACKAGE_TYPE: PackageType = PackageType::Generic;
+const BINARY_PLIST_MAGIC: &[u8] = b"bplist00";
+
+pub struct AppleBundleInfoPlistParser;
+
+impl PackageParser for AppleBundleInfoPlistParser {
+    const PACKAGE_TYPE: PackageType = PACKAGE_TYPE;
+
+    fn is_match(path: &Path) -> bool {
+        path.file_name().is_some_and(|name| name == "Info.plist")
+            && path.ancestors().skip(1).take(4).any(|ancestor| {
+                ancestor.extension().is_some_and(|ext| {
+                    ext.eq_ignore_ascii_case("framework") || ext.eq_ignore_ascii_case("xcframework")
+                })
+            })
+    }
+
+    fn extract_packages(path: &Path) -> Vec<PackageData> {
+        let bytes = match fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                warn!("Failed to read Info.plist {:?}: {}", path, e);
+                return vec![default_package_data()];
+            }
+        };
+
+        let entries = if bytes.starts_with(BINARY_PLIST_MAGIC) {
+            parse_binary_plist(&bytes)
+        } else {
+            match std::str::from_utf8(&bytes) {
+                Ok(content) => parse_xml_plist(content),
+                Err(e) => {
+                    warn!("Info.plist {:?} is not valid UTF-8: {}", path, e);
+                    None
+                }
+            }
+        };
+
+        match entries {
+            Some(entries) => vec![package_data_from_entries(&entries)],
+            None => {
+                warn!("Failed to parse Info.plist {:?}", path);
+                vec![default_package_data()]
+            }
+        }
+    }
+}
+
+fn default_package_data() -> PackageData {
+    PackageData {
+        package_type: Some(PACKAGE_TYPE),
+        datasource_id: Some(DatasourceId::AppleInfoPlist),
+        ..Default::default()
+    }
+}
+
+fn package_data_from_entries(entries: &BTreeMap<String, String>) -> PackageData {
+    let get = |key: &str| {
+        entries
+            .get(key)
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+
+    let bundle_name = get("CFBundleName");
+    let name = get("CFBundleIdentifier").or_else(|| bundle_name.clone());
+    let version = get("CFBundleShortVersionString").or_else(|| get("CFBundleVersion"));
+    let purl = name
+        .as_deref()
+        .and_then(|name| build_purl(PACKAGE_TYPE.as_str(), None, name, version.as_deref()));
+
+    let license_statements: Vec<String> = entries
+        .iter()
+        .filter(|(key, _)| key.to_ascii_lowercase().contains("license"))
+        .map(|(_, value)| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .collect();
+
+    let mut extra_data = serde_json::Map::new();
+    if let Some(bundle_name) = bundle_name {
+        extra_data.insert("bundle_name".to_string(), Value::String(bundle_name));
+    }
+    if let Some(build_version) = get("CFBundleVersion") {
+        extra_data.insert("bundle_version".to_string(), Value::String(build_version));
+    }
+
+    PackageData {
+        package_type: Some(PACKAGE_TYPE),
+        name,
+        version,
+        description: get("CFBundleGetInfoString"),
+        copyright: get("NSHumanReadableCopyright"),
+        extracted_license_statement: (!license_statements.is_empty())
+            .then(|| license_statements.join("\n")),
+        extra_data: (!extra_data.is_empty()).then(|| extra_data.into_iter().collect()),
+        datasource_id: Some(DatasourceId::AppleInfoPlist),
+        purl,
+        ..Default::default()
+    }
+}
+
+/// Read the top-level string entries of an XML property list.
+pub(crate) fn parse_xml_plist(content: &str) -> Option<BTreeMap<String, String>> {
+    let mut reader = Reader::from_str(content);
+
+    let mut entries = BTreeMap::new();
+    let mut buf = Vec::new();
+    // Nesting of <dict>/<array> containers; the top-level dict is depth 1.
+    let mut depth = 0usize;
+    let mut saw_dict = false;
+    let mut pending_key: Option<String> = None;
+    let mut current_element: Option<Vec<u8>> = None;
+    let mut text = String::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e)) => {
+                let name = e.name().as_ref().to_vec();
+                match name.as_slice() {
+                    b"dict" | b"array" => {
+                        if depth == 1 {
+                            pending_key = None;
+                        }
+                        depth += 1;
+                        saw_dict |= name == b"dict";
+                    }
+                    _ => {
+                        text.clear();
+                        current_element = Some(name);
+                    }
+                }
+            }
+            Ok(Event::End(e)) => {
+                match e.name().as_ref() {
+                    b"dict" | b"array" => depth = depth.saturating_sub(1),
+                    b"key" if depth == 1 => pending_key = Some(text.trim().to_string()),
+                    b"string" if depth == 1 => {
+                        if let Some(key) = pending_key.take() {
+                            entries.insert(key, text.trim().to_string());
+                        }
+                    }
+                    _ if depth == 1 => pending_key = None,
+                    _ => {}
+                }
+                current_element = None;
+            }
+            Ok(Event::Empty(_)) if depth == 1 => pending_key = None,
+            Ok(Event::Text(e)) if current_element.is_some() => {
+                text.push_str(&e.decode().ok()?);
+            }
+            Ok(Event::GeneralRef(e)) if current_element.is_some() => {
+                if let Some(ch) = e.resolve_char_ref().ok()? {
+                    text.push(ch);
+                } else {
+                    let name = e.decode().ok()?;
+                    text.push_str(quick_xml::escape::resolve_predefined_entity(&name)?);
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                warn!(
+                    "Error parsing plist XML at position {}: {}",
+                    reader.buffer_position(),
+                    e
+                );
+                return None;
+            }
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    saw_dict.then_some(entries)
+}
+
+/// Read the top-level string entries of a binary (`bplist00`) property list.
+pub(crate) fn parse_binary_plist(bytes: &[u8]) -> Option<BTreeMap<String, String>> {
+    let plist = BinaryPlist::new(bytes)?;
+    let (marker, count, start) = plist.object_header(plist.top_object)?;
+    if marker != 0xD {
+        return None;
+    }
+
+    // The counts come from the file: make sure the key and value references
+    // fit in it before reading them.
+    let refs_len = count.checked_mul(2)?.checked_mul(plist.ref_size)?;
+    if start.checked_add(refs_len)? > bytes.len() {
+        return None;
+    }
+    let values_start = start + count * plist.ref_size;
+
+    let mut entries = BTreeMap::new();
+    for index in 0..count {
+        let key_ref = plist.read_ref(start + index * plist.ref_size)?;
+        let value_ref = plist.read_ref(values_start + index * plist.ref_size)?;
+        if let (Some(key), Some(value)) = (plist.read_string(key_ref), plist.read_string(value_ref))
+        {
+            entries.insert(key, value);
+        }
+    }
+    Some(entries)
+}
+
+struct BinaryPlist<'a> {
+    bytes: &'a [u8],
+    offset_size: usize,
+    ref_size: usize,
+    num_objects: usize,
+    top_object: usize,
+    offset_table: usize,
+}
+
+impl<'a> BinaryPlist<'a> {
+    fn new(bytes: &'a [u8]) -> Option<Self> {
+        let trailer = bytes.get(bytes.len().checked_sub(32)?..)?;
+        let plist = Self {
+            bytes,
+            offset_size: usize::from(trailer[6]),
+            ref_size: usize::from(trailer[7]),
+            num_objects: read_be(&trailer[8..16])?,
+            top_object: read_be(&trailer[16..24])?,
+            offset_table: read_be(&trailer[24..32])?,
+        };
+        (plist.offset_size > 0 && plist.ref_size > 0 && plist.top_object < plist.num_objects)
+            .then_some(plist)
+    }
+
+    fn read_ref(&self, position: usize) -> Option<usize> {
+        read_be(
+            self.bytes
+                .get(position..position.checked_add(self.ref_size)?)?,
+        )
+    }
+
+    fn object_offset(&self, object: usize) -> Option<usize> {
+        if object >= self.num_objects {
+            return None;
+        }
+        let position = self
+            .offset_table
+            .checked_add(object.checked_mul(self.offset_size)?)?;
+        read_be(
+            self.bytes
+                .get(position..position.checked_add(self.offset_size)?)?,
+        )
+    }
+
+    /// Return the marker nibble, element count and payload start of `object`.
+    fn object_header(&self, object: usize) -> Option<(u8, usize, usize)> {
+        let offset = self.object_offset(object)?;
+        let marker = *self.bytes.get(offset)?;
+        let (kind, low) = (marker >> 4, marker & 0x0F);
+        if low != 0x0F {
+            return Some((kind, usize::from(low), offset.checked_add(1)?));
+        }
+
+        // Counts of 15 or more follow the marker as an integer object.
+        let int_marker = *self.bytes.get(offset.checked_add(1)?)?;
+        if int_marker >> 4 != 0x1 {
+            return None;
+        }
+        let int_size = 1usize << (int_marker & 0x0F);
+        let start = offset.checked_add(2)?;
+        let end = start.checked_add(int_size)?;
+        let count = read_be(self.bytes.get(start..end)?)?;
+        Some((kind, count, end))
+    }
+
+    fn read_string(&self, object: usize) -> Option<String> {
+        let (kind, count, start) = self.object_header(object)?;
+        match kind {
+            0x5 => {
+                let bytes = self.bytes.get(start..start.checked_add(count)?)?;
+                Some(bytes.iter().map(|&byte| char::from(byte)).collect())
+            }
+            0x6 => {
+                let bytes = self
+                    .bytes
+                    .get(start..start.checked_add(count.checked_mul(2)?)?)?;
+                let units: Vec<u16> = bytes
+                    .chunks_exact(2)
+                    .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                    .collect();
+                String::from_utf16(&units).ok()
+            }
+            _ => None,
+        }
+    }
+}
+
+fn read_be(bytes: &[u8]) -> Option<usize> {
+    if bytes.len() > 8 {
+        return None;
+    }
+    let value = bytes
+        .iter()
+        .fold(0u64, |value, &byte| (value << 8) | u64::from(byte));
+    usize::try_from(value).ok()
+}
+
+crate::register_parser!(
+    "Apple framework bundle Info.plist",
+    &[
+        "**/*.framework/Info.plist",
+        "**/*.framework/Resources/Info.plist",
+        "**/*.framework/Versions/*/Resources/Info.plist",
+        "**/*.xcframework/Info.plist"
+    ],
+    "generic",
+    "",
+    Some("https://developer.apple.com/documentation/bundleresources/information_property_list"),
+);
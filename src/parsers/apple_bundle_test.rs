@@ -0,0 +1,192 @@
+#[cfg(test)]
+mod tests {
+    use super::super::PackageParser;
+    use super::super::apple_bundle::*;
+    use crate::models::{DatasourceId, PackageType};
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use tempfile::TempDir;
+
+    const XML_INFO_PLIST: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+	<key>CFBundleDevelopmentRegion</key>
+	<string>en</string>
+	<key>CFBundleIdentifier</key>
+	<string>org.example.Widgets</string>
+	<key>CFBundleName</key>
+	<string>Widgets</string>
+	<key>CFBundleShortVersionString</key>
+	<string>2.4.1</string>
+	<key>CFBundleVersion</key>
+	<string>241</string>
+	<key>CFBundleSupportedPlatforms</key>
+	<array>
+		<string>iPhoneOS</string>
+	</array>
+	<key>NSHumanReadableCopyright</key>
+	<string>Copyright &#169; 2024 Example Inc.</string>
+	<key>WidgetsLicense</key>
+	<string>MIT</string>
+</dict>
+</plist>
+"#;
+
+    fn write_bundle_plist(dir: &Path, bundle: &str, content: &[u8]) -> PathBuf {
+        let bundle_dir = dir.join(bundle);
+        fs::create_dir_all(&bundle_dir).expect("Failed to create bundle dir");
+        let path = bundle_dir.join("Info.plist");
+        fs::write(&path, content).expect("Failed to write Info.plist");
+        path
+    }
+
+    /// Encode a flat string dictionary as a `bplist00` file with 1-byte refs.
+    fn binary_plist(entries: &[(&str, &str)]) -> Vec<u8> {
+        let strings: Vec<&str> = entries
+            .iter()
+            .map(|(key, _)| *key)
+            .chain(entries.iter().map(|(_, value)| *value))
+            .collect();
+
+        let mut bytes = b"bplist00".to_vec();
+        let mut offsets = vec![bytes.len()];
+        bytes.push(0xD0 | entries.len() as u8);
+        bytes.extend((1..=strings.len()).map(|index| index as u8));
+        for string in strings {
+            offsets.push(bytes.len());
+            if string.len() < 15 {
+                bytes.push(0x50 | string.len() as u8);
+            } else {
+                bytes.extend([0x5F, 0x10, string.len() as u8]);
+            }
+            bytes.extend(string.as_bytes());
+        }
+
+        let offset_table = bytes.len();
+        bytes.extend(offsets.iter().map(|offset| *offset as u8));
+        bytes.extend([0; 6]);
+        bytes.extend([1, 1]);
+        bytes.extend((offsets.len() as u64).to_be_bytes());
+        bytes.extend(0u64.to_be_bytes());
+        bytes.extend((offset_table as u64).to_be_bytes());
+        bytes
+    }
+
+    #[test]
+    fn test_is_match() {
+        assert!(AppleBundleInfoPlistParser::is_match(&PathBuf::from(
+            "Frameworks/Widgets.framework/Info.plist"
+        )));
+        assert!(AppleBundleInfoPlistParser::is_match(&PathBuf::from(
+            "Widgets.framework/Versions/A/Resources/Info.plist"
+        )));
+        assert!(AppleBundleInfoPlistParser::is_match(&PathBuf::from(
+            "Widgets.xcframework/Info.plist"
+        )));
+        assert!(!AppleBundleInfoPlistParser::is_match(&PathBuf::from(
+            "MyApp/Info.plist"
+        )));
+        assert!(!AppleBundleInfoPlistParser::is_match(&PathBuf::from(
+            "Widgets.framework/Other.plist"
+        )));
+    }
+
+    #[test]
+    fn test_extract_xml_info_plist() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let path = write_bundle_plist(
+            temp_dir.path(),
+            "Widgets.framework",
+            XML_INFO_PLIST.as_bytes(),
+        );
+
+        let packages = AppleBundleInfoPlistParser::extract_packages(&path);
+        assert_eq!(packages.len(), 1);
+        let pkg = &packages[0];
+
+        assert_eq!(pkg.package_type, Some(PackageType::Generic));
+        assert_eq!(pkg.datasource_id, Some(DatasourceId::AppleInfoPlist));
+        assert_eq!(pkg.name.as_deref(), Some("org.example.Widgets"));
+        assert_eq!(pkg.version.as_deref(), Some("2.4.1"));
+        assert_eq!(
+            pkg.purl.as_deref(),
+            Some("pkg:generic/org.example.Widgets@2.4.1")
+        );
+        assert_eq!(
+            pkg.copyright.as_deref(),
+            Some("Copyright \u{a9} 2024 Example Inc.")
+        );
+        assert_eq!(pkg.extracted_license_statement.as_deref(), Some("MIT"));
+
+        let extra_data = pkg.extra_data.as_ref().expect("extra_data should be set");
+        assert_eq!(extra_data["bundle_name"], "Widgets");
+        assert_eq!(extra_data["bundle_version"], "241");
+    }
+
+    #[test]
+    fn test_xml_plist_ignores_nested_containers() {
+        let content = r#"<plist version="1.0"><dict>
+<key>AvailableLibraries</key>
+<array><dict><key>CFBundleIdentifier</key><string>nested.id</string></dict></array>
+<key>CFBundlePackageType</key>
+<string>XFWK</string>
+</dict></plist>"#;
+
+        let entries = parse_xml_plist(content).expect("plist should parse");
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries["CFBundlePackageType"], "XFWK");
+    }
+
+    #[test]
+    fn test_extract_binary_info_plist() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let content = binary_plist(&[
+            ("CFBundleIdentifier", "com.example.Binary"),
+            ("CFBundleShortVersionString", "1.0"),
+        ]);
+        let path = write_bundle_plist(temp_dir.path(), "Binary.framework", &content);
+
+        let packages = AppleBundleInfoPlistParser::extract_packages(&path);
+        assert_eq!(packages.len(), 1);
+        assert_eq!(packages[0].name.as_deref(), Some("com.example.Binary"));
+        assert_eq!(packages[0].version.as_deref(), Some("1.0"));
+    }
+
+    #[test]
+    fn test_extract_invalid_plist_returns_default() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let path = write_bundle_plist(temp_dir.path(), "Broken.framework", b"bplist00\x00");
+
+        let packages = AppleBundleInfoPlistParser::extract_packages(&path);
+        assert_eq!(packages.len(), 1);
+        assert_eq!(packages[0].name, None);
+        assert_eq!(
+            packages[0].datasource_id,
+            Some(DatasourceId::AppleInfoPlist)
+        );
+    }
+
+    #[test]
+    fn test_binary_plist_with_oversized_dict_count_is_rejected() {
+        // A dict whose count is an 8-byte integer of u64::MAX.
+        let mut bytes = b"bplist00".to_vec();
+        bytes.extend([0xDF, 0x13]);
+        bytes.extend([0xFF; 8]);
+        let offset_table = bytes.len();
+        bytes.push(8);
+        bytes.extend([0; 6]);
+        bytes.extend([1, 1]);
+        bytes.extend(1u64.to_be_bytes());
+        bytes.extend(0u64.to_be_bytes());
+        bytes.extend((offset_table as u64).to_be_bytes());
+
+        assert_eq!(parse_binary_plist(&bytes), None);
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let path = write_bundle_plist(temp_dir.path(), "Crafted.framework", &bytes);
+        let packages = AppleBundleInfoPlistParser::extract_packages(&path);
+        assert_eq!(packages.len(), 1);
+        assert_eq!(packages[0].name, None);
+    }
+}
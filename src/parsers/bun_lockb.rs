@@ -6,7 +6,8 @@ use base64::Engine;
 use serde_json::Value as JsonValue;
 
 use crate::models::{DatasourceId, Dependency, PackageData, PackageType, ResolvedPackage};
-use crate::parsers::utils::{npm_purl, parse_sri};
+use crate::parsers::utils::parse_sri;
+use crate::utils::purl::npm_purl;
 
 use super::PackageParser;
 
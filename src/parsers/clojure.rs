@@ -3,10 +3,10 @@ use std::fs;
 use std::path::Path;
 
 use crate::parser_warn as warn;
-use packageurl::PackageUrl;
 use serde_json::Value as JsonValue;
 
 use crate::models::{DatasourceId, Dependency, PackageData, PackageType};
+use crate::utils::purl::maven_purl;
 
 use super::PackageParser;
 
@@ -634,14 +634,7 @@ fn format_license_map(entries: &[(Form, Form)]) -> Option<String> {
 }
 
 fn build_maven_purl(namespace: Option<&str>, name: &str, version: Option<&str>) -> Option<String> {
-    let mut purl = PackageUrl::new(PackageType::Maven.as_str(), name).ok()?;
-    if let Some(namespace) = namespace {
-        purl.with_namespace(namespace).ok()?;
-    }
-    if let Some(version) = version {
-        purl.with_version(version).ok()?;
-    }
-    Some(purl.to_string())
+    maven_purl(namespace, name, version)
 }
 
 fn is_exact_version(version: &str) -> bool {
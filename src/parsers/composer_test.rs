@@ -574,6 +574,29 @@ mod tests {
                 .and_then(|value| value.as_str()),
             Some("bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb")
         );
+        assert_eq!(
+            extra_data.get("dist_url").and_then(|value| value.as_str()),
+            Some("https://example.com/runtime.zip")
+        );
+    }
+
+    #[test]
+    fn test_extract_lock_dev_packages_are_not_runtime() {
+        let content = sample_composer_lock();
+        let (_temp_dir, composer_path) = create_temp_file("composer.lock", &content);
+        let package_data = ComposerLockParser::extract_first_package(&composer_path);
+
+        let runtime_dep = find_dependency(
+            &package_data.dependencies,
+            "pkg:composer/acme/runtime@1.0.0",
+        );
+        assert_eq!(runtime_dep.scope.as_deref(), Some("require"));
+        assert_eq!(runtime_dep.is_runtime, Some(true));
+
+        let dev_dep = find_dependency(&package_data.dependencies, "pkg:composer/acme/devpkg@2.0.0");
+        assert_eq!(dev_dep.scope.as_deref(), Some("require-dev"));
+        assert_eq!(dev_dep.is_runtime, Some(false));
+        assert_eq!(dev_dep.is_pinned, Some(true));
     }
 
     #[test]
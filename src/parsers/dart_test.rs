@@ -774,4 +774,41 @@ executables:
         let override_dep = find_dependency(&package_data.dependencies, "path");
         assert!(override_dep.is_some());
     }
+
+    #[test]
+    fn test_archive_url_overrides_default_pub_download_url() {
+        let content = r#"
+name: mirrored
+version: 0.4.2
+archive_url: https://mirror.example.com/mirrored-0.4.2.tar.gz
+"#;
+
+        let (_temp_dir, pubspec_path) = create_temp_file("pubspec.yaml", content);
+        let package_data = PubspecYamlParser::extract_first_package(&pubspec_path);
+
+        assert_eq!(
+            package_data.download_url.as_deref(),
+            Some("https://mirror.example.com/mirrored-0.4.2.tar.gz")
+        );
+        assert_eq!(
+            package_data.repository_download_url.as_deref(),
+            Some("https://pub.dartlang.org/packages/mirrored/versions/0.4.2.tar.gz")
+        );
+    }
+
+    #[test]
+    fn test_download_url_defaults_to_pub_archive_without_archive_url() {
+        let content = r#"
+name: mirrored
+version: 0.4.2
+"#;
+
+        let (_temp_dir, pubspec_path) = create_temp_file("pubspec.yaml", content);
+        let package_data = PubspecYamlParser::extract_first_package(&pubspec_path);
+
+        assert_eq!(
+            package_data.download_url,
+            package_data.repository_download_url
+        );
+    }
 }
@@ -36,6 +36,7 @@ use std::path::Path;
 use crate::parser_warn as warn;
 use packageurl::PackageUrl;
 use regex::Regex;
+use serde::Serialize;
 
 use crate::models::{
     DatasourceId, Dependency, FileReference, LicenseDetection, PackageData, PackageType, Party,
@@ -1478,6 +1479,80 @@ fn parse_copyright_file(content: &str, package_name: Option<&str>) -> PackageDat
     }
 }
 
+/// `extra_data` key holding the `Files:` paragraphs of a DEP-5 copyright file.
+const DEP5_FILES_EXTRA_DATA_KEY: &str = "files";
+
+/// License and copyright holders that one `Files:` paragraph of a DEP-5
+/// debian/copyright file declares for the files matching its patterns.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct Dep5FilesParagraph {
+    /// Glob patterns, relative to the source package root.
+    pub(crate) files: Vec<String>,
+    pub(crate) license_expression: Option<String>,
+    pub(crate) license_expression_spdx: Option<String>,
+    pub(crate) holders: Vec<String>,
+}
+
+/// Parse the `Files:` paragraphs of a DEP-5 copyright file, in file order
+/// (where patterns overlap, the last matching paragraph applies). Returns
+/// nothing for files that are not machine-readable.
+pub(crate) fn parse_dep5_files_paragraphs(content: &str) -> Vec<Dep5FilesParagraph> {
+    let paragraphs = parse_copyright_paragraphs_with_lines(content);
+    let is_dep5 = paragraphs
+        .first()
+        .and_then(|p| rfc822::get_header_first(&p.metadata.headers, "format"))
+        .is_some();
+    if !is_dep5 {
+        return Vec::new();
+    }
+
+    paragraphs
+        .iter()
+        .filter_map(|para| {
+            let files = rfc822::get_header_first(&para.metadata.headers, "files")?;
+            let license = rfc822::get_header_first(&para.metadata.headers, "license")
+                .map(|license| {
+                    license
+                        .lines()
+                        .next()
+                        .unwrap_or_default()
+                        .trim()
+                        .to_string()
+                })
+                .filter(|license_name| !license_name.is_empty())
+                .map(|license_name| normalize_debian_license_name(&license_name));
+            let holders = rfc822::get_header_first(&para.metadata.headers, "copyright")
+                .map(|text| parse_copyright_holders(&text))
+                .unwrap_or_default();
+            Some(Dep5FilesParagraph {
+                files: files.split_whitespace().map(str::to_string).collect(),
+                license_expression: license
+                    .as_ref()
+                    .map(|license| license.declared_license_expression.clone()),
+                license_expression_spdx: license
+                    .map(|license| license.declared_license_expression_spdx),
+                holders,
+            })
+        })
+        .collect()
+}
+
+/// Record the `Files:` paragraphs of the DEP-5 copyright `content` in the
+/// `extra_data` of `package` (only with `--debian-copyright-files`).
+pub(crate) fn attach_dep5_files_paragraphs(package: &mut PackageData, content: &str) {
+    let paragraphs = parse_dep5_files_paragraphs(content);
+    if paragraphs.is_empty() {
+        return;
+    }
+    let Ok(value) = serde_json::to_value(paragraphs) else {
+        return;
+    };
+    package
+        .extra_data
+        .get_or_insert_with(HashMap::new)
+        .insert(DEP5_FILES_EXTRA_DATA_KEY.to_string(), value);
+}
+
 #[derive(Debug)]
 struct CopyrightParagraph {
     metadata: Rfc822Metadata,
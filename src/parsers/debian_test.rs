@@ -255,4 +255,54 @@ mod tests {
             Some(DatasourceId::DebianInstalledStatusDb)
         );
     }
+
+    #[test]
+    fn test_parse_dep5_files_paragraphs_maps_patterns_to_license_and_holders() {
+        let content = "\
+Format: https://www.debian.org/doc/packaging-manuals/copyright-format/1.0/
+Upstream-Name: libseccomp
+
+Files: *
+Copyright: 2012 Paul Moore <pmoore@redhat.com>
+ 2012 Eric Paris <eparis@redhat.com>
+License: LGPL-2.1
+
+Files: src/hash.* tools/hash.c
+Copyright: 2006 Bob Jenkins <bob_jenkins@burtleburtle.net>
+License: GPL-2+
+
+License: LGPL-2.1
+ This library is free software.
+";
+
+        let paragraphs = parse_dep5_files_paragraphs(content);
+
+        assert_eq!(
+            paragraphs,
+            vec![
+                Dep5FilesParagraph {
+                    files: vec!["*".to_string()],
+                    license_expression: Some("lgpl-2.1".to_string()),
+                    license_expression_spdx: Some("LGPL-2.1-only".to_string()),
+                    holders: vec![
+                        "Paul Moore <pmoore@redhat.com>".to_string(),
+                        "Eric Paris <eparis@redhat.com>".to_string(),
+                    ],
+                },
+                Dep5FilesParagraph {
+                    files: vec!["src/hash.*".to_string(), "tools/hash.c".to_string()],
+                    license_expression: Some("gpl-2.0-plus".to_string()),
+                    license_expression_spdx: Some("GPL-2.0-or-later".to_string()),
+                    holders: vec!["Bob Jenkins <bob_jenkins@burtleburtle.net>".to_string()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_dep5_files_paragraphs_ignores_unstructured_copyright() {
+        let content = "This package was debianized by someone.\n\nCopyright: 2001 Someone\n";
+
+        assert!(parse_dep5_files_paragraphs(content).is_empty());
+    }
 }
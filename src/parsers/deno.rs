@@ -3,11 +3,11 @@ use std::fs;
 use std::path::Path;
 
 use crate::parser_warn as warn;
-use packageurl::PackageUrl;
 use serde_json::Value;
 use url::Url;
 
 use crate::models::{DatasourceId, Dependency, PackageData, PackageType};
+use crate::utils::purl::build_purl;
 
 use super::PackageParser;
 
@@ -70,7 +70,7 @@ fn parse_deno_config(json: &Value) -> PackageData {
     let dependencies = extract_import_dependencies(json);
     let extra_data = extract_extra_data(json);
     let purl = match (namespace.as_deref(), name.as_deref(), version.as_deref()) {
-        (_, Some(name), version) => create_generic_purl(namespace.as_deref(), name, version),
+        (_, Some(name), version) => build_purl("generic", namespace.as_deref(), name, version),
         _ => None,
     };
 
@@ -137,12 +137,17 @@ fn build_import_dependency(alias: &str, specifier: &str) -> Dependency {
     let (purl, is_pinned) = if let Some((namespace, name, version)) = parse_jsr_specifier(specifier)
     {
         (
-            create_generic_purl(Some(&format!("jsr.io/{}", namespace)), &name, None),
+            build_purl(
+                "generic",
+                Some(&format!("jsr.io/{}", namespace)),
+                &name,
+                None,
+            ),
             Some(version.is_some_and(is_exact_version)),
         )
     } else if let Some((namespace, name, version)) = parse_npm_specifier(specifier) {
         (
-            create_npm_purl(namespace.as_deref(), &name, None),
+            build_purl("npm", namespace.as_deref(), &name, None),
             Some(version.is_some_and(is_exact_version)),
         )
     } else {
@@ -224,36 +229,6 @@ fn extract_non_empty_string(json: &Value, field: &str) -> Option<String> {
         .map(|value| value.to_string())
 }
 
-fn create_npm_purl(namespace: Option<&str>, name: &str, version: Option<&str>) -> Option<String> {
-    let mut purl = PackageUrl::new("npm", name).ok()?;
-    if let Some(namespace) = namespace {
-        purl.with_namespace(namespace).ok()?;
-    }
-    if let Some(version) = version
-        && is_exact_version(version)
-    {
-        purl.with_version(version).ok()?;
-    }
-    Some(purl.to_string())
-}
-
-fn create_generic_purl(
-    namespace: Option<&str>,
-    name: &str,
-    version: Option<&str>,
-) -> Option<String> {
-    let mut purl = PackageUrl::new("generic", name).ok()?;
-    if let Some(namespace) = namespace {
-        purl.with_namespace(namespace).ok()?;
-    }
-    if let Some(version) = version
-        && !version.is_empty()
-    {
-        purl.with_version(version).ok()?;
-    }
-    Some(purl.to_string())
-}
-
 fn create_remote_purl(specifier: &str) -> Option<String> {
     let url = Url::parse(specifier).ok()?;
     let segments: Vec<&str> = url.path_segments()?.collect();
@@ -267,7 +242,7 @@ fn create_remote_purl(specifier: &str) -> Option<String> {
     } else {
         url.host_str().map(|host| host.to_string())
     };
-    create_generic_purl(namespace.as_deref(), &name, None)
+    build_purl("generic", namespace.as_deref(), &name, None)
 }
 
 fn split_package_identity(name: &str) -> (Option<&str>, &str) {
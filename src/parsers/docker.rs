@@ -1,11 +1,12 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
 use crate::parser_warn as warn;
 use serde_json::json;
 
-use crate::models::{DatasourceId, PackageData, PackageType};
+use crate::models::{DatasourceId, Dependency, PackageData, PackageType};
 use crate::parsers::utils::read_file_to_string;
+use crate::utils::purl::docker_purl;
 
 use super::PackageParser;
 use super::license_normalization::normalize_spdx_declared_license;
@@ -35,7 +36,7 @@ impl PackageParser for DockerfileParser {
                 matches!(
                     name.as_str(),
                     "dockerfile" | "containerfile" | "containerfile.core"
-                )
+                ) || name.ends_with(".dockerfile")
             })
     }
 
@@ -54,8 +55,15 @@ impl PackageParser for DockerfileParser {
 
 pub(crate) fn parse_dockerfile(content: &str) -> PackageData {
     let oci_labels = extract_oci_labels(content);
-    let extra_data = (!oci_labels.is_empty())
-        .then(|| HashMap::from([("oci_labels".to_string(), json!(oci_labels))]));
+    let (dependencies, stages) = extract_base_images(content);
+    let mut extra_data = HashMap::new();
+    if !oci_labels.is_empty() {
+        extra_data.insert("oci_labels".to_string(), json!(oci_labels));
+    }
+    if !stages.is_empty() {
+        extra_data.insert("stages".to_string(), json!(stages));
+    }
+    let extra_data = (!extra_data.is_empty()).then_some(extra_data);
     let extracted_license_statement = oci_labels.get("org.opencontainers.image.licenses").cloned();
     let (declared_license_expression, declared_license_expression_spdx, license_detections) =
         normalize_spdx_declared_license(extracted_license_statement.as_deref());
@@ -76,10 +84,165 @@ pub(crate) fn parse_dockerfile(content: &str) -> PackageData {
         license_detections,
         extracted_license_statement,
         extra_data,
+        dependencies,
         ..Default::default()
     }
 }
 
+/// Base images from `FROM` instructions and external images from
+/// `COPY --from=<image>`, plus the names of the build stages.
+///
+/// `FROM` and `--from` values naming an earlier stage (by alias or index) are
+/// internal to the build and are not emitted. Only the last stage's base image
+/// ends up in the built image, so it alone is marked as runtime. `ARG`
+/// defaults seen so far are substituted into image references; unresolved
+/// variables are kept literally.
+fn extract_base_images(content: &str) -> (Vec<Dependency>, Vec<String>) {
+    let mut args: HashMap<String, String> = HashMap::new();
+    let mut stages: Vec<String> = Vec::new();
+    let mut stage_count = 0usize;
+    let mut dependencies = Vec::new();
+    let mut last_stage_dependency: Option<usize> = None;
+
+    for instruction in logical_lines(content) {
+        let trimmed = instruction.trim_start();
+        if starts_with_instruction(trimmed, "ARG") {
+            for token in tokenize_label_arguments(trimmed[3..].trim_start()) {
+                if let Some((name, default)) = token.split_once('=') {
+                    let default = substitute_args(default, &args);
+                    args.insert(name.to_string(), default);
+                }
+            }
+        } else if starts_with_instruction(trimmed, "FROM") {
+            let tokens = tokenize_label_arguments(trimmed[4..].trim_start());
+            let platform = tokens
+                .iter()
+                .find_map(|token| token.strip_prefix("--platform="));
+            let mut operands = tokens.iter().filter(|token| !token.starts_with("--"));
+            let Some(reference) = operands.next() else {
+                continue;
+            };
+            let alias = match (operands.next(), operands.next()) {
+                (Some(keyword), Some(alias)) if keyword.eq_ignore_ascii_case("AS") => {
+                    Some(alias.clone())
+                }
+                _ => None,
+            };
+            stage_count += 1;
+            last_stage_dependency = None;
+
+            let reference = substitute_args(reference, &args);
+            if reference != "scratch" && !is_stage_name(&stages, &reference) {
+                let mut dependency = build_image_dependency(&reference, "base_image");
+                if alias.is_some() || platform.is_some() {
+                    let extra_data = dependency.extra_data.get_or_insert_with(HashMap::new);
+                    if let Some(alias) = &alias {
+                        extra_data.insert("stage".to_string(), json!(alias));
+                    }
+                    if let Some(platform) = platform {
+                        extra_data.insert("platform".to_string(), json!(platform));
+                    }
+                }
+                last_stage_dependency = Some(dependencies.len());
+                dependencies.push(dependency);
+            }
+            if let Some(alias) = alias {
+                stages.push(alias);
+            }
+        } else if starts_with_instruction(trimmed, "COPY") {
+            let external_images = tokenize_label_arguments(trimmed[4..].trim_start())
+                .into_iter()
+                .filter_map(|token| Some(token.strip_prefix("--from=")?.to_string()))
+                .map(|reference| substitute_args(&reference, &args))
+                .filter(|reference| {
+                    !is_stage_name(&stages, reference)
+                        && !matches!(reference.parse::<usize>(), Ok(index) if index < stage_count)
+                });
+            for reference in external_images {
+                let mut dependency = build_image_dependency(&reference, "copy_from");
+                dependency.is_runtime = Some(false);
+                dependencies.push(dependency);
+            }
+        }
+    }
+
+    for (index, dependency) in dependencies.iter_mut().enumerate() {
+        if dependency.scope.as_deref() == Some("base_image") {
+            dependency.is_runtime = Some(Some(index) == last_stage_dependency);
+        }
+    }
+
+    let mut seen = HashSet::new();
+    stages.retain(|stage| seen.insert(stage.clone()));
+    (dependencies, stages)
+}
+
+/// Whether `reference` names an earlier build stage. Stage names are
+/// case-insensitive.
+fn is_stage_name(stages: &[String], reference: &str) -> bool {
+    stages
+        .iter()
+        .any(|stage| stage.eq_ignore_ascii_case(reference))
+}
+
+/// Replace `$NAME`, `${NAME}` and `${NAME:-default}` with known `ARG` values.
+fn substitute_args(value: &str, args: &HashMap<String, String>) -> String {
+    let mut result = String::new();
+    let mut rest = value;
+    while let Some(start) = rest.find('$') {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let (expression, consumed) = if let Some(braced) = after.strip_prefix('{') {
+            match braced.find('}') {
+                Some(end) => (&braced[..end], end + 2),
+                None => ("", 0),
+            }
+        } else {
+            let end = after
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(after.len());
+            (&after[..end], end)
+        };
+        if expression.is_empty() {
+            result.push('$');
+            rest = after;
+            continue;
+        }
+
+        let (name, default) = match expression.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (expression, None),
+        };
+        match args.get(name).filter(|value| !value.is_empty()) {
+            Some(value) => result.push_str(value),
+            None => match default {
+                Some(default) => result.push_str(default),
+                None => result.push_str(&rest[start..start + 1 + consumed]),
+            },
+        }
+        rest = &after[consumed..];
+    }
+    result.push_str(rest);
+    result
+}
+
+fn build_image_dependency(reference: &str, scope: &str) -> Dependency {
+    let digest = reference.split_once('@').map(|(_, digest)| digest);
+    let extra_data = digest.map(|digest| HashMap::from([("digest".to_string(), json!(digest))]));
+
+    Dependency {
+        purl: docker_purl(reference),
+        extracted_requirement: Some(reference.to_string()),
+        scope: Some(scope.to_string()),
+        is_runtime: Some(true),
+        is_optional: Some(false),
+        is_pinned: Some(digest.is_some()),
+        is_direct: Some(true),
+        resolved_package: None,
+        extra_data,
+    }
+}
+
 fn extract_oci_labels(content: &str) -> HashMap<String, String> {
     let mut labels = HashMap::new();
 
@@ -231,6 +394,7 @@ crate::register_parser!(
         "**/containerfile",
         "**/Containerfile.core",
         "**/containerfile.core",
+        "**/*.dockerfile",
     ],
     "docker",
     "Dockerfile",
@@ -10,7 +10,12 @@ mod tests {
         let (files, result) = scan_and_assemble(Path::new("testdata/docker-golden/pulp"));
 
         assert!(result.packages.is_empty());
-        assert!(result.dependencies.is_empty());
+        assert_eq!(result.dependencies.len(), 1);
+        assert_eq!(
+            result.dependencies[0].purl.as_deref(),
+            Some("pkg:docker/pulp/pulp-base@latest?repository_url=quay.io")
+        );
+        assert!(result.dependencies[0].for_package_uid.is_none());
 
         let containerfile = files
             .iter()
@@ -13,6 +13,7 @@ mod tests {
         assert!(DockerfileParser::is_match(&PathBuf::from(
             "containerfile.core"
         )));
+        assert!(DockerfileParser::is_match(&PathBuf::from("app.dockerfile")));
 
         assert!(!DockerfileParser::is_match(&PathBuf::from(
             "Dockerfile.dev"
@@ -139,4 +140,106 @@ LABEL org.opencontainers.image.title="Jitsi Broadcasting Infrastructure (jibri)"
         assert_eq!(package.datasource_id, Some(DatasourceId::Dockerfile));
         assert!(package.extra_data.is_none());
     }
+
+    #[test]
+    fn test_parse_multi_stage_base_images_skip_internal_stages() {
+        let content = r#"
+ARG NODE_VERSION=20.11
+ARG REGISTRY
+FROM --platform=linux/amd64 node:${NODE_VERSION}-alpine AS builder
+RUN npm ci
+FROM builder AS tester
+RUN npm test
+FROM ${REGISTRY}/runtime:1.0 AS base
+FROM gcr.io/distroless/nodejs20-debian12@sha256:0123abcd
+COPY --from=builder /app/dist /app
+COPY --from=1 /reports /reports
+COPY --from=docker.io/library/nginx:1.25 /etc/nginx /etc/nginx
+"#;
+        let package = parse_dockerfile(content);
+        let purls: Vec<_> = package
+            .dependencies
+            .iter()
+            .map(|dep| dep.purl.as_deref().unwrap_or_default())
+            .collect();
+        assert_eq!(package.dependencies.len(), 4, "{purls:?}");
+
+        let node = &package.dependencies[0];
+        assert_eq!(node.purl.as_deref(), Some("pkg:docker/node@20.11-alpine"));
+        assert_eq!(
+            node.extracted_requirement.as_deref(),
+            Some("node:20.11-alpine")
+        );
+        assert_eq!(node.scope.as_deref(), Some("base_image"));
+        assert_eq!(node.is_runtime, Some(false));
+        assert_eq!(node.is_pinned, Some(false));
+        let extra = node.extra_data.as_ref().expect("stage extra_data");
+        assert_eq!(extra.get("stage"), Some(&serde_json::json!("builder")));
+        assert_eq!(
+            extra.get("platform"),
+            Some(&serde_json::json!("linux/amd64"))
+        );
+
+        let unresolved = &package.dependencies[1];
+        assert_eq!(
+            unresolved.extracted_requirement.as_deref(),
+            Some("${REGISTRY}/runtime:1.0")
+        );
+
+        let distroless = &package.dependencies[2];
+        let distroless_purl = distroless.purl.as_deref().unwrap_or_default();
+        assert!(distroless_purl.starts_with("pkg:docker/distroless/nodejs20-debian12@sha256"));
+        assert!(distroless_purl.ends_with("?repository_url=gcr.io"));
+        assert_eq!(distroless.is_runtime, Some(true));
+        assert_eq!(distroless.is_pinned, Some(true));
+        assert_eq!(
+            distroless
+                .extra_data
+                .as_ref()
+                .and_then(|extra| extra.get("digest")),
+            Some(&serde_json::json!("sha256:0123abcd"))
+        );
+
+        let nginx = &package.dependencies[3];
+        assert_eq!(nginx.purl.as_deref(), Some("pkg:docker/nginx@1.25"));
+        assert_eq!(nginx.scope.as_deref(), Some("copy_from"));
+        assert_eq!(nginx.is_runtime, Some(false));
+
+        assert_eq!(
+            package
+                .extra_data
+                .as_ref()
+                .and_then(|extra| extra.get("stages")),
+            Some(&serde_json::json!(["builder", "tester", "base"]))
+        );
+    }
+
+    #[test]
+    fn test_parse_plain_base_image_has_no_extra_data() {
+        let package = parse_dockerfile("FROM python:3.12-slim\nRUN pip install .\n");
+
+        assert_eq!(package.dependencies.len(), 1);
+        let python = &package.dependencies[0];
+        assert_eq!(python.purl.as_deref(), Some("pkg:docker/python@3.12-slim"));
+        assert!(python.extra_data.is_none());
+    }
+
+    #[test]
+    fn test_parse_stage_references_ignore_case() {
+        let content = "FROM golang:1.22 AS Builder\n\
+                       FROM builder AS test\n\
+                       FROM alpine:3.19\n\
+                       COPY --from=BUILDER /out/app /app\n";
+        let package = parse_dockerfile(content);
+
+        let purls: Vec<_> = package
+            .dependencies
+            .iter()
+            .map(|dep| dep.purl.as_deref().unwrap_or_default())
+            .collect();
+        assert_eq!(
+            purls,
+            vec!["pkg:docker/golang@1.22", "pkg:docker/alpine@3.19"]
+        );
+    }
 }
@@ -24,7 +24,7 @@
 
 use crate::models::{DatasourceId, Dependency, PackageData, PackageType};
 use crate::parser_warn as warn;
-use packageurl::PackageUrl;
+use crate::utils::purl::{golang_purl, split_golang_module_path};
 use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::Path;
@@ -140,9 +140,9 @@ pub fn parse_go_mod(content: &str) -> PackageData {
         if let Some(module_path) = trimmed.strip_prefix("module ") {
             let module_path = strip_comment(module_path).trim();
             if !module_path.is_empty() {
-                let (ns, n) = split_module_path(module_path);
-                namespace = ns;
-                name = Some(n);
+                let (ns, n) = split_golang_module_path(module_path);
+                namespace = ns.map(str::to_string);
+                name = Some(n.to_string());
             }
             continue;
         }
@@ -419,17 +419,6 @@ fn parse_retract_value(value: &str) -> Vec<String> {
     }
 }
 
-pub(crate) fn split_module_path(path: &str) -> (Option<String>, String) {
-    match path.rfind('/') {
-        Some(idx) => {
-            let namespace = &path[..idx];
-            let name = &path[idx + 1..];
-            (Some(namespace.to_string()), name.to_string())
-        }
-        None => (None, path.to_string()),
-    }
-}
-
 /// Strips inline comments (everything after `//`) from a line.
 ///
 /// Preserves the content before the comment marker.
@@ -445,40 +434,14 @@ fn strip_comment(line: &str) -> &str {
 /// Format: `pkg:golang/namespace/name@version`
 /// The module path is split into namespace and name for PURL construction.
 pub(crate) fn create_golang_purl(module_path: &str, version: Option<&str>) -> Option<String> {
-    let (namespace, name) = split_module_path(module_path);
-
-    let mut purl = match PackageUrl::new(PACKAGE_TYPE.as_str(), &name) {
-        Ok(p) => p,
-        Err(e) => {
-            warn!(
-                "Failed to create PURL for golang module '{}': {}",
-                module_path, e
-            );
-            return None;
-        }
-    };
-
-    if let Some(ns) = &namespace
-        && let Err(e) = purl.with_namespace(ns)
-    {
-        warn!(
-            "Failed to set namespace '{}' for golang module '{}': {}",
-            ns, module_path, e
-        );
-        return None;
-    }
-
-    if let Some(v) = version
-        && let Err(e) = purl.with_version(v)
-    {
+    let purl = golang_purl(module_path, version);
+    if purl.is_none() {
         warn!(
-            "Failed to set version '{}' for golang module '{}': {}",
-            v, module_path, e
+            "Failed to create PURL for golang module '{}' (version {:?})",
+            module_path, version
         );
-        return None;
     }
-
-    Some(purl.to_string())
+    purl
 }
 
 /// Returns a default empty PackageData for Go modules.
@@ -1071,8 +1034,8 @@ pub fn parse_godeps_json(content: &str) -> PackageData {
 
     let (namespace, name) = match &import_path {
         Some(ip) => {
-            let (ns, n) = split_module_path(ip);
-            (ns, Some(n))
+            let (ns, n) = split_golang_module_path(ip);
+            (ns.map(str::to_string), Some(n.to_string()))
         }
         None => (None, None),
     };
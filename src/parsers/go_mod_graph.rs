@@ -5,9 +5,10 @@ use std::path::Path;
 use crate::parser_warn as warn;
 
 use crate::models::{DatasourceId, Dependency, PackageData, PackageType};
+use crate::utils::purl::split_golang_module_path;
 
 use super::PackageParser;
-use super::go::{create_golang_purl, split_module_path};
+use super::go::create_golang_purl;
 
 const PACKAGE_TYPE: PackageType = PackageType::Golang;
 
@@ -104,7 +105,10 @@ pub(crate) fn parse_go_mod_graph(content: &str) -> PackageData {
 
     let (namespace, name): (Option<String>, String) = root_module
         .as_deref()
-        .map(split_module_path)
+        .map(|module| {
+            let (namespace, name) = split_golang_module_path(module);
+            (namespace.map(str::to_string), name.to_string())
+        })
         .unwrap_or((None, String::new()));
 
     let homepage_url = root_module
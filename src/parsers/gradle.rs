@@ -25,11 +25,11 @@ use std::fs;
 use std::path::Path;
 
 use crate::parser_warn as warn;
-use packageurl::PackageUrl;
 use serde_json::json;
 
 use crate::models::{DatasourceId, Dependency, PackageData, PackageType};
 use crate::parsers::PackageParser;
+use crate::utils::purl::maven_purl;
 
 use super::license_normalization::{
     DeclaredLicenseMatchMetadata, build_declared_license_data, empty_declared_license_data,
@@ -846,20 +846,12 @@ fn create_dependency(raw: &RawDep) -> Option<Dependency> {
         return None;
     }
 
-    let mut purl = PackageUrl::new("maven", name).ok()?;
-
-    if !namespace.is_empty() {
-        purl.with_namespace(namespace).ok()?;
-    }
-
-    if !version.is_empty() {
-        purl.with_version(version).ok()?;
-    }
+    let purl = maven_purl(Some(namespace), name, Some(version))?;
 
     let (is_runtime, is_optional) = classify_scope(scope);
     let is_pinned = !version.is_empty();
 
-    let purl_string = purl.to_string().replace("$", "%24").replace('\'', "%27");
+    let purl_string = purl.replace("$", "%24").replace('\'', "%27");
     let mut extra_data = std::collections::HashMap::new();
     if let Some(alias) = &raw.catalog_alias {
         extra_data.insert("catalog_alias".to_string(), json!(alias));
@@ -881,7 +873,7 @@ fn create_dependency(raw: &RawDep) -> Option<Dependency> {
     })
 }
 
-fn classify_scope(scope: &str) -> (bool, bool) {
+pub(super) fn classify_scope(scope: &str) -> (bool, bool) {
     let scope_lower = scope.to_lowercase();
 
     if scope_lower.contains("test") {
@@ -926,17 +918,11 @@ fn resolve_gradle_version_catalog_aliases(path: &Path, dependencies: &mut [Depen
             continue;
         };
 
-        let mut purl = PackageUrl::new("maven", &entry.name).ok();
-        if let Some(ref mut purl) = purl {
-            if !entry.namespace.is_empty() {
-                let _ = purl.with_namespace(&entry.namespace);
-            }
-            if let Some(version) = &entry.version {
-                let _ = purl.with_version(version);
-            }
-        }
-
-        dep.purl = purl.map(|p| p.to_string());
+        dep.purl = maven_purl(
+            Some(&entry.namespace),
+            &entry.name,
+            entry.version.as_deref(),
+        );
         dep.extracted_requirement = entry.version.clone();
         dep.is_pinned = Some(entry.version.is_some());
     }
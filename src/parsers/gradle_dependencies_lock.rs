@@ -0,0 +1,160 @@
+//! Parser for Nebula `dependencies.lock` files.
+//!
+//! Extracts resolved dependency versions written by the Nebula
+//! gradle-dependency-lock-plugin, the older JSON-based alternative to Gradle's
+//! native `gradle.lockfile`.
+//!
+//! # Supported Formats
+//! - dependencies.lock (JSON keyed by configuration, then `group:name`)
+//!
+//! # Key Features
+//! - Locked versions become pinned `pkg:maven/...` purls
+//! - Configuration names are kept as `scope` and classified like build-script
+//!   configurations (test configurations are not runtime)
+//! - Entries carrying a `requested` version are direct dependencies
+//!
+//! # Implementation Notes
+//! - Coordinates are split on the first `:` only, so artifact names containing
+//!   dots (e.g. `jackson-module-kotlin.jdk8`) stay intact
+//! - Project (multi-module) entries without a `locked` version are skipped
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::models::{DatasourceId, Dependency, PackageData, PackageType, ResolvedPackage};
+use crate::parser_warn as warn;
+use crate::utils::purl::maven_purl;
+
+use super::PackageParser;
+use super::gradle::classify_scope;
+
+/// Nebula `dependencies.lock` parser.
+pub struct GradleDependenciesLockParser;
+
+impl PackageParser for GradleDependenciesLockParser {
+    const PACKAGE_TYPE: PackageType = PackageType::Maven;
+
+    fn is_match(path: &Path) -> bool {
+        path.file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name == "dependencies.lock")
+    }
+
+    fn extract_packages(path: &Path) -> Vec<PackageData> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) => {
+                warn!("Failed to read dependencies.lock at {:?}: {}", path, e);
+                return vec![default_package_data()];
+            }
+        };
+
+        let json: Value = match serde_json::from_str(&content) {
+            Ok(json) => json,
+            Err(e) => {
+                warn!("Failed to parse dependencies.lock at {:?}: {}", path, e);
+                return vec![default_package_data()];
+            }
+        };
+
+        vec![PackageData {
+            dependencies: extract_dependencies(&json),
+            ..default_package_data()
+        }]
+    }
+}
+
+fn extract_dependencies(json: &Value) -> Vec<Dependency> {
+    let Some(configurations) = json.as_object() else {
+        return Vec::new();
+    };
+
+    let mut dependencies = Vec::new();
+    for (configuration, entries) in configurations {
+        let Some(entries) = entries.as_object() else {
+            continue;
+        };
+        for (coordinates, entry) in entries {
+            if let Some(dependency) = build_dependency(configuration, coordinates, entry) {
+                dependencies.push(dependency);
+            }
+        }
+    }
+
+    dependencies
+}
+
+fn build_dependency(configuration: &str, coordinates: &str, entry: &Value) -> Option<Dependency> {
+    let (group, artifact) = coordinates.split_once(':')?;
+    if group.is_empty() || artifact.is_empty() {
+        return None;
+    }
+    let version = entry.get("locked").and_then(Value::as_str)?;
+    let requested = entry
+        .get("requested")
+        .and_then(Value::as_str)
+        .map(str::to_string);
+
+    let purl = maven_purl(Some(group), artifact, Some(version));
+    let (is_runtime, is_optional) = classify_scope(configuration);
+
+    let mut extra_data = HashMap::new();
+    for key in ["transitive", "firstLevelTransitive"] {
+        if let Some(parents) = entry.get(key).filter(|value| value.is_array()) {
+            extra_data.insert(key.to_string(), parents.clone());
+        }
+    }
+
+    let resolved_package = ResolvedPackage {
+        package_type: PackageType::Maven,
+        namespace: group.to_string(),
+        name: artifact.to_string(),
+        version: version.to_string(),
+        primary_language: None,
+        download_url: None,
+        sha1: None,
+        sha256: None,
+        sha512: None,
+        md5: None,
+        is_virtual: false,
+        extra_data: None,
+        dependencies: Vec::new(),
+        repository_homepage_url: None,
+        repository_download_url: None,
+        api_data_url: None,
+        datasource_id: Some(DatasourceId::GradleDependenciesLock),
+        purl: purl.clone(),
+    };
+
+    Some(Dependency {
+        purl,
+        is_direct: Some(requested.is_some()),
+        extracted_requirement: requested,
+        scope: Some(configuration.to_string()),
+        is_runtime: Some(is_runtime),
+        is_optional: Some(is_optional),
+        is_pinned: Some(true),
+        resolved_package: Some(Box::new(resolved_package)),
+        extra_data: (!extra_data.is_empty()).then_some(extra_data),
+    })
+}
+
+fn default_package_data() -> PackageData {
+    PackageData {
+        package_type: Some(GradleDependenciesLockParser::PACKAGE_TYPE),
+        primary_language: Some("Java".to_string()),
+        datasource_id: Some(DatasourceId::GradleDependenciesLock),
+        ..Default::default()
+    }
+}
+
+crate::register_parser!(
+    "Nebula Gradle dependency lock",
+    &["**/dependencies.lock"],
+    "maven",
+    "Java",
+    Some("https://github.com/nebula-plugins/gradle-dependency-lock-plugin"),
+);
@@ -0,0 +1,157 @@
+// Tests for Nebula dependencies.lock parser
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+use crate::models::{DatasourceId, PackageType};
+use crate::parsers::PackageParser;
+use crate::parsers::gradle_dependencies_lock::GradleDependenciesLockParser;
+
+fn write_lock(content: &str) -> (TempDir, PathBuf) {
+    let dir = TempDir::new().expect("Failed to create temp dir");
+    let path = dir.path().join("dependencies.lock");
+    fs::write(&path, content).expect("Failed to write dependencies.lock");
+    (dir, path)
+}
+
+#[test]
+fn test_is_match_dependencies_lock() {
+    assert!(GradleDependenciesLockParser::is_match(Path::new(
+        "/project/dependencies.lock"
+    )));
+    assert!(!GradleDependenciesLockParser::is_match(Path::new(
+        "gradle.lockfile"
+    )));
+    assert!(!GradleDependenciesLockParser::is_match(Path::new(
+        "package-lock.json"
+    )));
+}
+
+#[test]
+fn test_parse_pinned_dependencies() {
+    let (_dir, path) = write_lock(
+        r#"{
+  "compileClasspath": {
+    "com.google.guava:guava": {
+      "locked": "31.1-jre",
+      "requested": "31.+"
+    },
+    "com.google.guava:failureaccess": {
+      "locked": "1.0.1",
+      "transitive": ["com.google.guava:guava"]
+    }
+  }
+}"#,
+    );
+
+    let package_data = GradleDependenciesLockParser::extract_first_package(&path);
+
+    assert_eq!(package_data.package_type, Some(PackageType::Maven));
+    assert_eq!(
+        package_data.datasource_id,
+        Some(DatasourceId::GradleDependenciesLock)
+    );
+    assert_eq!(package_data.dependencies.len(), 2);
+
+    let guava = package_data
+        .dependencies
+        .iter()
+        .find(|dep| dep.purl.as_deref() == Some("pkg:maven/com.google.guava/guava@31.1-jre"))
+        .expect("guava should be present");
+    assert_eq!(guava.extracted_requirement.as_deref(), Some("31.+"));
+    assert_eq!(guava.scope.as_deref(), Some("compileClasspath"));
+    assert_eq!(guava.is_pinned, Some(true));
+    assert_eq!(guava.is_direct, Some(true));
+    assert_eq!(guava.is_runtime, Some(true));
+
+    let failureaccess = package_data
+        .dependencies
+        .iter()
+        .find(|dep| dep.purl.as_deref() == Some("pkg:maven/com.google.guava/failureaccess@1.0.1"))
+        .expect("failureaccess should be present");
+    assert_eq!(failureaccess.is_direct, Some(false));
+    assert_eq!(failureaccess.is_pinned, Some(true));
+    assert!(
+        failureaccess
+            .extra_data
+            .as_ref()
+            .is_some_and(|extra| extra.contains_key("transitive"))
+    );
+}
+
+#[test]
+fn test_parse_keeps_dotted_artifact_names() {
+    let (_dir, path) = write_lock(
+        r#"{
+  "runtimeClasspath": {
+    "com.fasterxml.jackson.module:jackson-module-kotlin.jdk8": {
+      "locked": "2.15.2"
+    }
+  }
+}"#,
+    );
+
+    let package_data = GradleDependenciesLockParser::extract_first_package(&path);
+    let dependency = &package_data.dependencies[0];
+    let resolved = dependency.resolved_package.as_ref().unwrap();
+
+    assert_eq!(resolved.namespace, "com.fasterxml.jackson.module");
+    assert_eq!(resolved.name, "jackson-module-kotlin.jdk8");
+    assert_eq!(resolved.version, "2.15.2");
+    assert_eq!(
+        dependency.purl.as_deref(),
+        Some("pkg:maven/com.fasterxml.jackson.module/jackson-module-kotlin.jdk8@2.15.2")
+    );
+}
+
+#[test]
+fn test_parse_test_configuration_is_not_runtime() {
+    let (_dir, path) = write_lock(
+        r#"{
+  "testRuntimeClasspath": {
+    "junit:junit": { "locked": "4.13.2", "requested": "4.13.2" }
+  }
+}"#,
+    );
+
+    let package_data = GradleDependenciesLockParser::extract_first_package(&path);
+    let dependency = &package_data.dependencies[0];
+
+    assert_eq!(dependency.scope.as_deref(), Some("testRuntimeClasspath"));
+    assert_eq!(dependency.is_runtime, Some(false));
+    assert_eq!(dependency.is_optional, Some(true));
+}
+
+#[test]
+fn test_parse_skips_project_entries_without_locked_version() {
+    let (_dir, path) = write_lock(
+        r#"{
+  "compileClasspath": {
+    "com.example:sibling-module": { "project": true },
+    "org.slf4j:slf4j-api": { "locked": "2.0.7" }
+  }
+}"#,
+    );
+
+    let package_data = GradleDependenciesLockParser::extract_first_package(&path);
+
+    assert_eq!(package_data.dependencies.len(), 1);
+    assert_eq!(
+        package_data.dependencies[0].purl.as_deref(),
+        Some("pkg:maven/org.slf4j/slf4j-api@2.0.7")
+    );
+}
+
+#[test]
+fn test_parse_invalid_json_returns_default_package() {
+    let (_dir, path) = write_lock("{ not json");
+
+    let package_data = GradleDependenciesLockParser::extract_first_package(&path);
+
+    assert!(package_data.dependencies.is_empty());
+    assert_eq!(
+        package_data.datasource_id,
+        Some(DatasourceId::GradleDependenciesLock)
+    );
+}
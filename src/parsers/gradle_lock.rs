@@ -20,7 +20,7 @@
 
 use crate::models::{DatasourceId, Dependency, PackageData, PackageType, ResolvedPackage};
 use crate::parser_warn as warn;
-use packageurl::PackageUrl;
+use crate::utils::purl::maven_purl;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
@@ -154,11 +154,7 @@ fn parse_dependency_line(line: &str) -> Option<Dependency> {
     let version = parts[2].to_string();
 
     // Generate purl
-    let purl = PackageUrl::new("maven", &artifact).ok().and_then(|mut p| {
-        p.with_namespace(&group).ok()?;
-        p.with_version(&version).ok()?;
-        Some(p.to_string())
-    });
+    let purl = maven_purl(Some(&group), &artifact, Some(&version));
 
     // Build extra_data with group and artifact separately
     let mut extra_data: Option<HashMap<String, serde_json::Value>> = None;
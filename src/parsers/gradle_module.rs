@@ -4,10 +4,10 @@ use std::io::BufReader;
 use std::path::Path;
 
 use crate::parser_warn as warn;
-use packageurl::PackageUrl;
 use serde_json::{Map as JsonMap, Value};
 
 use crate::models::{DatasourceId, Dependency, FileReference, PackageData, PackageType};
+use crate::utils::purl::maven_purl;
 
 use super::PackageParser;
 
@@ -562,14 +562,7 @@ fn extract_file_hashes(file: &JsonMap<String, Value>) -> ArtifactHashes {
 }
 
 fn build_maven_purl(namespace: &str, name: &str, version: Option<&str>) -> Option<String> {
-    let mut purl = PackageUrl::new("maven", name).ok()?;
-    if !namespace.trim().is_empty() {
-        purl.with_namespace(namespace).ok()?;
-    }
-    if let Some(version) = version.filter(|value| !value.trim().is_empty()) {
-        purl.with_version(version).ok()?;
-    }
-    Some(purl.to_string())
+    maven_purl(Some(namespace), name, version)
 }
 
 fn is_exact_version(version: &str) -> bool {
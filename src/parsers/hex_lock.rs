@@ -13,7 +13,7 @@ use super::PackageParser;
 pub struct HexLockParser;
 
 #[derive(Clone, Debug)]
-enum Term {
+pub(super) enum Term {
     Map(Vec<(Term, Term)>),
     Tuple(Vec<Term>),
     List(Vec<Term>),
@@ -24,7 +24,7 @@ enum Term {
     Integer(i64),
 }
 
-struct Parser<'a> {
+pub(super) struct Parser<'a> {
     chars: Vec<char>,
     pos: usize,
     source: &'a str,
@@ -101,6 +101,10 @@ fn build_dependency_from_lock_entry(
         _ => return Ok(None),
     };
 
+    if tuple.first().map(term_to_atom).transpose()?.as_deref() == Some("git") {
+        return build_git_dependency_from_lock_entry(app_name, tuple).map(Some);
+    }
+
     if tuple.len() < 8 {
         return Ok(None);
     }
@@ -131,7 +135,7 @@ fn build_dependency_from_lock_entry(
         primary_language: Some("Elixir".to_string()),
         download_url: None,
         sha1: None,
-        sha256: Some(inner_checksum),
+        sha256: Some(inner_checksum.clone()),
         sha512: None,
         md5: None,
         is_virtual: true,
@@ -166,13 +170,61 @@ fn build_dependency_from_lock_entry(
         is_pinned: Some(true),
         is_direct: Some(false),
         resolved_package: Some(Box::new(resolved_package)),
-        extra_data: Some(HashMap::from([(
-            "app".to_string(),
-            JsonValue::String(app_name),
-        )])),
+        extra_data: Some(HashMap::from([
+            ("app".to_string(), JsonValue::String(app_name)),
+            (
+                "inner_checksum".to_string(),
+                JsonValue::String(inner_checksum),
+            ),
+            (
+                "outer_checksum".to_string(),
+                JsonValue::String(outer_checksum),
+            ),
+        ])),
     }))
 }
 
+/// Git entries (`{:git, url, revision, opts}`) are pinned to a commit rather
+/// than a Hex release, so the purl carries no version and the checkout is kept
+/// in `extra_data`.
+fn build_git_dependency_from_lock_entry(
+    app_name: String,
+    tuple: &[Term],
+) -> Result<Dependency, String> {
+    let mut extra_data = HashMap::from([("app".to_string(), JsonValue::String(app_name.clone()))]);
+    if let Some(url) = tuple.get(1) {
+        extra_data.insert(
+            "vcs_url".to_string(),
+            JsonValue::String(term_to_string(url)?),
+        );
+    }
+    if let Some(revision) = tuple.get(2) {
+        extra_data.insert(
+            "commit".to_string(),
+            JsonValue::String(term_to_string(revision)?),
+        );
+    }
+    if let Some(opts) = tuple.get(3) {
+        for (key, value) in term_to_keyword_map(opts)? {
+            if let Ok(value) = term_to_string(&value) {
+                extra_data.insert(key, JsonValue::String(value));
+            }
+        }
+    }
+
+    Ok(Dependency {
+        purl: build_hex_purl(&app_name, None, None),
+        extracted_requirement: None,
+        scope: Some("dependencies".to_string()),
+        is_runtime: Some(true),
+        is_optional: Some(false),
+        is_pinned: Some(true),
+        is_direct: Some(false),
+        resolved_package: None,
+        extra_data: Some(extra_data),
+    })
+}
+
 fn build_nested_dependency(tuple: DependencyTuple) -> Result<Dependency, String> {
     let package_name = tuple
         .hex_name
@@ -246,7 +298,7 @@ fn term_to_dependency_tuples(term: &Term) -> Result<Vec<DependencyTuple>, String
     Ok(result)
 }
 
-fn term_to_keyword_map(term: &Term) -> Result<HashMap<String, Term>, String> {
+pub(super) fn term_to_keyword_map(term: &Term) -> Result<HashMap<String, Term>, String> {
     match term {
         Term::KeywordList(entries) => Ok(entries.iter().cloned().collect()),
         Term::List(entries) => {
@@ -264,7 +316,11 @@ fn term_to_keyword_map(term: &Term) -> Result<HashMap<String, Term>, String> {
     }
 }
 
-fn build_hex_purl(name: &str, version: Option<&str>, repo: Option<&str>) -> Option<String> {
+pub(super) fn build_hex_purl(
+    name: &str,
+    version: Option<&str>,
+    repo: Option<&str>,
+) -> Option<String> {
     let mut purl = PackageUrl::new("hex", name).ok()?;
     if let Some(repo) = repo
         && repo != "hexpm"
@@ -293,7 +349,7 @@ fn build_hex_api_url(name: &str, repo: &str) -> String {
     }
 }
 
-fn term_to_string(term: &Term) -> Result<String, String> {
+pub(super) fn term_to_string(term: &Term) -> Result<String, String> {
     match term {
         Term::String(value) => Ok(value.clone()),
         Term::Atom(value) => Ok(value.clone()),
@@ -302,7 +358,7 @@ fn term_to_string(term: &Term) -> Result<String, String> {
     }
 }
 
-fn term_to_atom(term: &Term) -> Result<String, String> {
+pub(super) fn term_to_atom(term: &Term) -> Result<String, String> {
     match term {
         Term::Atom(value) => Ok(value.clone()),
         _ => Err("Expected atom".to_string()),
@@ -318,7 +374,7 @@ fn term_to_atom_list(term: &Term) -> Result<Vec<String>, String> {
 }
 
 impl<'a> Parser<'a> {
-    fn new(source: &'a str) -> Self {
+    pub(super) fn new(source: &'a str) -> Self {
         Self {
             chars: source.chars().collect(),
             pos: 0,
@@ -326,7 +382,7 @@ impl<'a> Parser<'a> {
         }
     }
 
-    fn parse_term(&mut self) -> Result<Term, String> {
+    pub(super) fn parse_term(&mut self) -> Result<Term, String> {
         self.skip_ws();
         match self.peek() {
             Some('%') => self.parse_map(),
@@ -368,21 +424,33 @@ impl<'a> Parser<'a> {
         Ok(Term::Map(entries))
     }
 
+    /// Parse a tuple. Trailing `key: value` entries (Elixir's keyword-list
+    /// sugar, as in `{:dep, "~> 1.0", only: :test}`) become a final
+    /// `Term::KeywordList` item.
     fn parse_tuple(&mut self) -> Result<Term, String> {
         self.expect('{')?;
         let mut items = Vec::new();
+        let mut keyword_entries = Vec::new();
         loop {
             self.skip_ws();
             if self.peek() == Some('}') {
                 self.pos += 1;
                 break;
             }
-            items.push(self.parse_term()?);
+            if let Some(keyword) = self.try_parse_keyword_key() {
+                let value = self.parse_term()?;
+                keyword_entries.push((keyword, value));
+            } else {
+                items.push(self.parse_term()?);
+            }
             self.skip_ws();
             if self.peek() == Some(',') {
                 self.pos += 1;
             }
         }
+        if !keyword_entries.is_empty() {
+            items.push(Term::KeywordList(keyword_entries));
+        }
         Ok(Term::Tuple(items))
     }
 
@@ -520,10 +588,19 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Skip whitespace and `#` line comments, which `mix.exs` dependency
+    /// lists may contain between entries.
     fn skip_ws(&mut self) {
         while let Some(c) = self.peek() {
             if c.is_whitespace() {
                 self.pos += 1;
+            } else if c == '#' {
+                while let Some(c) = self.peek() {
+                    if c == '\n' {
+                        break;
+                    }
+                    self.pos += 1;
+                }
             } else {
                 break;
             }
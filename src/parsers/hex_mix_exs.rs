@@ -0,0 +1,341 @@
+//! Parser for Elixir `mix.exs` project files.
+//!
+//! # Supported Formats
+//! - `mix.exs` - Mix project definition (`project/0` keywords and `deps/0` list)
+//!
+//! # Implementation Notes
+//! - `mix.exs` is Elixir code, so nothing is evaluated: project keywords are
+//!   read with regexes (resolving simple `@attribute "value"` module
+//!   attributes) and each `deps/0` tuple is parsed as a literal term.
+//! - Tuples that are not literal (e.g. `only: Mix.env()`) fall back to the
+//!   dependency name and requirement only.
+//! - `only:` environments without `:prod` mark a dependency as non-runtime.
+//! - Git, GitHub and path dependencies keep their source in `extra_data`.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::LazyLock;
+
+use crate::parser_warn as warn;
+use regex::Regex;
+use serde_json::Value as JsonValue;
+
+use crate::models::{DatasourceId, Dependency, PackageData, PackageType};
+
+use super::PackageParser;
+use super::hex_lock::{
+    Parser, Term, build_hex_purl, term_to_atom, term_to_keyword_map, term_to_string,
+};
+use super::license_normalization::normalize_spdx_declared_license;
+
+static RE_MODULE_ATTRIBUTE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?m)^\s*@(\w+)\s+"([^"]*)""#).unwrap());
+static RE_APP: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\bapp:\s*:(\w+)").unwrap());
+static RE_LICENSES: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\blicenses:\s*\[([^\]]*)\]").unwrap());
+static RE_QUOTED_STRING: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#""([^"]*)""#).unwrap());
+static RE_DEPS_FUNCTION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\bdefp?\s+deps(?:\s*\(\s*\))?\s+do\b").unwrap());
+static RE_FALLBACK_DEP: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"^\{\s*:(\w+)\s*(?:,\s*"([^"]*)")?"#).unwrap());
+
+/// Dependency options copied verbatim into `extra_data` when present.
+const SOURCE_OPTIONS: &[&str] = &[
+    "git", "github", "path", "branch", "tag", "ref", "sparse", "subdir",
+];
+
+pub struct HexMixExsParser;
+
+impl PackageParser for HexMixExsParser {
+    const PACKAGE_TYPE: PackageType = PackageType::Hex;
+
+    fn is_match(path: &Path) -> bool {
+        path.file_name().and_then(|name| name.to_str()) == Some("mix.exs")
+    }
+
+    fn extract_packages(path: &Path) -> Vec<PackageData> {
+        match fs::read_to_string(path) {
+            Ok(content) => vec![parse_mix_exs(&content)],
+            Err(e) => {
+                warn!("Failed to read mix.exs at {:?}: {}", path, e);
+                vec![default_package_data()]
+            }
+        }
+    }
+}
+
+fn default_package_data() -> PackageData {
+    PackageData {
+        package_type: Some(PackageType::Hex),
+        primary_language: Some("Elixir".to_string()),
+        datasource_id: Some(DatasourceId::HexMixExs),
+        ..Default::default()
+    }
+}
+
+fn parse_mix_exs(content: &str) -> PackageData {
+    let attributes: HashMap<&str, &str> = RE_MODULE_ATTRIBUTE
+        .captures_iter(content)
+        .filter_map(|caps| Some((caps.get(1)?.as_str(), caps.get(2)?.as_str())))
+        .collect();
+
+    let name = RE_APP
+        .captures(content)
+        .and_then(|caps| caps.get(1))
+        .map(|m| m.as_str().to_string());
+    let version = keyword_string(content, "version", &attributes);
+    let source_url = keyword_string(content, "source_url", &attributes);
+    let homepage_url =
+        keyword_string(content, "homepage_url", &attributes).or_else(|| source_url.clone());
+
+    let licenses: Vec<String> = RE_LICENSES
+        .captures(content)
+        .and_then(|caps| caps.get(1))
+        .map(|list| {
+            RE_QUOTED_STRING
+                .captures_iter(list.as_str())
+                .filter_map(|caps| caps.get(1).map(|m| m.as_str().to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    let extracted_license_statement = (!licenses.is_empty()).then(|| licenses.join(" AND "));
+    let (declared_license_expression, declared_license_expression_spdx, license_detections) =
+        normalize_spdx_declared_license(extracted_license_statement.as_deref());
+
+    let mut package = default_package_data();
+    package.purl = name
+        .as_deref()
+        .and_then(|name| build_hex_purl(name, version.as_deref(), None));
+    package.repository_homepage_url = name
+        .as_deref()
+        .map(|name| format!("https://hex.pm/packages/{}", name));
+    package.api_data_url = name
+        .as_deref()
+        .map(|name| format!("https://hex.pm/api/packages/{}", name));
+    package.name = name;
+    package.version = version;
+    package.description = keyword_string(content, "description", &attributes);
+    package.homepage_url = homepage_url;
+    package.vcs_url = source_url;
+    package.extracted_license_statement = extracted_license_statement;
+    package.declared_license_expression = declared_license_expression;
+    package.declared_license_expression_spdx = declared_license_expression_spdx;
+    package.license_detections = license_detections;
+    package.dependencies = extract_deps_list(content)
+        .map(|list| {
+            dependency_tuples(list)
+                .filter_map(build_dependency)
+                .collect()
+        })
+        .unwrap_or_default();
+    package
+}
+
+/// Value of a `key: "literal"` or `key: @attribute` keyword in the project.
+fn keyword_string(content: &str, key: &str, attributes: &HashMap<&str, &str>) -> Option<String> {
+    let pattern = Regex::new(&format!(r#"\b{}:\s*(?:"([^"]*)"|@(\w+))"#, key)).ok()?;
+    let caps = pattern.captures(content)?;
+    let value = match (caps.get(1), caps.get(2)) {
+        (Some(literal), _) => literal.as_str(),
+        (None, Some(attribute)) => attributes.get(attribute.as_str())?,
+        (None, None) => return None,
+    };
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// The `[...]` list returned by the `deps/0` function.
+fn extract_deps_list(content: &str) -> Option<&str> {
+    let body_start = RE_DEPS_FUNCTION.find(content)?.end();
+    let list_start = body_start + content[body_start..].find('[')?;
+    let list_end = matching_close(content, list_start, '[', ']')?;
+    Some(&content[list_start + 1..list_end])
+}
+
+/// Top-level `{...}` tuples of a dependency list, as source slices.
+fn dependency_tuples(list: &str) -> impl Iterator<Item = &str> {
+    let mut offset = 0;
+    std::iter::from_fn(move || {
+        let start = offset + skip_to_tuple(&list[offset..])?;
+        let end = matching_close(list, start, '{', '}')?;
+        offset = end + 1;
+        Some(&list[start..=end])
+    })
+}
+
+fn skip_to_tuple(text: &str) -> Option<usize> {
+    let mut in_comment = false;
+    for (index, c) in text.char_indices() {
+        match c {
+            '\n' => in_comment = false,
+            '#' => in_comment = true,
+            '{' if !in_comment => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Byte index of the delimiter closing the one at `open_index`, skipping
+/// string literals and `#` comments.
+fn matching_close(text: &str, open_index: usize, open: char, close: char) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut in_comment = false;
+    let mut escaped = false;
+    for (index, c) in text[open_index..].char_indices() {
+        if in_comment {
+            in_comment = c != '\n';
+            continue;
+        }
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '#' => in_comment = true,
+            _ if c == open => depth += 1,
+            _ if c == close => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open_index + index);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn build_dependency(source: &str) -> Option<Dependency> {
+    match Parser::new(source).parse_term() {
+        Ok(Term::Tuple(items)) => build_dependency_from_tuple(&items),
+        _ => build_fallback_dependency(source),
+    }
+}
+
+fn build_dependency_from_tuple(items: &[Term]) -> Option<Dependency> {
+    let app_name = term_to_atom(items.first()?).ok()?;
+    let requirement = items.get(1).and_then(|term| match term {
+        Term::String(value) => Some(value.clone()),
+        _ => None,
+    });
+    let opts = items
+        .iter()
+        .skip(1)
+        .find_map(|term| match term {
+            Term::KeywordList(_) | Term::List(_) => term_to_keyword_map(term).ok(),
+            _ => None,
+        })
+        .unwrap_or_default();
+
+    let package_name = opts
+        .get("hex")
+        .and_then(|term| term_to_atom(term).ok())
+        .unwrap_or_else(|| app_name.clone());
+    let repo = opts
+        .get("repo")
+        .or_else(|| opts.get("organization"))
+        .and_then(|term| term_to_string(term).ok());
+    let environments = opts.get("only").map(term_to_environments);
+    let is_runtime = environments
+        .as_ref()
+        .is_none_or(|envs| envs.iter().any(|env| env == "prod"));
+    let is_optional = matches!(opts.get("optional"), Some(Term::Bool(true)));
+
+    let mut extra_data = HashMap::new();
+    if package_name != app_name {
+        extra_data.insert("app".to_string(), JsonValue::String(app_name.clone()));
+    }
+    for key in SOURCE_OPTIONS {
+        if let Some(value) = opts.get(*key).and_then(|term| term_to_string(term).ok()) {
+            extra_data.insert(key.to_string(), JsonValue::String(value));
+        }
+    }
+    if let Some(github) = opts
+        .get("github")
+        .and_then(|term| term_to_string(term).ok())
+    {
+        extra_data.insert(
+            "vcs_url".to_string(),
+            JsonValue::String(format!("https://github.com/{}.git", github)),
+        );
+    } else if let Some(git) = opts.get("git").and_then(|term| term_to_string(term).ok()) {
+        extra_data.insert("vcs_url".to_string(), JsonValue::String(git));
+    }
+    if matches!(opts.get("override"), Some(Term::Bool(true))) {
+        extra_data.insert("override".to_string(), JsonValue::Bool(true));
+    }
+
+    let is_pinned = match requirement.as_deref() {
+        Some(requirement) => is_exact_requirement(requirement),
+        None => opts.contains_key("ref") || opts.contains_key("tag"),
+    };
+
+    Some(Dependency {
+        purl: build_hex_purl(&package_name, None, repo.as_deref()),
+        extracted_requirement: requirement,
+        scope: Some(
+            environments
+                .map(|envs| envs.join(","))
+                .unwrap_or_else(|| "dependencies".to_string()),
+        ),
+        is_runtime: Some(is_runtime),
+        is_optional: Some(is_optional),
+        is_pinned: Some(is_pinned),
+        is_direct: Some(true),
+        resolved_package: None,
+        extra_data: (!extra_data.is_empty()).then_some(extra_data),
+    })
+}
+
+fn build_fallback_dependency(source: &str) -> Option<Dependency> {
+    let caps = RE_FALLBACK_DEP.captures(source)?;
+    let app_name = caps.get(1)?.as_str();
+    let requirement = caps.get(2).map(|m| m.as_str().to_string());
+    Some(Dependency {
+        purl: build_hex_purl(app_name, None, None),
+        is_pinned: Some(requirement.as_deref().is_some_and(is_exact_requirement)),
+        extracted_requirement: requirement,
+        scope: Some("dependencies".to_string()),
+        is_runtime: Some(true),
+        is_optional: Some(false),
+        is_direct: Some(true),
+        resolved_package: None,
+        extra_data: None,
+    })
+}
+
+fn term_to_environments(term: &Term) -> Vec<String> {
+    match term {
+        Term::List(items) => items
+            .iter()
+            .filter_map(|item| term_to_atom(item).ok())
+            .collect(),
+        other => term_to_atom(other).into_iter().collect(),
+    }
+}
+
+fn is_exact_requirement(requirement: &str) -> bool {
+    let version = requirement.trim().trim_start_matches("==").trim();
+    !version.is_empty()
+        && version
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+'))
+        && version.starts_with(|c: char| c.is_ascii_digit())
+}
+
+crate::register_parser!(
+    "Hex mix.exs project file",
+    &["**/mix.exs"],
+    "hex",
+    "Elixir",
+    Some("https://hexdocs.pm/mix/Mix.Project.html"),
+);
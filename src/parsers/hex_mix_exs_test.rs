@@ -0,0 +1,133 @@
+use std::path::PathBuf;
+
+use crate::models::{DatasourceId, PackageType};
+
+use super::PackageParser;
+use super::hex_lock::HexLockParser;
+use super::hex_mix_exs::HexMixExsParser;
+
+#[test]
+fn test_hex_mix_exs_is_match() {
+    assert!(HexMixExsParser::is_match(&PathBuf::from("/tmp/mix.exs")));
+    assert!(!HexMixExsParser::is_match(&PathBuf::from("/tmp/mix.lock")));
+}
+
+#[test]
+fn test_parse_hex_mix_exs_project_and_deps() {
+    let path = PathBuf::from("testdata/hex/mix-exs/mix.exs");
+    let package_data = HexMixExsParser::extract_first_package(&path);
+
+    assert_eq!(package_data.package_type, Some(PackageType::Hex));
+    assert_eq!(package_data.datasource_id, Some(DatasourceId::HexMixExs));
+    assert_eq!(package_data.name.as_deref(), Some("demo"));
+    assert_eq!(package_data.version.as_deref(), Some("0.4.2"));
+    assert_eq!(package_data.purl.as_deref(), Some("pkg:hex/demo@0.4.2"));
+    assert_eq!(
+        package_data.description.as_deref(),
+        Some("A demo Phoenix application")
+    );
+    assert_eq!(
+        package_data.vcs_url.as_deref(),
+        Some("https://github.com/example/demo")
+    );
+    assert_eq!(
+        package_data.extracted_license_statement.as_deref(),
+        Some("Apache-2.0")
+    );
+    assert_eq!(package_data.dependencies.len(), 8);
+
+    let dep = |purl: &str| {
+        package_data
+            .dependencies
+            .iter()
+            .find(|dep| dep.purl.as_deref() == Some(purl))
+            .unwrap_or_else(|| panic!("expected {purl} dependency"))
+    };
+
+    let phoenix = dep("pkg:hex/phoenix");
+    assert_eq!(phoenix.extracted_requirement.as_deref(), Some("~> 1.7.10"));
+    assert_eq!(phoenix.scope.as_deref(), Some("dependencies"));
+    assert_eq!(phoenix.is_runtime, Some(true));
+    assert_eq!(phoenix.is_pinned, Some(false));
+    assert_eq!(phoenix.is_direct, Some(true));
+
+    assert_eq!(dep("pkg:hex/jason").is_pinned, Some(true));
+
+    let ex_doc = dep("pkg:hex/ex_doc");
+    assert_eq!(ex_doc.scope.as_deref(), Some("dev"));
+    assert_eq!(ex_doc.is_runtime, Some(false));
+
+    let mox = dep("pkg:hex/mox");
+    assert_eq!(mox.scope.as_deref(), Some("test"));
+    assert_eq!(mox.is_runtime, Some(false));
+
+    assert_eq!(dep("pkg:hex/telemetry").is_optional, Some(true));
+
+    let gettext = dep("pkg:hex/gettext");
+    assert_eq!(gettext.extracted_requirement, None);
+    assert_eq!(gettext.is_pinned, Some(true));
+    let extra = gettext.extra_data.as_ref().expect("git extra_data");
+    assert_eq!(
+        extra.get("vcs_url"),
+        Some(&serde_json::json!(
+            "https://github.com/elixir-lang/gettext.git"
+        ))
+    );
+    assert_eq!(extra.get("tag"), Some(&serde_json::json!("v0.24.0")));
+
+    let plug_cowboy = dep("pkg:hex/plug_cowboy");
+    assert_eq!(plug_cowboy.is_pinned, Some(false));
+    assert_eq!(
+        plug_cowboy
+            .extra_data
+            .as_ref()
+            .and_then(|extra| extra.get("vcs_url")),
+        Some(&serde_json::json!(
+            "https://github.com/elixir-plug/plug_cowboy.git"
+        ))
+    );
+
+    let credo = dep("pkg:hex/credo");
+    assert_eq!(credo.extracted_requirement.as_deref(), Some("~> 1.7"));
+}
+
+#[test]
+fn test_parse_hex_mix_lock_keeps_checksums_and_git_entries() {
+    let path = PathBuf::from("testdata/hex/git-lock/mix.lock");
+    let package_data = HexLockParser::extract_first_package(&path);
+    assert_eq!(package_data.dependencies.len(), 2);
+
+    let jason = package_data
+        .dependencies
+        .iter()
+        .find(|dep| dep.purl.as_deref() == Some("pkg:hex/jason@1.4.1"))
+        .expect("expected jason dependency");
+    let extra = jason.extra_data.as_ref().expect("extra_data");
+    assert_eq!(
+        extra.get("inner_checksum"),
+        Some(&serde_json::json!(
+            "af1504e35f629ddcdd6addb3513c3853991f694921b1b9368b0bd32beb9f1b63"
+        ))
+    );
+    assert_eq!(
+        extra.get("outer_checksum"),
+        Some(&serde_json::json!(
+            "fbb01ecdfd565b56261302f7e1fcc27c4fb8f32d56eab74db621fc154604a7a1"
+        ))
+    );
+
+    let gettext = package_data
+        .dependencies
+        .iter()
+        .find(|dep| dep.purl.as_deref() == Some("pkg:hex/gettext"))
+        .expect("expected gettext git dependency");
+    assert_eq!(gettext.is_pinned, Some(true));
+    let extra = gettext.extra_data.as_ref().expect("extra_data");
+    assert_eq!(
+        extra.get("commit"),
+        Some(&serde_json::json!(
+            "2ff4a4f6a1c1e8d8e1bc6a1f2d5c6f3d2f9b8a01"
+        ))
+    );
+    assert_eq!(extra.get("tag"), Some(&serde_json::json!("v0.24.0")));
+}
@@ -226,9 +226,16 @@ pub(crate) fn build_declared_license_detection(
                 .referenced_filenames
                 .map(|filenames| filenames.iter().map(|name| (*name).to_string()).collect()),
             matched_text_diagnostics: None,
+            match_type: None,
+            language: None,
+            custom_license_files: Vec::new(),
         }],
         detection_log: vec![],
         identifier: None,
+        is_public_domain: None,
+        license_facet: None,
+        component: None,
+        in_header: None,
     }
 }
 
@@ -310,9 +317,16 @@ pub(crate) fn finalize_package_declared_license_references(package_data: &mut Pa
                 matched_text: Some(statement.to_string()),
                 referenced_filenames: Some(referenced_filenames),
                 matched_text_diagnostics: None,
+                match_type: None,
+                language: None,
+                custom_license_files: Vec::new(),
             }],
             detection_log: vec![],
             identifier: None,
+            is_public_domain: None,
+            license_facet: None,
+            component: None,
+            in_header: None,
         }];
     }
 }
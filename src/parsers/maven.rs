@@ -35,6 +35,7 @@ use super::PackageParser;
 use super::license_normalization::{
     DeclaredLicenseMatchMetadata, NormalizedDeclaredLicense, build_declared_license_data,
     combine_normalized_licenses, empty_declared_license_data, normalize_declared_license_key,
+    normalize_spdx_expression,
 };
 
 #[derive(Clone, Default)]
@@ -333,6 +334,8 @@ fn build_maven_qualifiers(
     (!qualifiers.is_empty()).then_some(qualifiers)
 }
 
+// Formatted by hand rather than through `utils::purl`: ScanCode leaves
+// unresolved `${property}` placeholders unencoded in Maven purls.
 fn build_maven_purl(
     group_id: &str,
     artifact_id: &str,
@@ -2136,9 +2139,6 @@ fn parse_manifest_mf(path: &Path) -> PackageData {
         // Bundle-DocURL
         package_data.homepage_url = headers_map.get("Bundle-DocURL").cloned();
 
-        // Bundle-License
-        package_data.extracted_license_statement = headers_map.get("Bundle-License").cloned();
-
         // Import-Package -> dependencies with scope "import"
         if let Some(import_pkg) = headers_map.get("Import-Package") {
             let deps = parse_osgi_package_list(import_pkg, "import");
@@ -2237,9 +2237,53 @@ fn parse_manifest_mf(path: &Path) -> PackageData {
         }
     }
 
+    // Bundle-License is an OSGi header but is also common in plain JAR manifests.
+    package_data.extracted_license_statement = headers_map.get("Bundle-License").cloned();
+    let (declared_license_expression, declared_license_expression_spdx, license_detections) =
+        build_bundle_license_data(package_data.extracted_license_statement.as_deref());
+    package_data.declared_license_expression = declared_license_expression;
+    package_data.declared_license_expression_spdx = declared_license_expression_spdx;
+    package_data.license_detections = license_detections;
+
     package_data
 }
 
+/// Normalize a `Bundle-License` header into declared license data.
+///
+/// Entries are comma-separated and may carry `;link=` / `;description=` attributes.
+/// URL-only entries cannot be mapped to a license key and are left to the
+/// extracted statement.
+fn build_bundle_license_data(
+    statement: Option<&str>,
+) -> (
+    Option<String>,
+    Option<String>,
+    Vec<crate::models::LicenseDetection>,
+) {
+    let Some(statement) = statement.map(str::trim).filter(|value| !value.is_empty()) else {
+        return empty_declared_license_data();
+    };
+
+    let normalized: Vec<_> = split_osgi_list(statement)
+        .iter()
+        .filter_map(|entry| entry.split(';').next())
+        .map(str::trim)
+        .filter(|license| !license.is_empty() && !license.contains("://"))
+        .filter_map(|license| {
+            normalize_spdx_expression(license).or_else(|| normalize_declared_license_key(license))
+        })
+        .collect();
+
+    let Some(combined) = combine_normalized_licenses(normalized, " AND ") else {
+        return empty_declared_license_data();
+    };
+
+    build_declared_license_data(
+        combined,
+        DeclaredLicenseMatchMetadata::single_line(statement),
+    )
+}
+
 /// Parse OSGi Import-Package header into dependencies.
 ///
 /// Format: comma-separated list of packages with optional directives:
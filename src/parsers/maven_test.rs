@@ -456,6 +456,51 @@ mod tests {
         assert_eq!(vendor.name, Some("Example Corp".to_string()));
     }
 
+    #[test]
+    fn test_parse_manifest_mf_bundle_license_and_vendor() {
+        let temp_dir = TempDir::new().expect("temp dir");
+        let manifest_path = temp_dir.path().join("MANIFEST.MF");
+        fs::write(
+            &manifest_path,
+            "Manifest-Version: 1.0\nImplementation-Title: widgets\nImplementation-Version: 4.2.0\nImplementation-Vendor: Acme Widgets Inc.\nBundle-License: Apache-2.0;link=\"https://www.apache.org/licenses/LICENSE-2.0\"\n",
+        )
+        .expect("write manifest");
+
+        let package_data = MavenParser::extract_first_package(&manifest_path);
+
+        assert_eq!(
+            package_data.datasource_id,
+            Some(DatasourceId::JavaJarManifest)
+        );
+        assert_eq!(package_data.name.as_deref(), Some("widgets"));
+        assert_eq!(package_data.version.as_deref(), Some("4.2.0"));
+        assert_eq!(
+            package_data.parties[0].name.as_deref(),
+            Some("Acme Widgets Inc.")
+        );
+        assert_eq!(
+            package_data.declared_license_expression.as_deref(),
+            Some("apache-2.0")
+        );
+        assert_eq!(
+            package_data.declared_license_expression_spdx.as_deref(),
+            Some("Apache-2.0")
+        );
+        assert_eq!(package_data.license_detections.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_manifest_mf_bundle_license_url_is_not_normalized() {
+        let manifest_path = PathBuf::from("testdata/osgi/basic/META-INF/MANIFEST.MF");
+        let package_data = MavenParser::extract_first_package(&manifest_path);
+
+        assert_eq!(
+            package_data.extracted_license_statement.as_deref(),
+            Some("https://www.apache.org/licenses/LICENSE-2.0")
+        );
+        assert!(package_data.declared_license_expression.is_none());
+    }
+
     #[test]
     fn test_missing_manifest_mf_preserves_manifest_datasource() {
         let manifest_path = PathBuf::from("/nonexistent/MANIFEST.MF");
@@ -6,6 +6,9 @@ mod about_test;
 mod alpine;
 #[cfg(test)]
 mod alpine_scan_test;
+mod apple_bundle;
+#[cfg(test)]
+mod apple_bundle_test;
 mod arch;
 #[cfg(test)]
 mod arch_scan_test;
@@ -130,6 +133,9 @@ mod go_work_test;
 #[cfg(all(test, feature = "golden-tests"))]
 pub(crate) mod golden_test_utils;
 mod gradle;
+mod gradle_dependencies_lock;
+#[cfg(test)]
+mod gradle_dependencies_lock_test;
 mod gradle_lock;
 #[cfg(test)]
 mod gradle_lock_test;
@@ -158,6 +164,9 @@ mod helm_test;
 mod hex_lock;
 #[cfg(test)]
 mod hex_lock_test;
+mod hex_mix_exs;
+#[cfg(test)]
+mod hex_mix_exs_test;
 mod license_normalization;
 mod maven;
 #[cfg(test)]
@@ -240,6 +249,9 @@ mod python_test;
 mod readme;
 #[cfg(test)]
 mod readme_test;
+mod registry;
+#[cfg(test)]
+mod registry_test;
 mod requirements_txt;
 #[cfg(test)]
 mod requirements_txt_test;
@@ -297,7 +309,9 @@ mod yarn_lock_test;
 #[cfg(all(test, feature = "golden-tests"))]
 mod golden_test;
 
+use std::any::Any;
 use std::cell::RefCell;
+use std::panic::{AssertUnwindSafe, catch_unwind};
 use std::path::Path;
 
 use crate::models::{PackageData, PackageType};
@@ -321,15 +335,25 @@ where
         stack.borrow_mut().push(Vec::new());
     });
 
-    let packages = extract()
-        .into_iter()
-        .map(|mut package| {
-            finalize_package_declared_license_references(&mut package);
-            package
-        })
-        .collect();
-    let scan_errors =
+    let extracted = catch_unwind(AssertUnwindSafe(extract));
+    let mut scan_errors =
         PARSER_DIAGNOSTIC_STACK.with(|stack| stack.borrow_mut().pop().unwrap_or_default());
+    let packages = match extracted {
+        Ok(packages) => packages
+            .into_iter()
+            .map(|mut package| {
+                finalize_package_declared_license_references(&mut package);
+                package
+            })
+            .collect(),
+        Err(payload) => {
+            scan_errors.push(format!(
+                "Package parser panicked: {}",
+                panic_message(payload.as_ref())
+            ));
+            Vec::new()
+        }
+    };
 
     ParsePackagesResult {
         packages,
@@ -337,6 +361,15 @@ where
     }
 }
 
+/// Describe a caught panic payload for a scan error message.
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
 pub(crate) fn record_parser_diagnostic(message: String) -> bool {
     PARSER_DIAGNOSTIC_STACK.with(|stack| {
         let mut stack = stack.borrow_mut();
@@ -438,6 +471,7 @@ pub trait PackageParser {
 
 pub use self::about::AboutFileParser;
 pub use self::alpine::{AlpineApkParser, AlpineApkbuildParser, AlpineInstalledParser};
+pub use self::apple_bundle::AppleBundleInfoPlistParser;
 pub use self::arch::{ArchPkginfoParser, ArchSrcinfoParser};
 pub use self::autotools::AutotoolsConfigureParser;
 pub use self::bazel::{BazelBuildParser, BazelModuleParser};
@@ -460,6 +494,7 @@ pub use self::cpan_dist_ini::CpanDistIniParser;
 pub use self::cpan_makefile_pl::CpanMakefilePlParser;
 pub use self::cran::CranParser;
 pub use self::dart::{PubspecLockParser, PubspecYamlParser};
+pub(crate) use self::debian::attach_dep5_files_paragraphs;
 pub use self::debian::{
     DebianControlInExtractedDebParser, DebianControlParser, DebianCopyrightParser, DebianDebParser,
     DebianDebianTarParser, DebianDistrolessInstalledParser, DebianDscParser,
@@ -474,12 +509,14 @@ pub use self::gitmodules::GitmodulesParser;
 pub use self::go::{GoModParser, GoSumParser, GoWorkParser, GodepsParser};
 pub use self::go_mod_graph::GoModGraphParser;
 pub use self::gradle::GradleParser;
+pub use self::gradle_dependencies_lock::GradleDependenciesLockParser;
 pub use self::gradle_lock::GradleLockfileParser;
 pub use self::gradle_module::GradleModuleParser;
 pub use self::hackage::{HackageCabalParser, HackageCabalProjectParser, HackageStackYamlParser};
 pub use self::haxe::HaxeParser;
 pub use self::helm::{HelmChartLockParser, HelmChartYamlParser};
 pub use self::hex_lock::HexLockParser;
+pub use self::hex_mix_exs::HexMixExsParser;
 pub use self::maven::MavenParser;
 pub use self::meson::MesonParser;
 pub use self::microsoft_update_manifest::MicrosoftUpdateManifestParser;
@@ -514,12 +551,14 @@ pub use self::poetry_lock::PoetryLockParser;
 pub use self::pylock_toml::PylockTomlParser;
 pub use self::python::PythonParser;
 pub use self::readme::ReadmeParser;
+pub use self::registry::{DynPackageParser, register_parser};
 pub use self::requirements_txt::RequirementsTxtParser;
 pub use self::rpm_db::{RpmBdbDatabaseParser, RpmNdbDatabaseParser, RpmSqliteDatabaseParser};
 pub use self::rpm_license_files::RpmLicenseFilesParser;
 pub use self::rpm_mariner_manifest::RpmMarinerManifestParser;
 pub use self::rpm_parser::RpmParser;
 pub use self::rpm_specfile::RpmSpecfileParser;
+pub(crate) use self::rpm_specfile::attach_declared_license as attach_rpm_specfile_declared_license;
 pub use self::rpm_yumdb::RpmYumdbParser;
 pub use self::ruby::{
     GemArchiveParser, GemMetadataExtractedParser, GemfileLockParser, GemfileParser, GemspecParser,
@@ -536,13 +575,17 @@ pub use self::yarn_lock::YarnLockParser;
 ///
 /// Parsers are tried first, then recognizers. This ordering is important because
 /// recognizers match broadly by file extension (e.g., `.jar`) and would shadow
-/// more specific parsers if checked first.
+/// more specific parsers if checked first. Parsers registered at runtime with
+/// [`register_parser`] are tried before both.
 macro_rules! register_package_handlers {
     (
         parsers: [$($parser:ty),* $(,)?],
         recognizers: [$($recognizer:ty),* $(,)?] $(,)?
     ) => {
         pub fn try_parse_file(path: &Path) -> Option<ParsePackagesResult> {
+            if let Some(result) = registry::try_parse_registered(path) {
+                return Some(result);
+            }
             $(
                 if <$parser>::is_match(path) {
                     return Some(capture_parser_diagnostics(|| <$parser>::extract_packages(path)));
@@ -556,8 +599,34 @@ macro_rules! register_package_handlers {
             None
         }
 
+        /// Whether [`try_parse_file`] would dispatch `path` to a parser or recognizer.
+        /// Only the path is inspected; the file need not exist.
+        pub fn has_package_handler(path: &Path) -> bool {
+            registry::is_registered_match(path)
+                $(|| <$parser>::is_match(path))*
+                $(|| <$recognizer>::is_match(path))*
+        }
+
+        /// Parse `path` with the parser or recognizer named `type_name`, regardless of its
+        /// filename. Used for runtime `--manifest-pattern`/`--lockfile-pattern` routing.
+        pub fn try_parse_file_with(type_name: &str, path: &Path) -> Option<ParsePackagesResult> {
+            match type_name {
+                $(
+                    stringify!($parser) => {
+                        Some(capture_parser_diagnostics(|| <$parser>::extract_packages(path)))
+                    }
+                )*
+                $(
+                    stringify!($recognizer) => {
+                        Some(capture_parser_diagnostics(|| <$recognizer>::extract_packages(path)))
+                    }
+                )*
+                _ => None,
+            }
+        }
+
         // Used by the parser-golden maintenance tool in `xtask`.
-        // Scanner runtime dispatch goes through `try_parse_file()`.
+        // Scanner runtime dispatch goes through `try_parse_file()` and `try_parse_file_with()`.
         #[allow(dead_code)]
         pub fn parse_by_type_name(type_name: &str, path: &Path) -> Option<PackageData> {
             match type_name {
@@ -593,6 +662,7 @@ register_package_handlers! {
         AlpineApkParser,
         AlpineApkbuildParser,
         AlpineInstalledParser,
+        AppleBundleInfoPlistParser,
         ArchPkginfoParser,
         ArchSrcinfoParser,
         AutotoolsConfigureParser,
@@ -651,6 +721,7 @@ register_package_handlers! {
         GoModGraphParser,
         GoSumParser,
         GoWorkParser,
+        GradleDependenciesLockParser,
         GradleLockfileParser,
         GradleParser,
         GradleModuleParser,
@@ -661,6 +732,7 @@ register_package_handlers! {
         HelmChartLockParser,
         HaxeParser,
         HexLockParser,
+        HexMixExsParser,
         MavenParser,
         MesonParser,
         MicrosoftUpdateManifestParser,
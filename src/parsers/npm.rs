@@ -20,7 +20,8 @@
 
 use crate::models::{DatasourceId, Dependency, PackageData, PackageType, Party};
 use crate::parser_warn as warn;
-use crate::parsers::utils::{npm_purl, parse_sri};
+use crate::parsers::utils::parse_sri;
+use crate::utils::purl::npm_purl;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::fs;
@@ -142,6 +143,13 @@ impl PackageParser for NpmParser {
             extra_data_map.insert("dependenciesMeta".to_string(), dependencies_meta);
         }
 
+        if let Some(directory) = extract_repository_directory(&json) {
+            extra_data_map.insert(
+                "repositoryDirectory".to_string(),
+                serde_json::Value::String(directory),
+            );
+        }
+
         let extra_data = if extra_data_map.is_empty() {
             None
         } else {
@@ -954,6 +962,14 @@ fn extract_workspaces(json: &Value) -> Option<serde_json::Value> {
     json.get(FIELD_WORKSPACES).cloned()
 }
 
+/// Extracts the monorepo subpath from `repository.directory`.
+fn extract_repository_directory(json: &Value) -> Option<String> {
+    json.get(FIELD_REPOSITORY)?
+        .get("directory")?
+        .as_str()
+        .and_then(normalize_non_empty_string)
+}
+
 fn extract_private(json: &Value) -> Option<bool> {
     json.get(FIELD_PRIVATE).and_then(|v| v.as_bool())
 }
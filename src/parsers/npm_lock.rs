@@ -21,7 +21,8 @@
 
 use crate::models::{DatasourceId, Dependency, PackageData, PackageType, ResolvedPackage};
 use crate::parser_warn as warn;
-use crate::parsers::utils::{npm_purl, parse_sri};
+use crate::parsers::utils::parse_sri;
+use crate::utils::purl::npm_purl;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::fs;
@@ -153,6 +154,20 @@ fn parse_lockfile_v2_plus(
         collect_root_dependency_names(root_package.get("optionalDependencies"), &mut root_deps);
     }
 
+    // The root entry only carries an integrity hash for published tarballs,
+    // but when present it describes the package itself. npm lockfiles are the
+    // only supported lockfile format that records such a hash for the root
+    // package: Cargo.lock, yarn.lock, pnpm-lock.yaml, bun.lock, uv.lock and the
+    // others hash only their dependencies, and the `_meta`/`content-hash`
+    // digests in Pipfile.lock and poetry.lock cover the manifest, not a
+    // package artifact. Those parsers therefore leave the root hashes unset.
+    let root_digests = parse_integrity_field(
+        packages
+            .get("")
+            .and_then(|root_package| root_package.get(FIELD_INTEGRITY))
+            .and_then(|v| v.as_str()),
+    );
+
     let mut dependencies = Vec::new();
 
     for (key, value) in packages {
@@ -246,10 +261,10 @@ fn parse_lockfile_v2_plus(
         homepage_url: None,
         download_url: None,
         size: None,
-        sha1: None,
+        sha1: root_digests.sha1,
         md5: None,
-        sha256: None,
-        sha512: None,
+        sha256: root_digests.sha256,
+        sha512: root_digests.sha512,
         bug_tracking_url: None,
         code_view_url: None,
         vcs_url: None,
@@ -529,21 +544,35 @@ fn is_direct_dependency_path(package_path: &str) -> bool {
     }
 }
 
+/// Hex digests decoded from an npm `integrity` field.
+#[derive(Debug, Default)]
+struct IntegrityDigests {
+    sha1: Option<String>,
+    sha256: Option<String>,
+    sha512: Option<String>,
+}
+
 /// Parse integrity field like "sha512-base64string==" or "sha1-base64string="
-/// Returns: (sha1, sha512) as hex strings
-fn parse_integrity_field(integrity: Option<&str>) -> (Option<String>, Option<String>) {
-    let integrity = match integrity {
-        Some(i) => i,
-        None => return (None, None),
+/// into hex digests.
+fn parse_integrity_field(integrity: Option<&str>) -> IntegrityDigests {
+    let Some((algo, hex_digest)) = integrity.and_then(parse_sri) else {
+        return IntegrityDigests::default();
     };
 
-    match parse_sri(integrity) {
-        Some((algo, hex_digest)) => match algo.as_str() {
-            "sha1" => (Some(hex_digest), None),
-            "sha512" => (None, Some(hex_digest)),
-            _ => (None, None),
+    match algo.as_str() {
+        "sha1" => IntegrityDigests {
+            sha1: Some(hex_digest),
+            ..Default::default()
+        },
+        "sha256" => IntegrityDigests {
+            sha256: Some(hex_digest),
+            ..Default::default()
         },
-        None => (None, None),
+        "sha512" => IntegrityDigests {
+            sha512: Some(hex_digest),
+            ..Default::default()
+        },
+        _ => IntegrityDigests::default(),
     }
 }
 
@@ -697,9 +726,9 @@ fn build_npm_dependency(
             )
         };
 
-    let (sha1_from_integrity, sha512_from_integrity) = parse_integrity_field(integrity);
+    let digests = parse_integrity_field(integrity);
     let sha1_from_url = resolved.and_then(parse_resolved_url);
-    let sha1 = sha1_from_integrity.or(sha1_from_url);
+    let sha1 = digests.sha1.or(sha1_from_url);
 
     let mut dep_extra_data = HashMap::new();
     if let Some(from) = from {
@@ -717,8 +746,8 @@ fn build_npm_dependency(
         primary_language: Some("JavaScript".to_string()),
         download_url,
         sha1,
-        sha256: None,
-        sha512: sha512_from_integrity,
+        sha256: digests.sha256,
+        sha512: digests.sha512,
         md5: None,
         is_virtual: true,
         extra_data: None,
@@ -251,6 +251,40 @@ mod tests {
         assert_eq!(sha1, "c3b33ab5ee360d86e0e628f0468ae7ef27d654df");
     }
 
+    #[test]
+    fn test_parse_root_integrity_populates_package_hashes() {
+        let content = r#"{
+            "name": "test",
+            "version": "1.0.0",
+            "lockfileVersion": 3,
+            "packages": {
+                "": {
+                    "name": "test",
+                    "version": "1.0.0",
+                    "integrity": "sha256-47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU="
+                },
+                "node_modules/test-pkg": {
+                    "version": "1.0.0",
+                    "integrity": "sha256-47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU="
+                }
+            }
+        }"#;
+
+        let (_temp, path) = create_temp_lock_file(content);
+        let package_data = NpmLockParser::extract_first_package(&path);
+
+        let empty_sha256 = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+        assert_eq!(package_data.sha256.as_deref(), Some(empty_sha256));
+        assert!(package_data.sha1.is_none());
+        assert!(package_data.sha512.is_none());
+
+        let resolved = package_data.dependencies[0]
+            .resolved_package
+            .as_ref()
+            .unwrap();
+        assert_eq!(resolved.sha256.as_deref(), Some(empty_sha256));
+    }
+
     #[test]
     fn test_parse_integrity_missing() {
         let content = r#"{
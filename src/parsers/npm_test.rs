@@ -317,6 +317,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_dist_tarball_overrides_default_registry_download_url() {
+        let content = r#"
+{
+  "name": "@example/mirrored",
+  "version": "2.3.4",
+  "dist": {
+    "tarball": "https://mirror.example.com/npm/mirrored-2.3.4.tgz"
+  }
+}
+"#;
+
+        let (_temp_dir, package_path) = create_temp_package_json(content);
+        let package_data = NpmParser::extract_first_package(&package_path);
+
+        assert_eq!(
+            package_data.download_url.as_deref(),
+            Some("https://mirror.example.com/npm/mirrored-2.3.4.tgz")
+        );
+        assert_eq!(
+            package_data.repository_download_url.as_deref(),
+            Some("https://registry.npmjs.org/@example/mirrored/-/mirrored-2.3.4.tgz")
+        );
+    }
+
     #[test]
     fn test_extract_dependencies() {
         let content = r#"
@@ -1973,6 +1998,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_extract_repository_directory_into_extra_data() {
+        let content = r#"{
+  "name": "@scope/foo",
+  "version": "2.1.0",
+  "repository": {
+    "type": "git",
+    "url": "git+https://github.com/example/monorepo.git",
+    "directory": "packages/foo"
+  }
+}"#;
+
+        let (_temp_file, path) = create_temp_package_json(content);
+        let package_data = NpmParser::extract_first_package(&path);
+
+        let extra_data = package_data
+            .extra_data
+            .expect("extra_data should be present");
+        assert_eq!(
+            extra_data.get("repositoryDirectory"),
+            Some(&serde_json::Value::String("packages/foo".to_string()))
+        );
+        assert_eq!(
+            package_data.vcs_url,
+            Some("git+https://github.com/example/monorepo.git#packages/foo".to_string())
+        );
+    }
+
+    #[test]
+    fn test_string_repository_has_no_repository_directory() {
+        let content = r#"{
+  "name": "test-package",
+  "version": "1.0.0",
+  "repository": "github:user/test-package"
+}"#;
+
+        let (_temp_file, path) = create_temp_package_json(content);
+        let package_data = NpmParser::extract_first_package(&path);
+
+        assert!(
+            package_data
+                .extra_data
+                .is_none_or(|extra_data| !extra_data.contains_key("repositoryDirectory"))
+        );
+    }
+
     #[test]
     fn test_extract_vcs_url_object_with_git_head() {
         let content = r#"{
@@ -457,12 +457,16 @@ fn parse_packages_config_package(element: &quick_xml::events::BytesStart) -> Opt
     let mut id = None;
     let mut version = None;
     let mut target_framework = None;
+    let mut development_dependency = false;
 
     for attr in element.attributes().filter_map(|a| a.ok()) {
         match attr.key.as_ref() {
             b"id" => id = String::from_utf8(attr.value.to_vec()).ok(),
             b"version" => version = String::from_utf8(attr.value.to_vec()).ok(),
             b"targetFramework" => target_framework = String::from_utf8(attr.value.to_vec()).ok(),
+            b"developmentDependency" => {
+                development_dependency = attr.value.as_ref().eq_ignore_ascii_case(b"true")
+            }
             _ => {}
         }
     }
@@ -474,7 +478,7 @@ fn parse_packages_config_package(element: &quick_xml::events::BytesStart) -> Opt
         purl,
         extracted_requirement: version,
         scope: target_framework,
-        is_runtime: Some(true),
+        is_runtime: Some(!development_dependency),
         is_optional: Some(false),
         is_pinned: Some(true),
         is_direct: Some(true),
@@ -942,6 +946,7 @@ struct ProjectReferenceData {
     version: Option<String>,
     version_override: Option<String>,
     condition: Option<String>,
+    private_assets: Option<String>,
 }
 
 #[derive(Default)]
@@ -1122,12 +1127,18 @@ impl PackageParser for PackageReferenceProjectParser {
                                 .find(|attr| attr.key.as_ref() == b"Condition")
                                 .and_then(|attr| String::from_utf8(attr.value.to_vec()).ok())
                                 .or_else(|| current_item_group_condition.clone());
+                            let private_assets = e
+                                .attributes()
+                                .filter_map(|a| a.ok())
+                                .find(|attr| attr.key.as_ref() == b"PrivateAssets")
+                                .and_then(|attr| String::from_utf8(attr.value.to_vec()).ok());
 
                             current_package_reference = Some(ProjectReferenceData {
                                 name,
                                 version,
                                 version_override,
                                 condition,
+                                private_assets,
                             });
                         }
                         _ => {}
@@ -1158,12 +1169,18 @@ impl PackageParser for PackageReferenceProjectParser {
                             .find(|attr| attr.key.as_ref() == b"Condition")
                             .and_then(|attr| String::from_utf8(attr.value.to_vec()).ok())
                             .or_else(|| current_item_group_condition.clone());
+                        let private_assets = e
+                            .attributes()
+                            .filter_map(|a| a.ok())
+                            .find(|attr| attr.key.as_ref() == b"PrivateAssets")
+                            .and_then(|attr| String::from_utf8(attr.value.to_vec()).ok());
 
                         package_references.push(ProjectReferenceData {
                             name,
                             version,
                             version_override,
                             condition,
+                            private_assets,
                         });
                     }
                 }
@@ -1183,6 +1200,10 @@ impl PackageParser for PackageReferenceProjectParser {
                             && let Some(reference) = &mut current_package_reference
                         {
                             reference.version_override = Some(text);
+                        } else if current_element.as_str() == "PrivateAssets"
+                            && let Some(reference) = &mut current_package_reference
+                        {
+                            reference.private_assets = Some(text);
                         }
                     } else if in_property_group && current_property_group_condition.is_none() {
                         project_properties.insert(current_element.clone(), text.clone());
@@ -1256,6 +1277,7 @@ impl PackageParser for PackageReferenceProjectParser {
                     reference.version,
                     reference.version_override,
                     reference.condition,
+                    reference.private_assets,
                     &project_properties,
                 )
             })
@@ -1556,15 +1578,22 @@ fn build_project_file_dependency(
     version: Option<String>,
     version_override: Option<String>,
     condition: Option<String>,
+    private_assets: Option<String>,
     project_properties: &HashMap<String, String>,
 ) -> Option<Dependency> {
     let name = name?.trim().to_string();
     if name.is_empty() {
         return None;
     }
+    // PrivateAssets="all" keeps the package out of consumers' dependency
+    // graphs, which is how analyzers and build tools are referenced.
+    let is_development_only = private_assets
+        .as_deref()
+        .is_some_and(|assets| assets.trim().eq_ignore_ascii_case("all"));
 
     let mut extra_data = serde_json::Map::new();
     insert_extra_string(&mut extra_data, "condition", condition);
+    insert_extra_string(&mut extra_data, "private_assets", private_assets);
     insert_extra_string(
         &mut extra_data,
         "version_override",
@@ -1582,7 +1611,7 @@ fn build_project_file_dependency(
         purl: build_nuget_purl(Some(&name), None),
         extracted_requirement: version,
         scope: None,
-        is_runtime: Some(true),
+        is_runtime: Some(!is_development_only),
         is_optional: Some(false),
         is_pinned: Some(false),
         is_direct: Some(true),
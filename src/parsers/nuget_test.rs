@@ -97,6 +97,24 @@ mod tests {
         assert_eq!(package_data.dependencies[0].scope, None);
     }
 
+    #[test]
+    fn test_packages_config_development_dependency_is_not_runtime() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<packages>
+  <package id="Newtonsoft.Json" version="13.0.1" />
+  <package id="StyleCop.Analyzers" version="1.1.118" developmentDependency="true" />
+</packages>"#;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(xml.as_bytes()).unwrap();
+
+        let package_data = PackagesConfigParser::extract_first_package(temp_file.path());
+
+        assert_eq!(package_data.dependencies.len(), 2);
+        assert_eq!(package_data.dependencies[0].is_runtime, Some(true));
+        assert_eq!(package_data.dependencies[1].is_runtime, Some(false));
+    }
+
     #[test]
     fn test_nuspec_basic() {
         let xml = r#"<?xml version="1.0" encoding="utf-8"?>
@@ -1285,6 +1303,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_csproj_private_assets_all_is_not_runtime() {
+        let xml = r#"<Project Sdk="Microsoft.NET.Sdk">
+  <ItemGroup>
+    <PackageReference Include="Serilog" Version="2.12.0" />
+    <PackageReference Include="Microsoft.SourceLink.GitHub" Version="1.1.1" PrivateAssets="All" />
+    <PackageReference Include="coverlet.collector" Version="6.0.0">
+      <PrivateAssets>all</PrivateAssets>
+      <IncludeAssets>runtime; build; native; contentfiles; analyzers</IncludeAssets>
+    </PackageReference>
+  </ItemGroup>
+</Project>"#;
+
+        let mut temp_file = Builder::new().suffix(".csproj").tempfile().unwrap();
+        temp_file.write_all(xml.as_bytes()).unwrap();
+
+        let package_data = PackageReferenceProjectParser::extract_first_package(temp_file.path());
+        let runtime: Vec<_> = package_data
+            .dependencies
+            .iter()
+            .map(|dependency| dependency.is_runtime)
+            .collect();
+
+        assert_eq!(runtime, vec![Some(true), Some(false), Some(false)]);
+        assert_eq!(
+            package_data.dependencies[2]
+                .extra_data
+                .as_ref()
+                .and_then(|extra| extra.get("private_assets"))
+                .and_then(|value| value.as_str()),
+            Some("all")
+        );
+    }
+
     #[test]
     fn test_csproj_package_reference_preserves_literal_version_override_metadata() {
         let xml = r#"<Project Sdk="Microsoft.NET.Sdk">
@@ -1414,6 +1466,32 @@ mod tests {
         assert!(package_data.dependencies.is_empty());
     }
 
+    #[test]
+    fn test_csproj_package_license_file_is_recorded_without_declared_expression() {
+        let xml = r#"<Project Sdk="Microsoft.NET.Sdk">
+  <PropertyGroup>
+    <PackageId>Contoso.Licensed</PackageId>
+    <Version>2.0.0</Version>
+    <PackageLicenseFile>LICENSE.txt</PackageLicenseFile>
+  </PropertyGroup>
+</Project>"#;
+
+        let mut temp_file = Builder::new().suffix(".csproj").tempfile().unwrap();
+        temp_file.write_all(xml.as_bytes()).unwrap();
+
+        let package_data = PackageReferenceProjectParser::extract_first_package(temp_file.path());
+        let extra = package_data.extra_data.unwrap();
+
+        assert_eq!(
+            package_data.extracted_license_statement.as_deref(),
+            Some("LICENSE.txt")
+        );
+        assert!(package_data.declared_license_expression.is_none());
+        assert!(package_data.license_detections.is_empty());
+        assert_eq!(extra["license_type"], "file");
+        assert_eq!(extra["license_file"], "LICENSE.txt");
+    }
+
     #[test]
     fn test_csproj_package_reference_extracts_metadata_and_dependencies() {
         let xml = r#"<Project Sdk="Microsoft.NET.Sdk">
@@ -9,7 +9,7 @@
 //! # Key Features
 //! - Dependency extraction from default and develop sections
 //! - Direct dependency tracking (top-level locks are direct)
-//! - Exact version resolution with hash verification
+//! - Exact version resolution, with package hashes kept in dependency `extra_data`
 //! - Package URL (purl) generation for PyPI packages
 //! - Markers and extras dependency handling
 //!
@@ -154,7 +154,13 @@ fn build_lockfile_dependency(
     let version = strip_pipfile_lock_version(&requirement);
     let purl = create_pypi_purl(&normalized_name, version.as_deref());
 
-    let _hashes = extract_lockfile_hashes(value);
+    let hashes = extract_lockfile_hashes(value);
+    let extra_data = (!hashes.is_empty()).then(|| {
+        HashMap::from([(
+            FIELD_HASHES.to_string(),
+            JsonValue::Array(hashes.into_iter().map(JsonValue::String).collect()),
+        )])
+    });
 
     Some(Dependency {
         purl,
@@ -165,7 +171,7 @@ fn build_lockfile_dependency(
         is_pinned: Some(true),
         is_direct: Some(true),
         resolved_package: None,
-        extra_data: None,
+        extra_data,
     })
 }
 
@@ -180,23 +186,20 @@ fn extract_lockfile_requirement(value: &JsonValue) -> Option<String> {
     }
 }
 
+/// Hashes recorded for a locked package, kept with their `algorithm:` prefix.
 fn extract_lockfile_hashes(value: &JsonValue) -> Vec<String> {
-    let mut hashes = Vec::new();
-    let hash_values = value
+    value
         .get(FIELD_HASHES)
-        .and_then(|hashes_value| hashes_value.as_array());
-
-    if let Some(hash_values) = hash_values {
-        for hash_value in hash_values {
-            if let Some(hash) = hash_value.as_str()
-                && let Some(stripped) = hash.strip_prefix("sha256:")
-            {
-                hashes.push(stripped.to_string());
-            }
-        }
-    }
-
-    hashes
+        .and_then(|hashes_value| hashes_value.as_array())
+        .map(|hash_values| {
+            hash_values
+                .iter()
+                .filter_map(|hash_value| hash_value.as_str())
+                .filter(|hash| hash.contains(':'))
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
 }
 
 fn strip_pipfile_lock_version(requirement: &str) -> Option<String> {
@@ -57,4 +57,77 @@ mod tests {
             assert_eq!(dep.is_runtime, Some(false));
         }
     }
+
+    #[test]
+    fn test_pipfile_lock_keeps_hashes_in_dependency_extra_data() {
+        use std::fs;
+        use tempfile::tempdir;
+
+        let content = r#"{
+    "_meta": {"hash": {"sha256": "test-hash"}, "pipfile-spec": 6},
+    "default": {
+        "requests": {
+            "hashes": ["sha256:abc123", "sha256:def456"],
+            "version": "==2.28.0"
+        }
+    },
+    "develop": {}
+}"#;
+
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("Pipfile.lock");
+        fs::write(&file_path, content).unwrap();
+
+        let package_data = PipfileLockParser::extract_first_package(&file_path);
+        let dependency = &package_data.dependencies[0];
+
+        assert_eq!(dependency.is_pinned, Some(true));
+        assert_eq!(dependency.purl.as_deref(), Some("pkg:pypi/requests@2.28.0"));
+        assert_eq!(
+            dependency
+                .extra_data
+                .as_ref()
+                .and_then(|extra| extra.get("hashes")),
+            Some(&serde_json::json!(["sha256:abc123", "sha256:def456"]))
+        );
+    }
+
+    #[test]
+    fn test_pipfile_reads_sections_and_source_index() {
+        use std::fs;
+        use tempfile::tempdir;
+
+        let content = r#"
+[[source]]
+url = "https://pypi.org/simple"
+verify_ssl = true
+name = "pypi"
+
+[packages]
+requests = "*"
+
+[dev-packages]
+pytest = ">=7.0"
+"#;
+
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("Pipfile");
+        fs::write(&file_path, content).unwrap();
+
+        let package_data = PipfileLockParser::extract_first_package(&file_path);
+        let runtime: Vec<_> = package_data
+            .dependencies
+            .iter()
+            .map(|dep| (dep.purl.as_deref(), dep.is_runtime))
+            .collect();
+
+        assert!(runtime.contains(&(Some("pkg:pypi/requests"), Some(true))));
+        assert!(runtime.contains(&(Some("pkg:pypi/pytest"), Some(false))));
+        let sources = package_data
+            .extra_data
+            .as_ref()
+            .and_then(|extra| extra.get("sources"))
+            .expect("sources should be captured");
+        assert_eq!(sources[0]["url"], "https://pypi.org/simple");
+    }
 }
@@ -21,7 +21,7 @@
 //! - Direct dependencies tracked via `importers['.'].dependencies`
 
 use crate::models::{DatasourceId, Dependency, PackageData, PackageType, ResolvedPackage};
-use crate::parsers::utils::npm_purl;
+use crate::utils::purl::npm_purl;
 use serde_yaml::Value;
 use std::fs;
 use std::path::Path;
@@ -155,7 +155,16 @@ fn create_dependency(
         return None;
     }
 
-    let purl = PackageUrl::new("cocoapods", name).ok()?;
+    // A subspec (`Root/Subspec`) is recorded with its root pod as namespace,
+    // matching the purls of Podfile.lock entries.
+    let purl = match name.split_once('/') {
+        Some((root_pod, subspec)) => {
+            let mut purl = PackageUrl::new("cocoapods", subspec).ok()?;
+            purl.with_namespace(root_pod).ok()?;
+            purl
+        }
+        None => PackageUrl::new("cocoapods", name).ok()?,
+    };
 
     let is_pinned = version_req
         .as_ref()
@@ -263,6 +272,20 @@ pod 'RestKit', '~> 0.20', :git => 'https://github.com/RestKit/RestKit.git'
         assert_eq!(deps[0].extracted_requirement, Some("~> 0.20".to_string()));
     }
 
+    #[test]
+    fn test_extract_subspec_uses_root_pod_as_namespace() {
+        let content = r#"
+pod 'Firebase/Analytics', '10.0.0'
+"#;
+        let deps = extract_dependencies(content);
+        assert_eq!(deps.len(), 1);
+        assert_eq!(
+            deps[0].purl,
+            Some("pkg:cocoapods/Firebase/Analytics".to_string())
+        );
+        assert_eq!(deps[0].is_pinned, Some(true));
+    }
+
     #[test]
     fn test_ignores_comments() {
         let content = r#"
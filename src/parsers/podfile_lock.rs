@@ -11,6 +11,8 @@
 //! - Exact version resolution from lockfile
 //! - Pod source and repository information
 //! - Spec repository tracking
+//! - SPEC CHECKSUMS kept in dependency `extra_data["spec_checksum"]`, with
+//!   subspecs (`Root/Subspec`) using their root pod's checksum
 //! - YAML multi-section aggregation (PODS, DEPENDENCIES, SPEC REPOS, PODFILE LOCK)
 //!
 //! # Implementation Notes
@@ -297,6 +299,29 @@ fn build_pod_dependency(
 
     let purl = create_cocoapods_purl(namespace.as_deref(), &name, version.as_deref());
 
+    // SPEC CHECKSUMS only lists root pods, so a subspec (`Root/Subspec`, with
+    // the root pod as purl namespace) carries the checksum of its root pod.
+    let root_pod = namespace
+        .as_deref()
+        .filter(|ns| !ns.is_empty())
+        .unwrap_or(&name);
+    let mut extra_data = HashMap::new();
+    if let Some(spec_checksum) = dep_data
+        .checksum_by_base_purl
+        .get(&make_base_purl(root_pod))
+    {
+        extra_data.insert(
+            "spec_checksum".to_string(),
+            serde_json::Value::String(spec_checksum.clone()),
+        );
+    }
+    if namespace.is_some() {
+        extra_data.insert(
+            "root_pod".to_string(),
+            serde_json::Value::String(root_pod.to_string()),
+        );
+    }
+
     Dependency {
         purl,
         extracted_requirement: requirement,
@@ -306,7 +331,7 @@ fn build_pod_dependency(
         is_pinned: Some(true),
         is_direct: Some(is_direct),
         resolved_package: Some(Box::new(resolved_package)),
-        extra_data: None,
+        extra_data: (!extra_data.is_empty()).then_some(extra_data),
     }
 }
 
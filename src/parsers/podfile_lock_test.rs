@@ -316,4 +316,30 @@ mod tests {
         assert_eq!(nested.is_runtime, None);
         assert_eq!(nested.is_optional, None);
     }
+
+    #[test]
+    fn test_spec_checksum_is_kept_in_extra_data_for_pods_and_subspecs() {
+        let path = PathBuf::from("testdata/cocoapods/podfile_lock/braintree_ios_Podfile.lock");
+        let pkg = PodfileLockParser::extract_first_package(&path);
+
+        let ohhttpstubs = &pkg.dependencies[3];
+        let extra_data = ohhttpstubs.extra_data.as_ref().unwrap();
+        assert_eq!(
+            extra_data["spec_checksum"],
+            "cb29d2a9d09a828ecb93349a2b0c64f99e0db89f"
+        );
+        assert!(!extra_data.contains_key("root_pod"));
+
+        let ohhttpstubs_core = &pkg.dependencies[4];
+        assert_eq!(
+            ohhttpstubs_core.purl.as_deref(),
+            Some("pkg:cocoapods/OHHTTPStubs/Core@9.0.0")
+        );
+        let extra_data = ohhttpstubs_core.extra_data.as_ref().unwrap();
+        assert_eq!(
+            extra_data["spec_checksum"],
+            "cb29d2a9d09a828ecb93349a2b0c64f99e0db89f"
+        );
+        assert_eq!(extra_data["root_pod"], "OHHTTPStubs");
+    }
 }
@@ -8,7 +8,10 @@
 //!
 //! # Key Features
 //! - Direct vs transitive dependency tracking via `is_direct` flag
-//! - Dependency groups support (main, dev, etc.) via scope field
+//! - Dependency groups support (main, dev, etc.) via scope field, from the
+//!   `category` of each locked package in older lockfiles
+//! - Locked file hashes, from each package's `files` or the legacy
+//!   `[metadata.files]` table, kept in dependency `extra_data`
 //! - Dependency resolution with exact versions
 //! - Package URL (purl) generation for PyPI packages
 //! - Extra dependencies and optional package handling
@@ -40,6 +43,8 @@ const FIELD_PYTHON_VERSIONS: &str = "python-versions";
 const FIELD_DEPENDENCIES: &str = "dependencies";
 const FIELD_EXTRAS: &str = "extras";
 const FIELD_LOCK_VERSION: &str = "lock-version";
+const FIELD_CATEGORY: &str = "category";
+const FIELD_FILES: &str = "files";
 
 /// Poetry lockfile parser for poetry.lock files.
 ///
@@ -80,10 +85,14 @@ fn parse_poetry_lock(toml_content: &TomlValue) -> PackageData {
         .get(FIELD_METADATA)
         .and_then(|value| value.as_table());
 
+    let metadata_files = metadata
+        .and_then(|metadata| metadata.get(FIELD_FILES))
+        .and_then(|value| value.as_table());
+
     let mut dependencies = Vec::new();
     for package in packages {
         if let Some(package_table) = package.as_table()
-            && let Some(dependency) = build_dependency_from_package(package_table)
+            && let Some(dependency) = build_dependency_from_package(package_table, metadata_files)
         {
             dependencies.push(dependency);
         }
@@ -176,7 +185,10 @@ fn build_metadata_extra_data(
     }
 }
 
-fn build_dependency_from_package(package_table: &TomlMap<String, TomlValue>) -> Option<Dependency> {
+fn build_dependency_from_package(
+    package_table: &TomlMap<String, TomlValue>,
+    metadata_files: Option<&TomlMap<String, TomlValue>>,
+) -> Option<Dependency> {
     let name = package_table
         .get(FIELD_NAME)
         .and_then(|value| value.as_str())
@@ -196,19 +208,66 @@ fn build_dependency_from_package(package_table: &TomlMap<String, TomlValue>) ->
         .and_then(|value| value.as_bool())
         .unwrap_or(false);
 
+    // Lockfiles before Poetry 1.5 record whether a package is only needed for
+    // development as `category = "dev"`.
+    let category = package_table
+        .get(FIELD_CATEGORY)
+        .and_then(|value| value.as_str())
+        .map(|value| value.to_string());
+    let is_dev = category
+        .as_deref()
+        .is_some_and(|category| category != "main");
+
+    let mut extra_data = HashMap::new();
+    if let Some(python_versions) = package_table
+        .get(FIELD_PYTHON_VERSIONS)
+        .and_then(|value| value.as_str())
+        .filter(|value| !value.is_empty())
+    {
+        extra_data.insert(
+            "python_versions".to_string(),
+            serde_json::Value::String(python_versions.to_string()),
+        );
+    }
+    let files = package_table.get(FIELD_FILES).or_else(|| {
+        package_table
+            .get(FIELD_NAME)
+            .and_then(|value| value.as_str())
+            .and_then(|raw_name| metadata_files?.get(raw_name))
+    });
+    let hashes = extract_file_hashes(files);
+    if !hashes.is_empty() {
+        extra_data.insert(
+            "hashes".to_string(),
+            serde_json::Value::Array(hashes.into_iter().map(serde_json::Value::String).collect()),
+        );
+    }
+
     Some(Dependency {
         purl,
         extracted_requirement: None,
-        scope: None,
-        is_runtime: Some(!is_optional),
+        scope: category,
+        is_runtime: Some(!is_optional && !is_dev),
         is_optional: Some(is_optional),
         is_pinned: Some(true),
         is_direct: Some(false),
         resolved_package: Some(Box::new(resolved_package)),
-        extra_data: None,
+        extra_data: (!extra_data.is_empty()).then_some(extra_data),
     })
 }
 
+/// Hashes of the distribution files listed as `{file = ..., hash = ...}` tables.
+fn extract_file_hashes(files: Option<&TomlValue>) -> Vec<String> {
+    files
+        .and_then(|files| files.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|file| file.get("hash").and_then(|hash| hash.as_str()))
+        .filter(|hash| !hash.is_empty())
+        .map(|hash| hash.to_string())
+        .collect()
+}
+
 fn build_resolved_package(
     package_table: &TomlMap<String, TomlValue>,
     name: &str,
@@ -385,7 +444,7 @@ fn encode_pypi_name(name: &str) -> String {
 
 fn extract_sha256_from_files(package_table: &TomlMap<String, TomlValue>) -> Option<String> {
     package_table
-        .get("files")
+        .get(FIELD_FILES)
         .and_then(|files| files.as_array())
         .and_then(|files_array| files_array.first())
         .and_then(|first_file| first_file.as_table())
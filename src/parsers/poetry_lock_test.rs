@@ -69,4 +69,83 @@ content-hash = "test"
             "Optional package should have is_runtime=false"
         );
     }
+
+    #[test]
+    fn test_poetry_lock_legacy_category_and_metadata_files_hashes() {
+        use std::fs;
+        use tempfile::TempDir;
+
+        let content = r#"[[package]]
+name = "requests"
+version = "2.28.1"
+description = "Python HTTP for Humans."
+category = "main"
+optional = false
+python-versions = ">=3.7, <4"
+
+[package.dependencies]
+idna = ">=2.5,<4"
+
+[[package]]
+name = "pytest"
+version = "7.1.2"
+description = "pytest: simple powerful testing with Python"
+category = "dev"
+optional = false
+python-versions = ">=3.7"
+
+[metadata]
+lock-version = "1.1"
+python-versions = "^3.8"
+content-hash = "test"
+
+[metadata.files]
+requests = [
+    {file = "requests-2.28.1-py3-none-any.whl", hash = "sha256:aaa"},
+    {file = "requests-2.28.1.tar.gz", hash = "sha256:bbb"},
+]
+pytest = []
+"#;
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let lock_path = temp_dir.path().join("poetry.lock");
+        fs::write(&lock_path, content).expect("Failed to write poetry.lock");
+
+        let package_data = PoetryLockParser::extract_first_package(&lock_path);
+
+        assert_eq!(package_data.dependencies.len(), 2);
+
+        let requests = &package_data.dependencies[0];
+        assert_eq!(requests.purl.as_deref(), Some("pkg:pypi/requests@2.28.1"));
+        assert_eq!(requests.scope.as_deref(), Some("main"));
+        assert_eq!(requests.is_runtime, Some(true));
+        assert_eq!(requests.is_pinned, Some(true));
+        let extra_data = requests.extra_data.as_ref().expect("extra_data");
+        assert_eq!(extra_data["python_versions"], ">=3.7, <4");
+        assert_eq!(
+            extra_data["hashes"],
+            serde_json::json!(["sha256:aaa", "sha256:bbb"])
+        );
+        let resolved = requests
+            .resolved_package
+            .as_ref()
+            .expect("resolved package");
+        assert_eq!(resolved.dependencies.len(), 1);
+        assert_eq!(
+            resolved.dependencies[0].purl.as_deref(),
+            Some("pkg:pypi/idna")
+        );
+
+        let pytest = &package_data.dependencies[1];
+        assert_eq!(pytest.purl.as_deref(), Some("pkg:pypi/pytest@7.1.2"));
+        assert_eq!(pytest.scope.as_deref(), Some("dev"));
+        assert_eq!(pytest.is_runtime, Some(false));
+        assert_eq!(pytest.is_optional, Some(false));
+        assert!(
+            !pytest
+                .extra_data
+                .as_ref()
+                .is_some_and(|extra_data| extra_data.contains_key("hashes"))
+        );
+    }
 }
@@ -2035,12 +2035,19 @@ fn extract_from_pyproject_toml(path: &Path) -> PackageData {
         })
         .unwrap_or_default();
 
+    let description = project_table
+        .get("description")
+        .and_then(|value| value.as_str())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(String::from);
+
     let extracted_license_statement = extract_raw_license_string(&project_table);
     let (declared_license_expression, declared_license_expression_spdx, license_detections) =
         normalize_spdx_declared_license(extract_license_expression_candidate(&project_table));
 
     // URLs can be in different formats depending on the tool (poetry, flit, etc.)
-    let (homepage_url, repository_url) = extract_urls(&project_table);
+    let (homepage_url, repository_url, explicit_download_url) = extract_urls(&project_table);
 
     let (dependencies, optional_dependencies) = extract_dependencies(&project_table, &toml_content);
     let extra_data = extract_pyproject_extra_data(&toml_content);
@@ -2103,12 +2110,14 @@ fn extract_from_pyproject_toml(path: &Path) -> PackageData {
         qualifiers: None,
         subpath: None,
         primary_language: None,
-        description: None,
+        description,
         release_date: None,
         parties: extract_parties(&project_table),
         keywords: Vec::new(),
         homepage_url: homepage_url.or(pypi_homepage_url),
-        download_url: repository_url.clone().or(pypi_download_url),
+        download_url: explicit_download_url
+            .or_else(|| repository_url.clone())
+            .or(pypi_download_url),
         size: None,
         sha1: None,
         md5: None,
@@ -2170,9 +2179,12 @@ fn extract_license_expression_candidate(project: &TomlMap<String, TomlValue>) ->
     }
 }
 
-fn extract_urls(project: &TomlMap<String, TomlValue>) -> (Option<String>, Option<String>) {
+fn extract_urls(
+    project: &TomlMap<String, TomlValue>,
+) -> (Option<String>, Option<String>, Option<String>) {
     let mut homepage_url = None;
     let mut repository_url = None;
+    let mut download_url = None;
 
     // Check for URLs table
     if let Some(urls) = project.get(FIELD_URLS).and_then(|v| v.as_table()) {
@@ -2184,6 +2196,12 @@ fn extract_urls(project: &TomlMap<String, TomlValue>) -> (Option<String>, Option
             .get(FIELD_REPOSITORY)
             .and_then(|v| v.as_str())
             .map(String::from);
+        // Project URL labels are free-form; compare them normalized as in PEP 753.
+        download_url = urls
+            .iter()
+            .find(|(label, _)| normalize_url_label(label) == "download")
+            .and_then(|(_, v)| v.as_str())
+            .map(String::from);
     }
 
     // If not found in URLs table, check for top-level keys
@@ -2201,7 +2219,15 @@ fn extract_urls(project: &TomlMap<String, TomlValue>) -> (Option<String>, Option
             .map(String::from);
     }
 
-    (homepage_url, repository_url)
+    (homepage_url, repository_url, download_url)
+}
+
+fn normalize_url_label(label: &str) -> String {
+    label
+        .chars()
+        .filter(|c| !c.is_ascii_punctuation() && !c.is_whitespace())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
 }
 
 fn extract_parties(project: &TomlMap<String, TomlValue>) -> Vec<Party> {
@@ -2395,6 +2421,40 @@ fn extract_pyproject_extra_data(toml_content: &TomlValue) -> Option<HashMap<Stri
         extra_data.insert("tool_uv".to_string(), toml_value_to_json(tool_uv));
     }
 
+    if let Some(build_backend) = toml_content
+        .get("build-system")
+        .and_then(|value| value.as_table())
+        .and_then(|build_system| build_system.get("build-backend"))
+        .and_then(|value| value.as_str())
+    {
+        extra_data.insert(
+            "build_backend".to_string(),
+            JsonValue::String(build_backend.to_string()),
+        );
+    }
+
+    // PEP 639 uses a list of globs; the earlier PEP 621 draft a `paths` or
+    // `globs` table.
+    let license_files = toml_content
+        .get(FIELD_PROJECT)
+        .and_then(|project| project.get("license-files"))
+        .and_then(|value| match value {
+            TomlValue::Table(table) => table.get("paths").or_else(|| table.get("globs")),
+            other => Some(other),
+        })
+        .and_then(|value| value.as_array())
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(|value| value.as_str())
+                .map(|value| JsonValue::String(value.to_string()))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if !license_files.is_empty() {
+        extra_data.insert("license_files".to_string(), JsonValue::Array(license_files));
+    }
+
     if extra_data.is_empty() {
         None
     } else {
@@ -3136,6 +3196,22 @@ fn build_setup_py_package_data(values: &HashMap<String, Value>) -> PackageData {
     let extracted_license_statement = license.clone();
 
     let dependencies = build_setup_py_dependencies(values);
+    let file_references = values
+        .get("license_files")
+        .and_then(value_to_string_list)
+        .map(|paths| parse_setup_cfg_license_files(&paths))
+        .unwrap_or_default()
+        .into_iter()
+        .map(|path| FileReference {
+            path,
+            size: None,
+            sha1: None,
+            md5: None,
+            sha256: None,
+            sha512: None,
+            extra_data: None,
+        })
+        .collect();
     let purl = build_setup_py_purl(name.as_deref(), version.as_deref());
     let mut homepage_from_project_urls = None;
     let (mut bug_tracking_url, mut code_view_url, mut vcs_url) = (None, None, None);
@@ -3191,7 +3267,7 @@ fn build_setup_py_package_data(values: &HashMap<String, Value>) -> PackageData {
         extracted_license_statement,
         notice_text: None,
         source_packages: Vec::new(),
-        file_references: Vec::new(),
+        file_references,
         is_private: has_private_classifier(&classifiers),
         is_virtual: false,
         extra_data,
@@ -4139,6 +4215,8 @@ fn extract_from_setup_cfg(path: &Path) -> PackageData {
     let maintainer = get_ini_value(&sections, "metadata", "maintainer");
     let maintainer_email = get_ini_value(&sections, "metadata", "maintainer_email");
     let license = get_ini_value(&sections, "metadata", "license");
+    let license_files =
+        parse_setup_cfg_license_files(&get_ini_values(&sections, "metadata", "license_files"));
     let mut homepage_url = get_ini_value(&sections, "metadata", "url");
     let classifiers = get_ini_values(&sections, "metadata", "classifiers");
     let keywords = parse_setup_cfg_keywords(get_ini_value(&sections, "metadata", "keywords"));
@@ -4182,6 +4260,32 @@ fn extract_from_setup_cfg(path: &Path) -> PackageData {
 
     let dependencies = extract_setup_cfg_dependencies(&sections);
 
+    if !license_files.is_empty() {
+        extra_data.insert(
+            "license_files".to_string(),
+            serde_json::Value::Array(
+                license_files
+                    .iter()
+                    .cloned()
+                    .map(serde_json::Value::String)
+                    .collect(),
+            ),
+        );
+    }
+
+    let file_references = license_files
+        .into_iter()
+        .map(|path| FileReference {
+            path,
+            size: None,
+            sha1: None,
+            md5: None,
+            sha256: None,
+            sha512: None,
+            extra_data: None,
+        })
+        .collect();
+
     if let Some(value) = python_requires {
         extra_data.insert(
             "python_requires".to_string(),
@@ -4245,7 +4349,7 @@ fn extract_from_setup_cfg(path: &Path) -> PackageData {
         extracted_license_statement,
         notice_text: None,
         source_packages: Vec::new(),
-        file_references: Vec::new(),
+        file_references,
         is_private: has_private_classifier(&classifiers),
         is_virtual: false,
         extra_data,
@@ -4258,6 +4362,17 @@ fn extract_from_setup_cfg(path: &Path) -> PackageData {
     }
 }
 
+/// Split `license_files` entries (comma- or line-separated), skipping glob patterns.
+fn parse_setup_cfg_license_files(entries: &[String]) -> Vec<String> {
+    entries
+        .iter()
+        .flat_map(|entry| entry.split(','))
+        .map(str::trim)
+        .filter(|path| !path.is_empty() && !path.contains(['*', '?', '[']))
+        .map(str::to_string)
+        .collect()
+}
+
 fn parse_setup_cfg_keywords(value: Option<String>) -> Vec<String> {
     let Some(keywords) = value else {
         return Vec::new();
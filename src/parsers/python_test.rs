@@ -2069,6 +2069,37 @@ docs = ["sphinx>=5.0"]
         );
     }
 
+    #[test]
+    fn test_pyproject_pep621_description_build_backend_and_license_files() {
+        let content = r#"
+[build-system]
+requires = ["hatchling"]
+build-backend = "hatchling.build"
+
+[project]
+name = "demo"
+version = "2.1.0"
+description = "  A demo package  "
+license = "Apache-2.0"
+license-files = ["LICENSE*", "NOTICE"]
+"#;
+
+        let (_temp_dir, file_path) = create_temp_file(content, "pyproject.toml");
+        let package_data = PythonParser::extract_first_package(&file_path);
+
+        assert_eq!(package_data.description.as_deref(), Some("A demo package"));
+        assert_eq!(package_data.purl.as_deref(), Some("pkg:pypi/demo@2.1.0"));
+        let extra_data = package_data.extra_data.expect("extra_data should be set");
+        assert_eq!(
+            extra_data.get("build_backend"),
+            Some(&serde_json::json!("hatchling.build"))
+        );
+        assert_eq!(
+            extra_data.get("license_files"),
+            Some(&serde_json::json!(["LICENSE*", "NOTICE"]))
+        );
+    }
+
     #[test]
     fn test_pyproject_extracts_uv_dependency_groups_and_tool_config() {
         let content = r#"
@@ -2209,6 +2240,75 @@ test = coverage>=6.0
         assert!(regular_dep.iter().all(|d| d.is_optional == Some(false)));
     }
 
+    #[test]
+    fn test_setup_cfg_license_files_emit_file_references() {
+        let content = r#"
+[metadata]
+name = demo
+version = 2.1.0
+author = Jane Doe
+license = MIT
+license_files =
+    LICENSE
+    NOTICE, AUTHORS.rst
+    LICENSES/*.txt
+
+[options]
+install_requires =
+    requests>=2.0
+"#;
+
+        let (_temp_dir, file_path) = create_temp_file(content, "setup.cfg");
+        let package_data = PythonParser::extract_first_package(&file_path);
+
+        assert_eq!(package_data.datasource_id, Some(DatasourceId::PypiSetupCfg));
+        assert_eq!(package_data.name.as_deref(), Some("demo"));
+        assert_eq!(package_data.version.as_deref(), Some("2.1.0"));
+        assert_eq!(
+            package_data.extracted_license_statement.as_deref(),
+            Some("MIT")
+        );
+        assert_eq!(package_data.parties[0].name.as_deref(), Some("Jane Doe"));
+        assert_eq!(package_data.dependencies.len(), 1);
+
+        let references: Vec<&str> = package_data
+            .file_references
+            .iter()
+            .map(|reference| reference.path.as_str())
+            .collect();
+        assert_eq!(references, vec!["LICENSE", "NOTICE", "AUTHORS.rst"]);
+    }
+
+    #[test]
+    fn test_setup_py_literal_kwargs_match_setup_cfg_fields() {
+        let content = r#"
+from setuptools import setup
+
+setup(
+    name="demo",
+    version="2.1.0",
+    author="Jane Doe",
+    license="MIT",
+    install_requires=["requests>=2.0"],
+    license_files=["LICENSE"],
+)
+"#;
+
+        let (_temp_dir, file_path) = create_temp_file(content, "setup.py");
+        let package_data = PythonParser::extract_first_package(&file_path);
+
+        assert_eq!(package_data.datasource_id, Some(DatasourceId::PypiSetupPy));
+        assert_eq!(package_data.file_references[0].path, "LICENSE");
+        assert_eq!(package_data.name.as_deref(), Some("demo"));
+        assert_eq!(package_data.version.as_deref(), Some("2.1.0"));
+        assert_eq!(
+            package_data.extracted_license_statement.as_deref(),
+            Some("MIT")
+        );
+        assert_eq!(package_data.parties[0].name.as_deref(), Some("Jane Doe"));
+        assert_eq!(package_data.dependencies.len(), 1);
+    }
+
     #[test]
     fn test_setup_cfg_extracts_richer_metadata() {
         let path = PathBuf::from("testdata/python/golden/setup_cfg_wheel/setup.cfg");
@@ -2378,6 +2478,27 @@ classifiers = ["Private :: Do Not Upload"]
         assert!(package_data.is_private);
     }
 
+    #[test]
+    fn test_pyproject_explicit_download_url_wins_over_default() {
+        let content = r#"
+[project]
+name = "archive-package"
+version = "1.2.0"
+
+[project.urls]
+Repository = "https://github.com/example/archive-package"
+Download = "https://downloads.example.com/archive-package-1.2.0.tar.gz"
+"#;
+
+        let (_temp_dir, file_path) = create_temp_file(content, "pyproject.toml");
+        let package_data = PythonParser::extract_first_package(&file_path);
+
+        assert_eq!(
+            package_data.download_url.as_deref(),
+            Some("https://downloads.example.com/archive-package-1.2.0.tar.gz")
+        );
+    }
+
     #[test]
     fn test_extract_from_pypi_json() {
         let content = r#"
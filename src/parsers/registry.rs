@@ -0,0 +1,74 @@
+//! Runtime registration of package parsers supplied by library consumers.
+//!
+//! Built-in parsers are wired in at compile time by `register_package_handlers!`.
+//! Downstream crates that need to recognize a proprietary manifest format
+//! implement [`DynPackageParser`] and pass it to [`register_parser`] together
+//! with the [`AssemblerConfig`] that merges its package data into packages.
+
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+use crate::assembly::{self, AssemblerConfig};
+use crate::models::{PackageData, PackageType};
+
+use super::{ParsePackagesResult, capture_parser_diagnostics};
+
+/// Object-safe counterpart of [`PackageParser`](super::PackageParser) for
+/// parsers registered at runtime.
+pub trait DynPackageParser: Send + Sync {
+    /// Package URL type of the packages this parser extracts, used when a
+    /// returned [`PackageData`] leaves `package_type` unset.
+    fn package_type(&self) -> PackageType;
+
+    /// Checks if the given file path matches this parser's expected format.
+    fn is_match(&self, path: &Path) -> bool;
+
+    /// Extracts all packages from the given file path.
+    fn extract_packages(&self, path: &Path) -> Vec<PackageData>;
+}
+
+static REGISTERED_PARSERS: RwLock<Vec<Arc<dyn DynPackageParser>>> = RwLock::new(Vec::new());
+
+/// Register `parser` and the assembler for the datasources it emits.
+///
+/// Registered parsers are tried before the built-in ones, in registration
+/// order. The assembler may only claim datasources that no built-in assembler
+/// handles, such as [`DatasourceId::Custom`](crate::models::DatasourceId::Custom);
+/// several parsers of one ecosystem can share it by registering the same
+/// datasource list.
+pub fn register_parser(
+    parser: Box<dyn DynPackageParser>,
+    assembler: AssemblerConfig,
+) -> Result<(), String> {
+    assembly::register_assembler(assembler)?;
+    REGISTERED_PARSERS
+        .write()
+        .map_err(|_| "Parser registry is poisoned".to_string())?
+        .push(Arc::from(parser));
+    Ok(())
+}
+
+/// Whether a registered parser matches `path`.
+pub(crate) fn is_registered_match(path: &Path) -> bool {
+    REGISTERED_PARSERS
+        .read()
+        .is_ok_and(|parsers| parsers.iter().any(|parser| parser.is_match(path)))
+}
+
+/// Parse `path` with the first registered parser that matches it.
+pub(crate) fn try_parse_registered(path: &Path) -> Option<ParsePackagesResult> {
+    let parser = REGISTERED_PARSERS
+        .read()
+        .ok()?
+        .iter()
+        .find(|parser| parser.is_match(path))
+        .cloned()?;
+
+    Some(capture_parser_diagnostics(|| {
+        let mut packages = parser.extract_packages(path);
+        for package in &mut packages {
+            package.package_type.get_or_insert(parser.package_type());
+        }
+        packages
+    }))
+}
@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use super::super::scan_test_utils::scan_and_assemble;
+    use crate::assembly::{AssemblerConfig, AssemblyMode};
+    use crate::models::{DatasourceId, PackageData, PackageType};
+    use crate::parsers::{DynPackageParser, register_parser};
+
+    /// Reads `name=` and `version=` lines from an `acme.pkg` manifest.
+    struct AcmeManifestParser;
+
+    impl DynPackageParser for AcmeManifestParser {
+        fn package_type(&self) -> PackageType {
+            PackageType::Generic
+        }
+
+        fn is_match(&self, path: &Path) -> bool {
+            path.file_name().is_some_and(|name| name == "acme.pkg")
+        }
+
+        fn extract_packages(&self, path: &Path) -> Vec<PackageData> {
+            let content = fs::read_to_string(path).unwrap_or_default();
+            let field = |key: &str| {
+                content
+                    .lines()
+                    .find_map(|line| line.strip_prefix(key))
+                    .map(|value| value.trim().to_string())
+            };
+            let name = field("name=");
+            let version = field("version=");
+            vec![PackageData {
+                purl: name
+                    .as_deref()
+                    .zip(version.as_deref())
+                    .map(|(name, version)| format!("pkg:generic/{name}@{version}")),
+                name,
+                version,
+                datasource_id: Some(DatasourceId::Custom),
+                ..Default::default()
+            }]
+        }
+    }
+
+    fn acme_assembler() -> AssemblerConfig {
+        AssemblerConfig {
+            datasource_ids: &[DatasourceId::Custom],
+            sibling_file_patterns: &["acme.pkg"],
+            mode: AssemblyMode::SiblingMerge,
+        }
+    }
+
+    #[test]
+    fn test_registered_parser_package_data_is_scanned_and_assembled() {
+        register_parser(Box::new(AcmeManifestParser), acme_assembler())
+            .expect("custom parser should register");
+
+        let temp_dir = tempfile::tempdir().expect("create temp dir");
+        fs::write(
+            temp_dir.path().join("acme.pkg"),
+            "name=widget\nversion=1.2.0\n",
+        )
+        .expect("write manifest");
+
+        let (files, result) = scan_and_assemble(temp_dir.path());
+
+        let manifest = files
+            .iter()
+            .find(|file| file.path.ends_with("acme.pkg"))
+            .expect("manifest should be scanned");
+        assert_eq!(manifest.package_data.len(), 1);
+        let package_data = &manifest.package_data[0];
+        assert_eq!(package_data.package_type, Some(PackageType::Generic));
+        assert_eq!(package_data.name.as_deref(), Some("widget"));
+        assert_eq!(package_data.datasource_id, Some(DatasourceId::Custom));
+
+        let package = result
+            .packages
+            .iter()
+            .find(|package| package.purl.as_deref() == Some("pkg:generic/widget@1.2.0"))
+            .expect("custom package should be assembled");
+        assert_eq!(package.datasource_ids, vec![DatasourceId::Custom]);
+        assert_eq!(manifest.for_packages, vec![package.package_uid.clone()]);
+    }
+
+    /// Panics on every `boom.pkg` file it is given.
+    struct PanickingParser;
+
+    impl DynPackageParser for PanickingParser {
+        fn package_type(&self) -> PackageType {
+            PackageType::Generic
+        }
+
+        fn is_match(&self, path: &Path) -> bool {
+            path.file_name().is_some_and(|name| name == "boom.pkg")
+        }
+
+        fn extract_packages(&self, _path: &Path) -> Vec<PackageData> {
+            panic!("malformed boom manifest");
+        }
+    }
+
+    #[test]
+    fn test_panicking_parser_is_reported_as_a_file_scan_error() {
+        register_parser(Box::new(PanickingParser), acme_assembler())
+            .expect("parser sharing the acme assembler should register");
+
+        let temp_dir = tempfile::tempdir().expect("create temp dir");
+        fs::write(temp_dir.path().join("boom.pkg"), "name=boom\n").expect("write manifest");
+        fs::write(temp_dir.path().join("README.txt"), "hello\n").expect("write readme");
+
+        let (files, _) = scan_and_assemble(temp_dir.path());
+
+        let boom = files
+            .iter()
+            .find(|file| file.path.ends_with("boom.pkg"))
+            .expect("panicking file should still be reported");
+        assert!(boom.package_data.is_empty());
+        assert_eq!(
+            boom.scan_errors,
+            vec!["Package parser panicked: malformed boom manifest".to_string()]
+        );
+        assert!(files.iter().any(|file| file.path.ends_with("README.txt")));
+    }
+
+    #[test]
+    fn test_register_parser_rejects_datasources_of_builtin_assemblers() {
+        let result = register_parser(
+            Box::new(AcmeManifestParser),
+            AssemblerConfig {
+                datasource_ids: &[DatasourceId::NpmPackageJson],
+                sibling_file_patterns: &["acme.pkg"],
+                mode: AssemblyMode::SiblingMerge,
+            },
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -12,7 +12,10 @@
 //! # Key Features
 //! - PEP 508 requirement parsing with environment marker evaluation
 //! - Recursive file inclusion support (`-r` and `-c` directives)
-//! - VCS/URL dependency detection and handling
+//! - VCS/URL dependency detection and handling; VCS URLs naming a revision and
+//!   remote archives are pinned
+//! - `--hash` options, including ones on the lines after a requirement, are kept
+//!   in the requirement's `extra_data["hash_options"]`
 //! - Package URL (purl) generation for PyPI packages
 //! - Line comment handling and continuation lines
 //!
@@ -92,7 +95,8 @@ fn extract_from_requirements_txt(path: &Path) -> PackageData {
 
     let (scope, is_runtime) = scope_from_filename(path);
 
-    parse_requirements_with_includes(path, &mut state, &scope, is_runtime);
+    parse_requirements_with_includes(path, &mut state, &scope, is_runtime, false);
+    apply_constraint_pins(&mut state.dependencies);
 
     let mut extra_data = HashMap::new();
     if let Some(url) = state.index_url {
@@ -138,11 +142,15 @@ fn extract_from_requirements_txt(path: &Path) -> PackageData {
     default_package_data(state.dependencies, extra_data)
 }
 
+/// Parse `path` into `state`, following `-r` includes and `-c` constraint
+/// files. Entries read from a constraint file (or anything it includes) are
+/// flagged with `is_constraint`.
 fn parse_requirements_with_includes(
     path: &Path,
     state: &mut ParseState,
     scope: &str,
     is_runtime: bool,
+    is_constraint: bool,
 ) {
     let abs_path = match path.canonicalize() {
         Ok(p) => p,
@@ -167,6 +175,10 @@ fn parse_requirements_with_includes(
         }
     };
 
+    // Index of the dependency read from the previous line, which a line of
+    // bare `--hash` options belongs to.
+    let mut previous_dependency = None;
+
     for line in collect_logical_lines(&content) {
         let cleaned = strip_inline_comment(&line);
         let trimmed = cleaned.trim();
@@ -174,6 +186,18 @@ fn parse_requirements_with_includes(
             continue;
         }
 
+        if trimmed.starts_with("--hash") {
+            let (rest, hashes) = split_hash_options(trimmed);
+            if rest.trim().is_empty()
+                && let Some(dependency) =
+                    previous_dependency.and_then(|index| state.dependencies.get_mut(index))
+            {
+                append_hash_options(dependency, hashes);
+            }
+            continue;
+        }
+        previous_dependency = None;
+
         if let Some(url) = parse_option_value(trimmed, "--extra-index-url") {
             state.extra_index_urls.push(url);
             continue;
@@ -194,7 +218,13 @@ fn parse_requirements_with_includes(
                 .join(&path_value);
 
             if included_path.exists() {
-                parse_requirements_with_includes(&included_path, state, scope, is_runtime);
+                parse_requirements_with_includes(
+                    &included_path,
+                    state,
+                    scope,
+                    is_runtime,
+                    is_constraint,
+                );
             } else {
                 warn!("Included file not found: {:?}", included_path);
             }
@@ -211,7 +241,7 @@ fn parse_requirements_with_includes(
                 .join(&path_value);
 
             if constraint_path.exists() {
-                parse_requirements_with_includes(&constraint_path, state, scope, is_runtime);
+                parse_requirements_with_includes(&constraint_path, state, scope, is_runtime, true);
             } else {
                 warn!("Constraint file not found: {:?}", constraint_path);
             }
@@ -225,12 +255,24 @@ fn parse_requirements_with_includes(
             continue;
         }
 
-        if let Some(dependency) = build_dependency(trimmed, scope, is_runtime) {
+        if let Some(dependency) = build_dependency(trimmed, scope, is_runtime, is_constraint) {
+            previous_dependency = Some(state.dependencies.len());
             state.dependencies.push(dependency);
         }
     }
 }
 
+fn append_hash_options(dependency: &mut Dependency, hashes: Vec<String>) {
+    let Some(JsonValue::Array(hash_options)) = dependency
+        .extra_data
+        .as_mut()
+        .and_then(|extra_data| extra_data.get_mut("hash_options"))
+    else {
+        return;
+    };
+    hash_options.extend(hashes.into_iter().map(JsonValue::String));
+}
+
 fn default_package_data(
     dependencies: Vec<Dependency>,
     extra_data: Option<HashMap<String, JsonValue>>,
@@ -252,7 +294,8 @@ fn collect_logical_lines(content: &str) -> Vec<String> {
     for raw_line in content.lines() {
         let line = raw_line.trim_end_matches('\r');
         let trimmed = line.trim_end();
-        let is_continuation = trimmed.ends_with('\\');
+        // Like pip, a backslash that ends a comment does not continue the line.
+        let is_continuation = trimmed.ends_with('\\') && !has_comment(trimmed);
         let line_without = if is_continuation {
             trimmed.trim_end_matches('\\')
         } else {
@@ -279,6 +322,11 @@ fn collect_logical_lines(content: &str) -> Vec<String> {
     lines
 }
 
+/// Whether `line` carries a `#` comment: at its start or after whitespace.
+fn has_comment(line: &str) -> bool {
+    line.trim_start().starts_with('#') || line.contains(" #") || line.contains("\t#")
+}
+
 fn strip_inline_comment(line: &str) -> String {
     let mut in_single = false;
     let mut in_double = false;
@@ -331,7 +379,12 @@ fn scope_from_filename(path: &Path) -> (String, bool) {
     ("install".to_string(), true)
 }
 
-fn build_dependency(line: &str, scope: &str, is_runtime: bool) -> Option<Dependency> {
+fn build_dependency(
+    line: &str,
+    scope: &str,
+    is_runtime: bool,
+    is_constraint: bool,
+) -> Option<Dependency> {
     let trimmed = line.trim();
     if trimmed.is_empty() {
         return None;
@@ -363,7 +416,11 @@ fn build_dependency(line: &str, scope: &str, is_runtime: bool) -> Option<Depende
         .specifiers
         .as_deref()
         .and_then(extract_pinned_version);
-    let is_pinned = pinned_version.is_some();
+    let is_pinned = pinned_version.is_some()
+        || parsed
+            .link
+            .as_deref()
+            .is_some_and(|link| is_pinned_link(link, &parsed));
 
     let purl = parsed
         .name
@@ -384,7 +441,7 @@ fn build_dependency(line: &str, scope: &str, is_runtime: bool) -> Option<Depende
         "hash_options".to_string(),
         JsonValue::Array(hash_options.into_iter().map(JsonValue::String).collect()),
     );
-    extra_data.insert("is_constraint".to_string(), JsonValue::Bool(false));
+    extra_data.insert("is_constraint".to_string(), JsonValue::Bool(is_constraint));
     extra_data.insert(
         "is_archive".to_string(),
         parsed
@@ -437,6 +494,58 @@ fn build_dependency(line: &str, scope: &str, is_runtime: bool) -> Option<Depende
     })
 }
 
+/// Pin unpinned requirements to the exact `==` version of a matching
+/// constraint, recording the constraint in `extra_data["pinned_by_constraint"]`.
+fn apply_constraint_pins(dependencies: &mut [Dependency]) {
+    let mut pins: HashMap<String, (String, String)> = HashMap::new();
+    for dependency in dependencies.iter().filter(|d| is_constraint_dependency(d)) {
+        if let Some((name, Some(version))) = dependency.purl.as_deref().and_then(pypi_purl_parts) {
+            pins.entry(name).or_insert((
+                version,
+                dependency.extracted_requirement.clone().unwrap_or_default(),
+            ));
+        }
+    }
+    if pins.is_empty() {
+        return;
+    }
+
+    for dependency in dependencies
+        .iter_mut()
+        .filter(|d| !is_constraint_dependency(d) && d.is_pinned != Some(true))
+    {
+        let Some((name, _)) = dependency.purl.as_deref().and_then(pypi_purl_parts) else {
+            continue;
+        };
+        let Some((version, constraint)) = pins.get(&name) else {
+            continue;
+        };
+        dependency.purl = create_pypi_purl(&name, Some(version));
+        dependency.is_pinned = Some(true);
+        dependency
+            .extra_data
+            .get_or_insert_with(HashMap::new)
+            .insert(
+                "pinned_by_constraint".to_string(),
+                JsonValue::String(constraint.clone()),
+            );
+    }
+}
+
+fn is_constraint_dependency(dependency: &Dependency) -> bool {
+    dependency
+        .extra_data
+        .as_ref()
+        .and_then(|extra_data| extra_data.get("is_constraint"))
+        .and_then(JsonValue::as_bool)
+        .unwrap_or(false)
+}
+
+fn pypi_purl_parts(purl: &str) -> Option<(String, Option<String>)> {
+    let purl = purl.parse::<PackageUrl>().ok()?;
+    Some((purl.name().to_string(), purl.version().map(str::to_string)))
+}
+
 fn split_hash_options(input: &str) -> (String, Vec<String>) {
     let mut filtered = Vec::new();
     let mut hashes = Vec::new();
@@ -606,6 +715,26 @@ fn parse_link_flags(link: &str) -> LinkFlags {
     }
 }
 
+/// Whether a link requirement always resolves to the same code: a VCS URL
+/// naming a revision (`git+https://host/repo.git@v1.0`) or a remote archive.
+fn is_pinned_link(link: &str, parsed: &ParsedRequirement) -> bool {
+    if parsed.is_local_path == Some(true) {
+        return false;
+    }
+    let without_fragment = link.split('#').next().unwrap_or(link);
+    if parsed.is_vcs_url == Some(true) {
+        let path = without_fragment
+            .split_once("://")
+            .and_then(|(_, rest)| rest.split_once('/'))
+            .map(|(_, path)| path)
+            .unwrap_or_default();
+        return path
+            .rsplit_once('@')
+            .is_some_and(|(_, revision)| !revision.is_empty());
+    }
+    parsed.is_url == Some(true) && parse_link_flags(without_fragment).is_archive == Some(true)
+}
+
 fn is_link_like(input: &str) -> bool {
     let trimmed = input.trim();
     trimmed.starts_with("git+")
@@ -138,4 +138,180 @@ mod tests {
         let extra_data = package_data.extra_data.unwrap();
         assert!(extra_data.contains_key("constraints"));
     }
+
+    #[test]
+    fn test_requirements_include_with_constraint_pins() {
+        let test_file =
+            PathBuf::from("testdata/python/requirements-include-constraints/requirements.txt");
+        let package_data = RequirementsTxtParser::extract_first_package(&test_file);
+
+        let is_constraint = |dependency: &crate::models::Dependency| {
+            dependency
+                .extra_data
+                .as_ref()
+                .and_then(|extra_data| extra_data.get("is_constraint"))
+                .and_then(|value| value.as_bool())
+                .unwrap_or(false)
+        };
+
+        let mut direct: Vec<&str> = package_data
+            .dependencies
+            .iter()
+            .filter(|d| !is_constraint(d))
+            .filter_map(|d| d.purl.as_deref())
+            .collect();
+        direct.sort_unstable();
+        assert_eq!(
+            direct,
+            vec![
+                "pkg:pypi/click",
+                "pkg:pypi/flask",
+                "pkg:pypi/requests@2.31.0",
+                "pkg:pypi/widget",
+            ]
+        );
+
+        let constraints: Vec<&str> = package_data
+            .dependencies
+            .iter()
+            .filter(|d| is_constraint(d))
+            .filter_map(|d| d.purl.as_deref())
+            .collect();
+        assert_eq!(
+            constraints,
+            vec!["pkg:pypi/requests@2.31.0", "pkg:pypi/urllib3@2.0.7"]
+        );
+
+        let requests = package_data
+            .dependencies
+            .iter()
+            .find(|d| !is_constraint(d) && d.purl.as_deref() == Some("pkg:pypi/requests@2.31.0"))
+            .expect("requests should be pinned by the constraint");
+        assert_eq!(requests.is_pinned, Some(true));
+        assert_eq!(requests.extracted_requirement.as_deref(), Some("requests"));
+        assert_eq!(
+            requests
+                .extra_data
+                .as_ref()
+                .and_then(|extra_data| extra_data.get("pinned_by_constraint")),
+            Some(&serde_json::Value::String("requests==2.31.0".to_string()))
+        );
+
+        let widget = package_data
+            .dependencies
+            .iter()
+            .find(|d| d.purl.as_deref() == Some("pkg:pypi/widget"))
+            .expect("editable install should be recorded");
+        let widget_extra = widget.extra_data.as_ref().expect("extra_data");
+        assert_eq!(
+            widget_extra.get("is_editable"),
+            Some(&serde_json::Value::Bool(true))
+        );
+        assert_eq!(
+            widget_extra.get("link"),
+            Some(&serde_json::Value::String(
+                "git+https://github.com/example/widget.git@v1.2#egg=widget".to_string()
+            ))
+        );
+        assert_eq!(
+            widget_extra.get("is_vcs_url"),
+            Some(&serde_json::Value::Bool(true))
+        );
+    }
+
+    #[test]
+    fn test_requirements_continuation_lines_keep_marker() {
+        let temp_dir = tempfile::tempdir().expect("create temp dir");
+        let requirements = temp_dir.path().join("requirements.txt");
+        std::fs::write(
+            &requirements,
+            "requests>=2.0,\\\n    <3 \\\n    ; python_version < \"3.8\"\n\
+             # pinned below \\\n\
+             six==1.16.0\n",
+        )
+        .expect("write requirements");
+
+        let package_data = RequirementsTxtParser::extract_first_package(&requirements);
+
+        assert_eq!(package_data.dependencies.len(), 2);
+        let requests = &package_data.dependencies[0];
+        assert_eq!(requests.purl.as_deref(), Some("pkg:pypi/requests"));
+        assert_eq!(requests.is_pinned, Some(false));
+        assert_eq!(
+            requests.extra_data.as_ref().expect("extra_data")["markers"],
+            "python_version < \"3.8\""
+        );
+
+        let six = &package_data.dependencies[1];
+        assert_eq!(six.purl.as_deref(), Some("pkg:pypi/six@1.16.0"));
+        assert_eq!(six.is_pinned, Some(true));
+    }
+
+    #[test]
+    fn test_requirements_hash_lines_and_pinned_links() {
+        let temp_dir = tempfile::tempdir().expect("create temp dir");
+        std::fs::write(temp_dir.path().join("base.txt"), "six==1.16.0\n").expect("write base");
+        let requirements = temp_dir.path().join("requirements.txt");
+        std::fs::write(
+            &requirements,
+            "-r base.txt\n\
+             requests>=2.0,<3; python_version < \"3.8\"\n\
+             --hash=sha256:aaa\n\
+             --hash=sha256:bbb\n\
+             -e git+https://github.com/org/tool.git@v1.2.0#egg=tool\n\
+             git+ssh://git@github.com/org/lib.git#egg=lib\n\
+             https://example.com/dist/archive-1.0.tar.gz#egg=archive\n\
+             ./local/pkg\n",
+        )
+        .expect("write requirements");
+
+        let package_data = RequirementsTxtParser::extract_first_package(&requirements);
+        let dependency = |name: &str| {
+            package_data
+                .dependencies
+                .iter()
+                .find(|dependency| {
+                    dependency
+                        .purl
+                        .as_deref()
+                        .is_some_and(|purl| purl.starts_with(&format!("pkg:pypi/{name}")))
+                })
+                .unwrap_or_else(|| panic!("missing {name}"))
+        };
+
+        assert_eq!(
+            package_data.extra_data.as_ref().expect("extra_data")["requirements_includes"],
+            serde_json::json!(["base.txt"])
+        );
+        assert_eq!(dependency("six").is_pinned, Some(true));
+
+        let requests = dependency("requests");
+        assert_eq!(
+            requests.extracted_requirement.as_deref(),
+            Some("requests>=2.0,<3; python_version < \"3.8\"")
+        );
+        assert_eq!(requests.is_pinned, Some(false));
+        let extra_data = requests.extra_data.as_ref().expect("extra_data");
+        assert_eq!(extra_data["markers"], "python_version < \"3.8\"");
+        assert_eq!(
+            extra_data["hash_options"],
+            serde_json::json!(["sha256:aaa", "sha256:bbb"])
+        );
+
+        let tool = dependency("tool");
+        assert_eq!(tool.is_pinned, Some(true));
+        assert_eq!(
+            tool.extra_data.as_ref().expect("extra_data")["link"],
+            "git+https://github.com/org/tool.git@v1.2.0#egg=tool"
+        );
+        assert_eq!(dependency("lib").is_pinned, Some(false));
+        assert_eq!(dependency("archive").is_pinned, Some(true));
+
+        let local = package_data
+            .dependencies
+            .iter()
+            .find(|dependency| dependency.extracted_requirement.as_deref() == Some("./local/pkg"))
+            .expect("local path requirement");
+        assert_eq!(local.is_pinned, Some(false));
+    }
 }
@@ -11,7 +11,8 @@
 //! - Preamble tag extraction (Name, Version, Release, Summary, License, etc.)
 //! - Dependency extraction (BuildRequires, Requires, Provides)
 //! - %description section parsing
-//! - Basic macro expansion (%{name}, %{version}, %{release})
+//! - Macro expansion (%{name}, %name, %{version}, %{release}), including macros
+//!   defined in terms of other macros
 //! - %define and %global macro definitions
 //! - Conditional macro handling (%{?dist})
 //! - Multi-line dependency lists (comma-separated)
@@ -24,6 +25,8 @@
 //! - BuildRequires dependencies have is_runtime=false, scope="build"
 //! - Runtime Requires dependencies have is_runtime=true, scope="runtime"
 //! - datasource_id is "rpm_specfile"
+//! - The License: tag is only mapped to a declared license expression on request
+//!   (`--rpm-specfile-licenses`), see [`attach_declared_license`]
 
 use std::collections::HashMap;
 use std::path::Path;
@@ -34,12 +37,23 @@ use packageurl::PackageUrl;
 use regex::Regex;
 
 use crate::models::{DatasourceId, Dependency, PackageData, PackageType, Party};
+use crate::parsers::license_normalization::{
+    DeclaredLicenseMatchMetadata, build_declared_license_data, normalize_spdx_expression,
+};
 use crate::parsers::utils::{read_file_to_string, split_name_email};
 
 use super::PackageParser;
 
 static RE_CONDITIONAL_MACRO: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"%\{\?[^}]+\}").unwrap());
+static RE_MACRO: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"%\{(\w+)\}|%(\w+)").unwrap());
+/// Splits a License: value on parentheses and `and`/`or` operators, leaving
+/// multi-word license names such as `ASL 2.0` intact.
+static RE_LICENSE_OPERATOR: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\s+(?:and|or|with)\s+|[()]").unwrap());
+
+/// Bounds expansion of macros that refer to other macros (or to themselves).
+const MAX_MACRO_EXPANSION_ROUNDS: usize = 16;
 
 const PACKAGE_TYPE: PackageType = PackageType::Rpm;
 
@@ -362,21 +376,119 @@ fn is_conditional_preamble_directive(line: &str) -> bool {
     .any(|directive| line.starts_with(directive))
 }
 
-/// Expands simple macros in a string (%{name}, %{version}, %{release}, %{?dist})
+/// Expands macros in a string (%{name}, %name, %{version}, %{?dist}), repeating
+/// until macros defined in terms of other macros are resolved. Unknown macros
+/// are left as is.
 fn expand_macros(s: &str, macros: &HashMap<String, String>) -> String {
-    let mut result = s.to_string();
+    let mut result = RE_CONDITIONAL_MACRO.replace_all(s, "").into_owned();
+
+    for _ in 0..MAX_MACRO_EXPANSION_ROUNDS {
+        let expanded = RE_MACRO.replace_all(&result, |caps: &regex::Captures| {
+            let key = caps
+                .get(1)
+                .or_else(|| caps.get(2))
+                .map_or("", |m| m.as_str());
+            macros
+                .get(key)
+                .cloned()
+                .unwrap_or_else(|| caps[0].to_string())
+        });
+        let expanded = RE_CONDITIONAL_MACRO.replace_all(&expanded, "").into_owned();
+        if expanded == result {
+            break;
+        }
+        result = expanded;
+    }
+
+    result
+}
+
+/// Set the declared license of a specfile package from its License: tag.
+///
+/// Fedora-style short names (`GPLv2+`, `ASL 2.0`, ...) are mapped to SPDX
+/// identifiers first. Nothing is declared if any part of the tag is unknown.
+pub(crate) fn attach_declared_license(package: &mut PackageData) {
+    let Some(statement) = package.extracted_license_statement.as_deref() else {
+        return;
+    };
+    let Some(normalized) = rpm_license_to_spdx(statement)
+        .as_deref()
+        .and_then(normalize_spdx_expression)
+    else {
+        return;
+    };
 
-    result = RE_CONDITIONAL_MACRO.replace_all(&result, "").to_string();
+    let (declared, declared_spdx, detections) = build_declared_license_data(
+        normalized,
+        DeclaredLicenseMatchMetadata::single_line(statement),
+    );
+    package.declared_license_expression = declared;
+    package.declared_license_expression_spdx = declared_spdx;
+    package.license_detections = detections;
+}
 
-    // Expand simple macros %{macro}
-    for (key, value) in macros {
-        let pattern = format!("%{{{}}}", key);
-        result = result.replace(&pattern, value);
+/// Rewrite an RPM License: value as an SPDX expression.
+fn rpm_license_to_spdx(statement: &str) -> Option<String> {
+    let statement = statement.trim();
+    if statement.is_empty() {
+        return None;
     }
 
-    result = RE_CONDITIONAL_MACRO.replace_all(&result, "").to_string();
+    let mut expression = String::new();
+    let mut last_end = 0;
+    for separator in RE_LICENSE_OPERATOR.find_iter(statement) {
+        expression.push_str(&rpm_license_token_to_spdx(
+            &statement[last_end..separator.start()],
+        )?);
+        match separator.as_str().trim() {
+            parenthesis @ ("(" | ")") => expression.push_str(parenthesis),
+            operator => {
+                expression.push(' ');
+                expression.push_str(&operator.to_ascii_uppercase());
+                expression.push(' ');
+            }
+        }
+        last_end = separator.end();
+    }
+    expression.push_str(&rpm_license_token_to_spdx(&statement[last_end..])?);
 
-    result
+    Some(expression)
+}
+
+/// Map a single license name, keeping the empty text around parentheses.
+fn rpm_license_token_to_spdx(token: &str) -> Option<String> {
+    let token = token.trim();
+    if token.is_empty() {
+        return Some(String::new());
+    }
+
+    let spdx = match token {
+        "GPL+" => "GPL-1.0-or-later",
+        "GPLv2" => "GPL-2.0-only",
+        "GPLv2+" => "GPL-2.0-or-later",
+        "GPLv3" => "GPL-3.0-only",
+        "GPLv3+" => "GPL-3.0-or-later",
+        "LGPLv2" => "LGPL-2.0-only",
+        "LGPLv2+" => "LGPL-2.0-or-later",
+        "LGPLv2.1" => "LGPL-2.1-only",
+        "LGPLv2.1+" => "LGPL-2.1-or-later",
+        "LGPLv3" => "LGPL-3.0-only",
+        "LGPLv3+" => "LGPL-3.0-or-later",
+        "AGPLv3" => "AGPL-3.0-only",
+        "AGPLv3+" => "AGPL-3.0-or-later",
+        "ASL 1.1" => "Apache-1.1",
+        "ASL 2.0" => "Apache-2.0",
+        "MPLv1.1" => "MPL-1.1",
+        "MPLv2.0" => "MPL-2.0",
+        "Artistic 2.0" => "Artistic-2.0",
+        "Boost" => "BSL-1.0",
+        "zlib" => "Zlib",
+        // Already SPDX (newer Fedora specs) or unknown; a single word either way.
+        other if !other.contains(char::is_whitespace) => other,
+        _ => return None,
+    };
+
+    Some(spdx.to_string())
 }
 
 /// Extracts the package name from a dependency string (removes version constraints)
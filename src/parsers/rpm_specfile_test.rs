@@ -352,3 +352,72 @@ Macro package
         .unwrap();
     assert_eq!(provides[0].as_str(), Some("macro-pkg-libs = 2.4"));
 }
+
+#[test]
+fn test_nested_and_unbraced_macros_are_expanded() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_path = temp_dir.path().join("nested.spec");
+
+    let spec = r#"
+%global major 1
+%global upstream_version %{major}.7
+Name: nested-pkg
+Version: %{upstream_version}
+Release: 1
+License: MIT
+Requires: %name-common = %version
+Source0: https://example.com/%{name}-%{version}.tar.gz
+"#;
+
+    fs::write(&spec_path, spec).unwrap();
+    let pkg = RpmSpecfileParser::extract_first_package(&spec_path);
+
+    assert_eq!(pkg.version.as_deref(), Some("1.7"));
+    assert_eq!(pkg.purl.as_deref(), Some("pkg:rpm/nested-pkg@1.7"));
+    assert_eq!(
+        pkg.download_url.as_deref(),
+        Some("https://example.com/nested-pkg-1.7.tar.gz")
+    );
+    assert_eq!(
+        pkg.dependencies[0].extracted_requirement.as_deref(),
+        Some("nested-pkg-common = 1.7")
+    );
+}
+
+#[test]
+fn test_attach_declared_license_maps_rpm_license_names() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_path = temp_dir.path().join("license.spec");
+    fs::write(
+        &spec_path,
+        "Name: license-pkg\nVersion: 1.0\nLicense: GPLv2+ and (ASL 2.0 or MIT)\n",
+    )
+    .unwrap();
+
+    let mut pkg = RpmSpecfileParser::extract_first_package(&spec_path);
+    assert!(pkg.declared_license_expression.is_none());
+
+    super::attach_rpm_specfile_declared_license(&mut pkg);
+    assert_eq!(
+        pkg.declared_license_expression_spdx.as_deref(),
+        Some("GPL-2.0-or-later AND (Apache-2.0 OR MIT)")
+    );
+    assert_eq!(pkg.license_detections.len(), 1);
+}
+
+#[test]
+fn test_attach_declared_license_skips_unknown_license_names() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_path = temp_dir.path().join("license.spec");
+    fs::write(
+        &spec_path,
+        "Name: license-pkg\nVersion: 1.0\nLicense: Copyright only\n",
+    )
+    .unwrap();
+
+    let mut pkg = RpmSpecfileParser::extract_first_package(&spec_path);
+    super::attach_rpm_specfile_declared_license(&mut pkg);
+
+    assert!(pkg.declared_license_expression.is_none());
+    assert!(pkg.license_detections.is_empty());
+}
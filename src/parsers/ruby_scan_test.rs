@@ -78,4 +78,52 @@ mod tests {
             DatasourceId::GemfileLock,
         );
     }
+
+    #[test]
+    fn test_ruby_gemfile_lock_and_gemspec_scan_assembles_one_package() {
+        let temp_dir = tempfile::TempDir::new().expect("create temp dir");
+        for (fixture, name) in [
+            ("testdata/ruby/basic.gemspec", "example.gemspec"),
+            ("testdata/ruby/Gemfile", "Gemfile"),
+            ("testdata/ruby/Gemfile.lock", "Gemfile.lock"),
+        ] {
+            fs::copy(fixture, temp_dir.path().join(name)).expect("copy ruby fixture");
+        }
+
+        let (files, result) = scan_and_assemble(temp_dir.path());
+
+        let gem_packages: Vec<_> = result
+            .packages
+            .iter()
+            .filter(|package| package.package_type == Some(PackageType::Gem))
+            .collect();
+        assert_eq!(gem_packages.len(), 1);
+        let package = gem_packages[0];
+        assert_eq!(package.purl.as_deref(), Some("pkg:gem/example-gem@1.2.3"));
+
+        for (path, datasource_id) in [
+            ("/example.gemspec", DatasourceId::Gemspec),
+            ("/Gemfile", DatasourceId::Gemfile),
+            ("/Gemfile.lock", DatasourceId::GemfileLock),
+        ] {
+            assert_file_links_to_package(&files, path, &package.package_uid, datasource_id);
+        }
+
+        let pry = result
+            .dependencies
+            .iter()
+            .find(|dep| {
+                dep.purl
+                    .as_deref()
+                    .is_some_and(|purl| purl.starts_with("pkg:gem/pry"))
+                    && dep.datafile_path.ends_with("/Gemfile")
+            })
+            .expect("development gem from Gemfile should be hoisted");
+        assert_eq!(pry.is_runtime, Some(false));
+        assert_eq!(pry.scope.as_deref(), Some("development"));
+        assert_eq!(
+            pry.for_package_uid.as_deref(),
+            Some(package.package_uid.as_str())
+        );
+    }
 }
@@ -3,10 +3,10 @@ use std::fs;
 use std::path::Path;
 
 use crate::parser_warn as warn;
-use packageurl::PackageUrl;
 use serde_json::json;
 
 use crate::models::{DatasourceId, Dependency, PackageData, PackageType};
+use crate::utils::purl::maven_purl;
 
 use super::PackageParser;
 
@@ -956,19 +956,7 @@ fn build_maven_purl(
     name: Option<&str>,
     version: Option<&str>,
 ) -> Option<String> {
-    let name = name?.trim();
-    if name.is_empty() {
-        return None;
-    }
-
-    let mut purl = PackageUrl::new("maven", name).ok()?;
-    if let Some(namespace) = namespace.map(str::trim).filter(|value| !value.is_empty()) {
-        purl.with_namespace(namespace).ok()?;
-    }
-    if let Some(version) = version.map(str::trim).filter(|value| !value.is_empty()) {
-        purl.with_version(version).ok()?;
-    }
-    Some(purl.to_string())
+    maven_purl(namespace, name?, version)
 }
 
 fn format_license_entries(licenses: &[LicenseEntry]) -> Option<String> {
@@ -8,7 +8,6 @@ use std::path::Path;
 
 use anyhow::Result;
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64_STANDARD};
-use packageurl::PackageUrl;
 
 /// Reads a file's entire contents into a String.
 ///
@@ -37,35 +36,6 @@ pub fn read_file_to_string(path: &Path) -> Result<String> {
     Ok(content)
 }
 
-/// Creates a correctly-formatted npm Package URL for scoped or regular packages.
-///
-/// Handles namespace encoding for scoped packages (e.g., `@babel/core`) and ensures
-/// the slash between namespace and package name is NOT encoded as `%2F`.
-pub fn npm_purl(full_name: &str, version: Option<&str>) -> Option<String> {
-    let (namespace, name) = if full_name.starts_with('@') {
-        let parts: Vec<&str> = full_name.splitn(2, '/').collect();
-        if parts.len() == 2 {
-            (Some(parts[0]), parts[1])
-        } else {
-            (None, full_name)
-        }
-    } else {
-        (None, full_name)
-    };
-
-    let mut purl = PackageUrl::new("npm", name).ok()?;
-
-    if let Some(ns) = namespace {
-        purl.with_namespace(ns).ok()?;
-    }
-
-    if let Some(ver) = version {
-        purl.with_version(ver).ok()?;
-    }
-
-    Some(purl.to_string())
-}
-
 /// Parses Subresource Integrity (SRI) format and returns hash as hex string.
 ///
 /// SRI format: "algorithm-base64string" (e.g., "sha512-9NET910DNaIPng...")
@@ -179,37 +149,6 @@ mod tests {
         assert_eq!(content, "");
     }
 
-    #[test]
-    fn test_npm_purl_scoped_with_version() {
-        let purl = npm_purl("@babel/core", Some("7.0.0")).unwrap();
-        assert_eq!(purl, "pkg:npm/%40babel/core@7.0.0");
-    }
-
-    #[test]
-    fn test_npm_purl_scoped_without_version() {
-        let purl = npm_purl("@babel/core", None).unwrap();
-        assert_eq!(purl, "pkg:npm/%40babel/core");
-    }
-
-    #[test]
-    fn test_npm_purl_unscoped_with_version() {
-        let purl = npm_purl("lodash", Some("4.17.21")).unwrap();
-        assert_eq!(purl, "pkg:npm/lodash@4.17.21");
-    }
-
-    #[test]
-    fn test_npm_purl_unscoped_without_version() {
-        let purl = npm_purl("lodash", None).unwrap();
-        assert_eq!(purl, "pkg:npm/lodash");
-    }
-
-    #[test]
-    fn test_npm_purl_scoped_slash_not_encoded() {
-        let purl = npm_purl("@types/node", Some("18.0.0")).unwrap();
-        assert!(purl.contains("/%40types/node"));
-        assert!(!purl.contains("%2F"));
-    }
-
     #[test]
     fn test_parse_sri_sha512() {
         let (algo, hash) = parse_sri("sha512-9NET910DNaIPngYnLLPeg+Ogzqsi9uM4mSboU5y6p8S5DzMTVEsJZrawi+BoDNUVBa2DhJqQYUFvMDfgU062LQ==").unwrap();
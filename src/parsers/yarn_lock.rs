@@ -22,7 +22,8 @@
 
 use crate::models::{DatasourceId, Dependency, PackageData, PackageType, ResolvedPackage};
 use crate::parser_warn as warn;
-use crate::parsers::utils::{npm_purl, parse_sri};
+use crate::parsers::utils::parse_sri;
+use crate::utils::purl::npm_purl;
 use serde_json::Value as JsonValue;
 use serde_yaml::Value;
 use std::collections::{HashMap, HashSet};
@@ -107,7 +108,7 @@ fn parse_yarn_v2(
             continue;
         }
 
-        let _spec_str = match spec.as_str() {
+        let spec_str = match spec.as_str() {
             Some(s) => s,
             None => continue,
         };
@@ -156,7 +157,7 @@ fn parse_yarn_v2(
             download_url: None,
             sha1: None,
             sha256: None,
-            sha512: checksum,
+            sha512: checksum.clone(),
             md5: None,
             is_virtual: true,
             extra_data: resolved_extra_data,
@@ -195,10 +196,9 @@ fn parse_yarn_v2(
             is_pinned: Some(true),
             is_direct: Some(is_direct),
             resolved_package: Some(Box::new(resolved_package)),
-            extra_data: Some(HashMap::from([(
-                "resolution".to_string(),
-                JsonValue::String(resolution),
-            )])),
+            extra_data: Some(yarn_v2_dependency_extra_data(
+                resolution, checksum, spec_str,
+            )),
         };
 
         dependencies.push(dependency);
@@ -393,6 +393,10 @@ fn parse_yarn_v1_block(
     }
 
     let (namespace, name, constraint) = parse_yarn_v1_requirement(requirement_line);
+    let requirements: Vec<String> = split_requirement_keys(requirement_line)
+        .into_iter()
+        .map(|key| parse_single_yarn_v1_requirement(key).2)
+        .collect();
 
     if name.is_empty() {
         return None;
@@ -436,6 +440,7 @@ fn parse_yarn_v1_block(
     } else {
         parse_integrity_field(&integrity)
     };
+    let extra_data = yarn_v1_dependency_extra_data(&integrity, requirements);
 
     let full_name = full_package_name(&namespace, &name);
     let manifest_info = manifest_dependencies.get(&full_name);
@@ -485,10 +490,65 @@ fn parse_yarn_v1_block(
         is_pinned: Some(true),
         is_direct: Some(is_direct),
         resolved_package: Some(Box::new(resolved_package)),
-        extra_data: None,
+        extra_data,
     })
 }
 
+/// Keep the raw SRI `integrity` (whatever its algorithm) and, when several
+/// requirement keys share one entry, every requirement that resolved to it.
+fn yarn_v1_dependency_extra_data(
+    integrity: &str,
+    requirements: Vec<String>,
+) -> Option<HashMap<String, JsonValue>> {
+    let mut extra_data = HashMap::new();
+    if !integrity.is_empty() {
+        extra_data.insert(
+            "integrity".to_string(),
+            JsonValue::String(integrity.trim_matches('"').to_string()),
+        );
+    }
+    if requirements.len() > 1 {
+        extra_data.insert("requirements".to_string(), JsonValue::from(requirements));
+    }
+    (!extra_data.is_empty()).then_some(extra_data)
+}
+
+fn yarn_v2_dependency_extra_data(
+    resolution: String,
+    checksum: Option<String>,
+    spec: &str,
+) -> HashMap<String, JsonValue> {
+    let mut extra_data = HashMap::from([("resolution".to_string(), JsonValue::String(resolution))]);
+    if let Some(checksum) = checksum {
+        extra_data.insert("checksum".to_string(), JsonValue::String(checksum));
+    }
+    let requirements: Vec<String> = split_requirement_keys(spec)
+        .into_iter()
+        .filter_map(|key| split_yarn_descriptor(key).map(|(_, range)| range.to_string()))
+        .collect();
+    if requirements.len() > 1 {
+        extra_data.insert("requirements".to_string(), JsonValue::from(requirements));
+    }
+    extra_data
+}
+
+/// Split a lockfile entry key into its requirement descriptors:
+/// `"rimraf@2", "rimraf@~2.5.1"` -> `["rimraf@2", "rimraf@~2.5.1"]`.
+fn split_requirement_keys(line: &str) -> Vec<&str> {
+    line.split(',')
+        .map(|key| key.trim().trim_matches('"'))
+        .filter(|key| !key.is_empty())
+        .collect()
+}
+
+/// Split a yarn descriptor at the `@` that ends the (possibly scoped) name:
+/// `@babel/core@npm:^7.1.0` -> `("@babel/core", "npm:^7.1.0")`.
+fn split_yarn_descriptor(descriptor: &str) -> Option<(&str, &str)> {
+    let name_start = usize::from(descriptor.starts_with('@'));
+    let at_pos = name_start + descriptor[name_start..].find('@')?;
+    Some((&descriptor[..at_pos], &descriptor[at_pos + 1..]))
+}
+
 fn full_package_name(namespace: &str, name: &str) -> String {
     if namespace.is_empty() {
         name.to_string()
@@ -593,13 +653,11 @@ fn insert_manifest_dependency_info(
 
 /// Parse yarn v1 requirement line: "express@^4.0.0" or "@babel/core@^7.1.0"
 pub fn parse_yarn_v1_requirement(line: &str) -> (String, String, String) {
-    // Handle multiple aliases: "rimraf@2, rimraf@~2.5.1"
-    if line.contains(", ") {
-        // Use the first part for parsing
-        let first_part = line.split(", ").next().unwrap_or(line);
-        return parse_single_yarn_v1_requirement(first_part);
+    // Handle multiple aliases: "rimraf@2, rimraf@~2.5.1"; the first one names the package
+    match split_requirement_keys(line).first() {
+        Some(first_key) => parse_single_yarn_v1_requirement(first_key),
+        None => parse_single_yarn_v1_requirement(line),
     }
-    parse_single_yarn_v1_requirement(line)
 }
 
 /// Parse a single yarn v1 requirement
@@ -159,6 +159,83 @@ __metadata:
         assert!(requirement.contains("2") || requirement.contains("~"));
     }
 
+    #[test]
+    fn test_parse_v1_scoped_aliases_keep_every_requirement_and_integrity() {
+        let content = r#"# THIS IS AN AUTOGENERATED FILE
+# yarn lockfile v1
+
+"@babel/core@^7.0.0", "@babel/core@^7.1.0":
+  version "7.1.2"
+  resolved "https://registry.yarnpkg.com/@babel/core/-/core-7.1.2.tgz#f8d2a9ceb6832887329a7b60f9d035791400ba4e"
+  integrity sha1-+NKpzraDKIcymntg+dA1eRQAuk4=
+"#;
+
+        let (_temp_dir, path) = create_temp_lock_file(content);
+        let package_data = YarnLockParser::extract_first_package(&path);
+
+        assert_eq!(package_data.dependencies.len(), 1);
+        let dep = &package_data.dependencies[0];
+        assert_eq!(dep.purl.as_deref(), Some("pkg:npm/%40babel/core@7.1.2"));
+        assert_eq!(dep.extracted_requirement.as_deref(), Some("^7.0.0"));
+        assert_eq!(dep.is_pinned, Some(true));
+
+        let extra = dep.extra_data.as_ref().expect("dependency extra_data");
+        assert_eq!(
+            extra.get("requirements"),
+            Some(&serde_json::json!(["^7.0.0", "^7.1.0"]))
+        );
+        assert_eq!(
+            extra.get("integrity"),
+            Some(&serde_json::json!("sha1-+NKpzraDKIcymntg+dA1eRQAuk4="))
+        );
+
+        let resolved = dep.resolved_package.as_ref().expect("resolved package");
+        assert_eq!(
+            resolved.download_url.as_deref(),
+            Some(
+                "https://registry.yarnpkg.com/@babel/core/-/core-7.1.2.tgz#f8d2a9ceb6832887329a7b60f9d035791400ba4e"
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_v2_aliases_keep_every_requirement_and_checksum() {
+        let content = r#"__metadata:
+  version: 6
+
+"@babel/core@npm:^7.0.0, @babel/core@npm:^7.1.0":
+  version: 7.1.2
+  resolution: "@babel/core@npm:7.1.2"
+  checksum: 4f7d5c3e0a
+  languageName: node
+  linkType: hard
+"#;
+
+        let (_temp_dir, path) = create_temp_lock_file(content);
+        let package_data = YarnLockParser::extract_first_package(&path);
+
+        assert_eq!(package_data.dependencies.len(), 1);
+        let dep = &package_data.dependencies[0];
+        assert_eq!(dep.purl.as_deref(), Some("pkg:npm/%40babel/core@7.1.2"));
+        assert_eq!(dep.is_pinned, Some(true));
+
+        let extra = dep.extra_data.as_ref().expect("dependency extra_data");
+        assert_eq!(
+            extra.get("requirements"),
+            Some(&serde_json::json!(["npm:^7.0.0", "npm:^7.1.0"]))
+        );
+        assert_eq!(
+            extra.get("checksum"),
+            Some(&serde_json::json!("4f7d5c3e0a"))
+        );
+        assert_eq!(
+            dep.resolved_package
+                .as_ref()
+                .and_then(|resolved| resolved.sha512.as_deref()),
+            Some("4f7d5c3e0a")
+        );
+    }
+
     #[test]
     fn test_v1_peer_dependencies_not_distinguished() {
         let content = r#"# THIS IS AN AUTOGENERATED FILE
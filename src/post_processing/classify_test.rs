@@ -31,6 +31,7 @@ fn classify_key_files_marks_nested_ruby_license_from_file_references() {
         copyright: "Copyright (c) 2019 Chef Software Inc.".to_string(),
         start_line: 1,
         end_line: 1,
+        ..Default::default()
     }];
     license_file.holders = vec![Holder {
         holder: "Chef Software Inc.".to_string(),
@@ -56,9 +57,11 @@ fn classify_key_files_marks_nested_ruby_license_from_file_references() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
 
     let mut files = vec![metadata_file, license_file];
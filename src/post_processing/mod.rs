@@ -1,4 +1,4 @@
-use std::collections::{BTreeSet, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::env;
 use std::path::{Path, PathBuf};
 
@@ -17,12 +17,13 @@ use crate::license_detection::expression::{
     simplify_expression,
 };
 use crate::license_detection::index::LicenseIndex;
+use crate::license_detection::models::MatcherKind;
 use crate::license_detection::spdx_mapping::build_spdx_mapping;
 use crate::models::{
     DatasourceId, ExtraData, FacetTallies, FileInfo, FileType, Header, LicenseClarityScore,
     LicenseDetection, LicenseReference, LicenseRuleReference, Match, OUTPUT_FORMAT_VERSION, Output,
-    Package, PackageData, Summary, SystemEnvironment, Tallies, TallyEntry,
-    TopLevelLicenseDetection,
+    Package, PackageData, RuleHitCount, RuleStats, Summary, SystemEnvironment, Tallies, TallyEntry,
+    TopLevelLicenseDetection, UnknownLicenseText,
 };
 
 const SCANCODE_LICENSE_URL_BASE: &str =
@@ -35,9 +36,11 @@ const DETECTION_LOG_UNKNOWN_REFERENCE_IN_FILE_TO_PACKAGE: &str =
 const DETECTION_LOG_UNKNOWN_REFERENCE_IN_FILE_TO_NONEXISTENT_PACKAGE: &str =
     "unknown-reference-in-file-to-nonexistent-package";
 use crate::scanner;
+use crate::utils::file::{REUSE_LICENSES_DIR, find_reuse_license_file, is_legal_file_name};
 #[cfg(test)]
 use crate::utils::generated::generated_code_hints;
-use crate::utils::spdx::combine_license_expressions;
+use crate::utils::hash::calculate_sha1;
+use crate::utils::spdx::{combine_license_expressions, custom_license_refs, reuse_license_ids};
 
 #[cfg(test)]
 mod classify_test;
@@ -54,7 +57,7 @@ mod summary_test;
 #[cfg(test)]
 mod tallies_test;
 #[cfg(test)]
-mod test_utils;
+pub(crate) mod test_utils;
 
 pub(crate) struct CreateOutputOptions<'a> {
     pub(crate) facet_rules: &'a [FacetRule],
@@ -66,6 +69,9 @@ pub(crate) struct CreateOutputOptions<'a> {
     pub(crate) include_tallies_with_details: bool,
     pub(crate) include_tallies_by_facet: bool,
     pub(crate) include_generated: bool,
+    pub(crate) include_summary_match_stats: bool,
+    pub(crate) include_primary_license: bool,
+    pub(crate) include_system_environment: bool,
 }
 
 pub(crate) struct CreateOutputContext<'a> {
@@ -119,17 +125,21 @@ pub(crate) fn create_output(
         files_count: scan_result.files.len(),
         directories_count: context.total_dirs,
         excluded_count: scan_result.excluded_count,
-        system_environment: SystemEnvironment {
-            operating_system: sys_info::os_type().ok(),
-            cpu_architecture: env::consts::ARCH.to_string(),
-            platform: format!(
-                "{}-{}-{}",
-                sys_info::os_type().unwrap_or_else(|_| "unknown".to_string()),
-                sys_info::os_release().unwrap_or_else(|_| "unknown".to_string()),
-                env::consts::ARCH
-            ),
-            rust_version: rustc_version_runtime::version().to_string(),
-        },
+        system_environment: context
+            .options
+            .include_system_environment
+            .then(|| SystemEnvironment {
+                operating_system: sys_info::os_type().ok(),
+                cpu_architecture: env::consts::ARCH.to_string(),
+                platform: format!(
+                    "{}-{}-{}",
+                    sys_info::os_type().unwrap_or_else(|_| "unknown".to_string()),
+                    sys_info::os_release().unwrap_or_else(|_| "unknown".to_string()),
+                    env::consts::ARCH
+                ),
+                rust_version: rustc_version_runtime::version().to_string(),
+            }),
+        spdx_license_list_version: None,
     };
 
     let errors: Vec<String> = scan_result
@@ -185,7 +195,7 @@ pub(crate) fn create_output(
     } else {
         clear_resource_tallies(&mut files);
     }
-    let summary =
+    let mut summary =
         if context.options.include_summary || context.options.include_license_clarity_score {
             compute_summary_with_options(
                 &files,
@@ -197,6 +207,20 @@ pub(crate) fn create_output(
         } else {
             None
         };
+    if context.options.include_summary_match_stats
+        && let Some(summary) = summary.as_mut()
+    {
+        summary.match_coverage_histogram = compute_match_coverage_histogram(&files);
+        summary.matcher_counts = compute_matcher_counts(&files);
+    }
+    if context.options.include_primary_license
+        && let Some(summary) = summary.as_mut()
+    {
+        (
+            summary.primary_license_expression,
+            summary.primary_license_expression_spdx,
+        ) = compute_primary_license_expression(&files, &packages, &output_indexes);
+    }
     let tallies = if context.options.include_tallies || context.options.include_tallies_with_details
     {
         compute_tallies(&files)
@@ -236,6 +260,8 @@ pub(crate) fn create_output(
         files,
         license_references: context.license_references,
         license_rule_references: context.license_rule_references,
+        unknown_license_texts: vec![],
+        sbom_drift: None,
     }
 }
 
@@ -636,6 +662,50 @@ pub(crate) fn apply_package_reference_following(files: &mut [FileInfo], packages
     }
 }
 
+/// Record, as a clue on each referencing file, the license detected in the
+/// sibling files its `unknown-reference-to-local-file` detections point to
+/// (e.g. "see LICENSE"). Unlike reference following, the file's own detections
+/// are left untouched, and detections of other categories are ignored even when
+/// a match mentions a file name.
+pub(crate) fn annotate_local_license_references(files: &mut [FileInfo]) {
+    let expressions_by_path: HashMap<String, String> = files
+        .iter()
+        .filter(|file| file.file_type == FileType::File)
+        .filter_map(|file| {
+            combine_detection_expressions(&file.license_detections)
+                .map(|expression| (file.path.clone(), expression))
+        })
+        .collect();
+
+    for file in files
+        .iter_mut()
+        .filter(|file| file.file_type == FileType::File)
+    {
+        let Some(parent) = Path::new(&file.path).parent() else {
+            continue;
+        };
+        let parent = parent.to_string_lossy();
+
+        let mut referenced_filenames: Vec<String> = file
+            .license_detections
+            .iter()
+            .filter(|detection| is_unknown_reference_to_local_file(detection))
+            .flat_map(referenced_filenames_from_detection)
+            .collect();
+        referenced_filenames.sort();
+        referenced_filenames.dedup();
+
+        let referenced_expressions: Vec<String> = referenced_filenames
+            .iter()
+            .map(|name| join_reference_candidate(parent.as_ref(), name))
+            .filter(|candidate| candidate != &file.path)
+            .filter_map(|candidate| expressions_by_path.get(&candidate).cloned())
+            .collect();
+
+        file.referenced_license_expression = combine_license_expressions(referenced_expressions);
+    }
+}
+
 pub(crate) fn sync_packages_from_followed_package_data(
     files: &[FileInfo],
     packages: &mut [Package],
@@ -785,6 +855,14 @@ fn apply_resolved_reference_targets(
     internal_detection.detection_log = vec![detection_log.to_string()];
     let mut public_detection = internal_detection_to_public(internal_detection);
     public_detection.identifier = None;
+    // The concluded expression comes from the referenced detections, so it is a
+    // public-domain dedication only when all of them are.
+    public_detection.is_public_domain = detection.is_public_domain.map(|_| {
+        referenced_targets
+            .iter()
+            .flat_map(|target| target.detections.iter())
+            .all(|referenced| referenced.is_public_domain == Some(true))
+    });
     crate::models::file_info::enrich_license_detection_provenance(
         &mut public_detection,
         current_path,
@@ -810,6 +888,29 @@ fn referenced_filenames_from_detection(detection: &LicenseDetection) -> Vec<Stri
         .collect()
 }
 
+/// Whether a detection falls in the `unknown-reference-to-local-file` category.
+///
+/// The detection log is only kept with diagnostics enabled or once reference
+/// following resolved the detection; without it, only unknown license
+/// references that name a file are taken to be in that category.
+fn is_unknown_reference_to_local_file(detection: &LicenseDetection) -> bool {
+    if !detection.detection_log.is_empty() {
+        return detection
+            .detection_log
+            .iter()
+            .any(|entry| entry == DETECTION_LOG_UNKNOWN_REFERENCE_TO_LOCAL_FILE);
+    }
+    detection.matches.iter().any(|detection_match| {
+        detection_match
+            .license_expression
+            .contains("unknown-license-reference")
+            && detection_match
+                .referenced_filenames
+                .as_ref()
+                .is_some_and(|names| !names.is_empty())
+    })
+}
+
 fn inherits_license_from_package(detection: &LicenseDetection) -> bool {
     detection.matches.iter().any(|detection_match| {
         detection_match
@@ -1043,6 +1144,10 @@ fn internal_detection_to_public(
             .collect(),
         detection_log: detection.detection_log,
         identifier: detection.identifier,
+        is_public_domain: None,
+        license_facet: None,
+        component: None,
+        in_header: None,
     }
 }
 
@@ -1063,7 +1168,7 @@ fn public_match_to_internal(
             .matcher
             .as_deref()
             .and_then(|matcher| matcher.parse().ok())
-            .unwrap_or(crate::license_detection::models::MatcherKind::Hash),
+            .unwrap_or(MatcherKind::Hash),
         score: detection_match.score as f32,
         matched_length: detection_match.matched_length.unwrap_or_default(),
         rule_length: detection_match.matched_length.unwrap_or_default(),
@@ -1105,6 +1210,9 @@ fn internal_match_to_public(
         matched_text: detection_match.matched_text,
         referenced_filenames: detection_match.referenced_filenames,
         matched_text_diagnostics: None,
+        match_type: None,
+        language: None,
+        custom_license_files: Vec::new(),
     }
 }
 
@@ -1120,6 +1228,7 @@ pub(crate) fn collect_top_level_license_references(
 
     for file in files {
         collect_license_keys_from_expression(file.license_expression.as_deref(), &mut license_keys);
+        collect_license_keys_from_detections(&file.license_detections, &mut license_keys);
         collect_rule_identifiers_from_detections(&file.license_detections, &mut rule_identifiers);
         collect_rule_identifiers_from_matches(&file.license_clues, &mut rule_identifiers);
 
@@ -1238,6 +1347,157 @@ pub(crate) fn collect_top_level_license_references(
     (license_references, license_rule_references)
 }
 
+/// Record the REUSE `LICENSES/<id>.txt` file of each custom `LicenseRef-` license that a
+/// match names, and of each license an SPDX tag names, looking no higher than `scan_root`.
+///
+/// SPDX tags only reference a license, so the linked file backs them with its full text.
+///
+/// The files are looked up on disk relative to each file, so this must run before paths are
+/// normalized.
+pub(crate) fn link_custom_license_files(files: &mut [FileInfo], scan_root: &Path) {
+    for file in files {
+        let path = PathBuf::from(&file.path);
+        let matches = file
+            .license_detections
+            .iter_mut()
+            .flat_map(|detection| detection.matches.iter_mut());
+        for license_match in matches {
+            let license_ids =
+                if license_match.matcher.as_deref() == Some(MatcherKind::SpdxId.as_str()) {
+                    reuse_license_ids(&license_match.license_expression_spdx)
+                } else {
+                    custom_license_refs(&license_match.license_expression_spdx)
+                };
+            for license_ref in license_ids {
+                if find_reuse_license_file(&path, &license_ref, scan_root).is_none() {
+                    continue;
+                }
+                let license_file = format!("{REUSE_LICENSES_DIR}/{license_ref}.txt");
+                if !license_match.custom_license_files.contains(&license_file) {
+                    license_match.custom_license_files.push(license_file);
+                }
+            }
+        }
+    }
+}
+
+/// Build license references for custom `LicenseRef-` licenses backed by REUSE `LICENSES/` texts.
+///
+/// The texts are read from disk relative to each file, so this must run before paths are
+/// normalized.
+pub(crate) fn collect_custom_license_references(
+    files: &[FileInfo],
+    scan_root: &Path,
+) -> Vec<LicenseReference> {
+    let mut texts_by_ref: BTreeMap<String, String> = BTreeMap::new();
+
+    for file in files {
+        let matches = file
+            .license_detections
+            .iter()
+            .flat_map(|detection| detection.matches.iter());
+        for license_match in matches {
+            for license_ref in custom_license_refs(&license_match.license_expression_spdx) {
+                if texts_by_ref.contains_key(&license_ref) {
+                    continue;
+                }
+                if let Some(text) =
+                    find_reuse_license_file(Path::new(&file.path), &license_ref, scan_root)
+                        .and_then(|license_path| std::fs::read_to_string(license_path).ok())
+                {
+                    texts_by_ref.insert(license_ref, text);
+                }
+            }
+        }
+    }
+
+    texts_by_ref
+        .into_iter()
+        .map(|(license_ref, text)| LicenseReference {
+            key: Some(license_ref.clone()),
+            name: license_ref.clone(),
+            short_name: license_ref.clone(),
+            spdx_license_key: license_ref,
+            other_spdx_license_keys: vec![],
+            category: None,
+            notes: None,
+            minimum_coverage: None,
+            ignorable_copyrights: vec![],
+            ignorable_holders: vec![],
+            ignorable_authors: vec![],
+            ignorable_urls: vec![],
+            ignorable_emails: vec![],
+            scancode_url: None,
+            licensedb_url: None,
+            spdx_url: None,
+            text,
+        })
+        .collect()
+}
+
+/// Collect the texts of unknown and custom `LicenseRef-` license detections.
+///
+/// The text of each detection is the span of file lines its matches cover, read
+/// from disk, so this must run before paths are normalized. Identical texts are
+/// listed once, keyed by their SHA1, with every file they were found in.
+pub(crate) fn collect_unknown_license_texts(files: &[FileInfo]) -> Vec<UnknownLicenseText> {
+    let mut texts_by_sha1: BTreeMap<String, UnknownLicenseText> = BTreeMap::new();
+
+    for file in files {
+        let mut file_text: Option<Option<String>> = None;
+        for detection in file
+            .license_detections
+            .iter()
+            .filter(|detection| is_unknown_or_custom_detection(detection))
+        {
+            let Some(start_line) = detection.matches.iter().map(|m| m.start_line).min() else {
+                continue;
+            };
+            let end_line = detection
+                .matches
+                .iter()
+                .map(|m| m.end_line)
+                .max()
+                .unwrap_or(start_line);
+            let Some(content) = file_text
+                .get_or_insert_with(|| std::fs::read_to_string(&file.path).ok())
+                .as_deref()
+            else {
+                break;
+            };
+            let text = content
+                .lines()
+                .skip(start_line.saturating_sub(1))
+                .take(end_line + 1 - start_line.max(1))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let text = text.trim();
+            if text.is_empty() {
+                continue;
+            }
+
+            let entry = texts_by_sha1
+                .entry(calculate_sha1(text.as_bytes()))
+                .or_insert_with_key(|sha1| UnknownLicenseText {
+                    sha1: sha1.clone(),
+                    license_expression: detection.license_expression.clone(),
+                    text: text.to_string(),
+                    paths: Vec::new(),
+                });
+            if !entry.paths.contains(&file.path) {
+                entry.paths.push(file.path.clone());
+            }
+        }
+    }
+
+    texts_by_sha1.into_values().collect()
+}
+
+fn is_unknown_or_custom_detection(detection: &LicenseDetection) -> bool {
+    detection.license_expression.contains("unknown")
+        || !custom_license_refs(&detection.license_expression_spdx).is_empty()
+}
+
 fn collect_license_keys_from_package_data(
     package_data: &PackageData,
     license_keys: &mut BTreeSet<String>,
@@ -1278,6 +1538,66 @@ fn collect_license_keys_from_expression(
     }
 }
 
+/// Count how often each rule of `license_index` matched across `files`.
+///
+/// Matches merged in from another file by reference following are counted
+/// only on the file they were found in.
+pub(crate) fn collect_rule_stats(files: &[FileInfo], license_index: &LicenseIndex) -> RuleStats {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+
+    for file in files {
+        let own_matches = file
+            .license_detections
+            .iter()
+            .chain(file.package_data.iter().flat_map(|package_data| {
+                package_data
+                    .license_detections
+                    .iter()
+                    .chain(&package_data.other_license_detections)
+            }))
+            .flat_map(|detection| &detection.matches)
+            .chain(&file.license_clues)
+            .filter(|license_match| {
+                license_match
+                    .from_file
+                    .as_deref()
+                    .is_none_or(|from_file| from_file == file.path)
+            });
+        for license_match in own_matches {
+            if let Some(rule_identifier) = license_match.rule_identifier.as_deref() {
+                *counts.entry(rule_identifier).or_default() += 1;
+            }
+        }
+    }
+
+    let mut rules = Vec::new();
+    let mut never_fired_rules = Vec::new();
+    for rule in &license_index.rules_by_rid {
+        match counts.get(rule.identifier.as_str()) {
+            Some(&count) => rules.push(RuleHitCount {
+                identifier: rule.identifier.clone(),
+                license_expression: rule.license_expression.clone(),
+                count,
+            }),
+            None => never_fired_rules.push(rule.identifier.clone()),
+        }
+    }
+    rules.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then_with(|| a.identifier.cmp(&b.identifier))
+    });
+    never_fired_rules.sort();
+
+    RuleStats {
+        total_rules_count: license_index.rules_by_rid.len(),
+        fired_rules_count: rules.len(),
+        never_fired_rules_count: never_fired_rules.len(),
+        rules,
+        never_fired_rules,
+    }
+}
+
 fn collect_rule_identifiers_from_detections(
     detections: &[LicenseDetection],
     rule_identifiers: &mut BTreeSet<String>,
@@ -1583,25 +1903,6 @@ fn build_package_file_reference_map(files: &[FileInfo]) -> HashMap<String, HashS
     mapping
 }
 
-const LEGAL_STARTS_ENDS: &[&str] = &[
-    "copying",
-    "copyright",
-    "copyrights",
-    "copyleft",
-    "notice",
-    "license",
-    "licenses",
-    "licence",
-    "licences",
-    "licensing",
-    "licencing",
-    "legal",
-    "eula",
-    "agreement",
-    "patent",
-    "patents",
-];
-
 const MANIFEST_ENDS: &[&str] = &[
     ".about",
     "/bower.json",
@@ -1649,7 +1950,7 @@ fn name_or_base_name_matches(file: &FileInfo, patterns: &[&str]) -> bool {
 }
 
 fn is_legal_file(file: &FileInfo) -> bool {
-    name_or_base_name_matches(file, LEGAL_STARTS_ENDS)
+    is_legal_file_name(&file.name, &file.base_name)
 }
 
 fn is_manifest_file(path: &str) -> bool {
@@ -1813,24 +2114,29 @@ fn promote_package_metadata_from_key_files(
             continue;
         };
 
+        // A dedicated COPYRIGHT file states the package's copyright on purpose, so its
+        // statements win over ones that merely appear in a LICENSE or README.
+        let (copyright_files, other_key_files): (Vec<&FileInfo>, Vec<&FileInfo>) = key_file_indices
+            .iter()
+            .filter_map(|index| files.get(*index))
+            .partition(|file| is_copyright_file(file));
+
         if package.copyright.is_none() {
-            package.copyright = key_file_indices
+            package.copyright = copyright_files
                 .iter()
-                .filter_map(|index| files.get(*index))
+                .chain(other_key_files.iter())
                 .flat_map(|file| file.copyrights.iter())
                 .map(|copyright| copyright.copyright.clone())
                 .next();
         }
 
         if package.holder.is_none() {
-            let promoted_holders = unique(
-                &key_file_indices
-                    .iter()
-                    .filter_map(|index| files.get(*index))
-                    .flat_map(|file| file.holders.iter())
-                    .map(|holder| holder.holder.clone())
-                    .collect::<Vec<_>>(),
-            );
+            let copyright_file_holders = unique_key_file_holders(&copyright_files);
+            let promoted_holders = if copyright_file_holders.is_empty() {
+                unique_key_file_holders(&other_key_files)
+            } else {
+                copyright_file_holders
+            };
             if promoted_holders.len() == 1 {
                 package.holder = promoted_holders.into_iter().next();
             }
@@ -1838,6 +2144,20 @@ fn promote_package_metadata_from_key_files(
     }
 }
 
+fn unique_key_file_holders(key_files: &[&FileInfo]) -> Vec<String> {
+    unique(
+        &key_files
+            .iter()
+            .flat_map(|file| file.holders.iter())
+            .map(|holder| holder.holder.clone())
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn is_copyright_file(file: &FileInfo) -> bool {
+    name_or_base_name_matches(file, &["copyright"])
+}
+
 #[cfg(test)]
 fn compute_summary(files: &[FileInfo], packages: &[Package]) -> Option<Summary> {
     let indexes = build_output_indexes(files, None, false);
@@ -1969,9 +2289,180 @@ fn compute_summary_with_options(
         } else {
             vec![]
         },
+        match_coverage_histogram: vec![],
+        matcher_counts: vec![],
+        copyright_only_files: include_summary_fields
+            .then(|| count_copyright_only_files(files))
+            .flatten(),
+        primary_license_expression: None,
+        primary_license_expression_spdx: None,
+        scan_risk_label: None,
     })
 }
 
+/// Risk labels from most to least restrictive, each with the license index
+/// categories that map to it.
+const RISK_LABEL_CATEGORIES: &[(&str, &[&str])] = &[
+    ("Strong Copyleft", &["Copyleft"]),
+    ("Weak Copyleft", &["Copyleft Limited"]),
+    ("Permissive", &["Permissive", "Public Domain"]),
+];
+
+/// Single risk label for the whole scan: the most restrictive of the categories
+/// of every license found in files and packages, or `Unknown` when none of
+/// them is copyleft or permissive.
+pub(crate) fn compute_scan_risk_label(
+    files: &[FileInfo],
+    packages: &[Package],
+    license_index: &LicenseIndex,
+) -> String {
+    let mut license_keys = BTreeSet::new();
+    for file in files {
+        collect_license_keys_from_expression(file.license_expression.as_deref(), &mut license_keys);
+        collect_license_keys_from_detections(&file.license_detections, &mut license_keys);
+        for package_data in &file.package_data {
+            collect_license_keys_from_package_data(package_data, &mut license_keys);
+        }
+    }
+    for package in packages {
+        collect_license_keys_from_expression(
+            package.declared_license_expression.as_deref(),
+            &mut license_keys,
+        );
+        collect_license_keys_from_detections(&package.license_detections, &mut license_keys);
+    }
+
+    let categories: HashSet<&str> = license_keys
+        .iter()
+        .filter_map(|key| license_index.licenses_by_key.get(key))
+        .filter_map(|license| license.category.as_deref())
+        .collect();
+
+    RISK_LABEL_CATEGORIES
+        .iter()
+        .find(|(_, label_categories)| {
+            label_categories
+                .iter()
+                .any(|category| categories.contains(category))
+        })
+        .map_or("Unknown", |(label, _)| label)
+        .to_string()
+}
+
+/// Concluded license of the scan as a whole, as `(expression, spdx_expression)`.
+///
+/// This is the declared license of the root package when the scan has exactly
+/// one, and otherwise the combined licenses of the legal files at the top of the
+/// scan.
+fn compute_primary_license_expression(
+    files: &[FileInfo],
+    packages: &[Package],
+    indexes: &OutputIndexes,
+) -> (Option<String>, Option<String>) {
+    let root_package_uids = top_level_package_uids(packages, files, indexes);
+    let mut root_packages = packages
+        .iter()
+        .filter(|package| root_package_uids.contains(&package.package_uid));
+    if let (Some(root_package), None) = (root_packages.next(), root_packages.next())
+        && root_package.declared_license_expression.is_some()
+    {
+        return (
+            root_package.declared_license_expression.clone(),
+            root_package.declared_license_expression_spdx.clone(),
+        );
+    }
+
+    let top_level_detections: Vec<&LicenseDetection> = files
+        .iter()
+        .filter(|file| file.file_type == FileType::File && file.is_top_level && file.is_legal)
+        .flat_map(|file| file.license_detections.iter())
+        .collect();
+    let expressions = unique(
+        &top_level_detections
+            .iter()
+            .map(|detection| detection.license_expression.clone())
+            .collect::<Vec<_>>(),
+    );
+    let spdx_expressions = unique(
+        &top_level_detections
+            .iter()
+            .map(|detection| detection.license_expression_spdx.clone())
+            .collect::<Vec<_>>(),
+    );
+
+    (
+        combine_license_expressions(expressions),
+        combine_license_expressions(spdx_expressions),
+    )
+}
+
+/// Count files with copyright notices but no license, if any were flagged.
+fn count_copyright_only_files(files: &[FileInfo]) -> Option<usize> {
+    let count = files
+        .iter()
+        .filter(|file| file.has_copyright_no_license)
+        .count();
+    (count > 0).then_some(count)
+}
+
+const MATCH_COVERAGE_BUCKETS: [(f64, &str); 5] = [
+    (20.0, "0-20"),
+    (40.0, "20-40"),
+    (60.0, "40-60"),
+    (80.0, "60-80"),
+    (100.0, "80-100"),
+];
+
+fn file_license_matches(files: &[FileInfo]) -> impl Iterator<Item = &Match> {
+    files
+        .iter()
+        .flat_map(|file| file.license_detections.iter())
+        .flat_map(|detection| detection.matches.iter())
+}
+
+/// Bucket every file-level license match by coverage: `[0, 20)`, ..., `[80, 100)` and `100`.
+fn compute_match_coverage_histogram(files: &[FileInfo]) -> Vec<TallyEntry> {
+    let mut counts = [0usize; MATCH_COVERAGE_BUCKETS.len() + 1];
+    for license_match in file_license_matches(files) {
+        let coverage = license_match.match_coverage.unwrap_or(0.0);
+        let bucket = MATCH_COVERAGE_BUCKETS
+            .iter()
+            .position(|(upper, _)| coverage < *upper)
+            .unwrap_or(MATCH_COVERAGE_BUCKETS.len());
+        counts[bucket] += 1;
+    }
+
+    if counts.iter().all(|count| *count == 0) {
+        return Vec::new();
+    }
+
+    MATCH_COVERAGE_BUCKETS
+        .iter()
+        .map(|(_, label)| *label)
+        .chain(std::iter::once("100"))
+        .zip(counts)
+        .map(|(label, count)| TallyEntry {
+            value: Some(label.to_string()),
+            count,
+        })
+        .collect()
+}
+
+/// Count file-level license matches per matcher, most frequent first.
+fn compute_matcher_counts(files: &[FileInfo]) -> Vec<TallyEntry> {
+    let mut counts: HashMap<Option<String>, usize> = HashMap::new();
+    for license_match in file_license_matches(files) {
+        *counts.entry(license_match.matcher.clone()).or_insert(0) += 1;
+    }
+
+    let mut entries: Vec<TallyEntry> = counts
+        .into_iter()
+        .map(|(value, count)| TallyEntry { value, count })
+        .collect();
+    entries.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value)));
+    entries
+}
+
 fn materialize_generated_flags(files: &mut [FileInfo]) {
     for file in files.iter_mut() {
         if file.file_type != FileType::File {
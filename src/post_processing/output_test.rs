@@ -119,9 +119,11 @@ fn collect_top_level_license_references_includes_clues_packages_and_sorted_dedup
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
     source.license_clues = vec![Match {
         license_expression: "unknown-license-reference".to_string(),
@@ -139,6 +141,7 @@ fn collect_top_level_license_references_includes_clues_packages_and_sorted_dedup
         matched_text: None,
         referenced_filenames: None,
         matched_text_diagnostics: None,
+        ..Default::default()
     }];
     source.package_data = vec![PackageData {
         package_type: Some(PackageType::Npm),
@@ -182,6 +185,305 @@ fn collect_top_level_license_references_includes_clues_packages_and_sorted_dedup
     assert_eq!(license_rule_references[0].relevance, Some(100));
 }
 
+#[test]
+fn collect_rule_stats_counts_rule_hits_and_never_fired_rules() {
+    use crate::progress::{ProgressMode, ScanProgress};
+    use crate::scanner::{
+        LicenseScanOptions, TextDetectionOptions, collect_paths, process_collected,
+    };
+
+    let mit_text = std::fs::read_to_string(
+        "testdata/summarycode-golden/summary/license_ambiguity/unambiguous/mit.LICENSE",
+    )
+    .expect("read MIT fixture");
+    let temp_dir = tempfile::tempdir().expect("create temp dir");
+    std::fs::write(temp_dir.path().join("LICENSE"), &mit_text).expect("write LICENSE");
+    std::fs::write(temp_dir.path().join("COPYING"), &mit_text).expect("write COPYING");
+
+    let engine = super::test_utils::test_license_engine();
+    let collected = collect_paths(temp_dir.path(), 0, &[]);
+    let files = process_collected(
+        &collected,
+        std::sync::Arc::new(ScanProgress::new(ProgressMode::Quiet)),
+        Some(engine.clone()),
+        LicenseScanOptions::default(),
+        &TextDetectionOptions::default(),
+    )
+    .files;
+
+    let license_matches: Vec<_> = files
+        .iter()
+        .find(|file| file.path.ends_with("LICENSE"))
+        .map(|file| {
+            file.license_detections
+                .iter()
+                .flat_map(|detection| &detection.matches)
+                .collect()
+        })
+        .unwrap_or_default();
+    let mit_rule = license_matches
+        .iter()
+        .find(|license_match| license_match.license_expression == "mit")
+        .and_then(|license_match| license_match.rule_identifier.clone())
+        .expect("LICENSE should have an MIT match");
+    let hits_per_file = license_matches
+        .iter()
+        .filter(|license_match| license_match.rule_identifier.as_ref() == Some(&mit_rule))
+        .count();
+
+    let stats = collect_rule_stats(&files, engine.index());
+
+    let mit_hits = stats
+        .rules
+        .iter()
+        .find(|rule| rule.identifier == mit_rule)
+        .expect("MIT rule should be listed as fired");
+    assert_eq!(mit_hits.count, 2 * hits_per_file);
+    assert_eq!(mit_hits.license_expression, "mit");
+    assert_eq!(stats.fired_rules_count, stats.rules.len());
+    assert_eq!(stats.never_fired_rules_count, stats.never_fired_rules.len());
+    assert!(stats.never_fired_rules_count > 0);
+    assert_eq!(
+        stats.fired_rules_count + stats.never_fired_rules_count,
+        stats.total_rules_count
+    );
+}
+
+#[test]
+fn scan_risk_label_is_the_most_restrictive_license_category() {
+    use crate::progress::{ProgressMode, ScanProgress};
+    use crate::scanner::{
+        LicenseScanOptions, TextDetectionOptions, collect_paths, process_collected,
+    };
+
+    let temp_dir = tempfile::tempdir().expect("create temp dir");
+    std::fs::write(
+        temp_dir.path().join("main.c"),
+        "/* SPDX-License-Identifier: MIT */\nint main(void) { return 0; }\n",
+    )
+    .expect("write MIT source file");
+    std::fs::write(
+        temp_dir.path().join("util.c"),
+        "/* SPDX-License-Identifier: GPL-2.0-only */\nint util(void) { return 1; }\n",
+    )
+    .expect("write GPL source file");
+
+    let engine = super::test_utils::test_license_engine();
+    let collected = collect_paths(temp_dir.path(), 0, &[]);
+    let files = process_collected(
+        &collected,
+        std::sync::Arc::new(ScanProgress::new(ProgressMode::Quiet)),
+        Some(engine.clone()),
+        LicenseScanOptions::default(),
+        &TextDetectionOptions::default(),
+    )
+    .files;
+
+    assert_eq!(
+        compute_scan_risk_label(&files, &[], engine.index()),
+        "Strong Copyleft"
+    );
+    let mit_only: Vec<_> = files
+        .into_iter()
+        .filter(|file| file.path.ends_with("main.c"))
+        .collect();
+    assert_eq!(
+        compute_scan_risk_label(&mit_only, &[], engine.index()),
+        "Permissive"
+    );
+    assert_eq!(compute_scan_risk_label(&[], &[], engine.index()), "Unknown");
+}
+
+#[test]
+fn license_text_from_selects_source_or_rule_matched_text() {
+    use crate::progress::{ProgressMode, ScanProgress};
+    use crate::scanner::{
+        LicenseScanOptions, MatchedTextSource, TextDetectionOptions, collect_paths,
+        process_collected,
+    };
+
+    let temp_dir = tempfile::tempdir().expect("create temp dir");
+    std::fs::write(
+        temp_dir.path().join("main.c"),
+        "/* SPDX-License-Identifier: MIT */\nint main(void) { return 0; }\n",
+    )
+    .expect("write source file");
+
+    let engine = super::test_utils::test_license_engine();
+    let collected = collect_paths(temp_dir.path(), 0, &[]);
+    let mit_match = |matched_text_source| {
+        let files = process_collected(
+            &collected,
+            std::sync::Arc::new(ScanProgress::new(ProgressMode::Quiet)),
+            Some(engine.clone()),
+            LicenseScanOptions {
+                include_text: true,
+                matched_text_source,
+                ..LicenseScanOptions::default()
+            },
+            &TextDetectionOptions::default(),
+        )
+        .files;
+        files
+            .into_iter()
+            .flat_map(|file| file.license_detections)
+            .flat_map(|detection| detection.matches)
+            .find(|license_match| license_match.license_expression == "mit")
+            .expect("main.c should have an MIT match")
+    };
+
+    let source = mit_match(MatchedTextSource::Source);
+    assert!(
+        source
+            .matched_text
+            .as_deref()
+            .is_some_and(|text| text.contains("SPDX-License-Identifier: MIT"))
+    );
+
+    let rule = mit_match(MatchedTextSource::Rule);
+    let rule_identifier = rule.rule_identifier.as_deref().expect("rule identifier");
+    let rule_text = engine
+        .index()
+        .rules_by_rid
+        .iter()
+        .find(|candidate| candidate.identifier == rule_identifier)
+        .map(|candidate| candidate.text.as_str());
+    assert!(rule_text.is_some());
+    assert_eq!(rule.matched_text.as_deref(), rule_text);
+    assert_ne!(rule.matched_text, source.matched_text);
+}
+
+#[test]
+fn apply_package_reference_following_concludes_each_license_in_concatenated_copying() {
+    use crate::progress::{ProgressMode, ScanProgress};
+    use crate::scanner::{
+        LicenseScanOptions, TextDetectionOptions, collect_paths, process_collected,
+    };
+
+    let read_fixture = |path: &str| std::fs::read_to_string(path).expect("read license fixture");
+    let copying = format!(
+        "{}\n{}\n{}",
+        read_fixture(
+            "testdata/summarycode-golden/summary/license_ambiguity/unambiguous/mit.LICENSE"
+        )
+        .trim_end(),
+        "-".repeat(72),
+        read_fixture("testdata/license-golden/datadriven/lic4/gpl-3.0.txt"),
+    );
+    let temp_dir = tempfile::tempdir().expect("create temp dir");
+    std::fs::write(temp_dir.path().join("COPYING"), copying).expect("write COPYING");
+
+    let engine = super::test_utils::test_license_engine();
+    let collected = collect_paths(temp_dir.path(), 0, &[]);
+    let mut files = process_collected(
+        &collected,
+        std::sync::Arc::new(ScanProgress::new(ProgressMode::Quiet)),
+        Some(engine),
+        LicenseScanOptions::default(),
+        &TextDetectionOptions::default(),
+    )
+    .files;
+
+    let copying_file = files
+        .iter()
+        .find(|file| file.path.ends_with("COPYING"))
+        .expect("COPYING should be scanned");
+    let expressions: Vec<_> = copying_file
+        .license_detections
+        .iter()
+        .map(|detection| detection.license_expression.as_str())
+        .collect();
+    assert_eq!(expressions, vec!["mit", "gpl-3.0"]);
+
+    let project_dir = std::path::Path::new(&copying_file.path)
+        .parent()
+        .expect("COPYING should have a parent")
+        .to_string_lossy()
+        .into_owned();
+    let manifest_path = format!("{project_dir}/Cargo.toml");
+    let package_uid = "pkg:cargo/demo?uuid=test".to_string();
+    let mut package = super::test_utils::package(&package_uid, &manifest_path);
+    package.datafile_paths = vec![manifest_path.clone()];
+    package.license_detections = vec![crate::models::LicenseDetection {
+        license_expression: "unknown-license-reference".to_string(),
+        license_expression_spdx: "LicenseRef-scancode-unknown-license-reference".to_string(),
+        matches: vec![Match {
+            license_expression: "unknown-license-reference".to_string(),
+            license_expression_spdx: "LicenseRef-scancode-unknown-license-reference".to_string(),
+            from_file: Some(manifest_path.clone()),
+            start_line: 5,
+            end_line: 5,
+            matcher: Some("parser-declared-license".to_string()),
+            score: 100.0,
+            matched_length: Some(3),
+            match_coverage: Some(100.0),
+            rule_relevance: Some(100),
+            rule_identifier: None,
+            rule_url: None,
+            matched_text: Some("see COPYING".to_string()),
+            referenced_filenames: Some(vec!["COPYING".to_string()]),
+            matched_text_diagnostics: None,
+            ..Default::default()
+        }],
+        detection_log: vec![],
+        identifier: Some("unknown-ref".to_string()),
+        ..Default::default()
+    }];
+
+    let mut manifest = file(&manifest_path);
+    manifest.for_packages = vec![package_uid];
+    manifest.package_data = vec![PackageData {
+        package_type: Some(PackageType::Cargo),
+        license_detections: package.license_detections.clone(),
+        ..Default::default()
+    }];
+    files.push(manifest);
+
+    let mut packages = vec![package];
+    apply_package_reference_following(&mut files, &mut packages);
+
+    assert_eq!(
+        packages[0].declared_license_expression.as_deref(),
+        Some("mit AND gpl-3.0")
+    );
+}
+
+#[test]
+fn collect_top_level_license_references_includes_keys_of_file_detections() {
+    let mut license_index = LicenseIndex::default();
+    for license in [
+        sample_runtime_license("mit", "MIT License", Some("MIT")),
+        sample_runtime_license("bsd-new", "BSD 3-Clause", Some("BSD-3-Clause")),
+    ] {
+        license_index
+            .licenses_by_key
+            .insert(license.key.clone(), license);
+    }
+    let detection = |expression: &str, spdx: &str| crate::models::LicenseDetection {
+        license_expression: expression.to_string(),
+        license_expression_spdx: spdx.to_string(),
+        ..Default::default()
+    };
+    let mut first = file("project/a.c");
+    first.license_detections = vec![
+        detection("mit", "MIT"),
+        detection("bsd-new", "BSD-3-Clause"),
+    ];
+    let mut second = file("project/b.c");
+    second.license_detections = vec![detection("mit", "MIT")];
+
+    let (license_references, _) =
+        collect_top_level_license_references(&[first, second], &[], &license_index);
+
+    assert_eq!(
+        license_references
+            .iter()
+            .map(|reference| reference.key.as_deref())
+            .collect::<Vec<_>>(),
+        vec![Some("bsd-new"), Some("mit")]
+    );
+}
+
 #[test]
 fn collect_top_level_license_references_returns_empty_for_empty_inputs() {
     let license_index = LicenseIndex::default();
@@ -216,9 +518,11 @@ fn apply_local_file_reference_following_resolves_root_license_file() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         detection_log: vec![],
         identifier: Some("mit-license".to_string()),
+        ..Default::default()
     }];
 
     let mut notice = file("project/src/notice.js");
@@ -242,9 +546,11 @@ fn apply_local_file_reference_following_resolves_root_license_file() {
             matched_text: Some("See LICENSE".to_string()),
             referenced_filenames: Some(vec!["LICENSE".to_string()]),
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         detection_log: vec![],
         identifier: Some("unknown-ref".to_string()),
+        ..Default::default()
     }];
 
     let mut files = vec![dir("project"), license, notice];
@@ -290,9 +596,11 @@ fn apply_local_file_reference_following_requires_exact_filename_match() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         detection_log: vec![],
         identifier: Some("mit-license".to_string()),
+        ..Default::default()
     }];
 
     let mut notice = file("project/src/notice.js");
@@ -316,9 +624,11 @@ fn apply_local_file_reference_following_requires_exact_filename_match() {
             matched_text: Some("See LICENSE.txt".to_string()),
             referenced_filenames: Some(vec!["LICENSE.txt".to_string()]),
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         detection_log: vec![],
         identifier: Some("unknown-ref".to_string()),
+        ..Default::default()
     }];
 
     let mut files = vec![dir("project"), license, notice];
@@ -363,9 +673,11 @@ fn apply_local_file_reference_following_resolves_files_beside_manifest() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         detection_log: vec![],
         identifier: Some("mit-license".to_string()),
+        ..Default::default()
     }];
 
     let mut source = file("project/demo/__init__.py");
@@ -390,9 +702,11 @@ fn apply_local_file_reference_following_resolves_files_beside_manifest() {
             matched_text: Some("See LICENSE".to_string()),
             referenced_filenames: Some(vec!["LICENSE".to_string()]),
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         detection_log: vec![],
         identifier: Some("unknown-ref".to_string()),
+        ..Default::default()
     }];
 
     let mut files = vec![dir("project"), license, source];
@@ -410,6 +724,139 @@ fn apply_local_file_reference_following_resolves_files_beside_manifest() {
     );
 }
 
+#[test]
+fn annotate_local_license_references_surfaces_sibling_license_expression() {
+    let mut source = file("project/main.c");
+    source.license_expression = Some("unknown-license-reference".to_string());
+    source.license_detections = vec![crate::models::LicenseDetection {
+        license_expression: "unknown-license-reference".to_string(),
+        license_expression_spdx: "LicenseRef-scancode-unknown-license-reference".to_string(),
+        matches: vec![Match {
+            license_expression: "unknown-license-reference".to_string(),
+            license_expression_spdx: "LicenseRef-scancode-unknown-license-reference".to_string(),
+            from_file: Some("project/main.c".to_string()),
+            start_line: 2,
+            end_line: 2,
+            matcher: Some("2-aho".to_string()),
+            score: 100.0,
+            matched_length: Some(4),
+            match_coverage: Some(100.0),
+            rule_relevance: Some(100),
+            rule_identifier: Some("unknown-license-reference_see_license.RULE".to_string()),
+            rule_url: None,
+            matched_text: Some("See LICENSE file".to_string()),
+            referenced_filenames: Some(vec!["LICENSE".to_string()]),
+            matched_text_diagnostics: None,
+            ..Default::default()
+        }],
+        detection_log: vec![],
+        identifier: Some("unknown-ref".to_string()),
+        ..Default::default()
+    }];
+    let original_detections = source.license_detections.clone();
+
+    let mut license = file("project/LICENSE");
+    license.license_expression = Some("mit".to_string());
+    license.license_detections = vec![crate::models::LicenseDetection {
+        license_expression: "mit".to_string(),
+        license_expression_spdx: "MIT".to_string(),
+        matches: vec![Match {
+            license_expression: "mit".to_string(),
+            license_expression_spdx: "MIT".to_string(),
+            from_file: Some("project/LICENSE".to_string()),
+            start_line: 1,
+            end_line: 20,
+            matcher: Some("1-hash".to_string()),
+            score: 100.0,
+            matched_length: Some(100),
+            match_coverage: Some(100.0),
+            rule_relevance: Some(100),
+            rule_identifier: Some("mit.LICENSE".to_string()),
+            rule_url: None,
+            matched_text: None,
+            referenced_filenames: None,
+            matched_text_diagnostics: None,
+            ..Default::default()
+        }],
+        detection_log: vec![],
+        identifier: Some("mit-license".to_string()),
+        ..Default::default()
+    }];
+
+    let mut files = vec![dir("project"), source, license];
+    annotate_local_license_references(&mut files);
+
+    assert_eq!(
+        files[1].referenced_license_expression.as_deref(),
+        Some("mit")
+    );
+    assert_eq!(files[1].license_detections, original_detections);
+    assert_eq!(
+        files[1].license_expression.as_deref(),
+        Some("unknown-license-reference")
+    );
+    assert_eq!(files[2].referenced_license_expression, None);
+}
+
+#[test]
+fn annotate_local_license_references_skips_other_detection_categories() {
+    let referencing_match = |expression: &str, spdx: &str| Match {
+        license_expression: expression.to_string(),
+        license_expression_spdx: spdx.to_string(),
+        from_file: Some("project/README".to_string()),
+        start_line: 1,
+        end_line: 1,
+        matcher: Some("2-aho".to_string()),
+        score: 100.0,
+        matched_length: Some(8),
+        match_coverage: Some(100.0),
+        rule_relevance: Some(100),
+        referenced_filenames: Some(vec!["LICENSE".to_string()]),
+        ..Default::default()
+    };
+
+    let mut readme = file("project/README");
+    readme.license_detections = vec![
+        crate::models::LicenseDetection {
+            license_expression: "apache-2.0".to_string(),
+            license_expression_spdx: "Apache-2.0".to_string(),
+            matches: vec![referencing_match("apache-2.0", "Apache-2.0")],
+            ..Default::default()
+        },
+        crate::models::LicenseDetection {
+            license_expression: "unknown-license-reference".to_string(),
+            license_expression_spdx: "LicenseRef-scancode-unknown-license-reference".to_string(),
+            matches: vec![referencing_match(
+                "unknown-license-reference",
+                "LicenseRef-scancode-unknown-license-reference",
+            )],
+            detection_log: vec!["license-clues".to_string()],
+            ..Default::default()
+        },
+    ];
+
+    let mut license = file("project/LICENSE");
+    license.license_detections = vec![crate::models::LicenseDetection {
+        license_expression: "mit".to_string(),
+        license_expression_spdx: "MIT".to_string(),
+        matches: vec![Match {
+            license_expression: "mit".to_string(),
+            license_expression_spdx: "MIT".to_string(),
+            from_file: Some("project/LICENSE".to_string()),
+            start_line: 1,
+            end_line: 20,
+            score: 100.0,
+            ..Default::default()
+        }],
+        ..Default::default()
+    }];
+
+    let mut files = vec![dir("project"), readme, license];
+    annotate_local_license_references(&mut files);
+
+    assert_eq!(files[1].referenced_license_expression, None);
+}
+
 #[test]
 fn apply_package_reference_following_resolves_manifest_origin_local_file() {
     let package_uid = "pkg:cargo/demo?uuid=test".to_string();
@@ -434,9 +881,11 @@ fn apply_package_reference_following_resolves_manifest_origin_local_file() {
             matched_text: Some("MIT".to_string()),
             referenced_filenames: Some(vec!["LICENSE".to_string()]),
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         detection_log: vec![],
         identifier: Some("unknown-ref".to_string()),
+        ..Default::default()
     }];
 
     let mut manifest = file("project/Cargo.toml");
@@ -468,9 +917,11 @@ fn apply_package_reference_following_resolves_manifest_origin_local_file() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         detection_log: vec![],
         identifier: Some("mit-license".to_string()),
+        ..Default::default()
     }];
 
     let mut files = vec![dir("project"), manifest, license];
@@ -519,9 +970,11 @@ fn apply_package_reference_following_falls_back_to_root_when_package_missing() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         detection_log: vec![],
         identifier: Some("gpl-root".to_string()),
+        ..Default::default()
     }];
 
     let mut po = file("project/po/en_US.po");
@@ -545,9 +998,11 @@ fn apply_package_reference_following_falls_back_to_root_when_package_missing() {
             matched_text: Some("same license as package".to_string()),
             referenced_filenames: Some(vec!["COPYING".to_string()]),
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         detection_log: vec![],
         identifier: Some("unknown-ref".to_string()),
+        ..Default::default()
     }];
 
     let mut files = vec![dir("project"), root_copying, po];
@@ -589,9 +1044,11 @@ fn apply_package_reference_following_inherits_license_from_package_context() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         detection_log: vec![],
         identifier: Some("package-license".to_string()),
+        ..Default::default()
     }];
 
     let mut source = file("project/locale/django.po");
@@ -616,9 +1073,11 @@ fn apply_package_reference_following_inherits_license_from_package_context() {
             matched_text: None,
             referenced_filenames: Some(vec!["INHERIT_LICENSE_FROM_PACKAGE".to_string()]),
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         detection_log: vec![],
         identifier: Some("unknown-package-ref".to_string()),
+        ..Default::default()
     }];
 
     let mut files = vec![dir("project"), source];
@@ -664,9 +1123,11 @@ fn apply_package_reference_following_falls_back_to_root_for_missing_package_refe
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         detection_log: vec![],
         identifier: Some("gpl-root".to_string()),
+        ..Default::default()
     }];
 
     let mut po = file("project/po/en_US.po");
@@ -690,9 +1151,11 @@ fn apply_package_reference_following_falls_back_to_root_for_missing_package_refe
             matched_text: None,
             referenced_filenames: Some(vec!["INHERIT_LICENSE_FROM_PACKAGE".to_string()]),
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         detection_log: vec![],
         identifier: Some("unknown-package-ref".to_string()),
+        ..Default::default()
     }];
 
     let mut files = vec![dir("project"), root_copying, po];
@@ -740,9 +1203,11 @@ fn apply_package_reference_following_leaves_ambiguous_multi_package_file_unresol
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         detection_log: vec![],
         identifier: Some("mit-license".to_string()),
+        ..Default::default()
     }];
 
     let mut second_package = super::test_utils::package(&second_uid, "project/b/PKG-INFO");
@@ -766,9 +1231,11 @@ fn apply_package_reference_following_leaves_ambiguous_multi_package_file_unresol
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         detection_log: vec![],
         identifier: Some("apache-license".to_string()),
+        ..Default::default()
     }];
 
     let mut shared_file = file("project/shared/locale.po");
@@ -793,9 +1260,11 @@ fn apply_package_reference_following_leaves_ambiguous_multi_package_file_unresol
             matched_text: None,
             referenced_filenames: Some(vec!["INHERIT_LICENSE_FROM_PACKAGE".to_string()]),
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         detection_log: vec![],
         identifier: Some("unknown-package-ref".to_string()),
+        ..Default::default()
     }];
 
     let mut files = vec![dir("project"), shared_file];
@@ -836,9 +1305,11 @@ fn collect_top_level_license_detections_groups_file_detections_and_preserves_pat
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         detection_log: vec!["imperfect-match-coverage".to_string()],
         identifier: Some("mit-shared-id".to_string()),
+        ..Default::default()
     }];
 
     let mut second = file("project/src/other.rs");
@@ -861,9 +1332,11 @@ fn collect_top_level_license_detections_groups_file_detections_and_preserves_pat
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         detection_log: vec![],
         identifier: Some("mit-shared-id".to_string()),
+        ..Default::default()
     }];
 
     let mut third = file("project/src/apache.rs");
@@ -886,9 +1359,11 @@ fn collect_top_level_license_detections_groups_file_detections_and_preserves_pat
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         detection_log: vec![],
         identifier: Some("apache-2.0-id".to_string()),
+        ..Default::default()
     }];
 
     let detections = collect_top_level_license_detections(&[first, second, third]);
@@ -932,9 +1407,11 @@ fn collect_top_level_license_detections_counts_same_identifier_regions_in_one_fi
                 matched_text: None,
                 referenced_filenames: None,
                 matched_text_diagnostics: None,
+                ..Default::default()
             }],
             detection_log: vec![],
             identifier: Some("mit-shared-id".to_string()),
+            ..Default::default()
         },
         crate::models::LicenseDetection {
             license_expression: "mit".to_string(),
@@ -955,9 +1432,11 @@ fn collect_top_level_license_detections_counts_same_identifier_regions_in_one_fi
                 matched_text: None,
                 referenced_filenames: None,
                 matched_text_diagnostics: None,
+                ..Default::default()
             }],
             detection_log: vec![],
             identifier: Some("mit-shared-id".to_string()),
+            ..Default::default()
         },
     ];
 
@@ -992,9 +1471,11 @@ fn collect_top_level_license_detections_includes_package_origin_detections() {
                 matched_text: Some("MIT".to_string()),
                 referenced_filenames: None,
                 matched_text_diagnostics: None,
+                ..Default::default()
             }],
             detection_log: vec![],
             identifier: None,
+            ..Default::default()
         }],
         other_license_detections: vec![crate::models::LicenseDetection {
             license_expression: "apache-2.0".to_string(),
@@ -1015,9 +1496,11 @@ fn collect_top_level_license_detections_includes_package_origin_detections() {
                 matched_text: Some("Apache-2.0".to_string()),
                 referenced_filenames: None,
                 matched_text_diagnostics: None,
+                ..Default::default()
             }],
             detection_log: vec![],
             identifier: None,
+            ..Default::default()
         }],
         ..PackageData::default()
     }];
@@ -1106,6 +1589,9 @@ fn create_output_preserves_top_level_license_references_from_context() {
                 include_tallies_with_details: false,
                 include_tallies_of_key_files: false,
                 include_generated: false,
+                include_summary_match_stats: false,
+                include_primary_license: false,
+                include_system_environment: true,
             },
         },
     );
@@ -1116,6 +1602,55 @@ fn create_output_preserves_top_level_license_references_from_context() {
     assert_eq!(output.license_rule_references[0].identifier, "mit_1.RULE");
 }
 
+#[test]
+fn create_output_omits_system_environment_when_disabled() {
+    let output_with = |include_system_environment| {
+        let start = Utc::now();
+        create_output(
+            start,
+            start,
+            crate::scanner::ProcessResult {
+                files: vec![],
+                excluded_count: 0,
+            },
+            CreateOutputContext {
+                total_dirs: 0,
+                assembly_result: crate::assembly::AssemblyResult {
+                    packages: vec![],
+                    dependencies: vec![],
+                },
+                license_detections: vec![],
+                license_references: vec![],
+                license_rule_references: vec![],
+                options: CreateOutputOptions {
+                    facet_rules: &[],
+                    include_classify: false,
+                    include_tallies_by_facet: false,
+                    include_summary: false,
+                    include_license_clarity_score: false,
+                    include_tallies: false,
+                    include_tallies_with_details: false,
+                    include_tallies_of_key_files: false,
+                    include_generated: false,
+                    include_summary_match_stats: false,
+                    include_primary_license: false,
+                    include_system_environment,
+                },
+            },
+        )
+    };
+
+    let with_env = serde_json::to_value(output_with(true)).expect("serialize output");
+    let without_env = serde_json::to_value(output_with(false)).expect("serialize output");
+
+    assert!(with_env["headers"][0]["extra_data"]["system_environment"].is_object());
+    assert!(
+        without_env["headers"][0]["extra_data"]
+            .get("system_environment")
+            .is_none()
+    );
+}
+
 #[test]
 fn create_output_preserves_top_level_license_detections_from_context() {
     let start = Utc::now();
@@ -1155,6 +1690,7 @@ fn create_output_preserves_top_level_license_detections_from_context() {
                     matched_text: None,
                     referenced_filenames: None,
                     matched_text_diagnostics: None,
+                    ..Default::default()
                 }],
             }],
             license_references: vec![],
@@ -1169,6 +1705,9 @@ fn create_output_preserves_top_level_license_detections_from_context() {
                 include_tallies_with_details: false,
                 include_tallies_of_key_files: false,
                 include_generated: false,
+                include_summary_match_stats: false,
+                include_primary_license: false,
+                include_system_environment: true,
             },
         },
     );
@@ -1213,6 +1752,9 @@ fn create_output_gates_summary_tallies_and_generated_sections() {
                 include_tallies_with_details: false,
                 include_tallies_of_key_files: false,
                 include_generated: false,
+                include_summary_match_stats: false,
+                include_primary_license: false,
+                include_system_environment: true,
             },
         },
     );
@@ -1248,9 +1790,11 @@ fn create_output_gates_summary_tallies_and_generated_sections() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
 
     let output_with_flags = create_output(
@@ -1279,6 +1823,9 @@ fn create_output_gates_summary_tallies_and_generated_sections() {
                 include_tallies_with_details: true,
                 include_tallies_of_key_files: true,
                 include_generated: true,
+                include_summary_match_stats: false,
+                include_primary_license: false,
+                include_system_environment: true,
             },
         },
     );
@@ -1334,6 +1881,9 @@ fn create_output_preserves_scanner_generated_flags_without_scan_root() {
                 include_tallies_with_details: false,
                 include_tallies_of_key_files: false,
                 include_generated: true,
+                include_summary_match_stats: false,
+                include_primary_license: false,
+                include_system_environment: true,
             },
         },
     );
@@ -1380,9 +1930,11 @@ fn create_output_score_only_keeps_clarity_without_full_summary_fields() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
 
     let output = create_output(
@@ -1411,6 +1963,9 @@ fn create_output_score_only_keeps_clarity_without_full_summary_fields() {
                 include_tallies_with_details: false,
                 include_tallies_of_key_files: false,
                 include_generated: false,
+                include_summary_match_stats: false,
+                include_primary_license: false,
+                include_system_environment: true,
             },
         },
     );
@@ -1448,6 +2003,7 @@ fn create_output_preserves_file_level_license_clues_in_json_shape() {
         ),
         referenced_filenames: None,
         matched_text_diagnostics: None,
+        ..Default::default()
     }];
 
     let output = create_output(
@@ -1476,6 +2032,9 @@ fn create_output_preserves_file_level_license_clues_in_json_shape() {
                 include_tallies_with_details: false,
                 include_tallies_of_key_files: false,
                 include_generated: false,
+                include_summary_match_stats: false,
+                include_primary_license: false,
+                include_system_environment: true,
             },
         },
     );
@@ -1538,6 +2097,9 @@ fn create_output_preserves_empty_package_data_license_and_dependency_arrays() {
                 include_tallies_with_details: false,
                 include_tallies_of_key_files: false,
                 include_generated: false,
+                include_summary_match_stats: false,
+                include_primary_license: false,
+                include_system_environment: true,
             },
         },
     );
@@ -1592,6 +2154,9 @@ fn create_output_tallies_by_facet_does_not_leak_resource_tallies() {
                 include_tallies_with_details: false,
                 include_tallies_of_key_files: false,
                 include_generated: false,
+                include_summary_match_stats: false,
+                include_primary_license: false,
+                include_system_environment: true,
             },
         },
     );
@@ -1611,6 +2176,7 @@ fn create_output_promotes_package_metadata_without_summary_flags() {
         copyright: "Copyright Example Corp.".to_string(),
         start_line: 1,
         end_line: 1,
+        ..Default::default()
     }];
     license.holders = vec![Holder {
         holder: "Example Corp.".to_string(),
@@ -1649,6 +2215,9 @@ fn create_output_promotes_package_metadata_without_summary_flags() {
                 include_tallies_with_details: false,
                 include_tallies_of_key_files: false,
                 include_generated: false,
+                include_summary_match_stats: false,
+                include_primary_license: false,
+                include_system_environment: true,
             },
         },
     );
@@ -1698,9 +2267,11 @@ fn create_output_summary_still_resolves_after_strip_root_normalization() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
 
     let mut files = vec![dir(root.to_str().unwrap()), manifest];
@@ -1730,6 +2301,9 @@ fn create_output_summary_still_resolves_after_strip_root_normalization() {
                 include_tallies_with_details: false,
                 include_tallies_of_key_files: false,
                 include_generated: false,
+                include_summary_match_stats: false,
+                include_primary_license: false,
+                include_system_environment: true,
             },
         },
     );
@@ -1773,6 +2347,9 @@ fn create_output_classify_only_sets_key_file_flags() {
                 include_tallies_with_details: false,
                 include_tallies_of_key_files: false,
                 include_generated: false,
+                include_summary_match_stats: false,
+                include_primary_license: false,
+                include_system_environment: true,
             },
         },
     );
@@ -1787,3 +2364,158 @@ fn create_output_classify_only_sets_key_file_flags() {
     assert!(readme.is_top_level);
     assert!(readme.is_key_file);
 }
+
+fn license_ref_tagged_file(path: &std::path::Path) -> FileInfo {
+    let mut source = file(&path.to_string_lossy());
+    source.license_detections = vec![crate::models::LicenseDetection {
+        license_expression: "unknown-spdx".to_string(),
+        license_expression_spdx: "LicenseRef-scancode-unknown-spdx".to_string(),
+        matches: vec![Match {
+            license_expression: "unknown-spdx".to_string(),
+            license_expression_spdx: "LicenseRef-MyCorp".to_string(),
+            start_line: 1,
+            end_line: 1,
+            matcher: Some("1-spdx-id".to_string()),
+            score: 100.0,
+            matched_length: Some(17),
+            match_coverage: Some(100.0),
+            rule_relevance: Some(100),
+            rule_identifier: Some("spdx-license-identifier-unknown-spdx".to_string()),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }];
+    source
+}
+
+fn write_reuse_license(dir: &std::path::Path) {
+    let licenses_dir = dir.join("LICENSES");
+    std::fs::create_dir_all(&licenses_dir).expect("create LICENSES dir");
+    std::fs::write(
+        licenses_dir.join("LicenseRef-MyCorp.txt"),
+        "MyCorp internal license terms.\n",
+    )
+    .expect("write custom license");
+}
+
+#[test]
+fn collect_custom_license_references_reads_reuse_license_texts() {
+    let temp_dir = tempfile::tempdir().expect("create temp dir");
+    write_reuse_license(temp_dir.path());
+    let mut files = vec![license_ref_tagged_file(
+        &temp_dir.path().join("src").join("main.c"),
+    )];
+
+    link_custom_license_files(&mut files, temp_dir.path());
+    let references = collect_custom_license_references(&files, temp_dir.path());
+
+    let tag_match = &files[0].license_detections[0].matches[0];
+    assert_eq!(
+        tag_match.custom_license_files,
+        vec!["LICENSES/LicenseRef-MyCorp.txt".to_string()]
+    );
+    assert_eq!(tag_match.referenced_filenames, None);
+    assert_eq!(references.len(), 1);
+    assert_eq!(references[0].key.as_deref(), Some("LicenseRef-MyCorp"));
+    assert_eq!(references[0].spdx_license_key, "LicenseRef-MyCorp");
+    assert_eq!(references[0].text, "MyCorp internal license terms.\n");
+}
+
+#[test]
+fn link_custom_license_files_backs_spdx_tags_with_reuse_license_texts() {
+    let temp_dir = tempfile::tempdir().expect("create temp dir");
+    let licenses_dir = temp_dir.path().join("LICENSES");
+    std::fs::create_dir_all(&licenses_dir).expect("create LICENSES dir");
+    std::fs::write(licenses_dir.join("MIT.txt"), "MIT License\n").expect("write MIT text");
+    let mut source = file(&temp_dir.path().join("src").join("main.c").to_string_lossy());
+    let spdx_tag_match = |matcher: &str| Match {
+        license_expression: "mit".to_string(),
+        license_expression_spdx: "MIT".to_string(),
+        start_line: 1,
+        end_line: 1,
+        matcher: Some(matcher.to_string()),
+        rule_identifier: Some("spdx-license-identifier-mit".to_string()),
+        ..Default::default()
+    };
+    source.license_detections = vec![crate::models::LicenseDetection {
+        license_expression: "mit".to_string(),
+        license_expression_spdx: "MIT".to_string(),
+        matches: vec![spdx_tag_match("1-spdx-id"), spdx_tag_match("2-aho")],
+        ..Default::default()
+    }];
+    let mut files = vec![source];
+
+    link_custom_license_files(&mut files, temp_dir.path());
+
+    let matches = &files[0].license_detections[0].matches;
+    assert_eq!(
+        matches[0].custom_license_files,
+        vec!["LICENSES/MIT.txt".to_string()]
+    );
+    assert!(matches[1].custom_license_files.is_empty());
+}
+
+#[test]
+fn link_custom_license_files_ignores_licenses_above_scan_root() {
+    let temp_dir = tempfile::tempdir().expect("create temp dir");
+    write_reuse_license(temp_dir.path());
+    let scan_root = temp_dir.path().join("src");
+    let mut files = vec![license_ref_tagged_file(&scan_root.join("main.c"))];
+
+    link_custom_license_files(&mut files, &scan_root);
+
+    assert!(
+        files[0].license_detections[0].matches[0]
+            .custom_license_files
+            .is_empty()
+    );
+    assert!(collect_custom_license_references(&files, &scan_root).is_empty());
+}
+
+#[test]
+fn collect_unknown_license_texts_dedupes_shared_notice_across_files() {
+    let temp_dir = tempfile::tempdir().expect("create temp dir");
+    let notice = "This software may only be used with written permission of Acme.";
+    let mut files = Vec::new();
+    for (name, body) in [("a.c", "int a;\n"), ("b.c", "int b;\n")] {
+        let path = temp_dir.path().join(name);
+        std::fs::write(&path, format!("/*\n * {notice}\n */\n{body}")).expect("write source");
+        let mut source = file(&path.to_string_lossy());
+        source.license_detections = vec![crate::models::LicenseDetection {
+            license_expression: "unknown".to_string(),
+            license_expression_spdx: "LicenseRef-scancode-unknown".to_string(),
+            matches: vec![Match {
+                license_expression: "unknown".to_string(),
+                license_expression_spdx: "LicenseRef-scancode-unknown".to_string(),
+                start_line: 2,
+                end_line: 2,
+                matcher: Some("6-unknown".to_string()),
+                score: 100.0,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }];
+        files.push(source);
+    }
+    let mut known = file(&temp_dir.path().join("c.c").to_string_lossy());
+    known.license_detections = vec![crate::models::LicenseDetection {
+        license_expression: "mit".to_string(),
+        license_expression_spdx: "MIT".to_string(),
+        ..Default::default()
+    }];
+    files.push(known);
+
+    let texts = collect_unknown_license_texts(&files);
+
+    assert_eq!(texts.len(), 1);
+    assert_eq!(texts[0].text, format!("* {notice}"));
+    assert_eq!(texts[0].license_expression, "unknown");
+    assert_eq!(
+        texts[0].sha1,
+        crate::utils::hash::calculate_sha1(texts[0].text.as_bytes())
+    );
+    assert_eq!(
+        texts[0].paths,
+        vec![files[0].path.clone(), files[1].path.clone()]
+    );
+}
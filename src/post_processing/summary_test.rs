@@ -46,14 +46,17 @@ fn key_file_license_clues_feed_summary_without_mutating_package_license_provenan
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
     license_file.copyrights = vec![Copyright {
         copyright: "Copyright (c) 2019 Chef Software Inc.".to_string(),
         start_line: 1,
         end_line: 1,
+        ..Default::default()
     }];
     license_file.holders = vec![Holder {
         holder: "Chef Software Inc.".to_string(),
@@ -87,6 +90,57 @@ fn key_file_license_clues_feed_summary_without_mutating_package_license_provenan
     assert!(!score.ambiguous_compound_licensing);
 }
 
+#[test]
+fn dedicated_copyright_file_holder_becomes_package_holder() {
+    let uid = "pkg:gem/inspec-bin@6.8.2?uuid=test";
+    let mut manifest = file("demo/demo.gemspec");
+    manifest.for_packages.push(uid.to_string());
+
+    let mut license_file = file("demo/LICENSE");
+    license_file.for_packages.push(uid.to_string());
+    license_file.copyrights = vec![Copyright {
+        copyright: "Copyright (c) 2019 Upstream Authors".to_string(),
+        start_line: 3,
+        end_line: 3,
+        ..Default::default()
+    }];
+    license_file.holders = vec![Holder {
+        holder: "Upstream Authors".to_string(),
+        start_line: 3,
+        end_line: 3,
+    }];
+
+    let mut copyright_file = file("demo/COPYRIGHT");
+    copyright_file.for_packages.push(uid.to_string());
+    copyright_file.copyrights = vec![Copyright {
+        copyright: "Copyright 2022 Acme".to_string(),
+        start_line: 1,
+        end_line: 1,
+        ..Default::default()
+    }];
+    copyright_file.holders = vec![Holder {
+        holder: "Acme".to_string(),
+        start_line: 1,
+        end_line: 1,
+    }];
+
+    let mut files = vec![manifest, license_file, copyright_file];
+    let mut packages = vec![package(uid, "demo/demo.gemspec")];
+
+    let classification_context = build_classification_context(&files, &packages);
+    apply_file_classification(&mut files, &classification_context);
+    let indexes = build_output_indexes(&files, Some(&classification_context), false);
+    promote_package_metadata_from_key_files(&files, &mut packages, &indexes);
+
+    assert!(files[2].is_legal);
+    assert!(files[2].is_key_file);
+    assert_eq!(packages[0].holder.as_deref(), Some("Acme"));
+    assert_eq!(
+        packages[0].copyright.as_deref(),
+        Some("Copyright 2022 Acme")
+    );
+}
+
 #[test]
 fn manifest_declared_license_survives_into_package_and_summary() {
     let mut gemspec = file("demo/demo.gemspec");
@@ -114,9 +168,11 @@ fn manifest_declared_license_survives_into_package_and_summary() {
                 matched_text: None,
                 referenced_filenames: None,
                 matched_text_diagnostics: None,
+                ..Default::default()
             }],
             identifier: None,
             detection_log: vec![],
+            ..Default::default()
         }],
         ..Default::default()
     }];
@@ -144,9 +200,11 @@ fn manifest_declared_license_survives_into_package_and_summary() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
 
     let mut files = vec![gemspec];
@@ -197,9 +255,11 @@ fn compute_summary_includes_package_other_license_detections_as_other_expression
                 matched_text: Some("GPL-2.0-only".to_string()),
                 referenced_filenames: None,
                 matched_text_diagnostics: None,
+                ..Default::default()
             }],
             identifier: Some("gpl-package-id".to_string()),
             detection_log: vec![],
+            ..Default::default()
         }],
         ..Default::default()
     }];
@@ -237,9 +297,11 @@ fn compute_summary_uses_root_prefixed_top_level_key_files() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
 
     classify_key_files(&mut files, &[]);
@@ -321,9 +383,11 @@ fn compute_summary_prefers_package_origin_info_and_preserves_other_tallies() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
 
     let mut mit = file("codebase/mit.LICENSE");
@@ -350,9 +414,11 @@ fn compute_summary_prefers_package_origin_info_and_preserves_other_tallies() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
 
     let summary = compute_summary(&[readme, apache, mit], &[package]).expect("summary exists");
@@ -376,6 +442,7 @@ fn compute_summary_resolves_joined_primary_license_without_ambiguity() {
         copyright: "Copyright Example Corp.".to_string(),
         start_line: 1,
         end_line: 1,
+        ..Default::default()
     }];
 
     let mut apache = file("codebase/apache-2.0.LICENSE");
@@ -402,9 +469,11 @@ fn compute_summary_resolves_joined_primary_license_without_ambiguity() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
 
     let mut mit = file("codebase/mit.LICENSE");
@@ -431,9 +500,11 @@ fn compute_summary_resolves_joined_primary_license_without_ambiguity() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
 
     let summary = compute_summary(&[readme, apache, mit], &[]).expect("summary exists");
@@ -457,6 +528,7 @@ fn compute_summary_penalizes_conflicting_non_key_licenses_without_false_ambiguit
         copyright: "Copyright Example Corp.".to_string(),
         start_line: 1,
         end_line: 1,
+        ..Default::default()
     }];
 
     let mut mit = file("codebase/mit.LICENSE");
@@ -483,9 +555,11 @@ fn compute_summary_penalizes_conflicting_non_key_licenses_without_false_ambiguit
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
 
     let mut non_key_gpl = file("codebase/tests/test_a.py");
@@ -509,9 +583,11 @@ fn compute_summary_penalizes_conflicting_non_key_licenses_without_false_ambiguit
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
 
     let summary = compute_summary(&[readme, mit, non_key_gpl], &[]).expect("summary exists");
@@ -629,9 +705,11 @@ fn compute_summary_keeps_null_other_license_expressions_when_declared_expression
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
 
     let summary = compute_summary(&[readme, mit], &[]).expect("summary exists");
@@ -812,9 +890,11 @@ fn compute_summary_combines_package_licenses_when_present_datafile_is_not_key_cl
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
 
     let summary = compute_summary(&[setup, cargo_toml], &[pypi, cargo]).expect("summary exists");
@@ -855,9 +935,11 @@ fn compute_summary_serializes_empty_declared_holder_when_none_found() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
     let summary = compute_summary(&[pkg_info], &[package]).expect("summary exists");
     assert_eq!(summary.declared_holder.as_deref(), Some(""));
@@ -890,14 +972,17 @@ fn compute_summary_joins_multiple_holders_from_single_top_level_license_file() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
     license.copyrights = vec![Copyright {
         copyright: "Copyright Mort Bay and Sun Microsystems.".to_string(),
         start_line: 1,
         end_line: 1,
+        ..Default::default()
     }];
     license.holders = vec![
         Holder {
@@ -931,6 +1016,7 @@ fn compute_score_mode_ignores_package_declared_license_without_key_file_license_
         copyright: "Copyright Example Corp.".to_string(),
         start_line: 1,
         end_line: 1,
+        ..Default::default()
     }];
     let files = vec![package_json];
     let indexes = build_output_indexes(&files, None, false);
@@ -954,6 +1040,7 @@ fn compute_score_mode_without_license_text_returns_zero_with_copyright_only() {
         copyright: "Copyright Example Corp.".to_string(),
         start_line: 1,
         end_line: 1,
+        ..Default::default()
     }];
     let files = vec![package_json];
     let indexes = build_output_indexes(&files, None, false);
@@ -1006,14 +1093,17 @@ fn compute_score_mode_uses_single_joined_expression_without_ambiguity() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
     cargo.copyrights = vec![Copyright {
         copyright: "Copyright The Rand Project Developers.".to_string(),
         start_line: 1,
         end_line: 1,
+        ..Default::default()
     }];
     let mut apache = file("no_license_ambiguity/LICENSE-APACHE");
     apache.is_legal = true;
@@ -1039,9 +1129,11 @@ fn compute_score_mode_uses_single_joined_expression_without_ambiguity() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
     let mut mit = file("no_license_ambiguity/LICENSE-MIT");
     mit.is_legal = true;
@@ -1067,9 +1159,11 @@ fn compute_score_mode_uses_single_joined_expression_without_ambiguity() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
     let files = vec![cargo, apache, mit];
     let indexes = build_output_indexes(&files, None, false);
@@ -1120,14 +1214,17 @@ fn compute_score_mode_does_not_treat_with_expression_as_covering_base_license()
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
     manifest.copyrights = vec![Copyright {
         copyright: "Copyright Example Corp.".to_string(),
         start_line: 1,
         end_line: 1,
+        ..Default::default()
     }];
 
     let mut gpl = file("with_exception_ambiguity/LICENSE-GPL");
@@ -1154,9 +1251,11 @@ fn compute_score_mode_does_not_treat_with_expression_as_covering_base_license()
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
 
     let files = vec![manifest, gpl];
@@ -1201,9 +1300,11 @@ fn compute_score_mode_scores_nested_manifest_key_file_without_copyright() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
     let mut license = file("jar/META-INF/LICENSE.txt");
     license.is_legal = true;
@@ -1229,9 +1330,11 @@ fn compute_score_mode_scores_nested_manifest_key_file_without_copyright() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
     let files = vec![pom, license];
     let indexes = build_output_indexes(&files, None, false);
@@ -1255,3 +1358,184 @@ fn compute_summary_without_license_evidence_has_no_clarity_score() {
     assert_eq!(summary.primary_language.as_deref(), Some("Ruby"));
     assert_eq!(summary.license_clarity_score.unwrap().score, 0);
 }
+
+fn coverage_match(matcher: &str, match_coverage: f64) -> Match {
+    Match {
+        license_expression: "mit".to_string(),
+        license_expression_spdx: "MIT".to_string(),
+        from_file: None,
+        start_line: 1,
+        end_line: 1,
+        matcher: Some(matcher.to_string()),
+        score: match_coverage,
+        matched_length: Some(10),
+        match_coverage: Some(match_coverage),
+        rule_relevance: Some(100),
+        rule_identifier: None,
+        rule_url: None,
+        matched_text: None,
+        referenced_filenames: None,
+        matched_text_diagnostics: None,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn match_coverage_histogram_buckets_sum_to_total_matches() {
+    let mut first = file("project/LICENSE");
+    first.license_detections = vec![crate::models::LicenseDetection {
+        license_expression: "mit".to_string(),
+        license_expression_spdx: "MIT".to_string(),
+        matches: vec![
+            coverage_match("1-hash", 100.0),
+            coverage_match("3-seq", 15.0),
+            coverage_match("3-seq", 79.9),
+        ],
+        identifier: None,
+        detection_log: vec![],
+        ..Default::default()
+    }];
+    let mut second = file("project/src/lib.rs");
+    second.license_detections = vec![crate::models::LicenseDetection {
+        license_expression: "mit".to_string(),
+        license_expression_spdx: "MIT".to_string(),
+        matches: vec![
+            coverage_match("1-spdx-id", 100.0),
+            coverage_match("2-aho", 80.0),
+        ],
+        identifier: None,
+        detection_log: vec![],
+        ..Default::default()
+    }];
+    let files = vec![dir("project"), first, second];
+
+    let histogram = compute_match_coverage_histogram(&files);
+    let bucket = |label: &str| {
+        histogram
+            .iter()
+            .find(|entry| entry.value.as_deref() == Some(label))
+            .map(|entry| entry.count)
+    };
+
+    assert_eq!(histogram.iter().map(|entry| entry.count).sum::<usize>(), 5);
+    assert_eq!(bucket("0-20"), Some(1));
+    assert_eq!(bucket("60-80"), Some(1));
+    assert_eq!(bucket("80-100"), Some(1));
+    assert_eq!(bucket("100"), Some(2));
+
+    let matcher_counts = compute_matcher_counts(&files);
+    assert_eq!(
+        matcher_counts,
+        vec![
+            TallyEntry {
+                value: Some("3-seq".to_string()),
+                count: 2,
+            },
+            TallyEntry {
+                value: Some("1-hash".to_string()),
+                count: 1,
+            },
+            TallyEntry {
+                value: Some("1-spdx-id".to_string()),
+                count: 1,
+            },
+            TallyEntry {
+                value: Some("2-aho".to_string()),
+                count: 1,
+            },
+        ]
+    );
+}
+
+#[test]
+fn match_coverage_histogram_is_empty_without_matches() {
+    let files = vec![dir("project"), file("project/README")];
+
+    assert!(compute_match_coverage_histogram(&files).is_empty());
+    assert!(compute_matcher_counts(&files).is_empty());
+}
+
+#[test]
+fn compute_summary_counts_copyright_only_files() {
+    let mut notice_only = file("project/src/main.c");
+    notice_only.has_copyright_no_license = true;
+    let plain = file("project/src/util.c");
+
+    let summary = compute_summary(&[notice_only, plain], &[]).expect("summary exists");
+    assert_eq!(summary.copyright_only_files, Some(1));
+
+    let summary = compute_summary(&[file("project/src/util.c")], &[]).expect("summary exists");
+    assert_eq!(summary.copyright_only_files, None);
+}
+
+fn mit_license_file(path: &str) -> FileInfo {
+    let mut license_file = file(path);
+    license_file.license_expression = Some("MIT".to_string());
+    license_file.license_detections = vec![crate::models::LicenseDetection {
+        license_expression: "mit".to_string(),
+        license_expression_spdx: "MIT".to_string(),
+        matches: vec![Match {
+            license_expression: "mit".to_string(),
+            license_expression_spdx: "MIT".to_string(),
+            from_file: Some(path.to_string()),
+            start_line: 1,
+            end_line: 21,
+            matcher: Some("1-hash".to_string()),
+            score: 100.0,
+            ..Default::default()
+        }],
+        ..Default::default()
+    }];
+    license_file
+}
+
+#[test]
+fn compute_primary_license_expression_uses_single_crate_root_package() {
+    let uid = "pkg:cargo/demo@0.1.0?uuid=test";
+    let mut root_package = package(uid, "demo/Cargo.toml");
+    root_package.package_type = Some(PackageType::Cargo);
+    root_package.name = Some("demo".to_string());
+    root_package.purl = Some("pkg:cargo/demo@0.1.0".to_string());
+    root_package.datasource_ids = vec![DatasourceId::CargoToml];
+    root_package.declared_license_expression = Some("mit".to_string());
+    root_package.declared_license_expression_spdx = Some("MIT".to_string());
+
+    let mut manifest = file("demo/Cargo.toml");
+    manifest.for_packages.push(uid.to_string());
+    let mut license_file = mit_license_file("demo/LICENSE");
+    license_file.for_packages.push(uid.to_string());
+    let mut files = vec![
+        dir("demo"),
+        manifest,
+        license_file,
+        file("demo/src/main.rs"),
+    ];
+    let packages = vec![root_package];
+
+    classify_key_files(&mut files, &packages);
+    let indexes = build_output_indexes(&files, None, false);
+
+    assert_eq!(
+        compute_primary_license_expression(&files, &packages, &indexes),
+        (Some("mit".to_string()), Some("MIT".to_string()))
+    );
+}
+
+#[test]
+fn compute_primary_license_expression_falls_back_to_top_level_legal_files() {
+    let mut files = vec![
+        dir("demo"),
+        mit_license_file("demo/LICENSE"),
+        mit_license_file("demo/vendor/zlib/LICENSE"),
+    ];
+    files[2].license_detections[0].license_expression = "zlib".to_string();
+    files[2].license_detections[0].license_expression_spdx = "Zlib".to_string();
+
+    classify_key_files(&mut files, &[]);
+    let indexes = build_output_indexes(&files, None, false);
+
+    assert_eq!(
+        compute_primary_license_expression(&files, &[], &indexes),
+        (Some("mit".to_string()), Some("MIT".to_string()))
+    );
+}
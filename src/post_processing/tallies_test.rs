@@ -27,14 +27,17 @@ fn compute_tallies_counts_file_findings_and_missing_values() {
             matched_text: None,
             referenced_filenames: None,
             matched_text_diagnostics: None,
+            ..Default::default()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
     mit_file.copyrights = vec![Copyright {
         copyright: "Copyright (c) Example Corp.".to_string(),
         start_line: 1,
         end_line: 1,
+        ..Default::default()
     }];
     mit_file.holders = vec![Holder {
         holder: "Example Corp.".to_string(),
@@ -71,9 +74,11 @@ fn compute_tallies_counts_file_findings_and_missing_values() {
                 matched_text: None,
                 referenced_filenames: None,
                 matched_text_diagnostics: None,
+                ..Default::default()
             }],
             identifier: None,
             detection_log: vec![],
+            ..Default::default()
         },
         crate::models::LicenseDetection {
             license_expression: "mit".to_string(),
@@ -94,15 +99,18 @@ fn compute_tallies_counts_file_findings_and_missing_values() {
                 matched_text: None,
                 referenced_filenames: None,
                 matched_text_diagnostics: None,
+                ..Default::default()
             }],
             identifier: None,
             detection_log: vec![],
+            ..Default::default()
         },
     ];
     dual_license_file.copyrights = vec![Copyright {
         copyright: "Copyright (c) Example Corp.".to_string(),
         start_line: 1,
         end_line: 1,
+        ..Default::default()
     }];
     dual_license_file.holders = vec![Holder {
         holder: "Example Corp.".to_string(),
@@ -152,6 +160,7 @@ fn compute_key_file_tallies_only_counts_key_files_and_drops_missing_values() {
         copyright: "Copyright (c) Example Corp.".to_string(),
         start_line: 1,
         end_line: 1,
+        ..Default::default()
     }];
     key_license.holders = vec![Holder {
         holder: "Example Corp.".to_string(),
@@ -215,9 +224,11 @@ fn compute_tallies_include_package_other_license_detections() {
                 matched_text: Some("GPL-2.0-only".to_string()),
                 referenced_filenames: None,
                 matched_text_diagnostics: None,
+                ..Default::default()
             }],
             identifier: Some("gpl-package-id".to_string()),
             detection_log: vec![],
+            ..Default::default()
         }],
         ..Default::default()
     }];
@@ -257,9 +268,11 @@ fn compute_key_file_tallies_include_package_other_license_detections() {
                 matched_text: Some("GPL-2.0-only".to_string()),
                 referenced_filenames: None,
                 matched_text_diagnostics: None,
+                ..Default::default()
             }],
             identifier: Some("gpl-package-id".to_string()),
             detection_log: vec![],
+            ..Default::default()
         }],
         ..Default::default()
     }];
@@ -280,6 +293,7 @@ fn compute_tallies_ignores_legal_file_copyright_holder_and_author_noise() {
         copyright: "copyright and related or neighboring rights".to_string(),
         start_line: 1,
         end_line: 1,
+        ..Default::default()
     }];
     legal.holders = vec![Holder {
         holder: "Related Rights".to_string(),
@@ -327,6 +341,7 @@ fn compute_key_file_tallies_excludes_legal_file_copyrights_holders_and_languages
         copyright: "copyright and related or neighboring rights".to_string(),
         start_line: 1,
         end_line: 1,
+        ..Default::default()
     }];
     legal.holders = vec![Holder {
         holder: "Related Rights".to_string(),
@@ -344,6 +359,7 @@ fn compute_tallies_normalizes_jboss_style_copyright_and_holder_values() {
         copyright: "Copyright 2005, JBoss Inc., and individual contributors as indicated by the @authors tag".to_string(),
         start_line: 1,
         end_line: 1,
+        ..Default::default()
     }];
     source.holders = vec![Holder {
         holder: "JBoss Inc., and individual contributors as indicated by the @authors tag"
@@ -371,6 +387,7 @@ fn compute_tallies_strips_leading_years_from_copyright_tallies() {
         copyright: "Copyright (c) 1995-2013 Jean-loup Gailly and Mark Adler".to_string(),
         start_line: 1,
         end_line: 1,
+        ..Default::default()
     }];
 
     let tallies = compute_tallies(&[source]).expect("tallies exist");
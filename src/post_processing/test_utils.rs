@@ -4,7 +4,6 @@ use std::path::Path;
 #[cfg(feature = "golden-tests")]
 use std::path::PathBuf;
 use std::sync::Arc;
-#[cfg(feature = "golden-tests")]
 use std::sync::OnceLock;
 
 #[cfg(feature = "golden-tests")]
@@ -22,7 +21,6 @@ use tempfile::{TempDir, tempdir};
 use super::*;
 use crate::assembly;
 use crate::cache::{DEFAULT_CACHE_DIR_NAME, build_collection_exclude_patterns};
-#[cfg(feature = "golden-tests")]
 use crate::license_detection::LicenseDetectionEngine;
 use crate::models::{FileInfo, FileType, Package, PackageType};
 use crate::progress::{ProgressMode, ScanProgress};
@@ -147,13 +145,13 @@ pub(crate) fn package(uid: &str, path: &str) -> Package {
         repository_download_url: None,
         api_data_url: None,
         datasource_ids: vec![DatasourceId::GemArchiveExtracted],
+        dependency_rollup: None,
         purl: Some("pkg:gem/inspec-bin@6.8.2".to_string()),
         package_uid: uid.to_string(),
         datafile_paths: vec![path.to_string()],
     }
 }
 
-#[cfg(feature = "golden-tests")]
 pub(crate) fn test_license_engine() -> Arc<LicenseDetectionEngine> {
     static ENGINE: OnceLock<Arc<LicenseDetectionEngine>> = OnceLock::new();
     ENGINE
@@ -513,6 +511,9 @@ pub(crate) fn compute_fixture_output(
                 include_tallies_with_details: options.include_tallies_with_details,
                 include_tallies_by_facet: options.include_tallies_by_facet,
                 include_generated: options.include_generated,
+                include_summary_match_stats: false,
+                include_primary_license: false,
+                include_system_environment: true,
             },
         },
     ))
@@ -52,6 +52,7 @@ fn only_findings_keeps_file_with_findings_and_parent_dirs() {
         copyright: "Copyright Example".to_string(),
         start_line: 1,
         end_line: 1,
+        ..Default::default()
     }];
 
     apply_only_findings_filter(&mut files);
@@ -117,11 +118,13 @@ fn filter_redundant_clues_keeps_distinct_line_ranges_and_dedupes_copyrights_and_
             copyright: "Copyright Example".to_string(),
             start_line: 1,
             end_line: 1,
+            ..Default::default()
         },
         Copyright {
             copyright: "Copyright Example".to_string(),
             start_line: 1,
             end_line: 1,
+            ..Default::default()
         },
     ];
     files[0].holders = vec![
@@ -168,11 +171,13 @@ fn filter_redundant_clues_with_rules_suppresses_ignorable_rule_and_cross_clues()
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
     files[0].copyrights = vec![Copyright {
         copyright: "Copyright Example Corp".to_string(),
         start_line: 2,
         end_line: 2,
+        ..Default::default()
     }];
     files[0].holders = vec![crate::models::Holder {
         holder: "Example Corp".to_string(),
@@ -222,6 +227,7 @@ fn filter_redundant_clues_suppresses_cross_clues_without_license_rules() {
         copyright: "Copyright Example <legal@example.com> https://example.com".to_string(),
         start_line: 2,
         end_line: 2,
+        ..Default::default()
     }];
     files[0].holders = vec![crate::models::Holder {
         holder: "Jane Example".to_string(),
@@ -279,6 +285,7 @@ fn filter_redundant_clues_with_rules_uses_package_origin_detections() {
             }],
             identifier: Some("mit-from-package".to_string()),
             detection_log: vec![],
+            ..Default::default()
         }],
         ..Default::default()
     }];
@@ -333,6 +340,7 @@ fn filter_redundant_clues_with_rules_ignores_low_coverage_matches() {
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
     files[0].emails = vec![OutputEmail {
         email: "legal@example.com".to_string(),
@@ -493,6 +501,7 @@ fn normalize_paths_updates_license_match_from_file_paths_too() {
         }],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
 
     normalize_paths(&mut files, "project", true, false);
@@ -536,6 +545,7 @@ fn normalize_paths_updates_package_level_license_match_from_file_paths_too() {
             }],
             identifier: None,
             detection_log: vec![],
+            ..Default::default()
         }],
         other_license_detections: vec![crate::models::LicenseDetection {
             license_expression: "apache-2.0".to_string(),
@@ -559,6 +569,7 @@ fn normalize_paths_updates_package_level_license_match_from_file_paths_too() {
             }],
             identifier: None,
             detection_log: vec![],
+            ..Default::default()
         }],
         ..Default::default()
     }];
@@ -595,6 +606,7 @@ fn only_findings_keeps_all_supported_finding_types() {
         matches: vec![],
         identifier: None,
         detection_log: vec![],
+        ..Default::default()
     }];
     files[2].package_data = vec![crate::models::PackageData::default()];
     files[3].scan_errors = vec!["boom".to_string()];
@@ -843,3 +855,132 @@ fn normalize_top_level_output_paths_only_applies_strip_root() {
     assert_eq!(packages[0].datafile_paths, vec!["package.json"]);
     assert_eq!(dependencies[0].datafile_path, "package.json");
 }
+
+#[test]
+fn strip_license_text_removes_matched_text_but_keeps_detections() {
+    let text_match = || Match {
+        license_expression: "mit".to_string(),
+        license_expression_spdx: "MIT".to_string(),
+        from_file: Some("project/LICENSE".to_string()),
+        start_line: 1,
+        end_line: 21,
+        matcher: Some("1-hash".to_string()),
+        score: 100.0,
+        matched_length: Some(161),
+        match_coverage: Some(100.0),
+        rule_relevance: Some(100),
+        rule_identifier: Some("mit.LICENSE".to_string()),
+        rule_url: None,
+        matched_text: Some("Permission is hereby granted...".to_string()),
+        matched_text_diagnostics: Some("[Permission] is hereby granted...".to_string()),
+        referenced_filenames: None,
+        ..Default::default()
+    };
+    let detection = || crate::models::LicenseDetection {
+        license_expression: "mit".to_string(),
+        license_expression_spdx: "MIT".to_string(),
+        matches: vec![text_match()],
+        detection_log: vec![],
+        identifier: Some("mit-1234".to_string()),
+        ..Default::default()
+    };
+
+    let mut license_file = file("project/LICENSE");
+    license_file.license_detections = vec![detection()];
+    license_file.license_clues = vec![text_match()];
+    license_file.package_data = vec![PackageData {
+        license_detections: vec![detection()],
+        ..PackageData::default()
+    }];
+
+    let mut output = Output {
+        summary: None,
+        tallies: None,
+        tallies_of_key_files: None,
+        tallies_by_facet: None,
+        headers: vec![],
+        packages: vec![],
+        dependencies: vec![],
+        license_detections: vec![crate::models::TopLevelLicenseDetection {
+            identifier: "mit-1234".to_string(),
+            license_expression: "mit".to_string(),
+            license_expression_spdx: "MIT".to_string(),
+            detection_count: 1,
+            detection_log: vec![],
+            reference_matches: vec![text_match()],
+        }],
+        files: vec![dir("project"), license_file],
+        license_references: vec![],
+        license_rule_references: vec![],
+        unknown_license_texts: vec![],
+        sbom_drift: None,
+    };
+
+    strip_license_text(&mut output);
+
+    let stripped = &output.files[1];
+    assert_eq!(stripped.license_detections.len(), 1);
+    assert_eq!(stripped.license_detections[0].matches[0].start_line, 1);
+    assert_eq!(stripped.license_detections[0].matches[0].score, 100.0);
+    assert_eq!(stripped.license_clues.len(), 1);
+    assert_eq!(output.license_detections[0].reference_matches.len(), 1);
+
+    let rendered = serde_json::to_string(&output).expect("output should serialize");
+    assert!(!rendered.contains("matched_text"), "{rendered}");
+    assert!(rendered.contains("\"license_expression\":\"mit\""));
+}
+
+#[test]
+fn round_match_scores_serializes_configured_precision_consistently() {
+    let noisy_match = || Match {
+        license_expression: "mit".to_string(),
+        license_expression_spdx: "MIT".to_string(),
+        start_line: 1,
+        end_line: 3,
+        matcher: Some("3-seq".to_string()),
+        score: 87.66666666666667,
+        matched_length: Some(42),
+        match_coverage: Some(91.30434782608695),
+        ..Default::default()
+    };
+    let build_output = || {
+        let mut license_file = file("project/LICENSE");
+        license_file.license_detections = vec![crate::models::LicenseDetection {
+            license_expression: "mit".to_string(),
+            license_expression_spdx: "MIT".to_string(),
+            matches: vec![noisy_match()],
+            detection_log: vec![],
+            identifier: Some("mit-1234".to_string()),
+            ..Default::default()
+        }];
+        license_file.license_clues = vec![noisy_match()];
+        Output {
+            summary: None,
+            tallies: None,
+            tallies_of_key_files: None,
+            tallies_by_facet: None,
+            headers: vec![],
+            packages: vec![],
+            dependencies: vec![],
+            license_detections: vec![],
+            files: vec![dir("project"), license_file],
+            license_references: vec![],
+            license_rule_references: vec![],
+            unknown_license_texts: vec![],
+            sbom_drift: None,
+        }
+    };
+
+    let render = || {
+        let mut output = build_output();
+        round_match_scores(&mut output, 2);
+        serde_json::to_string(&output).expect("output should serialize")
+    };
+    let first = render();
+
+    assert!(first.contains("\"score\":87.67"), "{first}");
+    assert!(first.contains("\"match_coverage\":91.3"), "{first}");
+    assert!(!first.contains("87.666"), "{first}");
+    assert!(!first.contains("91.304"), "{first}");
+    assert_eq!(first, render());
+}
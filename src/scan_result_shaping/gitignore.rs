@@ -0,0 +1,185 @@
+//! `.gitignore` / `.scancodeignore` support for `--respect-gitignore`.
+//!
+//! Each `.gitignore` applies to the directory it sits in and everything below,
+//! with rules from deeper files taking precedence. A `.scancodeignore` at the
+//! scan root is applied after the root `.gitignore`. As in git, the last
+//! matching rule wins, `!pattern` re-includes a path, `dir/` only matches
+//! directories, and nothing inside an ignored directory can be re-included.
+
+use glob::{MatchOptions, Pattern};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::scanner::CollectedPaths;
+
+use super::selection::normalize_scan_relative_path;
+
+#[cfg(test)]
+#[path = "gitignore_test.rs"]
+mod gitignore_test;
+
+const GITIGNORE_FILE: &str = ".gitignore";
+const SCANCODEIGNORE_FILE: &str = ".scancodeignore";
+
+const IGNORE_MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+struct IgnoreRule {
+    pattern: Pattern,
+    negated: bool,
+    dir_only: bool,
+    /// Matched against the path relative to the ignore file's directory
+    /// rather than against the file name alone.
+    anchored: bool,
+}
+
+impl IgnoreRule {
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+        let (negated, line) = match line.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, line.strip_prefix('\\').unwrap_or(line)),
+        };
+        let (dir_only, line) = match line.strip_suffix('/') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        let anchored = line.contains('/');
+        let line = line.strip_prefix('/').unwrap_or(line);
+        if line.is_empty() {
+            return None;
+        }
+        Some(Self {
+            pattern: Pattern::new(line).ok()?,
+            negated,
+            dir_only,
+            anchored,
+        })
+    }
+
+    fn matches(&self, relative_path: &str, is_dir: bool) -> bool {
+        if self.dir_only && !is_dir {
+            return false;
+        }
+        if self.anchored {
+            self.pattern
+                .matches_with(relative_path, IGNORE_MATCH_OPTIONS)
+        } else {
+            let name = relative_path.rsplit('/').next().unwrap_or(relative_path);
+            self.pattern.matches_with(name, IGNORE_MATCH_OPTIONS)
+        }
+    }
+}
+
+/// Ignore rules keyed by the scan-relative directory they were read from
+/// (`""` for the scan root).
+#[derive(Default)]
+struct IgnoreRules {
+    by_dir: HashMap<String, Vec<IgnoreRule>>,
+}
+
+impl IgnoreRules {
+    fn add_file(&mut self, relative_dir: &str, path: &Path) {
+        let Ok(content) = fs::read_to_string(path) else {
+            return;
+        };
+        self.by_dir
+            .entry(relative_dir.to_string())
+            .or_default()
+            .extend(content.lines().filter_map(IgnoreRule::parse));
+    }
+
+    /// Whether the rules themselves ignore `relative_path`, without looking
+    /// at whether one of its parent directories is ignored.
+    fn is_ignored(&self, relative_path: &str, is_dir: bool) -> bool {
+        let mut ignored = false;
+        // Offsets where each ancestor directory's part of the path ends.
+        let ancestor_ends =
+            std::iter::once(0).chain(relative_path.match_indices('/').map(|(index, _)| index + 1));
+        for end in ancestor_ends {
+            let dir = relative_path[..end].trim_end_matches('/');
+            let Some(rules) = self.by_dir.get(dir) else {
+                continue;
+            };
+            for rule in rules {
+                if rule.matches(&relative_path[end..], is_dir) {
+                    ignored = !rule.negated;
+                }
+            }
+        }
+        ignored
+    }
+}
+
+/// Read the `.gitignore` of every collected directory and the top-level
+/// `.scancodeignore`.
+fn load_ignore_rules(collected: &CollectedPaths, scan_root: &Path) -> IgnoreRules {
+    let mut rules = IgnoreRules::default();
+    for (dir, _) in &collected.directories {
+        let relative_dir = normalize_scan_relative_path(dir, scan_root);
+        rules.add_file(&relative_dir, &dir.join(GITIGNORE_FILE));
+        if relative_dir.is_empty() {
+            rules.add_file(&relative_dir, &dir.join(SCANCODEIGNORE_FILE));
+        }
+    }
+    rules
+}
+
+/// Drop collected files and directories ignored by `.gitignore` files found in
+/// the scanned tree or by a top-level `.scancodeignore`.
+///
+/// Returns the number of removed files and directories.
+pub(crate) fn apply_gitignore_filter_to_collected(
+    collected: &mut CollectedPaths,
+    scan_root: &Path,
+) -> usize {
+    let rules = load_ignore_rules(collected, scan_root);
+    if rules.by_dir.is_empty() {
+        return 0;
+    }
+
+    // Visit parents before their children, so their status is always known.
+    let mut dirs: Vec<&PathBuf> = collected.directories.iter().map(|(path, _)| path).collect();
+    dirs.sort_by_key(|path| path.components().count());
+    let mut ignored_dirs: HashMap<PathBuf, bool> = HashMap::new();
+    for dir in dirs {
+        let ignored = dir != scan_root
+            && (parent_is_ignored(dir, &ignored_dirs)
+                || rules.is_ignored(&normalize_scan_relative_path(dir, scan_root), true));
+        ignored_dirs.insert(dir.clone(), ignored);
+    }
+
+    let before_files = collected.files.len() + collected.symlinks.len();
+    let before_dirs = collected.directories.len();
+    let is_kept = |path: &Path| {
+        !parent_is_ignored(path, &ignored_dirs)
+            && !rules.is_ignored(&normalize_scan_relative_path(path, scan_root), false)
+    };
+    collected.files.retain(|(path, _)| is_kept(path));
+    collected.symlinks.retain(|(path, _)| is_kept(path));
+    collected
+        .directories
+        .retain(|(path, _)| !ignored_dirs.get(path).copied().unwrap_or(false));
+    collected.total_file_bytes = collected
+        .files
+        .iter()
+        .map(|(_, metadata)| metadata.len())
+        .sum();
+
+    (before_files - collected.files.len() - collected.symlinks.len())
+        + (before_dirs - collected.directories.len())
+}
+
+fn parent_is_ignored(path: &Path, ignored_dirs: &HashMap<PathBuf, bool>) -> bool {
+    path.parent()
+        .and_then(|parent| ignored_dirs.get(parent))
+        .copied()
+        .unwrap_or(false)
+}
@@ -0,0 +1,105 @@
+use super::*;
+use std::fs;
+
+fn kept_paths(collected: &CollectedPaths, scan_root: &Path) -> Vec<String> {
+    let mut paths: Vec<_> = collected
+        .files
+        .iter()
+        .chain(&collected.directories)
+        .map(|(path, _)| normalize_scan_relative_path(path, scan_root))
+        .collect();
+    paths.sort();
+    paths
+}
+
+#[test]
+fn is_ignored_applies_negation_and_directory_only_rules() {
+    let mut rules = IgnoreRules::default();
+    rules.by_dir.insert(
+        String::new(),
+        ["*.log", "!keep.log", "build/", "/docs/*.md"]
+            .into_iter()
+            .filter_map(IgnoreRule::parse)
+            .collect(),
+    );
+
+    assert!(rules.is_ignored("src/debug.log", false));
+    assert!(!rules.is_ignored("src/keep.log", false));
+    assert!(rules.is_ignored("src/build", true));
+    assert!(!rules.is_ignored("src/build", false));
+    assert!(rules.is_ignored("docs/guide.md", false));
+    assert!(!rules.is_ignored("src/docs/guide.md", false));
+}
+
+#[test]
+fn is_ignored_lets_nested_gitignore_override_parent_rules() {
+    let mut rules = IgnoreRules::default();
+    rules.by_dir.insert(
+        String::new(),
+        IgnoreRule::parse("*.txt").into_iter().collect(),
+    );
+    rules.by_dir.insert(
+        "vendor".to_string(),
+        IgnoreRule::parse("!NOTICE.txt").into_iter().collect(),
+    );
+
+    assert!(rules.is_ignored("notes.txt", false));
+    assert!(!rules.is_ignored("vendor/NOTICE.txt", false));
+    assert!(rules.is_ignored("vendor/other.txt", false));
+}
+
+#[test]
+fn apply_gitignore_filter_to_collected_skips_ignored_paths() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let scan_root = temp_dir.path();
+    fs::create_dir_all(scan_root.join("src")).expect("create src dir");
+    fs::create_dir_all(scan_root.join("target/debug")).expect("create target dir");
+    fs::create_dir_all(scan_root.join("third_party")).expect("create third_party dir");
+    fs::write(scan_root.join(".gitignore"), "target/\n*.tmp\n").expect("write gitignore");
+    fs::write(scan_root.join(".scancodeignore"), "third_party\n").expect("write scancodeignore");
+    fs::write(scan_root.join("src/.gitignore"), "!keep.tmp\n").expect("write nested gitignore");
+    fs::write(scan_root.join("src/lib.rs"), "pub fn kept() {}\n").expect("write source");
+    fs::write(scan_root.join("src/scratch.tmp"), "scratch").expect("write scratch");
+    fs::write(scan_root.join("src/keep.tmp"), "keep").expect("write kept tmp");
+    fs::write(scan_root.join("target/debug/app.o"), "artifact").expect("write artifact");
+    fs::write(scan_root.join("third_party/lib.c"), "int x;\n").expect("write vendored");
+
+    let mut collected = crate::scanner::collect_paths(scan_root, 0, &[]);
+    let removed = apply_gitignore_filter_to_collected(&mut collected, scan_root);
+
+    assert_eq!(
+        kept_paths(&collected, scan_root),
+        vec![
+            "",
+            ".gitignore",
+            ".scancodeignore",
+            "src",
+            "src/.gitignore",
+            "src/keep.tmp",
+            "src/lib.rs",
+        ]
+    );
+    // src/scratch.tmp, target, target/debug, target/debug/app.o, third_party and
+    // third_party/lib.c.
+    assert_eq!(removed, 6);
+    assert_eq!(
+        collected.total_file_bytes,
+        collected
+            .files
+            .iter()
+            .map(|(_, metadata)| metadata.len())
+            .sum::<u64>()
+    );
+}
+
+#[test]
+fn apply_gitignore_filter_to_collected_without_ignore_files_keeps_everything() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    fs::write(temp_dir.path().join("main.c"), "int main;\n").expect("write source");
+
+    let mut collected = crate::scanner::collect_paths(temp_dir.path(), 0, &[]);
+    let removed = apply_gitignore_filter_to_collected(&mut collected, temp_dir.path());
+
+    assert_eq!(removed, 0);
+    assert_eq!(collected.file_count(), 1);
+}
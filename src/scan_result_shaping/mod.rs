@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod core_test;
+pub(crate) mod gitignore;
 pub(crate) mod json_input;
+pub(crate) mod sbom_compare;
 pub(crate) mod selection;
 #[cfg(test)]
 mod test_fixtures;
@@ -12,13 +14,17 @@ use std::path::{Path, PathBuf};
 
 use crate::license_detection::LicenseDetectionEngine;
 use crate::license_detection::index::LicenseIndex;
-use crate::models::{FileInfo, Match, Package, TopLevelDependency};
+use crate::models::{
+    FileInfo, LicenseDetection, Match, Output, Package, TopLevelDependency, UnknownLicenseText,
+};
 use anyhow::Result;
 
+pub(crate) use gitignore::apply_gitignore_filter_to_collected;
 pub(crate) use json_input::load_and_merge_json_inputs;
+pub(crate) use sbom_compare::{compare_with_sbom, load_sbom_components};
 pub(crate) use selection::{
-    apply_cli_path_selection_filter, apply_user_path_filters_to_collected,
-    resolve_native_scan_inputs,
+    apply_cli_path_selection_filter, apply_tracked_only_filter_to_collected,
+    apply_user_path_filters_to_collected, resolve_native_scan_inputs,
 };
 
 fn retain_matching_files_with_ancestor_dirs<F>(files: &mut Vec<FileInfo>, mut keep_file: F)
@@ -27,12 +33,14 @@ where
 {
     let kept_file_paths: HashSet<String> = files
         .iter()
-        .filter(|entry| entry.file_type == crate::models::FileType::File && keep_file(entry))
+        .filter(|entry| entry.file_type != crate::models::FileType::Directory && keep_file(entry))
         .map(|entry| entry.path.clone())
         .collect();
 
     files.retain(|entry| match entry.file_type {
-        crate::models::FileType::File => kept_file_paths.contains(&entry.path),
+        crate::models::FileType::File | crate::models::FileType::Symlink => {
+            kept_file_paths.contains(&entry.path)
+        }
         crate::models::FileType::Directory => kept_file_paths
             .iter()
             .any(|path| Path::new(path).starts_with(Path::new(&entry.path))),
@@ -46,22 +54,8 @@ where
     retain_matching_files_with_ancestor_dirs(files, keep_file);
 }
 
-fn has_findings(file: &FileInfo) -> bool {
-    file.license_expression.is_some()
-        || !file.license_detections.is_empty()
-        || !file.license_clues.is_empty()
-        || !file.copyrights.is_empty()
-        || !file.holders.is_empty()
-        || !file.authors.is_empty()
-        || !file.emails.is_empty()
-        || !file.urls.is_empty()
-        || !file.package_data.is_empty()
-        || !file.scan_errors.is_empty()
-        || file.is_generated == Some(true)
-}
-
 pub(crate) fn apply_only_findings_filter(files: &mut Vec<FileInfo>) {
-    retain_matching_files_with_ancestor_dirs(files, has_findings);
+    retain_matching_files_with_ancestor_dirs(files, FileInfo::has_findings);
 }
 
 fn matches_any_regex<'a, I>(patterns: &[Regex], values: I) -> bool
@@ -478,6 +472,94 @@ pub(crate) fn normalize_paths(
     }
 }
 
+/// Normalize the file paths listed in `unknown_license_texts` entries the same
+/// way as the scanned files.
+pub(crate) fn normalize_unknown_license_text_paths(
+    texts: &mut [UnknownLicenseText],
+    scan_root: &str,
+    strip_root: bool,
+    full_root: bool,
+) {
+    for path in texts.iter_mut().flat_map(|text| text.paths.iter_mut()) {
+        if let Some(normalized_path) = normalize_path_value(path, scan_root, strip_root, full_root)
+        {
+            *path = normalized_path;
+        }
+    }
+}
+
+/// Drop `matched_text` and `matched_text_diagnostics` from every license match in the output.
+///
+/// License keys, scores and line ranges are kept so consumers can still resolve the text
+/// from license references.
+pub(crate) fn strip_license_text(output: &mut Output) {
+    for entry in &mut output.files {
+        strip_match_text(&mut entry.license_clues);
+        strip_detection_text(&mut entry.license_detections);
+
+        for package_data in &mut entry.package_data {
+            strip_detection_text(&mut package_data.license_detections);
+            strip_detection_text(&mut package_data.other_license_detections);
+        }
+    }
+
+    for package in &mut output.packages {
+        strip_detection_text(&mut package.license_detections);
+        strip_detection_text(&mut package.other_license_detections);
+    }
+
+    for detection in &mut output.license_detections {
+        strip_match_text(&mut detection.reference_matches);
+    }
+}
+
+/// Round every license match `score` and `match_coverage` in the output to
+/// `precision` decimal places.
+pub(crate) fn round_match_scores(output: &mut Output, precision: u32) {
+    let round_matches = |matches: &mut [Match]| {
+        for detection_match in matches {
+            detection_match.round_scores(precision);
+        }
+    };
+    let round_detections = |detections: &mut [LicenseDetection]| {
+        for detection in detections {
+            round_matches(&mut detection.matches);
+        }
+    };
+
+    for entry in &mut output.files {
+        round_matches(&mut entry.license_clues);
+        round_detections(&mut entry.license_detections);
+
+        for package_data in &mut entry.package_data {
+            round_detections(&mut package_data.license_detections);
+            round_detections(&mut package_data.other_license_detections);
+        }
+    }
+
+    for package in &mut output.packages {
+        round_detections(&mut package.license_detections);
+        round_detections(&mut package.other_license_detections);
+    }
+
+    for detection in &mut output.license_detections {
+        round_matches(&mut detection.reference_matches);
+    }
+}
+
+fn strip_detection_text(detections: &mut [LicenseDetection]) {
+    for detection in detections {
+        strip_match_text(&mut detection.matches);
+    }
+}
+
+fn strip_match_text(matches: &mut [Match]) {
+    for detection_match in matches {
+        detection_match.matched_text = None;
+        detection_match.matched_text_diagnostics = None;
+    }
+}
+
 fn normalize_match_paths(
     matches: &mut [Match],
     scan_root: &str,
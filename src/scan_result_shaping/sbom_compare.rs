@@ -0,0 +1,224 @@
+//! Compare assembled packages against an approved SPDX or CycloneDX SBOM.
+//!
+//! Components are matched by purl (ignoring qualifiers and subpath) and, when
+//! either side has no purl, by name and version. Unmatched entries on either
+//! side and license differences between matched entries are reported as
+//! [`SbomDrift`].
+
+use anyhow::{Result, anyhow};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+
+use crate::models::{Package, SbomComponent, SbomDrift, SbomLicenseChange};
+
+#[cfg(test)]
+#[path = "sbom_compare_test.rs"]
+mod sbom_compare_test;
+
+/// Read the components listed in an SPDX 2.x or CycloneDX JSON SBOM.
+pub(crate) fn load_sbom_components(path: &str) -> Result<Vec<SbomComponent>> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| anyhow!("Cannot read --compare-sbom file {path}: {e}"))?;
+    let document: Value = serde_json::from_str(&content)
+        .map_err(|e| anyhow!("--compare-sbom file is not valid JSON: {path}: {e}"))?;
+
+    if document.get("spdxVersion").is_some() {
+        Ok(spdx_components(&document))
+    } else if document.get("bomFormat").and_then(Value::as_str) == Some("CycloneDX") {
+        let mut components = Vec::new();
+        collect_cyclonedx_components(document.get("components"), &mut components);
+        Ok(components)
+    } else {
+        Err(anyhow!(
+            "Unsupported --compare-sbom file {path}: expected SPDX or CycloneDX JSON"
+        ))
+    }
+}
+
+/// Report packages missing from the SBOM, SBOM components missing from the
+/// scan, and license differences for components present on both sides.
+pub(crate) fn compare_with_sbom(
+    sbom_path: &str,
+    packages: &[Package],
+    sbom_components: &[SbomComponent],
+) -> SbomDrift {
+    let mut sbom_index: HashMap<String, usize> = HashMap::new();
+    for (index, component) in sbom_components.iter().enumerate() {
+        for key in component_keys(component) {
+            sbom_index.entry(key).or_insert(index);
+        }
+    }
+
+    let mut matched = HashSet::new();
+    let mut not_in_sbom = Vec::new();
+    let mut license_changes = Vec::new();
+    for package in packages {
+        let scanned = SbomComponent {
+            purl: package.purl.clone(),
+            name: package.name.clone(),
+            version: package.version.clone(),
+            license_expression: package.declared_license_expression_spdx.clone(),
+        };
+        let Some(&index) = component_keys(&scanned)
+            .iter()
+            .find_map(|key| sbom_index.get(key))
+        else {
+            not_in_sbom.push(scanned);
+            continue;
+        };
+        matched.insert(index);
+
+        let sbom_license = &sbom_components[index].license_expression;
+        if !same_license(&scanned.license_expression, sbom_license) {
+            license_changes.push(SbomLicenseChange {
+                purl: scanned.purl,
+                name: scanned.name,
+                version: scanned.version,
+                scan_license_expression: scanned.license_expression,
+                sbom_license_expression: sbom_license.clone(),
+            });
+        }
+    }
+
+    let not_in_scan = sbom_components
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| !matched.contains(index))
+        .map(|(_, component)| component.clone())
+        .collect();
+
+    SbomDrift {
+        sbom_path: sbom_path.to_string(),
+        not_in_sbom,
+        not_in_scan,
+        license_changes,
+    }
+}
+
+/// Lookup keys for a component, most specific first.
+fn component_keys(component: &SbomComponent) -> Vec<String> {
+    let mut keys = Vec::new();
+    if let Some(purl) = component.purl.as_deref() {
+        keys.push(format!("purl:{}", normalize_purl(purl)));
+    }
+    if let Some(name) = component.name.as_deref() {
+        keys.push(format!(
+            "name:{}@{}",
+            name.to_ascii_lowercase(),
+            component.version.as_deref().unwrap_or_default()
+        ));
+    }
+    keys
+}
+
+fn normalize_purl(purl: &str) -> String {
+    let base = purl.split(['?', '#']).next().unwrap_or(purl);
+    base.replace("%40", "@").to_ascii_lowercase()
+}
+
+fn same_license(left: &Option<String>, right: &Option<String>) -> bool {
+    match (left.as_deref(), right.as_deref()) {
+        (Some(left), Some(right)) => left.trim().eq_ignore_ascii_case(right.trim()),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+fn spdx_components(document: &Value) -> Vec<SbomComponent> {
+    // The package a document DESCRIBES without a purl stands for the scanned
+    // codebase itself rather than one of its components.
+    let described: HashSet<&str> = document
+        .get("documentDescribes")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .chain(
+            document
+                .get("relationships")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter(|relationship| {
+                    relationship.get("relationshipType").and_then(Value::as_str)
+                        == Some("DESCRIBES")
+                })
+                .filter_map(|relationship| {
+                    relationship
+                        .get("relatedSpdxElement")
+                        .and_then(Value::as_str)
+                }),
+        )
+        .collect();
+
+    document
+        .get("packages")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|package| {
+            let purl = package
+                .get("externalRefs")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .find(|reference| {
+                    reference.get("referenceType").and_then(Value::as_str) == Some("purl")
+                })
+                .and_then(|reference| string_field(reference, "referenceLocator"));
+            let is_described = package
+                .get("SPDXID")
+                .and_then(Value::as_str)
+                .is_some_and(|id| described.contains(id));
+            if purl.is_none() && is_described {
+                return None;
+            }
+            Some(SbomComponent {
+                purl,
+                name: string_field(package, "name"),
+                version: string_field(package, "versionInfo"),
+                license_expression: spdx_license_field(package, "licenseDeclared")
+                    .or_else(|| spdx_license_field(package, "licenseConcluded")),
+            })
+        })
+        .collect()
+}
+
+fn spdx_license_field(package: &Value, field: &str) -> Option<String> {
+    string_field(package, field).filter(|value| !matches!(value.as_str(), "NOASSERTION" | "NONE"))
+}
+
+fn collect_cyclonedx_components(components: Option<&Value>, out: &mut Vec<SbomComponent>) {
+    for component in components.and_then(Value::as_array).into_iter().flatten() {
+        let licenses: Vec<String> = component
+            .get("licenses")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|choice| {
+                string_field(choice, "expression").or_else(|| {
+                    choice.get("license").and_then(|license| {
+                        string_field(license, "id").or_else(|| string_field(license, "name"))
+                    })
+                })
+            })
+            .collect();
+        out.push(SbomComponent {
+            purl: string_field(component, "purl"),
+            name: string_field(component, "name"),
+            version: string_field(component, "version"),
+            license_expression: (!licenses.is_empty()).then(|| licenses.join(" AND ")),
+        });
+        collect_cyclonedx_components(component.get("components"), out);
+    }
+}
+
+fn string_field(value: &Value, field: &str) -> Option<String> {
+    value
+        .get(field)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
@@ -0,0 +1,128 @@
+use super::*;
+use crate::models::{DatasourceId, PackageData, PackageType};
+
+fn npm_package(name: &str, version: &str, license: &str) -> Package {
+    Package::from_package_data(
+        &PackageData {
+            package_type: Some(PackageType::Npm),
+            name: Some(name.to_string()),
+            version: Some(version.to_string()),
+            purl: Some(format!("pkg:npm/{name}@{version}")),
+            declared_license_expression_spdx: Some(license.to_string()),
+            datasource_id: Some(DatasourceId::NpmPackageJson),
+            ..Default::default()
+        },
+        format!("{name}/package.json"),
+    )
+}
+
+#[test]
+fn scanned_package_missing_from_cyclonedx_sbom_is_reported_as_drift() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let sbom_path = dir.path().join("approved.cdx.json");
+    std::fs::write(
+        &sbom_path,
+        serde_json::json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.5",
+            "metadata": {"component": {"name": "app"}},
+            "components": [
+                {
+                    "name": "express",
+                    "version": "4.18.2",
+                    "purl": "pkg:npm/express@4.18.2",
+                    "licenses": [{"license": {"id": "MIT"}}]
+                },
+                {
+                    "name": "lodash",
+                    "version": "4.17.21",
+                    "purl": "pkg:npm/lodash@4.17.21",
+                    "licenses": [{"license": {"id": "MIT"}}]
+                },
+                {
+                    "name": "left-pad",
+                    "version": "1.3.0",
+                    "purl": "pkg:npm/left-pad@1.3.0"
+                }
+            ]
+        })
+        .to_string(),
+    )
+    .expect("write sbom");
+    let sbom_path = sbom_path.to_string_lossy().into_owned();
+
+    let packages = vec![
+        npm_package("express", "4.18.2", "MIT"),
+        npm_package("lodash", "4.17.21", "Apache-2.0"),
+        npm_package("chalk", "5.3.0", "MIT"),
+    ];
+    let components = load_sbom_components(&sbom_path).expect("load sbom");
+    let drift = compare_with_sbom(&sbom_path, &packages, &components);
+
+    assert_eq!(drift.not_in_sbom.len(), 1);
+    assert_eq!(
+        drift.not_in_sbom[0].purl.as_deref(),
+        Some("pkg:npm/chalk@5.3.0")
+    );
+    assert_eq!(drift.not_in_scan.len(), 1);
+    assert_eq!(drift.not_in_scan[0].name.as_deref(), Some("left-pad"));
+    assert_eq!(drift.license_changes.len(), 1);
+    assert_eq!(drift.license_changes[0].name.as_deref(), Some("lodash"));
+    assert_eq!(
+        drift.license_changes[0].scan_license_expression.as_deref(),
+        Some("Apache-2.0")
+    );
+    assert_eq!(
+        drift.license_changes[0].sbom_license_expression.as_deref(),
+        Some("MIT")
+    );
+}
+
+#[test]
+fn spdx_sbom_components_skip_the_described_root_package() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let sbom_path = dir.path().join("approved.spdx.json");
+    std::fs::write(
+        &sbom_path,
+        serde_json::json!({
+            "spdxVersion": "SPDX-2.3",
+            "SPDXID": "SPDXRef-DOCUMENT",
+            "documentDescribes": ["SPDXRef-root"],
+            "packages": [
+                {"SPDXID": "SPDXRef-root", "name": "app", "licenseConcluded": "NOASSERTION"},
+                {
+                    "SPDXID": "SPDXRef-express",
+                    "name": "express",
+                    "versionInfo": "4.18.2",
+                    "licenseConcluded": "NOASSERTION",
+                    "licenseDeclared": "MIT",
+                    "externalRefs": [{
+                        "referenceCategory": "PACKAGE-MANAGER",
+                        "referenceType": "purl",
+                        "referenceLocator": "pkg:npm/express@4.18.2"
+                    }]
+                }
+            ]
+        })
+        .to_string(),
+    )
+    .expect("write sbom");
+
+    let components = load_sbom_components(&sbom_path.to_string_lossy()).expect("load spdx sbom");
+
+    assert_eq!(components.len(), 1);
+    assert_eq!(
+        components[0].purl.as_deref(),
+        Some("pkg:npm/express@4.18.2")
+    );
+    assert_eq!(components[0].license_expression.as_deref(), Some("MIT"));
+}
+
+#[test]
+fn load_sbom_components_rejects_unknown_documents() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let sbom_path = dir.path().join("scan.json");
+    std::fs::write(&sbom_path, r#"{"files": []}"#).expect("write json");
+
+    assert!(load_sbom_components(&sbom_path.to_string_lossy()).is_err());
+}
@@ -2,6 +2,7 @@ use anyhow::{Result, anyhow};
 use glob::Pattern;
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
+use std::process::Command;
 
 use crate::models::FileInfo;
 use crate::scanner::CollectedPaths;
@@ -79,12 +80,14 @@ pub(crate) fn apply_user_path_filters_to_collected(
     include_patterns: &[String],
     exclude_patterns: &[String],
 ) -> usize {
-    let before_files = collected.files.len();
+    let before_files = collected.files.len() + collected.symlinks.len();
     let before_dirs = collected.directories.len();
-    collected.files.retain(|(path, _)| {
+    let is_kept = |path: &Path| {
         let relative_path = normalize_scan_relative_path(path, scan_root);
         is_included_path(&relative_path, include_patterns, exclude_patterns)
-    });
+    };
+    collected.files.retain(|(path, _)| is_kept(path));
+    collected.symlinks.retain(|(path, _)| is_kept(path));
 
     let kept_file_paths: HashSet<_> = collected
         .files
@@ -99,7 +102,76 @@ pub(crate) fn apply_user_path_filters_to_collected(
                 .any(|file_path| file_path.starts_with(path))
     });
 
-    (before_files - collected.files.len()) + (before_dirs - collected.directories.len())
+    (before_files - collected.files.len() - collected.symlinks.len())
+        + (before_dirs - collected.directories.len())
+}
+
+/// Keep only the collected files that `git ls-files` reports as tracked.
+///
+/// `git ls-files` run from the scan root lists tracked paths relative to that
+/// root and limited to it, so the result is already intersected with the
+/// scanned tree. Directories are kept only when they still contain a tracked
+/// file. Returns the number of removed files and directories.
+pub(crate) fn apply_tracked_only_filter_to_collected(
+    collected: &mut CollectedPaths,
+    scan_root: &Path,
+) -> Result<usize> {
+    let tracked_paths = git_tracked_paths(scan_root)?;
+    let before_files = collected.files.len() + collected.symlinks.len();
+    let before_dirs = collected.directories.len();
+    collected
+        .files
+        .retain(|(path, _)| tracked_paths.contains(&normalize_scan_relative_path(path, scan_root)));
+    collected
+        .symlinks
+        .retain(|(path, _)| tracked_paths.contains(&normalize_scan_relative_path(path, scan_root)));
+    collected.total_file_bytes = collected
+        .files
+        .iter()
+        .map(|(_, metadata)| metadata.len())
+        .sum();
+
+    let kept_file_paths: Vec<_> = collected.files.iter().map(|(path, _)| path).collect();
+    collected.directories.retain(|(path, _)| {
+        path == scan_root
+            || kept_file_paths
+                .iter()
+                .any(|file_path| file_path.starts_with(path))
+    });
+
+    Ok(
+        (before_files - collected.files.len() - collected.symlinks.len())
+            + (before_dirs - collected.directories.len()),
+    )
+}
+
+fn git_tracked_paths(scan_root: &Path) -> Result<HashSet<String>> {
+    if !scan_root.is_dir() {
+        return Err(anyhow!(
+            "--tracked-only requires a directory input inside a git work tree"
+        ));
+    }
+
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(scan_root)
+        .args(["ls-files", "-z", "--cached"])
+        .output()
+        .map_err(|err| anyhow!("--tracked-only could not run git: {err}"))?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "--tracked-only: git ls-files failed in {}: {}",
+            scan_root.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(output
+        .stdout
+        .split(|byte| *byte == 0)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| String::from_utf8_lossy(entry).into_owned())
+        .collect())
 }
 
 pub(crate) fn apply_cli_path_selection_filter(
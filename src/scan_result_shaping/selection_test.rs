@@ -51,6 +51,7 @@ fn apply_user_path_filters_to_collected_filters_files_without_pruning_directorie
             (scan_root.join("src/test"), placeholder_metadata.clone()),
             (scan_root.join("other"), placeholder_metadata.clone()),
         ],
+        symlinks: Vec::new(),
         excluded_count: 0,
         total_file_bytes: 0,
         collection_errors: Vec::new(),
@@ -122,3 +123,51 @@ fn resolve_native_scan_inputs_uses_component_aware_prefix_for_siblings() {
     assert_eq!(scan_root, "src");
     assert_eq!(includes, vec!["src/bar", "src/baz"]);
 }
+
+#[test]
+fn apply_tracked_only_filter_to_collected_skips_untracked_build_artifacts() {
+    let git_available = std::process::Command::new("git")
+        .arg("--version")
+        .output()
+        .is_ok_and(|output| output.status.success());
+    if !git_available {
+        return;
+    }
+
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let scan_root = temp_dir.path();
+    fs::create_dir_all(scan_root.join("src")).expect("create src dir");
+    fs::create_dir_all(scan_root.join("target")).expect("create target dir");
+    fs::write(scan_root.join("src/lib.rs"), "pub fn tracked() {}\n").expect("write tracked");
+    fs::write(scan_root.join("target/app.o"), "artifact").expect("write artifact");
+
+    let run_git = |args: &[&str]| {
+        let status = std::process::Command::new("git")
+            .arg("-C")
+            .arg(scan_root)
+            .args(args)
+            .status()
+            .expect("run git");
+        assert!(status.success(), "git {args:?} failed");
+    };
+    run_git(&["init", "-q"]);
+    run_git(&["add", "src/lib.rs"]);
+
+    let mut collected = crate::scanner::collect_paths(scan_root, 0, &[]);
+    let removed =
+        apply_tracked_only_filter_to_collected(&mut collected, scan_root).expect("tracked filter");
+
+    let kept_files: Vec<_> = collected
+        .files
+        .iter()
+        .map(|(path, _)| normalize_scan_relative_path(path, scan_root))
+        .collect();
+    assert_eq!(kept_files, vec!["src/lib.rs"]);
+    assert!(
+        collected
+            .directories
+            .iter()
+            .all(|(path, _)| !path.ends_with("target"))
+    );
+    assert!(removed >= 2);
+}
@@ -0,0 +1,308 @@
+//! Expansion of archives found in the scanned tree (`--extract-archives`).
+//!
+//! Archives are unpacked into a temporary directory and their contents are
+//! appended to the collected paths, so they are counted and scanned like any
+//! other file. Once scanned, paths of extracted files are rewritten to
+//! `<archive path>!/<entry path>`.
+
+use std::fs::{self, File};
+use std::io::{self, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use flate2::read::GzDecoder;
+
+use super::collect::{CollectedPaths, collect_paths};
+use crate::models::FileInfo;
+
+/// Separator between an archive path and the path of an entry inside it.
+pub const ARCHIVE_ENTRY_SEPARATOR: &str = "!/";
+
+/// Nesting limit for archives inside archives when `--max-depth` is unset.
+const DEFAULT_MAX_ARCHIVE_NESTING: usize = 8;
+
+/// Entries expanding more than this many times their compressed size are skipped.
+const MAX_COMPRESSION_RATIO: u64 = 100;
+
+/// An archive unpacked under `root`, reported as `display_path`.
+#[derive(Debug, Clone)]
+pub struct ExtractedArchive {
+    pub display_path: String,
+    pub root: PathBuf,
+}
+
+#[derive(Clone, Copy)]
+enum ArchiveKind {
+    Zip,
+    Tar,
+    TarGz,
+}
+
+fn archive_kind(path: &Path) -> Option<ArchiveKind> {
+    let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+    if [".zip", ".jar", ".war", ".ear"]
+        .iter()
+        .any(|extension| name.ends_with(extension))
+    {
+        Some(ArchiveKind::Zip)
+    } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+        Some(ArchiveKind::TarGz)
+    } else if name.ends_with(".tar") {
+        Some(ArchiveKind::Tar)
+    } else {
+        None
+    }
+}
+
+/// Unpack every archive in `collected` under `dest_dir` and add their contents
+/// to `collected`, including archives nested up to `max_depth` levels deep
+/// (eight when `max_depth` is 0).
+///
+/// Archives that fail to unpack are recorded as collection errors and
+/// otherwise scanned as plain files.
+pub fn extract_archives(
+    collected: &mut CollectedPaths,
+    dest_dir: &Path,
+    max_depth: usize,
+) -> Vec<ExtractedArchive> {
+    let nesting_limit = if max_depth > 0 {
+        max_depth
+    } else {
+        DEFAULT_MAX_ARCHIVE_NESTING
+    };
+    let mut pending: Vec<(PathBuf, String, usize)> = collected
+        .files
+        .iter()
+        .filter(|(path, _)| archive_kind(path).is_some())
+        .map(|(path, _)| (path.clone(), path.to_string_lossy().into_owned(), 1))
+        .collect();
+    let mut extracted = Vec::new();
+
+    while let Some((archive_path, display_path, depth)) = pending.pop() {
+        let Some(kind) = archive_kind(&archive_path) else {
+            continue;
+        };
+        let root = dest_dir.join(extracted.len().to_string());
+        if let Err(err) = unpack_archive(&archive_path, kind, &root) {
+            collected
+                .collection_errors
+                .push((archive_path, format!("Failed to extract archive: {err:#}")));
+            let _ = fs::remove_dir_all(&root);
+            continue;
+        }
+
+        let mut contents = collect_paths(&root, max_depth, &[]);
+        // The extraction root stands for the archive file, which is already listed.
+        contents.directories.retain(|(path, _)| path != &root);
+        if depth < nesting_limit {
+            for (path, _) in &contents.files {
+                if archive_kind(path).is_some()
+                    && let Ok(relative) = path.strip_prefix(&root)
+                {
+                    pending.push((
+                        path.clone(),
+                        entry_display_path(&display_path, relative),
+                        depth + 1,
+                    ));
+                }
+            }
+        }
+
+        collected.total_file_bytes += contents.total_file_bytes;
+        collected.excluded_count += contents.excluded_count;
+        collected.files.append(&mut contents.files);
+        collected.directories.append(&mut contents.directories);
+        collected.symlinks.append(&mut contents.symlinks);
+        collected
+            .collection_errors
+            .append(&mut contents.collection_errors);
+        extracted.push(ExtractedArchive { display_path, root });
+    }
+
+    extracted
+}
+
+/// Rewrite the paths of files unpacked from `archives` to
+/// `<archive path>!/<entry path>`.
+pub fn attribute_archive_paths(files: &mut [FileInfo], archives: &[ExtractedArchive]) {
+    let rewrite = |path: &str| {
+        archives.iter().find_map(|archive| {
+            Path::new(path)
+                .strip_prefix(&archive.root)
+                .ok()
+                .map(|relative| entry_display_path(&archive.display_path, relative))
+        })
+    };
+
+    for file in files {
+        if let Some(path) = rewrite(&file.path) {
+            file.path = path;
+        }
+        let matches = file
+            .license_detections
+            .iter_mut()
+            .flat_map(|detection| detection.matches.iter_mut())
+            .chain(file.license_clues.iter_mut());
+        for license_match in matches {
+            if let Some(path) = license_match.from_file.as_deref().and_then(rewrite) {
+                license_match.from_file = Some(path);
+            }
+        }
+    }
+}
+
+fn entry_display_path(archive_display_path: &str, relative: &Path) -> String {
+    let relative = relative.to_string_lossy().replace('\\', "/");
+    format!("{archive_display_path}{ARCHIVE_ENTRY_SEPARATOR}{relative}")
+}
+
+fn unpack_archive(archive_path: &Path, kind: ArchiveKind, root: &Path) -> Result<()> {
+    fs::create_dir_all(root)
+        .with_context(|| format!("Failed to create extraction directory {:?}", root))?;
+    let reader = BufReader::new(File::open(archive_path)?);
+    match kind {
+        ArchiveKind::Zip => unpack_zip(reader, root),
+        ArchiveKind::Tar => unpack_tar(reader, root),
+        ArchiveKind::TarGz => unpack_tar(GzDecoder::new(reader), root),
+    }
+}
+
+fn unpack_zip(reader: BufReader<File>, root: &Path) -> Result<()> {
+    let mut archive = zip::ZipArchive::new(reader)?;
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index)?;
+        // `enclosed_name` rejects absolute paths and entries escaping `root`.
+        let Some(relative) = entry.enclosed_name() else {
+            continue;
+        };
+        let target = root.join(relative);
+        if entry.is_dir() {
+            fs::create_dir_all(&target)?;
+            continue;
+        }
+        if !entry.is_file() || entry.size() > entry.compressed_size().max(1) * MAX_COMPRESSION_RATIO
+        {
+            continue;
+        }
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        io::copy(&mut entry, &mut File::create(&target)?)?;
+    }
+    Ok(())
+}
+
+fn unpack_tar(reader: impl Read, root: &Path) -> Result<()> {
+    let mut archive = tar::Archive::new(reader);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let entry_type = entry.header().entry_type();
+        if !(entry_type.is_file() || entry_type.is_dir()) {
+            continue;
+        }
+        // `unpack_in` refuses entries that would escape `root`.
+        entry.unpack_in(root)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    fn write_zip(path: &Path, entries: &[(&str, &[u8])]) {
+        let mut zip = zip::ZipWriter::new(File::create(path).expect("create zip"));
+        let options = zip::write::SimpleFileOptions::default();
+        for (name, content) in entries {
+            zip.start_file(*name, options).expect("start zip entry");
+            zip.write_all(content).expect("write zip entry");
+        }
+        zip.finish().expect("finish zip");
+    }
+
+    #[test]
+    fn test_extract_archives_adds_nested_entries_with_archive_paths() {
+        let temp_dir = tempfile::tempdir().expect("create temp dir");
+        let scan_root = temp_dir.path().join("scan");
+        fs::create_dir_all(&scan_root).expect("create scan root");
+
+        let inner = temp_dir.path().join("inner.zip");
+        write_zip(&inner, &[("NOTICE", b"inner notice\n")]);
+        let inner_bytes = fs::read(&inner).expect("read inner zip");
+        write_zip(
+            &scan_root.join("app.jar"),
+            &[
+                ("META-INF/LICENSE", b"MIT License\n"),
+                ("lib/inner.zip", &inner_bytes),
+                ("../escape.txt", b"outside\n"),
+            ],
+        );
+
+        let mut collected = collect_paths(&scan_root, 0, &[]);
+        let dest_dir = temp_dir.path().join("extracted");
+        let archives = extract_archives(&mut collected, &dest_dir, 0);
+
+        assert_eq!(archives.len(), 2);
+        assert!(collected.collection_errors.is_empty());
+        assert_eq!(collected.file_count(), 4);
+        assert!(!temp_dir.path().join("escape.txt").exists());
+
+        let mut files: Vec<FileInfo> = collected
+            .files
+            .iter()
+            .map(|(path, _)| FileInfo {
+                path: path.to_string_lossy().into_owned(),
+                ..Default::default()
+            })
+            .collect();
+        attribute_archive_paths(&mut files, &archives);
+
+        let jar = scan_root.join("app.jar").to_string_lossy().into_owned();
+        let mut paths: Vec<_> = files.into_iter().map(|file| file.path).collect();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                jar.clone(),
+                format!("{jar}!/META-INF/LICENSE"),
+                format!("{jar}!/lib/inner.zip"),
+                format!("{jar}!/lib/inner.zip!/NOTICE"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_archives_stops_at_max_depth() {
+        let temp_dir = tempfile::tempdir().expect("create temp dir");
+        let scan_root = temp_dir.path().join("scan");
+        fs::create_dir_all(&scan_root).expect("create scan root");
+
+        let inner = temp_dir.path().join("inner.zip");
+        write_zip(&inner, &[("NOTICE", b"inner notice\n")]);
+        let inner_bytes = fs::read(&inner).expect("read inner zip");
+        write_zip(&scan_root.join("outer.zip"), &[("inner.zip", &inner_bytes)]);
+
+        let mut collected = collect_paths(&scan_root, 1, &[]);
+        let archives = extract_archives(&mut collected, &temp_dir.path().join("x"), 1);
+
+        assert_eq!(archives.len(), 1);
+        assert_eq!(collected.file_count(), 2);
+    }
+
+    #[test]
+    fn test_extract_archives_reports_corrupt_archives() {
+        let temp_dir = tempfile::tempdir().expect("create temp dir");
+        let scan_root = temp_dir.path().join("scan");
+        fs::create_dir_all(&scan_root).expect("create scan root");
+        fs::write(scan_root.join("broken.tar.gz"), b"not gzip").expect("write archive");
+
+        let mut collected = collect_paths(&scan_root, 0, &[]);
+        let archives = extract_archives(&mut collected, &temp_dir.path().join("x"), 0);
+
+        assert!(archives.is_empty());
+        assert_eq!(collected.file_count(), 1);
+        assert_eq!(collected.collection_errors.len(), 1);
+    }
+}
@@ -1,4 +1,5 @@
 use glob::Pattern;
+use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -7,6 +8,8 @@ use crate::utils::file::is_path_excluded;
 pub struct CollectedPaths {
     pub files: Vec<(PathBuf, fs::Metadata)>,
     pub directories: Vec<(PathBuf, fs::Metadata)>,
+    /// Symbolic links that were not followed, with the link's own metadata.
+    pub symlinks: Vec<(PathBuf, fs::Metadata)>,
     pub excluded_count: usize,
     pub total_file_bytes: u64,
     pub collection_errors: Vec<(PathBuf, String)>,
@@ -22,10 +25,25 @@ impl CollectedPaths {
     }
 }
 
+/// Collect the files and directories under `root`, recording symbolic links
+/// as [`CollectedPaths::symlinks`] without following them.
 pub fn collect_paths<P: AsRef<Path>>(
     root: P,
     max_depth: usize,
     exclude_patterns: &[Pattern],
+) -> CollectedPaths {
+    collect_paths_with_symlinks(root, max_depth, exclude_patterns, false)
+}
+
+/// Like [`collect_paths`], but with `follow_symlinks` set, links are resolved
+/// and walked like the files and directories they point to. A link to a
+/// directory that was already visited is recorded as a symlink instead, so
+/// cyclic links cannot hang the walk.
+pub fn collect_paths_with_symlinks<P: AsRef<Path>>(
+    root: P,
+    max_depth: usize,
+    exclude_patterns: &[Pattern],
+    follow_symlinks: bool,
 ) -> CollectedPaths {
     let depth_limit = depth_limit_from_cli(max_depth);
     let root = root.as_ref();
@@ -34,6 +52,7 @@ pub fn collect_paths<P: AsRef<Path>>(
         return CollectedPaths {
             files: Vec::new(),
             directories: Vec::new(),
+            symlinks: Vec::new(),
             excluded_count: 1,
             total_file_bytes: 0,
             collection_errors: Vec::new(),
@@ -46,6 +65,7 @@ pub fn collect_paths<P: AsRef<Path>>(
             return CollectedPaths {
                 files: Vec::new(),
                 directories: Vec::new(),
+                symlinks: Vec::new(),
                 excluded_count: 0,
                 total_file_bytes: 0,
                 collection_errors: vec![(root.to_path_buf(), error.to_string())],
@@ -58,25 +78,41 @@ pub fn collect_paths<P: AsRef<Path>>(
             total_file_bytes: metadata.len(),
             files: vec![(root.to_path_buf(), metadata)],
             directories: Vec::new(),
+            symlinks: Vec::new(),
             excluded_count: 0,
             collection_errors: Vec::new(),
         };
     }
 
-    collect_all_paths(root, &metadata, depth_limit, exclude_patterns)
+    collect_all_paths(
+        root,
+        &metadata,
+        depth_limit,
+        exclude_patterns,
+        follow_symlinks,
+    )
 }
 
+/// Walk the tree with an explicit work queue instead of recursion, so stack
+/// usage stays flat no matter how deeply directories are nested.
 fn collect_all_paths(
     root: &Path,
     root_metadata: &fs::Metadata,
     depth_limit: Option<usize>,
     exclude_patterns: &[Pattern],
+    follow_symlinks: bool,
 ) -> CollectedPaths {
     let mut files = Vec::new();
     let mut directories = vec![(root.to_path_buf(), root_metadata.clone())];
+    let mut symlinks = Vec::new();
     let mut excluded_count = 0;
     let mut total_file_bytes = 0_u64;
     let mut collection_errors = Vec::new();
+    // Canonical paths of walked directories; only tracked when following links.
+    let mut visited_dirs: HashSet<PathBuf> = HashSet::new();
+    if follow_symlinks && let Ok(canonical_root) = root.canonicalize() {
+        visited_dirs.insert(canonical_root);
+    }
 
     let mut pending_dirs: Vec<(PathBuf, Option<usize>)> = vec![(root.to_path_buf(), depth_limit)];
 
@@ -97,20 +133,34 @@ fn collect_all_paths(
                 continue;
             }
 
-            match entry.metadata() {
-                Ok(metadata) if metadata.is_file() => {
-                    total_file_bytes += metadata.len();
-                    files.push((path, metadata));
-                }
-                Ok(metadata) if metadata.is_dir() => {
-                    directories.push((path.clone(), metadata));
-                    let should_recurse = current_depth.is_none_or(|d| d > 0);
-                    if should_recurse {
-                        let next_depth = current_depth.map(|d| d - 1);
-                        pending_dirs.push((path, next_depth));
+            // `DirEntry::metadata` does not traverse symbolic links.
+            let metadata = match entry.metadata() {
+                Ok(metadata) if metadata.is_symlink() => {
+                    match followed_symlink_metadata(&path, follow_symlinks, &mut visited_dirs) {
+                        Some(target_metadata) => target_metadata,
+                        None => {
+                            symlinks.push((path, metadata));
+                            continue;
+                        }
                     }
                 }
-                _ => continue,
+                Ok(metadata) => metadata,
+                Err(_) => continue,
+            };
+
+            if metadata.is_file() {
+                total_file_bytes += metadata.len();
+                files.push((path, metadata));
+            } else if metadata.is_dir() {
+                if follow_symlinks && let Ok(canonical) = path.canonicalize() {
+                    visited_dirs.insert(canonical);
+                }
+                directories.push((path.clone(), metadata));
+                let should_recurse = current_depth.is_none_or(|d| d > 0);
+                if should_recurse {
+                    let next_depth = current_depth.map(|d| d - 1);
+                    pending_dirs.push((path, next_depth));
+                }
             }
         }
     }
@@ -118,12 +168,31 @@ fn collect_all_paths(
     CollectedPaths {
         files,
         directories,
+        symlinks,
         excluded_count,
         total_file_bytes,
         collection_errors,
     }
 }
 
+/// Metadata of the target of the link at `path` if the walk should descend
+/// into it: links are followed, the target exists, and it is not a directory
+/// that was already visited.
+fn followed_symlink_metadata(
+    path: &Path,
+    follow_symlinks: bool,
+    visited_dirs: &mut HashSet<PathBuf>,
+) -> Option<fs::Metadata> {
+    if !follow_symlinks {
+        return None;
+    }
+    let metadata = fs::metadata(path).ok()?;
+    if metadata.is_dir() && !visited_dirs.insert(path.canonicalize().ok()?) {
+        return None;
+    }
+    Some(metadata)
+}
+
 fn depth_limit_from_cli(max_depth: usize) -> Option<usize> {
     if max_depth == 0 {
         None
@@ -1,9 +1,12 @@
+mod archive;
 mod collect;
+mod oci;
 mod process;
 
 use std::path::PathBuf;
 
 use crate::models::FileInfo;
+use crate::utils::hash::{DEFAULT_HASH_ALGORITHMS, HashAlgorithm};
 
 pub struct ProcessResult {
     pub files: Vec<FileInfo>,
@@ -16,6 +19,31 @@ pub struct LicenseScanOptions {
     pub include_text_diagnostics: bool,
     pub include_diagnostics: bool,
     pub unknown_licenses: bool,
+    /// Report the rule category (`match_type`) of each license match.
+    pub include_match_type: bool,
+    /// Flag detections whose licenses are all public-domain dedications.
+    pub include_public_domain: bool,
+    /// Tag each detection with the license facet it came from.
+    pub include_license_facets: bool,
+    /// Detect each section of a multi-component license file separately.
+    pub split_license_components: bool,
+    /// Flag whether each detection in a source file is in its leading comment header.
+    pub include_in_header: bool,
+    /// Report detections with imperfect match coverage as license clues
+    /// instead of license detections.
+    pub imperfect_matches_as_clues: bool,
+    /// Where `matched_text` comes from when `include_text` is set.
+    pub matched_text_source: MatchedTextSource,
+}
+
+/// Provenance of the `matched_text` reported for a license match.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum MatchedTextSource {
+    /// The matched lines of the scanned file, as found.
+    #[default]
+    Source,
+    /// The canonical text of the rule that matched.
+    Rule,
 }
 
 #[derive(Debug, Clone)]
@@ -28,8 +56,42 @@ pub struct TextDetectionOptions {
     pub detect_urls: bool,
     pub max_emails: usize,
     pub max_urls: usize,
+    /// Skip XML namespace and schema URLs and links to well-known license texts.
+    pub exclude_common_urls: bool,
     pub timeout_seconds: f64,
     pub scan_cache_dir: Option<PathBuf>,
+    /// File extensions always decoded as text, bypassing the binary heuristic.
+    pub text_extensions: Vec<String>,
+    /// File extensions always treated as binary (printable strings only).
+    pub binary_extensions: Vec<String>,
+    /// Set `has_copyright_no_license` on files with copyrights but no license detections.
+    pub mark_copyright_only: bool,
+    /// Add a canonical `Copyright (c) <years> <holder>` form next to each detected copyright.
+    pub canonicalize_copyrights: bool,
+    /// Skip license and copyright detection for files that look like tabular data.
+    pub skip_data_files: bool,
+    /// Also report each person listed one per line in AUTHORS/CONTRIBUTORS files.
+    pub listed_authors: bool,
+    /// Drop year-less copyrights detected on `copyright(...)` function calls.
+    pub skip_code_copyrights: bool,
+    /// Extra filename patterns routed to a registered package parser.
+    pub parser_patterns: Vec<ParserPattern>,
+    /// Record the `Files:` paragraphs of DEP-5 debian/copyright files in their
+    /// package data's `extra_data`.
+    pub debian_copyright_files: bool,
+    /// Derive a declared license from the License: tag of RPM .spec files.
+    pub rpm_specfile_licenses: bool,
+    /// Digests computed for each file; empty skips hashing.
+    pub hash_algorithms: Vec<HashAlgorithm>,
+}
+
+/// A runtime filename pattern routed to a registered package parser.
+#[derive(Debug, Clone)]
+pub struct ParserPattern {
+    /// Parser type name, as listed by `list_parser_types`.
+    pub parser: String,
+    /// Glob matched against the file name.
+    pub pattern: glob::Pattern,
 }
 
 impl Default for TextDetectionOptions {
@@ -43,15 +105,31 @@ impl Default for TextDetectionOptions {
             detect_urls: false,
             max_emails: 50,
             max_urls: 50,
+            exclude_common_urls: false,
             timeout_seconds: 120.0,
             scan_cache_dir: None,
+            text_extensions: Vec::new(),
+            binary_extensions: Vec::new(),
+            mark_copyright_only: false,
+            canonicalize_copyrights: false,
+            skip_data_files: false,
+            listed_authors: false,
+            skip_code_copyrights: false,
+            parser_patterns: Vec::new(),
+            debian_copyright_files: false,
+            rpm_specfile_licenses: false,
+            hash_algorithms: DEFAULT_HASH_ALGORITHMS.to_vec(),
         }
     }
 }
 
 #[allow(unused_imports)]
-pub use self::collect::{CollectedPaths, collect_paths};
-pub use self::process::process_collected;
+pub use self::archive::{ExtractedArchive, attribute_archive_paths, extract_archives};
+pub use self::collect::{CollectedPaths, collect_paths, collect_paths_with_symlinks};
+#[allow(unused_imports)]
+pub use self::oci::{OciLayer, attribute_oci_layers, extract_oci_layers};
+pub(crate) use self::process::convert_detection_to_model;
+pub use self::process::{process_collected, scan_text, scan_text_with_options};
 
 #[cfg(test)]
 mod tests {
@@ -63,7 +141,11 @@ mod tests {
     use crate::models::FileType;
     use crate::progress::{ProgressMode, ScanProgress};
 
-    use super::{LicenseScanOptions, TextDetectionOptions, collect_paths, process_collected};
+    use super::{
+        HashAlgorithm, LicenseScanOptions, TextDetectionOptions, collect_paths,
+        collect_paths_with_symlinks, process_collected, scan_text, scan_text_with_options,
+    };
+    use crate::license_detection::LicenseDetectionEngine;
 
     #[test]
     fn default_options_keep_copyright_detection_enabled() {
@@ -100,6 +182,88 @@ mod tests {
             .expect("scanned file entry")
     }
 
+    #[test]
+    fn scanner_reports_only_requested_hashes() {
+        let options = TextDetectionOptions {
+            hash_algorithms: vec![HashAlgorithm::Sha512],
+            ..Default::default()
+        };
+        let scanned = scan_single_file("notes.txt", "abc", &options);
+
+        assert_eq!(scanned.sha1, None);
+        assert_eq!(scanned.md5, None);
+        assert_eq!(scanned.sha256, None);
+        assert_eq!(
+            scanned.sha512.as_deref(),
+            Some(
+                "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+                 2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+            )
+        );
+
+        let scanned = scan_single_file("notes.txt", "abc", &TextDetectionOptions::default());
+        assert!(scanned.sha1.is_some() && scanned.md5.is_some() && scanned.sha256.is_some());
+        assert_eq!(scanned.sha512, None);
+    }
+
+    #[test]
+    fn scan_text_matches_filesystem_scan() {
+        let content = r#"{
+  "name": "demo",
+  "version": "1.0.0",
+  "author": "Copyright (c) 2024 Example Corp."
+}
+"#;
+        let options = TextDetectionOptions {
+            detect_packages: true,
+            ..Default::default()
+        };
+        let on_disk = scan_single_file("package.json", content, &options);
+        let in_memory = scan_text_with_options(
+            content,
+            "package.json",
+            None,
+            LicenseScanOptions::default(),
+            &options,
+        );
+
+        assert_eq!(in_memory.path, "package.json");
+        assert_eq!(in_memory.name, on_disk.name);
+        assert_eq!(in_memory.size, on_disk.size);
+        assert_eq!(in_memory.sha1, on_disk.sha1);
+        assert_eq!(in_memory.programming_language, on_disk.programming_language);
+        assert_eq!(in_memory.package_data.len(), 1);
+        assert_eq!(in_memory.package_data[0].purl, on_disk.package_data[0].purl);
+        assert_eq!(
+            in_memory
+                .holders
+                .iter()
+                .map(|holder| holder.holder.as_str())
+                .collect::<Vec<_>>(),
+            on_disk
+                .holders
+                .iter()
+                .map(|holder| holder.holder.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert!(in_memory.scan_errors.is_empty());
+    }
+
+    #[test]
+    fn scan_text_detects_licenses_with_engine() {
+        let engine = LicenseDetectionEngine::from_embedded().expect("embedded license engine");
+        let scanned = scan_text(
+            "// SPDX-License-Identifier: Apache-2.0\nfn main() {}\n",
+            "src/main.rs",
+            &engine,
+        );
+
+        assert_eq!(scanned.path, "src/main.rs");
+        assert_eq!(scanned.license_expression.as_deref(), Some("Apache-2.0"));
+        assert_eq!(scanned.programming_language.as_deref(), Some("Rust"));
+        assert!(scanned.package_data.is_empty());
+    }
+
     #[test]
     fn scanner_reports_repeated_email_occurrences() {
         let options = TextDetectionOptions {
@@ -113,6 +277,7 @@ mod tests {
             max_urls: 50,
             timeout_seconds: 120.0,
             scan_cache_dir: None,
+            ..Default::default()
         };
         let scanned = scan_single_file(
             "contacts.txt",
@@ -151,6 +316,7 @@ mod tests {
             max_urls: 50,
             timeout_seconds: 120.0,
             scan_cache_dir: None,
+            ..Default::default()
         };
         let pem_fixture = concat!(
             "-----BEGIN CERTIFICATE-----\n",
@@ -208,6 +374,7 @@ mod tests {
             max_urls: 50,
             timeout_seconds: 120.0,
             scan_cache_dir: None,
+            ..Default::default()
         };
         let credits_fixture = concat!(
             "N: Jack Lloyd\n",
@@ -247,6 +414,7 @@ mod tests {
             max_urls: 50,
             timeout_seconds: 120.0,
             scan_cache_dir: None,
+            ..Default::default()
         };
         let scanned = scan_single_file(
             "generated.c",
@@ -270,6 +438,7 @@ mod tests {
             max_urls: 50,
             timeout_seconds: 120.0,
             scan_cache_dir: None,
+            ..Default::default()
         };
         let scanned = scan_single_file(
             "generated.c",
@@ -293,6 +462,7 @@ mod tests {
             max_urls: 50,
             timeout_seconds: 120.0,
             scan_cache_dir: None,
+            ..Default::default()
         };
         let scanned = scan_single_file(
             "package.json",
@@ -320,6 +490,7 @@ mod tests {
             max_urls: 50,
             timeout_seconds: 120.0,
             scan_cache_dir: None,
+            ..Default::default()
         };
         let scanned = scan_single_file(
             "package.json",
@@ -348,6 +519,7 @@ mod tests {
             max_urls: 50,
             timeout_seconds: 120.0,
             scan_cache_dir: None,
+            ..Default::default()
         };
         let with_info = TextDetectionOptions {
             collect_info: true,
@@ -378,6 +550,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn collect_paths_handles_very_deep_directory_chains() {
+        const DEPTH: usize = 1000;
+
+        let temp_dir = TempDir::new().expect("create temp dir");
+        let mut deepest = temp_dir.path().to_path_buf();
+        for _ in 0..DEPTH {
+            deepest.push("d");
+        }
+        fs::create_dir_all(&deepest).expect("create deep dir chain");
+        fs::write(deepest.join("leaf.txt"), "leaf\n").expect("write leaf file");
+
+        let root = temp_dir.path().to_path_buf();
+        let collected = std::thread::Builder::new()
+            .stack_size(128 * 1024)
+            .spawn(move || collect_paths(&root, 0, &[]))
+            .expect("spawn collector thread")
+            .join()
+            .expect("collection should not overflow a small stack");
+
+        assert_eq!(collected.directory_count(), DEPTH + 1);
+        assert_eq!(collected.file_count(), 1);
+        assert_eq!(collected.files[0].0, deepest.join("leaf.txt"));
+        assert!(collected.collection_errors.is_empty());
+    }
+
     #[test]
     fn collect_paths_supports_single_file_input() {
         let temp_dir = TempDir::new().expect("create temp dir");
@@ -390,4 +588,70 @@ mod tests {
         assert!(collected.directories.is_empty());
         assert_eq!(collected.files[0].0, file_path);
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn collect_paths_reports_symlinks_without_following_them() {
+        use std::os::unix::fs::symlink;
+
+        let temp_dir = TempDir::new().expect("create temp dir");
+        fs::create_dir_all(temp_dir.path().join("src")).expect("create src dir");
+        fs::write(temp_dir.path().join("src/main.rs"), "fn main() {}\n").expect("write file");
+        symlink("..", temp_dir.path().join("src/loop")).expect("create cyclic link");
+
+        let collected = collect_paths(temp_dir.path(), 0, &[]);
+        assert_eq!(collected.file_count(), 1);
+        assert_eq!(collected.symlinks.len(), 1);
+
+        let result = process_collected(
+            &collected,
+            Arc::new(ScanProgress::new(ProgressMode::Quiet)),
+            None,
+            LicenseScanOptions::default(),
+            &TextDetectionOptions::default(),
+        );
+        let link = result
+            .files
+            .iter()
+            .find(|entry| entry.file_type == FileType::Symlink)
+            .expect("symlink entry");
+        assert!(link.path.ends_with("src/loop"));
+        assert_eq!(link.link_target.as_deref(), Some(".."));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn collect_paths_with_symlinks_follows_links_but_not_cycles() {
+        use std::os::unix::fs::symlink;
+
+        let temp_dir = TempDir::new().expect("create temp dir");
+        let root = temp_dir.path().join("root");
+        let outside = temp_dir.path().join("outside");
+        fs::create_dir_all(root.join("src")).expect("create src dir");
+        fs::create_dir_all(&outside).expect("create outside dir");
+        fs::write(root.join("src/main.rs"), "fn main() {}\n").expect("write file");
+        fs::write(outside.join("LICENSE"), "MIT\n").expect("write outside file");
+        symlink(&outside, root.join("vendor")).expect("create directory link");
+        symlink(root.join("src/main.rs"), root.join("main.rs")).expect("create file link");
+        symlink(&root, root.join("src/loop")).expect("create cyclic link");
+
+        let collected = collect_paths_with_symlinks(&root, 0, &[], true);
+
+        let mut files: Vec<_> = collected
+            .files
+            .iter()
+            .map(|(path, _)| path.strip_prefix(&root).expect("under root").to_path_buf())
+            .collect();
+        files.sort();
+        assert_eq!(
+            files,
+            vec![
+                std::path::PathBuf::from("main.rs"),
+                "src/main.rs".into(),
+                "vendor/LICENSE".into(),
+            ]
+        );
+        assert_eq!(collected.symlinks.len(), 1);
+        assert_eq!(collected.symlinks[0].0, root.join("src/loop"));
+    }
 }
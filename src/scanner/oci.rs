@@ -0,0 +1,225 @@
+//! Scanning of extracted OCI image layouts.
+//!
+//! An OCI image layout directory holds an `index.json` pointing at image
+//! manifests stored as content-addressed blobs under `blobs/<algorithm>/<hex>`.
+//! Each manifest lists its layers, which are tar archives, optionally gzip- or
+//! zstd-compressed. [`extract_oci_layers`] unpacks every layer of the first image
+//! manifest into its own directory so the regular scanner can walk them, and
+//! [`attribute_oci_layers`] then tags each scanned file with the digest of the
+//! layer it came from.
+
+use std::fs::{self, File};
+use std::io::{BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, anyhow};
+use flate2::read::GzDecoder;
+use serde::Deserialize;
+
+use crate::models::FileInfo;
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+const WHITEOUT_PREFIX: &str = ".wh.";
+
+/// One image layer unpacked to disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OciLayer {
+    /// Content digest of the layer blob, e.g. `sha256:4f2c...`.
+    pub digest: String,
+    /// Directory the layer's filesystem was unpacked into.
+    pub root: PathBuf,
+}
+
+#[derive(Deserialize)]
+struct Descriptor {
+    digest: String,
+}
+
+#[derive(Deserialize)]
+struct IndexOrManifest {
+    #[serde(default)]
+    manifests: Vec<Descriptor>,
+    #[serde(default)]
+    layers: Option<Vec<Descriptor>>,
+}
+
+/// Unpack the layers of the image in the OCI layout at `image_dir` into
+/// `dest_dir`, one sub-directory per layer named after its digest.
+///
+/// Only the first image manifest reachable from `index.json` is used, so
+/// multi-platform indexes are not scanned once per platform. Whiteout entries
+/// (`.wh.*`) are skipped; layers are not squashed together.
+pub fn extract_oci_layers(image_dir: &Path, dest_dir: &Path) -> Result<Vec<OciLayer>> {
+    let index_path = image_dir.join("index.json");
+    let index: IndexOrManifest = read_json(&index_path)?;
+    let layers = find_image_layers(image_dir, &index, 0)?
+        .ok_or_else(|| anyhow!("No image manifest found in {:?}", index_path))?;
+
+    fs::create_dir_all(dest_dir)
+        .with_context(|| format!("Failed to create layer directory {:?}", dest_dir))?;
+
+    layers
+        .into_iter()
+        .map(|descriptor| {
+            let root = dest_dir.join(layer_dir_name(&descriptor.digest)?);
+            unpack_layer(&blob_path(image_dir, &descriptor.digest)?, &root)
+                .with_context(|| format!("Failed to unpack layer {}", descriptor.digest))?;
+            Ok(OciLayer {
+                digest: descriptor.digest,
+                root,
+            })
+        })
+        .collect()
+}
+
+/// Set `layer_digest` on files unpacked from `layers` and rewrite every path
+/// relative to `extraction_dir`, the temporary parent of the unpacked image.
+pub fn attribute_oci_layers(files: &mut [FileInfo], layers: &[OciLayer], extraction_dir: &Path) {
+    for file in files {
+        let path = Path::new(&file.path);
+        file.layer_digest = layers
+            .iter()
+            .find(|layer| path.starts_with(&layer.root))
+            .map(|layer| layer.digest.clone());
+        if let Ok(relative) = path.strip_prefix(extraction_dir) {
+            file.path = relative.to_string_lossy().into_owned();
+        }
+    }
+}
+
+fn find_image_layers(
+    image_dir: &Path,
+    index: &IndexOrManifest,
+    depth: usize,
+) -> Result<Option<Vec<Descriptor>>> {
+    // Nested indexes are legal but never deep in practice; bound the walk.
+    if depth > 4 {
+        return Ok(None);
+    }
+    for descriptor in &index.manifests {
+        let manifest: IndexOrManifest = read_json(&blob_path(image_dir, &descriptor.digest)?)?;
+        if let Some(layers) = manifest.layers {
+            return Ok(Some(layers));
+        }
+        if let Some(layers) = find_image_layers(image_dir, &manifest, depth + 1)? {
+            return Ok(Some(layers));
+        }
+    }
+    Ok(None)
+}
+
+fn unpack_layer(blob: &Path, root: &Path) -> Result<()> {
+    let mut reader = BufReader::new(File::open(blob)?);
+    let mut magic = [0u8; 4];
+    let read = reader.read(&mut magic)?;
+    let header = &magic[..read];
+    let stream = header.chain(reader);
+
+    if header.starts_with(&ZSTD_MAGIC) {
+        unpack_tar(zstd::stream::read::Decoder::new(stream)?, root)
+    } else if header.starts_with(&GZIP_MAGIC) {
+        unpack_tar(GzDecoder::new(stream), root)
+    } else {
+        unpack_tar(stream, root)
+    }
+}
+
+fn unpack_tar(reader: impl Read, root: &Path) -> Result<()> {
+    fs::create_dir_all(root)?;
+    let mut archive = tar::Archive::new(reader);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let is_whiteout = entry
+            .path()?
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with(WHITEOUT_PREFIX));
+        if is_whiteout {
+            continue;
+        }
+        // `unpack_in` refuses entries that would escape `root`.
+        entry.unpack_in(root)?;
+    }
+    Ok(())
+}
+
+fn read_json<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<T> {
+    let file = File::open(path).with_context(|| format!("Failed to open {:?}", path))?;
+    serde_json::from_reader(BufReader::new(file))
+        .with_context(|| format!("Failed to parse {:?}", path))
+}
+
+fn split_digest(digest: &str) -> Result<(&str, &str)> {
+    let valid_algorithm = |part: &str| {
+        part.starts_with(|c: char| c.is_ascii_alphanumeric())
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '.' | '_' | '-'))
+    };
+    let valid_hex =
+        |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric());
+    match digest.split_once(':') {
+        Some((algorithm, hex)) if valid_algorithm(algorithm) && valid_hex(hex) => {
+            Ok((algorithm, hex))
+        }
+        _ => Err(anyhow!("Invalid blob digest: {digest:?}")),
+    }
+}
+
+fn blob_path(image_dir: &Path, digest: &str) -> Result<PathBuf> {
+    let (algorithm, hex) = split_digest(digest)?;
+    Ok(image_dir.join("blobs").join(algorithm).join(hex))
+}
+
+fn layer_dir_name(digest: &str) -> Result<String> {
+    let (algorithm, hex) = split_digest(digest)?;
+    Ok(format!("{algorithm}-{hex}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_digest_rejects_path_traversal() {
+        assert_eq!(
+            split_digest("sha256:abc123").expect("valid digest"),
+            ("sha256", "abc123")
+        );
+        assert!(split_digest("sha256:../../etc/passwd").is_err());
+        assert!(split_digest("abc123").is_err());
+        assert!(split_digest("sha256:").is_err());
+    }
+
+    #[test]
+    fn test_unpack_layer_decodes_zstd_layers() {
+        let mut tar_bytes = Vec::new();
+        {
+            let mut builder = tar::Builder::new(&mut tar_bytes);
+            let content = b"zstd layer\n";
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, "etc/motd", &content[..])
+                .expect("append tar entry");
+            builder.finish().expect("finish tar");
+        }
+        let temp_dir = tempfile::tempdir().expect("create temp dir");
+        let blob = temp_dir.path().join("layer.tar.zst");
+        fs::write(
+            &blob,
+            zstd::encode_all(&tar_bytes[..], 0).expect("compress layer"),
+        )
+        .expect("write layer blob");
+
+        let root = temp_dir.path().join("root");
+        unpack_layer(&blob, &root).expect("unpack zstd layer");
+
+        assert_eq!(
+            fs::read_to_string(root.join("etc/motd")).expect("read unpacked file"),
+            "zstd layer\n"
+        );
+    }
+}
@@ -1,32 +1,47 @@
 use crate::license_detection::LicenseDetectionEngine;
-use crate::parsers::try_parse_file;
-use crate::utils::hash::{calculate_md5, calculate_sha1, calculate_sha256};
-use crate::utils::language::detect_language;
+use crate::license_detection::detection::DETECTION_LOG_IMPERFECT_COVERAGE;
+use crate::parsers::{
+    ParsePackagesResult, attach_dep5_files_paragraphs, attach_rpm_specfile_declared_license,
+    has_package_handler, panic_message, try_parse_file, try_parse_file_with,
+};
+use crate::utils::hash::{HashAlgorithm, calculate_hashes};
+use crate::utils::language::programming_language;
 use crate::utils::text::{is_source, remove_verbatim_escape_sequences};
 use anyhow::Error;
 use mime_guess::from_path;
 use rayon::prelude::*;
-use std::fs::{self};
-use std::path::Path;
+use std::fs::{self, File};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::path::{Component, Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
+use tempfile::TempDir;
 
 use crate::cache::{CachedScanFindings, read_cached_findings, write_cached_findings};
 use crate::copyright::{
     self, AuthorDetection, CopyrightDetection, CopyrightDetectionOptions, HolderDetection,
 };
 use crate::finder::{self, DetectionConfig};
-use crate::license_detection::models::LicenseMatch as InternalLicenseMatch;
+use crate::license_detection::models::{LicenseMatch as InternalLicenseMatch, RuleKind};
+use crate::license_detection::multi_component::split_components;
 use crate::license_detection::query::Query;
 use crate::models::{
-    Author, Copyright, FileInfo, FileInfoBuilder, FileType, Holder, LicenseDetection, Match,
-    OutputEmail, OutputURL,
+    Author, Copyright, DatasourceId, FileInfo, FileInfoBuilder, FileType, Holder, LicenseDetection,
+    LicenseFacet, Match, OutputEmail, OutputURL,
 };
 use crate::progress::ScanProgress;
 use crate::scanner::collect::CollectedPaths;
-use crate::scanner::{LicenseScanOptions, ProcessResult, TextDetectionOptions};
-use crate::utils::file::{ExtractedTextKind, extract_text_for_detection, get_creation_date};
+use crate::scanner::{
+    LicenseScanOptions, MatchedTextSource, ParserPattern, ProcessResult, TextDetectionOptions,
+};
+use crate::utils::comment_header::leading_comment_end_line;
+use crate::utils::file::{
+    ContentClassOverride, ExtractedTextKind, LOSSY_DECODE_NOTE, content_class_override,
+    extract_text_for_detection_with_override, get_creation_date, is_legal_file_name,
+};
 use crate::utils::generated::generated_code_hints_from_bytes;
+use crate::utils::preserved_comments::{PreservedComment, find_preserved_comments};
+use crate::utils::tabular::{SKIPPED_DATA_FILE_NOTE, is_tabular_data};
 
 const PEM_CERTIFICATE_HEADERS: &[(&str, &str)] = &[
     ("-----BEGIN CERTIFICATE-----", "-----END CERTIFICATE-----"),
@@ -68,12 +83,165 @@ pub fn process_collected(
         ));
     }
 
+    for (path, metadata) in &collected.symlinks {
+        all_files.push(process_symlink(path, metadata));
+    }
+
     ProcessResult {
         files: all_files,
         excluded_count: collected.excluded_count,
     }
 }
 
+/// Scan in-memory `content` as if it were the content of a file named
+/// `filename`, with license, copyright and package detection enabled.
+///
+/// See [`scan_text_with_options`] to choose which detections run.
+pub fn scan_text(content: &str, filename: &str, engine: &LicenseDetectionEngine) -> FileInfo {
+    let text_options = TextDetectionOptions {
+        detect_packages: true,
+        ..Default::default()
+    };
+    scan_text_with_options(
+        content,
+        filename,
+        Some(engine),
+        LicenseScanOptions::default(),
+        &text_options,
+    )
+}
+
+/// Scan in-memory `content` as if it were the content of a file named
+/// `filename`. License detection is skipped when `engine` is `None`.
+///
+/// `filename` may be a relative path such as `debian/copyright`; it selects
+/// the package parser and drives the same path-based heuristics as a
+/// filesystem scan, and becomes the reported `path`. Package parsers only read
+/// files, so when `filename` names a package manifest the content is written
+/// to a private temporary directory for the parser. Nothing else touches the
+/// filesystem, and `scan_cache_dir` is ignored.
+pub fn scan_text_with_options(
+    content: &str,
+    filename: &str,
+    engine: Option<&LicenseDetectionEngine>,
+    license_options: LicenseScanOptions,
+    text_options: &TextDetectionOptions,
+) -> FileInfo {
+    let path = Path::new(filename);
+    let buffer = content.as_bytes();
+    let mut scan_errors: Vec<String> = vec![];
+    let mut file_info_builder = FileInfoBuilder::default();
+    let started = Instant::now();
+
+    if !text_options.hash_algorithms.is_empty() {
+        match calculate_hashes(buffer, &text_options.hash_algorithms) {
+            Ok(hashes) => {
+                file_info_builder
+                    .sha1(hashes.sha1)
+                    .md5(hashes.md5)
+                    .sha256(hashes.sha256)
+                    .sha512(hashes.sha512);
+            }
+            Err(err) => scan_errors.push(format!("Failed to hash file: {err}")),
+        }
+    }
+
+    let generated_flag = text_options
+        .detect_generated
+        .then(|| !generated_code_hints_from_bytes(buffer).is_empty());
+    file_info_builder.programming_language(Some(programming_language(path, buffer)));
+
+    if !should_skip_text_detection(path, buffer) {
+        let mut staged = None;
+        if text_options.detect_packages
+            && (has_package_handler(path)
+                || routed_parser_pattern(path, &text_options.parser_patterns).is_some())
+        {
+            match stage_package_file(path, buffer) {
+                Ok(staged_file) => staged = Some(staged_file),
+                Err(err) => scan_errors.push(format!("Failed to stage package file: {err}")),
+            }
+        }
+
+        match catch_unwind(AssertUnwindSafe(|| {
+            extract_information_from_buffer(
+                &mut file_info_builder,
+                &mut scan_errors,
+                path,
+                staged
+                    .as_ref()
+                    .map(|(_, staged_path)| staged_path.as_path()),
+                buffer,
+                engine,
+                license_options,
+                text_options,
+                started,
+            )
+        })) {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => scan_errors.push(e.to_string()),
+            Err(payload) => scan_errors.push(format!(
+                "Scan failed unexpectedly: {}",
+                panic_message(payload.as_ref())
+            )),
+        }
+    }
+
+    let name = path.file_name().map_or_else(
+        || filename.to_string(),
+        |name| name.to_string_lossy().to_string(),
+    );
+    let mut file_info = file_info_builder
+        .base_name(
+            path.file_stem()
+                .map_or_else(|| name.clone(), |stem| stem.to_string_lossy().to_string()),
+        )
+        .name(name)
+        .extension(
+            path.extension()
+                .map_or("".to_string(), |ext| format!(".{}", ext.to_string_lossy())),
+        )
+        .path(filename.to_string())
+        .file_type(FileType::File)
+        .mime_type(Some(mime_type_for(path, text_options)))
+        .size(buffer.len() as u64)
+        .scan_errors(scan_errors)
+        .build()
+        .expect("FileInformationBuild not completely initialized");
+
+    finalize_file_info(
+        &mut file_info,
+        path,
+        generated_flag,
+        engine.is_some(),
+        license_options,
+        text_options,
+    );
+
+    file_info
+}
+
+/// Write `content` to a new temporary directory under the relative `path`, so
+/// that parsers matching on parent directory names still see them.
+fn stage_package_file(path: &Path, content: &[u8]) -> std::io::Result<(TempDir, PathBuf)> {
+    let temp_dir = tempfile::Builder::new()
+        .prefix("provenant-scan-text-")
+        .tempdir()?;
+    let relative: PathBuf = path
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(part) => Some(part),
+            _ => None,
+        })
+        .collect();
+    let staged_path = temp_dir.path().join(relative);
+    if let Some(parent) = staged_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&staged_path, content)?;
+    Ok((temp_dir, staged_path))
+}
+
 fn process_file(
     path: &Path,
     metadata: &fs::Metadata,
@@ -88,16 +256,24 @@ fn process_file(
     let started = Instant::now();
 
     let mut generated_flag = None;
-    match extract_information_from_content(
-        &mut file_info_builder,
-        &mut scan_errors,
-        path,
-        license_engine,
-        license_options,
-        text_options,
-    ) {
-        Ok(is_generated) => generated_flag = is_generated,
-        Err(e) => scan_errors.push(e.to_string()),
+    // A panic in one detector must not abort the whole scan; report it on the
+    // file and keep whatever was extracted before it.
+    match catch_unwind(AssertUnwindSafe(|| {
+        extract_information_from_content(
+            &mut file_info_builder,
+            &mut scan_errors,
+            path,
+            license_engine.clone(),
+            license_options,
+            text_options,
+        )
+    })) {
+        Ok(Ok(is_generated)) => generated_flag = is_generated,
+        Ok(Err(e)) => scan_errors.push(e.to_string()),
+        Err(payload) => scan_errors.push(format!(
+            "Scan failed unexpectedly: {}",
+            panic_message(payload.as_ref())
+        )),
     };
 
     if is_timeout_exceeded(started, text_options.timeout_seconds) {
@@ -121,21 +297,21 @@ fn process_file(
         )
         .path(path.to_string_lossy().to_string())
         .file_type(FileType::File)
-        .mime_type(Some(
-            from_path(path)
-                .first_or_octet_stream()
-                .essence_str()
-                .to_string(),
-        ))
+        .mime_type(Some(mime_type_for(path, text_options)))
         .size(metadata.len())
         .date(get_creation_date(metadata))
         .scan_errors(scan_errors)
         .build()
         .expect("FileInformationBuild not completely initialized");
 
-    if text_options.collect_info {
-        file_info.is_source = Some(is_source(path));
-    }
+    finalize_file_info(
+        &mut file_info,
+        path,
+        generated_flag,
+        license_enabled,
+        license_options,
+        text_options,
+    );
 
     if file_info.programming_language.as_deref() == Some("Go")
         && is_go_non_production_source(path).unwrap_or(false)
@@ -143,22 +319,18 @@ fn process_file(
         file_info.is_source = Some(false);
     }
 
-    if text_options.detect_generated {
-        file_info.is_generated = Some(generated_flag.unwrap_or(false));
-    }
-
-    if file_info.percentage_of_license_text.is_none() && license_enabled {
-        file_info.percentage_of_license_text = Some(0.0);
-    }
-
     if let (Some(scan_results_dir), Some(sha256)) = (
         text_options.scan_cache_dir.as_deref(),
         file_info.sha256.as_deref(),
     ) && file_info.scan_errors.is_empty()
     {
         let findings = CachedScanFindings::from_file_info(&file_info);
-        let options_fingerprint =
-            scan_cache_fingerprint(text_options, license_options, license_enabled);
+        let options_fingerprint = scan_cache_fingerprint(
+            path,
+            text_options,
+            license_options,
+            license_engine.as_deref(),
+        );
         if let Err(err) =
             write_cached_findings(scan_results_dir, sha256, &options_fingerprint, &findings)
         {
@@ -168,9 +340,62 @@ fn process_file(
         }
     }
 
+    if !text_options
+        .hash_algorithms
+        .contains(&HashAlgorithm::Sha256)
+    {
+        file_info.sha256 = None;
+    }
+
     file_info
 }
 
+/// Fill in the fields that depend on the scan options once detection is done.
+fn finalize_file_info(
+    file_info: &mut FileInfo,
+    path: &Path,
+    generated_flag: Option<bool>,
+    license_enabled: bool,
+    license_options: LicenseScanOptions,
+    text_options: &TextDetectionOptions,
+) {
+    if text_options.collect_info {
+        file_info.is_source = Some(is_source(path));
+    }
+
+    if text_options.detect_generated {
+        file_info.is_generated = Some(generated_flag.unwrap_or(false));
+    }
+
+    if file_info.percentage_of_license_text.is_none() && license_enabled {
+        file_info.percentage_of_license_text = Some(0.0);
+    }
+
+    // Always reset: cached findings may carry forms from a run with the flag on.
+    for detected in &mut file_info.copyrights {
+        detected.normalized_copyright = text_options
+            .canonicalize_copyrights
+            .then(|| copyright::canonicalize_copyright(&detected.copyright));
+    }
+
+    if license_options.include_license_facets {
+        for package_data in &mut file_info.package_data {
+            for detection in package_data
+                .license_detections
+                .iter_mut()
+                .chain(package_data.other_license_detections.iter_mut())
+            {
+                detection.license_facet = Some(LicenseFacet::Declared);
+            }
+        }
+    }
+
+    if text_options.mark_copyright_only && license_enabled {
+        file_info.has_copyright_no_license =
+            !file_info.copyrights.is_empty() && file_info.license_detections.is_empty();
+    }
+}
+
 fn extract_information_from_content(
     file_info_builder: &mut FileInfoBuilder,
     scan_errors: &mut Vec<String>,
@@ -180,8 +405,28 @@ fn extract_information_from_content(
     text_options: &TextDetectionOptions,
 ) -> Result<Option<bool>, Error> {
     let started = Instant::now();
+    // The scan cache is keyed on SHA-256, so it is computed for cache lookups
+    // even when it is not reported.
+    let mut hash_algorithms = text_options.hash_algorithms.clone();
+    if text_options.scan_cache_dir.is_some() && !hash_algorithms.contains(&HashAlgorithm::Sha256) {
+        hash_algorithms.push(HashAlgorithm::Sha256);
+    }
+    let mut sha256 = None;
+    if !hash_algorithms.is_empty() {
+        match File::open(path).and_then(|file| calculate_hashes(file, &hash_algorithms)) {
+            Ok(hashes) => {
+                sha256.clone_from(&hashes.sha256);
+                file_info_builder
+                    .sha1(hashes.sha1)
+                    .md5(hashes.md5)
+                    .sha256(hashes.sha256)
+                    .sha512(hashes.sha512);
+            }
+            Err(err) => scan_errors.push(format!("Failed to hash file: {err}")),
+        }
+    }
+
     let buffer = fs::read(path)?;
-    let license_enabled = license_engine.is_some();
 
     if is_timeout_exceeded(started, text_options.timeout_seconds) {
         return Err(Error::msg(format!(
@@ -190,25 +435,26 @@ fn extract_information_from_content(
         )));
     }
 
-    let sha256 = calculate_sha256(&buffer);
     let is_generated = text_options
         .detect_generated
         .then(|| !generated_code_hints_from_bytes(&buffer).is_empty());
 
-    file_info_builder
-        .sha1(Some(calculate_sha1(&buffer)))
-        .md5(Some(calculate_md5(&buffer)))
-        .sha256(Some(sha256.clone()))
-        .programming_language(Some(detect_language(path, &buffer)));
+    file_info_builder.programming_language(Some(programming_language(path, &buffer)));
 
     if should_skip_text_detection(path, &buffer) {
         return Ok(is_generated);
     }
 
-    if let Some(scan_results_dir) = text_options.scan_cache_dir.as_deref() {
-        let options_fingerprint =
-            scan_cache_fingerprint(text_options, license_options, license_enabled);
-        match read_cached_findings(scan_results_dir, &sha256, &options_fingerprint) {
+    if let (Some(scan_results_dir), Some(sha256)) =
+        (text_options.scan_cache_dir.as_deref(), sha256.as_deref())
+    {
+        let options_fingerprint = scan_cache_fingerprint(
+            path,
+            text_options,
+            license_options,
+            license_engine.as_deref(),
+        );
+        match read_cached_findings(scan_results_dir, sha256, &options_fingerprint) {
             Ok(Some(findings)) => {
                 file_info_builder
                     .package_data(findings.package_data)
@@ -221,7 +467,8 @@ fn extract_information_from_content(
                     .authors(findings.authors)
                     .emails(findings.emails)
                     .urls(findings.urls)
-                    .programming_language(findings.programming_language);
+                    .programming_language(findings.programming_language)
+                    .scan_notes(findings.scan_notes);
                 return Ok(is_generated);
             }
             Ok(None) => {}
@@ -231,12 +478,59 @@ fn extract_information_from_content(
         }
     }
 
+    extract_information_from_buffer(
+        file_info_builder,
+        scan_errors,
+        path,
+        Some(path),
+        &buffer,
+        license_engine.as_deref(),
+        license_options,
+        text_options,
+        started,
+    )?;
+
+    Ok(is_generated)
+}
+
+/// Package, copyright, email/URL and license detection on the content of
+/// `path`. Packages are parsed from `package_path` when given.
+#[allow(clippy::too_many_arguments)]
+fn extract_information_from_buffer(
+    file_info_builder: &mut FileInfoBuilder,
+    scan_errors: &mut Vec<String>,
+    path: &Path,
+    package_path: Option<&Path>,
+    buffer: &[u8],
+    license_engine: Option<&LicenseDetectionEngine>,
+    license_options: LicenseScanOptions,
+    text_options: &TextDetectionOptions,
+    started: Instant,
+) -> Result<(), Error> {
     // Package parsing and text-based detection (copyright, license) are independent.
     // Python ScanCode runs all enabled plugins on every file, so we do the same.
     if text_options.detect_packages
-        && let Some(parse_result) = try_parse_file(path)
+        && let Some(parse_result) = package_path.and_then(|package_path| {
+            parse_package_file(package_path, &text_options.parser_patterns)
+        })
     {
-        file_info_builder.package_data(parse_result.packages);
+        let mut packages = parse_result.packages;
+        if text_options.debian_copyright_files {
+            let content = String::from_utf8_lossy(buffer);
+            for package in packages
+                .iter_mut()
+                .filter(|package| package.datasource_id == Some(DatasourceId::DebianCopyright))
+            {
+                attach_dep5_files_paragraphs(package, &content);
+            }
+        }
+        if text_options.rpm_specfile_licenses {
+            packages
+                .iter_mut()
+                .filter(|package| package.datasource_id == Some(DatasourceId::RpmSpecfile))
+                .for_each(attach_rpm_specfile_declared_license);
+        }
+        file_info_builder.package_data(packages);
         scan_errors.extend(parse_result.scan_errors);
     }
 
@@ -247,8 +541,29 @@ fn extract_information_from_content(
         )));
     }
 
-    let (text_content, text_kind) = extract_text_for_detection(path, &buffer);
+    let content_override = content_class_override(
+        path,
+        &text_options.text_extensions,
+        &text_options.binary_extensions,
+    );
+    let (text_content, text_kind) =
+        extract_text_for_detection_with_override(path, buffer, content_override);
     let from_binary_strings = matches!(text_kind, ExtractedTextKind::BinaryStrings);
+    let preserved_comments = if is_source(path) {
+        find_preserved_comments(&text_content)
+    } else {
+        Vec::new()
+    };
+    let mut scan_notes = Vec::new();
+    if text_kind == ExtractedTextKind::LossyDecoded {
+        scan_notes.push(LOSSY_DECODE_NOTE.to_string());
+    }
+    scan_notes.extend(preserved_comments.iter().map(PreservedComment::note));
+    let skip_data_file = text_options.skip_data_files && is_tabular_data(&text_content);
+    if skip_data_file {
+        scan_notes.push(SKIPPED_DATA_FILE_NOTE.to_string());
+    }
+    file_info_builder.scan_notes(scan_notes.clone());
 
     if is_timeout_exceeded(started, text_options.timeout_seconds) {
         return Err(Error::msg(format!(
@@ -258,20 +573,26 @@ fn extract_information_from_content(
     }
 
     if text_content.is_empty() {
-        return Ok(is_generated);
+        return Ok(());
     }
 
-    if text_options.detect_copyrights {
+    if text_options.detect_copyrights && !skip_data_file {
         extract_copyright_information(
             file_info_builder,
             path,
             &text_content,
             text_options.timeout_seconds,
             from_binary_strings,
+            text_options.listed_authors,
+            text_options.skip_code_copyrights,
         );
     }
     extract_email_url_information(file_info_builder, &text_content, text_options);
 
+    if skip_data_file {
+        return Ok(());
+    }
+
     if is_timeout_exceeded(started, text_options.timeout_seconds) {
         return Err(Error::msg(format!(
             "Timeout before license scan (> {:.2}s)",
@@ -296,14 +617,31 @@ fn extract_information_from_content(
     extract_license_information(
         file_info_builder,
         scan_errors,
+        &mut scan_notes,
         path,
         text_content_for_license_detection,
         license_engine,
         license_options,
         from_binary_strings,
     )?;
+    file_info_builder.scan_notes(scan_notes);
 
-    Ok(is_generated)
+    Ok(())
+}
+
+fn mime_type_for(path: &Path, text_options: &TextDetectionOptions) -> String {
+    let guessed = from_path(path).first_or_octet_stream();
+    let is_text = guessed.type_() == mime_guess::mime::TEXT;
+
+    match content_class_override(
+        path,
+        &text_options.text_extensions,
+        &text_options.binary_extensions,
+    ) {
+        Some(ContentClassOverride::Text) if !is_text => "text/plain".to_string(),
+        Some(ContentClassOverride::Binary) if is_text => "application/octet-stream".to_string(),
+        _ => guessed.essence_str().to_string(),
+    }
 }
 
 fn is_timeout_exceeded(started: Instant, timeout_seconds: f64) -> bool {
@@ -312,25 +650,93 @@ fn is_timeout_exceeded(started: Instant, timeout_seconds: f64) -> bool {
         && started.elapsed().as_secs_f64() > timeout_seconds
 }
 
+/// Parse `path` with the parser its filename is routed to at runtime, if any, falling back
+/// to the built-in parser selection.
+fn parse_package_file(
+    path: &Path,
+    parser_patterns: &[ParserPattern],
+) -> Option<ParsePackagesResult> {
+    routed_parser_pattern(path, parser_patterns)
+        .and_then(|parser_pattern| try_parse_file_with(&parser_pattern.parser, path))
+        .or_else(|| try_parse_file(path))
+}
+
+fn routed_parser_pattern<'a>(
+    path: &Path,
+    parser_patterns: &'a [ParserPattern],
+) -> Option<&'a ParserPattern> {
+    let name = path.file_name()?.to_str()?;
+    parser_patterns
+        .iter()
+        .find(|parser_pattern| parser_pattern.pattern.matches(name))
+}
+
+/// Options that shape a file's cached findings.
+///
+/// Findings are cached by content, so path-dependent inputs (source-file handling such as
+/// preserved license comments, and source maps) are part of the fingerprint too.
 fn scan_cache_fingerprint(
+    path: &Path,
     text_options: &TextDetectionOptions,
     license_options: LicenseScanOptions,
-    license_enabled: bool,
+    license_engine: Option<&LicenseDetectionEngine>,
 ) -> String {
+    let min_rule_relevance = license_engine.map(LicenseDetectionEngine::min_rule_relevance);
+    let min_matched_length = license_engine.map_or(0, LicenseDetectionEngine::min_matched_length);
+    let min_license_score = license_engine.map_or(0, LicenseDetectionEngine::min_license_score);
+    let max_sequence_candidates =
+        license_engine.map_or(0, LicenseDetectionEngine::max_sequence_candidates);
+    let max_query_tokens = license_engine.map_or(0, LicenseDetectionEngine::max_query_tokens);
+    let translated_rules = license_engine.map_or(&[][..], LicenseDetectionEngine::translated_rules);
+    let additional_rules = license_engine.map_or(&[][..], LicenseDetectionEngine::additional_rules);
+    let rules_fingerprint = license_engine.and_then(LicenseDetectionEngine::rules_fingerprint);
     format!(
-        "packages={};copyrights={};emails={};urls={};max_emails={};max_urls={};timeout={:.6};license_enabled={};license_text={};license_text_diagnostics={};license_diagnostics={};unknown_licenses={}",
+        "source={};sourcemap={};packages={};copyrights={};emails={};urls={};exclude_common_urls={};max_emails={};max_urls={};timeout={:.6};text_ext={:?};binary_ext={:?};skip_data_files={};listed_authors={};skip_code_copyrights={};debian_copyright_files={};rpm_specfile_licenses={};parser_patterns={:?};license_enabled={};min_rule_relevance={};min_matched_length={};min_license_score={};max_sequence_candidates={};max_query_tokens={};translated_rules={:?};additional_rules={:?};rules_fingerprint={:?};license_text={};license_text_diagnostics={};license_diagnostics={};unknown_licenses={};match_type={};public_domain={};license_facets={};license_components={};in_header={};imperfect_matches_as_clues={};license_text_from={:?}",
+        is_source(path),
+        crate::utils::sourcemap::is_sourcemap(path),
         text_options.detect_packages,
         text_options.detect_copyrights,
         text_options.detect_emails,
         text_options.detect_urls,
+        text_options.exclude_common_urls,
         text_options.max_emails,
         text_options.max_urls,
         text_options.timeout_seconds,
-        license_enabled,
+        text_options.text_extensions,
+        text_options.binary_extensions,
+        text_options.skip_data_files,
+        text_options.listed_authors,
+        text_options.skip_code_copyrights,
+        text_options.debian_copyright_files,
+        text_options.rpm_specfile_licenses,
+        text_options
+            .parser_patterns
+            .iter()
+            .map(|parser_pattern| (
+                parser_pattern.parser.as_str(),
+                parser_pattern.pattern.as_str()
+            ))
+            .collect::<Vec<_>>(),
+        min_rule_relevance.is_some(),
+        min_rule_relevance.unwrap_or(0),
+        min_matched_length,
+        min_license_score,
+        max_sequence_candidates,
+        max_query_tokens,
+        translated_rules,
+        additional_rules,
+        rules_fingerprint,
         license_options.include_text,
         license_options.include_text_diagnostics,
         license_options.include_diagnostics,
         license_options.unknown_licenses,
+        license_options.include_match_type,
+        license_options.include_public_domain,
+        license_options.include_license_facets,
+        license_options.split_license_components,
+        license_options.include_in_header,
+        license_options.imperfect_matches_as_clues,
+        license_options.matched_text_source,
     )
 }
 
@@ -340,8 +746,11 @@ fn extract_copyright_information(
     text_content: &str,
     timeout_seconds: f64,
     from_binary_strings: bool,
+    listed_authors_enabled: bool,
+    skip_code_copyrights: bool,
 ) {
     // CREDITS files get special handling (Linux kernel style).
+    let mut listed_authors = Vec::new();
     if copyright::is_credits_file(path) {
         let author_detections = copyright::detect_credits_authors(text_content);
         if !author_detections.is_empty() {
@@ -357,6 +766,11 @@ fn extract_copyright_information(
             );
             return;
         }
+        // Plain AUTHORS/CONTRIBUTORS lists name one person per line; on request
+        // those are reported next to the detector's own authors.
+        if listed_authors_enabled {
+            listed_authors = copyright::detect_listed_authors(text_content);
+        }
     }
 
     let copyright_options = CopyrightDetectionOptions {
@@ -365,6 +779,7 @@ fn extract_copyright_information(
         } else {
             None
         },
+        suppress_code_like_copyrights: skip_code_copyrights,
         ..CopyrightDetectionOptions::default()
     };
 
@@ -375,6 +790,7 @@ fn extract_copyright_information(
     } else {
         (copyrights, holders, authors)
     };
+    let authors = merge_listed_authors(authors, listed_authors);
 
     file_info_builder.copyrights(
         copyrights
@@ -383,6 +799,7 @@ fn extract_copyright_information(
                 copyright: c.copyright,
                 start_line: c.start_line,
                 end_line: c.end_line,
+                normalized_copyright: None,
             })
             .collect::<Vec<Copyright>>(),
     );
@@ -519,6 +936,7 @@ fn extract_email_url_information(
             max_emails: text_options.max_emails,
             max_urls: text_options.max_urls,
             unique: false,
+            exclude_common_urls: false,
         };
         let emails = finder::find_emails(text_content, &config)
             .into_iter()
@@ -532,12 +950,11 @@ fn extract_email_url_information(
     }
 
     if text_options.detect_urls {
-        let config = DetectionConfig {
-            max_emails: text_options.max_emails,
-            max_urls: text_options.max_urls,
-            unique: true,
-        };
-        let urls = finder::find_urls(text_content, &config)
+        let mut urls = finder::detect_urls(text_content, text_options.exclude_common_urls);
+        if text_options.max_urls > 0 {
+            urls.truncate(text_options.max_urls);
+        }
+        let urls = urls
             .into_iter()
             .map(|d| OutputURL {
                 url: d.url,
@@ -552,9 +969,10 @@ fn extract_email_url_information(
 fn extract_license_information(
     file_info_builder: &mut FileInfoBuilder,
     scan_errors: &mut Vec<String>,
+    scan_notes: &mut Vec<String>,
     path: &Path,
     text_content: String,
-    license_engine: Option<Arc<LicenseDetectionEngine>>,
+    license_engine: Option<&LicenseDetectionEngine>,
     license_options: LicenseScanOptions,
     from_binary_strings: bool,
 ) -> Result<(), Error> {
@@ -562,65 +980,181 @@ fn extract_license_information(
         return Ok(());
     };
 
-    match engine.detect_with_kind_and_source(
-        &text_content,
-        license_options.unknown_licenses,
-        from_binary_strings,
-        &path.to_string_lossy(),
-    ) {
-        Ok(detections) => {
-            let query =
-                Query::from_extracted_text(&text_content, engine.index(), from_binary_strings).ok();
-            let mut model_detections = Vec::new();
-            let mut model_clues = Vec::new();
-
-            for detection in &detections {
-                let (public_detection, clue_matches) = convert_detection_to_model(
-                    detection,
-                    license_options,
-                    &text_content,
-                    query.as_ref(),
-                );
-
-                if let Some(public_detection) = public_detection {
-                    model_detections.push(public_detection);
-                }
+    let text_content = match engine.truncate_query_text(&text_content) {
+        Some(truncated) => {
+            scan_notes.push(format!(
+                "License detection only read the first {} tokens of this file",
+                engine.max_query_tokens()
+            ));
+            truncated.to_string()
+        }
+        None => text_content,
+    };
+
+    // Multi-component files are detected one section at a time; everything
+    // else is a single section covering the whole text.
+    let sections: Vec<(Option<String>, &str, usize)> = license_options
+        .split_license_components
+        .then(|| split_components(&text_content))
+        .flatten()
+        .map(|sections| {
+            sections
+                .into_iter()
+                .map(|section| (Some(section.name), section.text, section.start_line - 1))
+                .collect()
+        })
+        .unwrap_or_else(|| vec![(None, text_content.as_str(), 0)]);
+
+    let header_end_line = (license_options.include_in_header && is_source(path))
+        .then(|| leading_comment_end_line(&text_content));
+
+    let mut model_detections = Vec::new();
+    let mut model_clues = Vec::new();
+    let mut matched_positions = 0;
+    let mut query_tokens_length = 0;
+    let mut has_query = false;
 
-                model_clues.extend(clue_matches);
+    for (component, section_text, line_offset) in sections {
+        let detections = match engine.detect_with_kind_and_source(
+            section_text,
+            license_options.unknown_licenses,
+            from_binary_strings,
+            &path.to_string_lossy(),
+        ) {
+            Ok(detections) => detections,
+            Err(e) => {
+                scan_errors.push(format!("License detection failed: {}", e));
+                return Ok(());
             }
+        };
+        let query =
+            Query::from_extracted_text(section_text, engine.index(), from_binary_strings).ok();
 
-            if !model_detections.is_empty() {
-                let expressions: Vec<String> = model_detections
-                    .iter()
-                    .filter(|d| !d.license_expression_spdx.is_empty())
-                    .map(|d| d.license_expression_spdx.clone())
-                    .collect();
-
-                if !expressions.is_empty() {
-                    let combined = crate::utils::spdx::combine_license_expressions(expressions);
-                    if let Some(expr) = combined {
-                        file_info_builder.license_expression(Some(expr));
-                    }
+        for detection in &detections {
+            let (mut public_detection, mut clue_matches) = convert_detection_to_model(
+                detection,
+                license_options,
+                section_text,
+                query.as_ref(),
+            );
+            let converted_matches = match public_detection.as_mut() {
+                Some(public_detection) => &mut public_detection.matches,
+                None => &mut clue_matches,
+            };
+            for (converted, license_match) in converted_matches.iter_mut().zip(&detection.matches) {
+                converted.language = engine.match_language(license_match).map(str::to_string);
+                if license_options.include_text
+                    && license_options.matched_text_source == MatchedTextSource::Rule
+                {
+                    converted.matched_text = engine.rule_text(license_match).map(str::to_string);
                 }
+                converted.start_line += line_offset;
+                converted.end_line += line_offset;
             }
 
-            file_info_builder.license_detections(model_detections);
-            file_info_builder.license_clues(model_clues);
-            file_info_builder.percentage_of_license_text(
-                query
-                    .as_ref()
-                    .map(|query| compute_percentage_of_license_text(query, &detections)),
-            );
+            if let Some(mut public_detection) = public_detection {
+                if license_options.include_public_domain {
+                    public_detection.is_public_domain = Some(
+                        engine.is_public_domain_expression(&public_detection.license_expression),
+                    );
+                }
+                if license_options.include_license_facets {
+                    public_detection.license_facet = Some(license_facet_for(path, detection));
+                }
+                public_detection.component = component.clone();
+                if let Some(header_end_line) = header_end_line {
+                    public_detection.in_header = Some(header_end_line.is_some_and(|end_line| {
+                        public_detection
+                            .matches
+                            .iter()
+                            .all(|license_match| license_match.end_line <= end_line)
+                    }));
+                }
+                model_detections.push(public_detection);
+            }
+
+            model_clues.extend(clue_matches);
+        }
+
+        if let Some(query) = query.as_ref() {
+            let (matched, total) = license_text_token_counts(query, &detections);
+            matched_positions += matched;
+            query_tokens_length += total;
+            has_query = true;
         }
-        Err(e) => {
-            scan_errors.push(format!("License detection failed: {}", e));
+    }
+
+    if !model_detections.is_empty() {
+        let expressions: Vec<String> = model_detections
+            .iter()
+            .filter(|d| !d.license_expression_spdx.is_empty())
+            .map(|d| d.license_expression_spdx.clone())
+            .collect();
+
+        if !expressions.is_empty() {
+            let combined = crate::utils::spdx::combine_license_expressions(expressions);
+            if let Some(expr) = combined {
+                file_info_builder.license_expression(Some(expr));
+            }
         }
     }
 
+    file_info_builder.license_detections(model_detections);
+    file_info_builder.license_clues(model_clues);
+    file_info_builder.percentage_of_license_text(
+        has_query.then(|| percentage_of_tokens(matched_positions, query_tokens_length)),
+    );
+
     Ok(())
 }
 
-fn convert_detection_to_model(
+/// Classify a file-level detection: references and tags alone are
+/// `referenced`, anything else is `concluded` in legal files and `incidental`
+/// elsewhere.
+fn license_facet_for(
+    path: &Path,
+    detection: &crate::license_detection::LicenseDetection,
+) -> LicenseFacet {
+    let is_reference_only = !detection.matches.is_empty()
+        && detection.matches.iter().all(|license_match| {
+            !license_match.is_from_license
+                && matches!(license_match.rule_kind, RuleKind::Reference | RuleKind::Tag)
+        });
+    if is_reference_only {
+        return LicenseFacet::Referenced;
+    }
+
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let base_name = path.file_stem().unwrap_or_default().to_string_lossy();
+    if is_legal_file_name(&name, &base_name) {
+        LicenseFacet::Concluded
+    } else {
+        LicenseFacet::Incidental
+    }
+}
+
+/// Add listed authors on lines the detector found no author on, in line order.
+fn merge_listed_authors(
+    mut authors: Vec<AuthorDetection>,
+    listed_authors: Vec<AuthorDetection>,
+) -> Vec<AuthorDetection> {
+    if listed_authors.is_empty() {
+        return authors;
+    }
+    let listed: Vec<_> = listed_authors
+        .into_iter()
+        .filter(|listed| {
+            !authors.iter().any(|detected| {
+                detected.start_line <= listed.end_line && listed.start_line <= detected.end_line
+            })
+        })
+        .collect();
+    authors.extend(listed);
+    authors.sort_by_key(|author| (author.start_line, author.end_line));
+    authors
+}
+
+pub(crate) fn convert_detection_to_model(
     detection: &crate::license_detection::LicenseDetection,
     license_options: LicenseScanOptions,
     text_content: &str,
@@ -632,7 +1166,16 @@ fn convert_detection_to_model(
         .map(|m| convert_match_to_model(m, license_options, text_content, query))
         .collect();
 
-    if let Some(license_expression) = detection.license_expression.clone() {
+    let reported_as_clues = license_options.imperfect_matches_as_clues
+        && detection
+            .detection_log
+            .iter()
+            .any(|log| log == DETECTION_LOG_IMPERFECT_COVERAGE);
+    if let Some(license_expression) = detection
+        .license_expression
+        .clone()
+        .filter(|_| !reported_as_clues)
+    {
         (
             Some(LicenseDetection {
                 license_expression,
@@ -647,6 +1190,10 @@ fn convert_detection_to_model(
                     Vec::new()
                 },
                 identifier: detection.identifier.clone(),
+                is_public_domain: None,
+                license_facet: None,
+                component: None,
+                in_header: None,
             }),
             Vec::new(),
         )
@@ -698,6 +1245,12 @@ fn convert_match_to_model(
         matched_text,
         referenced_filenames: m.referenced_filenames.clone(),
         matched_text_diagnostics,
+        match_type: m
+            .match_type()
+            .filter(|_| license_options.include_match_type)
+            .map(str::to_string),
+        language: None,
+        custom_license_files: Vec::new(),
     }
 }
 
@@ -705,6 +1258,16 @@ fn compute_percentage_of_license_text(
     query: &Query<'_>,
     detections: &[crate::license_detection::LicenseDetection],
 ) -> f64 {
+    let (matched, total) = license_text_token_counts(query, detections);
+    percentage_of_tokens(matched, total)
+}
+
+/// Count the query positions covered by `detections` and the query length,
+/// unknown tokens included.
+fn license_text_token_counts(
+    query: &Query<'_>,
+    detections: &[crate::license_detection::LicenseDetection],
+) -> (usize, usize) {
     let matched_positions: std::collections::HashSet<usize> = detections
         .iter()
         .flat_map(|detection| detection.matches.iter())
@@ -712,11 +1275,15 @@ fn compute_percentage_of_license_text(
         .collect();
 
     let query_tokens_length = query.tokens.len() + query.unknowns_by_pos.values().sum::<usize>();
-    if query_tokens_length == 0 {
+    (matched_positions.len(), query_tokens_length)
+}
+
+fn percentage_of_tokens(matched: usize, total: usize) -> f64 {
+    if total == 0 {
         return 0.0;
     }
 
-    let percentage = (matched_positions.len() as f64 / query_tokens_length as f64) * 100.0;
+    let percentage = (matched as f64 / total as f64) * 100.0;
     (percentage * 100.0).round() / 100.0
 }
 
@@ -793,6 +1360,20 @@ fn is_pem_certificate_file(_path: &Path, buffer: &[u8]) -> bool {
     })
 }
 
+/// Report a symbolic link that was not followed: its target, but no scan of
+/// what it points to.
+fn process_symlink(path: &Path, metadata: &fs::Metadata) -> FileInfo {
+    let mut file_info = process_directory(path, metadata, false, false);
+    file_info.file_type = FileType::Symlink;
+    match fs::read_link(path) {
+        Ok(target) => file_info.link_target = Some(target.to_string_lossy().into_owned()),
+        Err(err) => file_info
+            .scan_errors
+            .push(format!("Failed to read symlink target: {err}")),
+    }
+    file_info
+}
+
 fn process_directory(
     path: &Path,
     metadata: &fs::Metadata,
@@ -818,6 +1399,7 @@ fn process_directory(
         sha1: None,
         md5: None,
         sha256: None,
+        sha512: None,
         programming_language: None,
         package_data: Vec::new(), // TODO: implement
         license_expression: None,
@@ -839,6 +1421,11 @@ fn process_directory(
         is_top_level: false,
         is_key_file: false,
         is_community: false,
+        has_copyright_no_license: false,
+        scan_notes: vec![],
+        link_target: None,
+        layer_digest: None,
+        referenced_license_expression: None,
         is_generated: None,
         facets: vec![],
         tallies: None,
@@ -848,14 +1435,15 @@ fn process_directory(
 #[cfg(test)]
 mod tests {
     use super::{
-        compute_percentage_of_license_text, convert_detection_to_model, is_go_non_production_source,
+        DETECTION_LOG_IMPERFECT_COVERAGE, compute_percentage_of_license_text,
+        convert_detection_to_model, is_go_non_production_source,
     };
     use crate::license_detection::LicenseDetection as InternalLicenseDetection;
     use crate::license_detection::index::LicenseIndex;
     use crate::license_detection::index::dictionary::TokenDictionary;
     use crate::license_detection::models::{LicenseMatch, MatcherKind, RuleKind};
     use crate::license_detection::query::Query;
-    use crate::scanner::LicenseScanOptions;
+    use crate::scanner::{LicenseScanOptions, MatchedTextSource};
     use std::fs;
     use tempfile::tempdir;
 
@@ -980,6 +1568,34 @@ mod tests {
         assert_eq!(clues[0].matched_text_diagnostics, None);
     }
 
+    #[test]
+    fn test_convert_detection_to_model_reports_imperfect_coverage_as_clues_when_enabled() {
+        let mut detection = make_detection("");
+        detection.detection_log = vec![DETECTION_LOG_IMPERFECT_COVERAGE.to_string()];
+        detection.matches[0].match_coverage = 75.0;
+
+        let (converted, clues) =
+            convert_detection_to_model(&detection, LicenseScanOptions::default(), "", None);
+        assert!(converted.is_some());
+        assert!(clues.is_empty());
+
+        let (converted, clues) = convert_detection_to_model(
+            &detection,
+            LicenseScanOptions {
+                imperfect_matches_as_clues: true,
+                ..LicenseScanOptions::default()
+            },
+            "",
+            None,
+        );
+        assert!(converted.is_none());
+        assert_eq!(clues.len(), 1);
+        assert_eq!(
+            clues[0].license_expression,
+            detection.matches[0].license_expression
+        );
+    }
+
     #[test]
     fn test_convert_detection_to_model_includes_diagnostics_when_enabled() {
         let text = concat!(
@@ -1036,6 +1652,13 @@ mod tests {
                 include_text_diagnostics: true,
                 include_diagnostics: true,
                 unknown_licenses: false,
+                include_match_type: false,
+                include_public_domain: false,
+                include_license_facets: false,
+                split_license_components: false,
+                include_in_header: false,
+                imperfect_matches_as_clues: false,
+                matched_text_source: MatchedTextSource::Source,
             },
             text,
             Some(&query),
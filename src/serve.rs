@@ -0,0 +1,325 @@
+//! Minimal HTTP server exposing license detection as a JSON service.
+//!
+//! The server answers `POST /detect` with a body of `{"text": "..."}` and
+//! returns `{"detections": [...]}`. A single preloaded
+//! [`LicenseDetectionEngine`] is shared by every connection, so the index is
+//! built once per process. Only the small subset of HTTP/1.1 needed for this
+//! endpoint is implemented, on top of `std::net`.
+//!
+//! Detections use the same `license_detections` / `license_clues` shapes as a
+//! file in the scan output.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::license_detection::LicenseDetectionEngine;
+use crate::models::{LicenseDetection, Match};
+use crate::scanner::{LicenseScanOptions, convert_detection_to_model};
+
+/// Largest request body accepted by `POST /detect`.
+const MAX_BODY_BYTES: usize = 16 * 1024 * 1024;
+/// Largest accepted request line or header line.
+const MAX_HEADER_LINE_BYTES: usize = 8 * 1024;
+/// Most header lines accepted in one request.
+const MAX_HEADER_LINES: usize = 100;
+/// Most connections handled at once; further connections get a 503.
+const MAX_CONCURRENT_CONNECTIONS: usize = 64;
+/// Read and write timeout for each connection socket.
+const SOCKET_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Deserialize)]
+struct DetectRequest {
+    text: String,
+    #[serde(default)]
+    unknown_licenses: bool,
+}
+
+#[derive(Serialize)]
+struct DetectResponse {
+    detections: Vec<LicenseDetection>,
+    license_clues: Vec<Match>,
+}
+
+#[derive(Serialize)]
+struct ErrorResponse<'a> {
+    error: &'a str,
+}
+
+/// HTTP server answering license detection requests with a shared engine.
+pub struct DetectServer {
+    listener: TcpListener,
+    engine: Arc<LicenseDetectionEngine>,
+}
+
+impl DetectServer {
+    /// Bind the server to `addr` (use port `0` to pick a free port).
+    pub fn bind(addr: impl ToSocketAddrs, engine: Arc<LicenseDetectionEngine>) -> Result<Self> {
+        let listener = TcpListener::bind(addr).context("Failed to bind detection server")?;
+        Ok(Self { listener, engine })
+    }
+
+    /// Address the server is listening on.
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    /// Accept connections until the listener fails, one thread per connection.
+    ///
+    /// At most [`MAX_CONCURRENT_CONNECTIONS`] are handled at once; connections
+    /// beyond that are answered with `503 Service Unavailable`.
+    pub fn serve(self) -> Result<()> {
+        let active = Arc::new(AtomicUsize::new(0));
+        for stream in self.listener.incoming() {
+            let mut stream = match stream {
+                Ok(stream) => stream,
+                Err(err) => {
+                    warn!("Failed to accept connection: {err}");
+                    continue;
+                }
+            };
+            if let Err(err) = set_socket_timeouts(&stream) {
+                warn!("Failed to configure connection: {err}");
+                continue;
+            }
+            let Some(slot) = ConnectionSlot::acquire(&active) else {
+                if let Err(err) = write_error(&mut stream, 503, "Too many concurrent requests") {
+                    warn!("Failed to reject connection: {err}");
+                }
+                continue;
+            };
+            let engine = Arc::clone(&self.engine);
+            thread::spawn(move || {
+                let _slot = slot;
+                if let Err(err) = handle_connection(stream, &engine) {
+                    warn!("Failed to handle detection request: {err}");
+                }
+            });
+        }
+        Ok(())
+    }
+}
+
+/// One of the [`MAX_CONCURRENT_CONNECTIONS`] connection slots, released on drop.
+struct ConnectionSlot(Arc<AtomicUsize>);
+
+impl ConnectionSlot {
+    fn acquire(active: &Arc<AtomicUsize>) -> Option<Self> {
+        active
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |count| {
+                (count < MAX_CONCURRENT_CONNECTIONS).then_some(count + 1)
+            })
+            .ok()
+            .map(|_| Self(Arc::clone(active)))
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+fn set_socket_timeouts(stream: &TcpStream) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(SOCKET_TIMEOUT))?;
+    stream.set_write_timeout(Some(SOCKET_TIMEOUT))
+}
+
+struct HttpRequest {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+fn handle_connection(stream: TcpStream, engine: &LicenseDetectionEngine) -> Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut stream = stream;
+
+    let request = match read_request(&mut reader) {
+        Ok(request) => request,
+        Err(status) => return write_error(&mut stream, status, status_reason(status)),
+    };
+
+    match (request.method.as_str(), request.path.as_str()) {
+        ("POST", "/detect") => {}
+        (_, "/detect") => return write_error(&mut stream, 405, "Only POST is supported"),
+        _ => return write_error(&mut stream, 404, "Not found"),
+    }
+
+    let detect_request: DetectRequest = match serde_json::from_slice(&request.body) {
+        Ok(detect_request) => detect_request,
+        Err(err) => {
+            return write_error(&mut stream, 400, &format!("Invalid request body: {err}"));
+        }
+    };
+
+    match engine.detect_with_kind(&detect_request.text, detect_request.unknown_licenses, false) {
+        Ok(detections) => {
+            let mut response = DetectResponse {
+                detections: Vec::new(),
+                license_clues: Vec::new(),
+            };
+            for detection in &detections {
+                let (detection, clues) = convert_detection_to_model(
+                    detection,
+                    LicenseScanOptions::default(),
+                    &detect_request.text,
+                    None,
+                );
+                response.detections.extend(detection);
+                response.license_clues.extend(clues);
+            }
+            write_json(&mut stream, 200, &serde_json::to_vec(&response)?)
+        }
+        Err(err) => write_error(&mut stream, 500, &format!("Detection failed: {err}")),
+    }
+}
+
+/// Read one request, returning the HTTP status to answer with on failure.
+fn read_request(reader: &mut impl BufRead) -> Result<HttpRequest, u16> {
+    let request_line = read_header_line(reader)?;
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(400);
+    };
+    let path = target.split('?').next().unwrap_or_default().to_string();
+
+    let mut content_length = 0;
+    let mut header_lines = 0;
+    loop {
+        let line = read_header_line(reader)?;
+        if line.is_empty() {
+            break;
+        }
+        header_lines += 1;
+        if header_lines > MAX_HEADER_LINES {
+            return Err(431);
+        }
+        if let Some((name, value)) = line.split_once(':')
+            && name.trim().eq_ignore_ascii_case("content-length")
+        {
+            content_length = value.trim().parse().map_err(|_| 400u16)?;
+        }
+    }
+
+    if content_length > MAX_BODY_BYTES {
+        return Err(413);
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).map_err(|_| 400u16)?;
+
+    Ok(HttpRequest {
+        method: method.to_string(),
+        path,
+        body,
+    })
+}
+
+fn read_header_line(reader: &mut impl BufRead) -> Result<String, u16> {
+    let mut line = Vec::new();
+    reader
+        .take(MAX_HEADER_LINE_BYTES as u64)
+        .read_until(b'\n', &mut line)
+        .map_err(|_| 400u16)?;
+    if !line.ends_with(b"\n") {
+        return Err(if line.len() >= MAX_HEADER_LINE_BYTES {
+            431
+        } else {
+            400
+        });
+    }
+    let line = String::from_utf8(line).map_err(|_| 400u16)?;
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
+
+fn status_reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        431 => "Request Header Fields Too Large",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    }
+}
+
+fn write_error(stream: &mut TcpStream, status: u16, message: &str) -> Result<()> {
+    let body = serde_json::to_vec(&ErrorResponse { error: message })?;
+    write_json(stream, status, &body)
+}
+
+fn write_json(stream: &mut TcpStream, status: u16, body: &[u8]) -> Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {status} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status_reason(status),
+        body.len()
+    )?;
+    stream.write_all(body)?;
+    stream.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_read_request_parses_method_path_and_body() {
+        let raw = "POST /detect?pretty=1 HTTP/1.1\r\nHost: localhost\r\nContent-Length: 13\r\n\r\n{\"text\":\"x\"}\n";
+        let request = read_request(&mut Cursor::new(raw)).expect("valid request");
+
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/detect");
+        assert_eq!(request.body, b"{\"text\":\"x\"}\n");
+    }
+
+    #[test]
+    fn test_read_request_rejects_oversized_body() {
+        let raw = format!(
+            "POST /detect HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+            MAX_BODY_BYTES + 1
+        );
+        assert_eq!(read_request(&mut Cursor::new(raw)).err(), Some(413));
+    }
+
+    #[test]
+    fn test_read_request_rejects_too_many_header_lines() {
+        let mut raw = "POST /detect HTTP/1.1\r\n".to_string();
+        for index in 0..=MAX_HEADER_LINES {
+            raw.push_str(&format!("X-Header-{index}: value\r\n"));
+        }
+        raw.push_str("Content-Length: 0\r\n\r\n");
+        assert_eq!(read_request(&mut Cursor::new(raw)).err(), Some(431));
+    }
+
+    #[test]
+    fn test_connection_slots_are_capped_and_released() {
+        let active = Arc::new(AtomicUsize::new(0));
+        let slots: Vec<_> = (0..MAX_CONCURRENT_CONNECTIONS)
+            .map(|_| ConnectionSlot::acquire(&active).expect("slot available"))
+            .collect();
+        assert!(ConnectionSlot::acquire(&active).is_none());
+
+        drop(slots);
+        assert_eq!(active.load(Ordering::Acquire), 0);
+        assert!(ConnectionSlot::acquire(&active).is_some());
+    }
+
+    #[test]
+    fn test_read_request_rejects_truncated_headers() {
+        let raw = "POST /detect HTTP/1.1\r\nContent-Length: 4";
+        assert_eq!(read_request(&mut Cursor::new(raw)).err(), Some(400));
+    }
+}
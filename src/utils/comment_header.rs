@@ -0,0 +1,91 @@
+//! Location of the leading comment header of a source file.
+//!
+//! A license in the comment block at the top of a source file is the file's
+//! license declaration, while license text deeper in the body is often
+//! incidental (test fixtures, embedded strings, vendored snippets). This module
+//! finds where that header ends so detections can be placed on either side.
+
+/// Block comment delimiters recognized at the start of a header.
+const BLOCK_COMMENTS: &[(&str, &str)] = &[
+    ("/*", "*/"),
+    ("<!--", "-->"),
+    ("\"\"\"", "\"\"\""),
+    ("'''", "'''"),
+    ("{-", "-}"),
+    ("(*", "*)"),
+];
+
+/// Line comment prefixes recognized in a header, including shebang lines.
+const LINE_COMMENTS: &[&str] = &["//", "#", "--", ";", "%"];
+
+/// 1-based last line of the comment header at the top of `text`.
+///
+/// The header is the first run of comments, which may be separated by blank
+/// lines (e.g. a shebang, an encoding line and a license block). Returns `None`
+/// when the first non-blank line is code. An unterminated block comment runs to
+/// the end of the text.
+pub fn leading_comment_end_line(text: &str) -> Option<usize> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut header_end = None;
+    let mut index = 0;
+
+    while index < lines.len() {
+        let line = lines[index].trim_start();
+        if line.trim().is_empty() {
+            index += 1;
+            continue;
+        }
+
+        if let Some((open, close)) = BLOCK_COMMENTS
+            .iter()
+            .find(|(open, _)| line.starts_with(open))
+        {
+            let mut end = index;
+            if !line[open.len()..].contains(close) {
+                end = (index + 1..lines.len())
+                    .find(|&next| lines[next].contains(close))
+                    .unwrap_or(lines.len() - 1);
+            }
+            header_end = Some(end + 1);
+            index = end + 1;
+        } else if LINE_COMMENTS.iter().any(|prefix| line.starts_with(prefix)) {
+            header_end = Some(index + 1);
+            index += 1;
+        } else {
+            break;
+        }
+    }
+
+    header_end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leading_comment_end_line_block_comment() {
+        let text = "/*\n * Copyright Foo\n * SPDX-License-Identifier: MIT\n */\n\nint main() {}\n";
+        assert_eq!(leading_comment_end_line(text), Some(4));
+    }
+
+    #[test]
+    fn test_leading_comment_end_line_spans_shebang_and_line_comments() {
+        let text = "#!/usr/bin/env python\n# -*- coding: utf-8 -*-\n\n# Licensed under MIT\n\nimport os\n# later comment\n";
+        assert_eq!(leading_comment_end_line(text), Some(4));
+    }
+
+    #[test]
+    fn test_leading_comment_end_line_none_when_code_comes_first() {
+        assert_eq!(
+            leading_comment_end_line("package main\n// MIT License\n"),
+            None
+        );
+        assert_eq!(leading_comment_end_line(""), None);
+    }
+
+    #[test]
+    fn test_leading_comment_end_line_unterminated_block() {
+        assert_eq!(leading_comment_end_line("/* MIT\n * text"), Some(2));
+    }
+}
@@ -2,7 +2,7 @@ use std::collections::BTreeSet;
 use std::fs;
 use std::io::{BufReader, Cursor, Read};
 use std::panic::{AssertUnwindSafe, catch_unwind};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use chrono::{TimeZone, Utc};
 use flate2::read::ZlibDecoder;
@@ -18,6 +18,91 @@ pub enum ExtractedTextKind {
     Pdf,
     BinaryStrings,
     ImageMetadata,
+    /// UTF-8 text with invalid byte sequences replaced by U+FFFD.
+    LossyDecoded,
+}
+
+/// Scan note recorded on files whose text was decoded with [`ExtractedTextKind::LossyDecoded`].
+pub const LOSSY_DECODE_NOTE: &str = "Invalid UTF-8 byte sequences were replaced during decoding";
+
+/// User-requested classification for files with a given extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentClassOverride {
+    Text,
+    Binary,
+}
+
+/// Resolve the forced text/binary classification for `path`, if any.
+///
+/// Extensions are matched case-insensitively, with or without a leading dot.
+/// Text overrides take precedence when an extension appears in both lists.
+pub fn content_class_override(
+    path: &Path,
+    text_extensions: &[String],
+    binary_extensions: &[String],
+) -> Option<ContentClassOverride> {
+    let ext = path.extension()?.to_str()?;
+    let matches_ext =
+        |candidate: &String| candidate.trim_start_matches('.').eq_ignore_ascii_case(ext);
+
+    if text_extensions.iter().any(matches_ext) {
+        Some(ContentClassOverride::Text)
+    } else if binary_extensions.iter().any(matches_ext) {
+        Some(ContentClassOverride::Binary)
+    } else {
+        None
+    }
+}
+
+/// REUSE directory holding custom license texts as `LICENSES/<id>.txt`.
+pub const REUSE_LICENSES_DIR: &str = "LICENSES";
+
+/// Find the REUSE `LICENSES/<license_ref>.txt` file nearest to `path`, searching its
+/// ancestors up to and including `scan_root`.
+pub fn find_reuse_license_file(
+    path: &Path,
+    license_ref: &str,
+    scan_root: &Path,
+) -> Option<PathBuf> {
+    let file_name = format!("{license_ref}.txt");
+    path.ancestors()
+        .skip(1)
+        .take_while(|dir| dir.starts_with(scan_root))
+        .map(|dir| dir.join(REUSE_LICENSES_DIR).join(&file_name))
+        .find(|candidate| candidate.is_file())
+}
+
+const LEGAL_STARTS_ENDS: &[&str] = &[
+    "copying",
+    "copyright",
+    "copyrights",
+    "copyleft",
+    "notice",
+    "license",
+    "licenses",
+    "licence",
+    "licences",
+    "licensing",
+    "licencing",
+    "legal",
+    "eula",
+    "agreement",
+    "patent",
+    "patents",
+];
+
+/// Whether a file name looks like a legal file (`LICENSE`, `COPYING`, `NOTICE`, ...).
+///
+/// Matches when the lowercased name or base name starts or ends with a legal word.
+pub fn is_legal_file_name(name: &str, base_name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    let base_name = base_name.to_ascii_lowercase();
+    LEGAL_STARTS_ENDS.iter().any(|pattern| {
+        name.starts_with(pattern)
+            || name.ends_with(pattern)
+            || base_name.starts_with(pattern)
+            || base_name.ends_with(pattern)
+    })
 }
 
 const MAX_IMAGE_METADATA_VALUES: usize = 64;
@@ -67,23 +152,53 @@ pub fn is_path_excluded(path: &Path, exclude_patterns: &[Pattern]) -> bool {
 /// so it can decode any byte sequence. This matches Python ScanCode's use of
 /// `UnicodeDammit` which auto-detects encoding with Latin-1 as fallback.
 pub fn decode_bytes_to_string(bytes: &[u8]) -> String {
-    match String::from_utf8(bytes.to_vec()) {
-        Ok(s) => s,
-        Err(e) => {
-            let bytes = e.into_bytes();
+    decode_bytes(bytes).0
+}
+
+/// Decode a byte buffer, reporting whether invalid UTF-8 sequences were replaced.
+///
+/// Content that is demonstrably UTF-8 (a valid multi-byte sequence anywhere in
+/// the buffer, before or after the corrupt bytes) is decoded lossily so a few
+/// corrupt bytes do not turn the rest of the file into Latin-1 mojibake. Other
+/// non-UTF-8 content falls back to Latin-1 as in [`decode_bytes_to_string`].
+fn decode_bytes(bytes: &[u8]) -> (String, bool) {
+    match std::str::from_utf8(bytes) {
+        Ok(s) => (s.to_string(), false),
+        Err(_) => {
             // Binary heuristic: >10% control chars (0x00-0x08, 0x0E-0x1F) means binary.
             let control_count = bytes
                 .iter()
                 .filter(|&&b| b < 0x09 || (b > 0x0D && b < 0x20))
                 .count();
             if control_count > bytes.len() / 10 {
-                return String::new();
+                return (String::new(), false);
             }
-            bytes.iter().map(|&b| b as char).collect()
+            decode_non_utf8_bytes(bytes)
         }
     }
 }
 
+/// Decode bytes that are not valid UTF-8: lossily when they still look like
+/// UTF-8, as Latin-1 otherwise. The flag reports a lossy decode.
+fn decode_non_utf8_bytes(bytes: &[u8]) -> (String, bool) {
+    if contains_utf8_multibyte_sequence(bytes) {
+        return (String::from_utf8_lossy(bytes).into_owned(), true);
+    }
+    (bytes.iter().map(|&b| b as char).collect(), false)
+}
+
+fn contains_utf8_multibyte_sequence(bytes: &[u8]) -> bool {
+    bytes.utf8_chunks().any(|chunk| !chunk.valid().is_ascii())
+}
+
+fn decoded_text(bytes: &[u8]) -> (String, ExtractedTextKind) {
+    match decode_bytes(bytes) {
+        (text, _) if text.is_empty() => (text, ExtractedTextKind::None),
+        (text, true) => (text, ExtractedTextKind::LossyDecoded),
+        (text, false) => (text, ExtractedTextKind::Decoded),
+    }
+}
+
 pub fn extract_text_for_detection(path: &Path, bytes: &[u8]) -> (String, ExtractedTextKind) {
     let ext = path
         .extension()
@@ -105,21 +220,16 @@ pub fn extract_text_for_detection(path: &Path, bytes: &[u8]) -> (String, Extract
             if is_supported_image_container(bytes, format) {
                 (String::new(), ExtractedTextKind::None)
             } else {
-                let decoded = decode_bytes_to_string(bytes);
-                if decoded.is_empty() {
-                    (String::new(), ExtractedTextKind::None)
-                } else {
-                    (decoded, ExtractedTextKind::Decoded)
-                }
+                decoded_text(bytes)
             }
         } else {
             (text, ExtractedTextKind::ImageMetadata)
         };
     }
 
-    let decoded = decode_bytes_to_string(bytes);
-    if !decoded.is_empty() {
-        return (decoded, ExtractedTextKind::Decoded);
+    let (decoded, kind) = decoded_text(bytes);
+    if kind != ExtractedTextKind::None {
+        return (decoded, kind);
     }
 
     if matches!(ext.as_deref(), Some("jar")) && is_zip_archive(bytes) {
@@ -140,6 +250,38 @@ pub fn extract_text_for_detection(path: &Path, bytes: &[u8]) -> (String, Extract
     }
 }
 
+/// Like [`extract_text_for_detection`], but honoring a forced text/binary classification.
+///
+/// Forced text skips the binary heuristic and decodes the whole buffer with the same
+/// UTF-8/Latin-1 rules as [`decode_bytes_to_string`]; forced binary skips decoding and
+/// only keeps printable strings.
+pub fn extract_text_for_detection_with_override(
+    path: &Path,
+    bytes: &[u8],
+    content_override: Option<ContentClassOverride>,
+) -> (String, ExtractedTextKind) {
+    let (text, kind) = match content_override {
+        None => return extract_text_for_detection(path, bytes),
+        Some(ContentClassOverride::Text) => match std::str::from_utf8(bytes) {
+            Ok(text) => (text.to_string(), ExtractedTextKind::Decoded),
+            Err(_) => match decode_non_utf8_bytes(bytes) {
+                (text, true) => (text, ExtractedTextKind::LossyDecoded),
+                (text, false) => (text, ExtractedTextKind::Decoded),
+            },
+        },
+        Some(ContentClassOverride::Binary) => (
+            extract_printable_strings(bytes),
+            ExtractedTextKind::BinaryStrings,
+        ),
+    };
+
+    if text.is_empty() {
+        (String::new(), ExtractedTextKind::None)
+    } else {
+        (text, kind)
+    }
+}
+
 fn supported_image_metadata_format(ext: Option<&str>) -> Option<ImageFormat> {
     match ext? {
         "jpg" | "jpeg" => Some(ImageFormat::Jpeg),
@@ -501,7 +643,46 @@ pub fn extract_printable_strings(bytes: &[u8]) -> String {
 mod tests {
     use std::path::Path;
 
-    use super::{ExtractedTextKind, extract_text_for_detection};
+    use super::{
+        ContentClassOverride, ExtractedTextKind, content_class_override, decode_bytes_to_string,
+        extract_text_for_detection, extract_text_for_detection_with_override,
+        find_reuse_license_file,
+    };
+
+    #[test]
+    fn test_find_reuse_license_file_searches_ancestors() {
+        let temp_dir = tempfile::tempdir().expect("create temp dir");
+        let licenses_dir = temp_dir.path().join("LICENSES");
+        std::fs::create_dir_all(&licenses_dir).expect("create LICENSES dir");
+        std::fs::write(licenses_dir.join("LicenseRef-MyCorp.txt"), "MyCorp terms")
+            .expect("write license file");
+        let source = temp_dir.path().join("src").join("nested").join("main.c");
+
+        assert_eq!(
+            find_reuse_license_file(&source, "LicenseRef-MyCorp", temp_dir.path()),
+            Some(licenses_dir.join("LicenseRef-MyCorp.txt"))
+        );
+        assert_eq!(
+            find_reuse_license_file(&source, "LicenseRef-Other", temp_dir.path()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_find_reuse_license_file_stops_at_scan_root() {
+        let temp_dir = tempfile::tempdir().expect("create temp dir");
+        let licenses_dir = temp_dir.path().join("LICENSES");
+        std::fs::create_dir_all(&licenses_dir).expect("create LICENSES dir");
+        std::fs::write(licenses_dir.join("LicenseRef-MyCorp.txt"), "MyCorp terms")
+            .expect("write license file");
+        let scan_root = temp_dir.path().join("src");
+        let source = scan_root.join("main.c");
+
+        assert_eq!(
+            find_reuse_license_file(&source, "LicenseRef-MyCorp", &scan_root),
+            None
+        );
+    }
 
     #[test]
     fn test_extract_text_for_detection_skips_jar_archives() {
@@ -515,4 +696,101 @@ mod tests {
         assert!(text.is_empty());
         assert_eq!(kind, ExtractedTextKind::None);
     }
+
+    #[test]
+    fn test_extract_text_for_detection_lossily_decodes_corrupt_utf8() {
+        let mut bytes = "// Copyright Zoë Example\n".as_bytes().to_vec();
+        bytes.extend([0xff, 0xfe, b'\n']);
+
+        let (text, kind) = extract_text_for_detection(Path::new("main.c"), &bytes);
+
+        assert_eq!(kind, ExtractedTextKind::LossyDecoded);
+        assert!(text.starts_with("// Copyright Zoë Example\n"));
+        assert!(text.contains('\u{fffd}'));
+    }
+
+    #[test]
+    fn test_extract_text_for_detection_lossily_decodes_corrupt_bytes_after_ascii_header() {
+        let mut bytes = b"// SPDX-License-Identifier: MIT\n".to_vec();
+        bytes.extend([0xff, 0xfe, b'\n']);
+        bytes.extend("// Author: Zoë Example\n".as_bytes());
+
+        let (text, kind) = extract_text_for_detection(Path::new("main.c"), &bytes);
+
+        assert_eq!(kind, ExtractedTextKind::LossyDecoded);
+        assert!(text.contains('\u{fffd}'));
+        assert!(text.ends_with("// Author: Zoë Example\n"));
+    }
+
+    #[test]
+    fn test_decode_bytes_to_string_keeps_latin1_fallback() {
+        assert_eq!(decode_bytes_to_string(b"Fran\xe7ois"), "Fran\u{e7}ois");
+    }
+
+    #[test]
+    fn test_content_class_override_matches_extensions_case_insensitively() {
+        let text = vec![".dat".to_string()];
+        let binary = vec!["TS".to_string(), "dat".to_string()];
+
+        assert_eq!(
+            content_class_override(Path::new("table.DAT"), &text, &binary),
+            Some(ContentClassOverride::Text)
+        );
+        assert_eq!(
+            content_class_override(Path::new("stream.ts"), &text, &binary),
+            Some(ContentClassOverride::Binary)
+        );
+        assert_eq!(
+            content_class_override(Path::new("main.rs"), &text, &binary),
+            None
+        );
+    }
+
+    #[test]
+    fn test_forced_text_override_bypasses_binary_heuristic() {
+        let mut bytes = b"Licensed under the MIT License.\n".to_vec();
+        bytes.extend([0x01; 16]);
+        bytes.push(0xff);
+        let path = Path::new("records.dat");
+
+        let (_, default_kind) = extract_text_for_detection(path, &bytes);
+        assert_eq!(default_kind, ExtractedTextKind::BinaryStrings);
+
+        let (text, kind) = extract_text_for_detection_with_override(
+            path,
+            &bytes,
+            Some(ContentClassOverride::Text),
+        );
+        assert_eq!(kind, ExtractedTextKind::Decoded);
+        assert!(text.starts_with("Licensed under the MIT License.\n"));
+    }
+
+    #[test]
+    fn test_forced_text_override_decodes_corrupt_utf8_lossily() {
+        let mut bytes = "Copyright Zoë Example\n".as_bytes().to_vec();
+        bytes.extend([0x01; 16]);
+        bytes.push(0xff);
+
+        let (text, kind) = extract_text_for_detection_with_override(
+            Path::new("records.dat"),
+            &bytes,
+            Some(ContentClassOverride::Text),
+        );
+
+        assert_eq!(kind, ExtractedTextKind::LossyDecoded);
+        assert!(text.starts_with("Copyright Zoë Example\n"));
+        assert!(text.ends_with('\u{fffd}'));
+    }
+
+    #[test]
+    fn test_forced_binary_override_extracts_printable_strings() {
+        let (text, kind) = extract_text_for_detection_with_override(
+            Path::new("notes.txt"),
+            b"plain\x00text",
+            Some(ContentClassOverride::Binary),
+        );
+
+        assert_eq!(kind, ExtractedTextKind::BinaryStrings);
+        assert_eq!(text, "plain\ntext");
+    }
 }
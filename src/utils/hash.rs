@@ -1,6 +1,97 @@
+use std::io::{self, Read};
+
 use md5::{Digest as Md5Digest, Md5};
 use sha1::Sha1;
-use sha2::{Digest as Sha2Digest, Sha256};
+use sha2::{Digest as Sha2Digest, Sha256, Sha512};
+
+/// Size of the chunks fed to the hashers while streaming a file.
+const HASH_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Digest algorithms that can be reported for scanned files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum HashAlgorithm {
+    Md5,
+    Sha1,
+    Sha256,
+    Sha512,
+}
+
+/// The algorithms computed when `--hash` is not given.
+pub const DEFAULT_HASH_ALGORITHMS: &[HashAlgorithm] = &[
+    HashAlgorithm::Sha1,
+    HashAlgorithm::Md5,
+    HashAlgorithm::Sha256,
+];
+
+/// Hex digests of a file; algorithms that were not requested are `None`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileHashes {
+    pub md5: Option<String>,
+    pub sha1: Option<String>,
+    pub sha256: Option<String>,
+    pub sha512: Option<String>,
+}
+
+/// Incrementally computes the requested digests over chunks of content.
+struct MultiHasher {
+    md5: Option<Md5>,
+    sha1: Option<Sha1>,
+    sha256: Option<Sha256>,
+    sha512: Option<Sha512>,
+}
+
+impl MultiHasher {
+    fn new(algorithms: &[HashAlgorithm]) -> Self {
+        let wants = |algorithm| algorithms.contains(&algorithm);
+        Self {
+            md5: wants(HashAlgorithm::Md5).then(Md5::new),
+            sha1: wants(HashAlgorithm::Sha1).then(Sha1::new),
+            sha256: wants(HashAlgorithm::Sha256).then(Sha256::new),
+            sha512: wants(HashAlgorithm::Sha512).then(Sha512::new),
+        }
+    }
+
+    fn update(&mut self, chunk: &[u8]) {
+        if let Some(hasher) = &mut self.md5 {
+            Md5Digest::update(hasher, chunk);
+        }
+        if let Some(hasher) = &mut self.sha1 {
+            Md5Digest::update(hasher, chunk);
+        }
+        if let Some(hasher) = &mut self.sha256 {
+            Sha2Digest::update(hasher, chunk);
+        }
+        if let Some(hasher) = &mut self.sha512 {
+            Sha2Digest::update(hasher, chunk);
+        }
+    }
+
+    fn finalize(self) -> FileHashes {
+        FileHashes {
+            md5: self.md5.map(|hasher| hex::encode(hasher.finalize())),
+            sha1: self.sha1.map(|hasher| hex::encode(hasher.finalize())),
+            sha256: self.sha256.map(|hasher| hex::encode(hasher.finalize())),
+            sha512: self.sha512.map(|hasher| hex::encode(hasher.finalize())),
+        }
+    }
+}
+
+/// Stream `reader` in fixed-size chunks through the requested hashers.
+pub fn calculate_hashes(
+    mut reader: impl Read,
+    algorithms: &[HashAlgorithm],
+) -> io::Result<FileHashes> {
+    let mut hasher = MultiHasher::new(algorithms);
+    let mut chunk = vec![0; HASH_CHUNK_SIZE];
+    loop {
+        match reader.read(&mut chunk) {
+            Ok(0) => return Ok(hasher.finalize()),
+            Ok(read) => hasher.update(&chunk[..read]),
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+}
 
 /// Calculate SHA1 hash of content and return it as a hex string
 pub fn calculate_sha1(content: &[u8]) -> String {
@@ -19,3 +110,48 @@ pub fn calculate_sha256(content: &[u8]) -> String {
     let digest = Sha256::digest(content);
     hex::encode(digest)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_hashes_matches_one_shot_digests_across_chunks() {
+        let content: Vec<u8> = (0..HASH_CHUNK_SIZE * 2 + 17)
+            .map(|index| (index % 251) as u8)
+            .collect();
+
+        let hashes = calculate_hashes(
+            content.as_slice(),
+            &[
+                HashAlgorithm::Md5,
+                HashAlgorithm::Sha1,
+                HashAlgorithm::Sha256,
+                HashAlgorithm::Sha512,
+            ],
+        )
+        .expect("hash in-memory content");
+
+        assert_eq!(hashes.md5, Some(calculate_md5(&content)));
+        assert_eq!(hashes.sha1, Some(calculate_sha1(&content)));
+        assert_eq!(hashes.sha256, Some(calculate_sha256(&content)));
+        assert_eq!(hashes.sha512, Some(hex::encode(Sha512::digest(&content))));
+    }
+
+    #[test]
+    fn test_calculate_hashes_computes_only_requested_algorithms() {
+        let hashes = calculate_hashes(&b"abc"[..], &[HashAlgorithm::Sha256]).expect("hash content");
+
+        assert_eq!(
+            hashes,
+            FileHashes {
+                sha256: Some(calculate_sha256(b"abc")),
+                ..FileHashes::default()
+            }
+        );
+        assert_eq!(
+            calculate_hashes(&b"abc"[..], &[]).expect("hash content"),
+            FileHashes::default()
+        );
+    }
+}
@@ -5,144 +5,269 @@ fn is_utf8_text(content_type: ContentType) -> bool {
     content_type == ContentType::UTF_8 || content_type == ContentType::UTF_8_BOM
 }
 
-pub fn detect_language(path: &Path, content: &[u8]) -> String {
+/// Programming language reported for a file: its detected language, `Binary`
+/// for non-text content, or `Text` / `Unknown` when no language is recognized.
+pub fn programming_language(path: &Path, content: &[u8]) -> String {
     if content.len() > 32 && !is_utf8_text(inspect(content)) {
         return "Binary".to_string();
     }
-
-    // Check for shebang in script files
-    if content.len() > 2 && content[0] == b'#' && content[1] == b'!' {
-        let shebang_end = content
-            .iter()
-            .position(|&b| b == b'\n')
-            .unwrap_or(content.len());
-        let shebang = String::from_utf8_lossy(&content[0..shebang_end]);
-
-        if shebang.contains("python") {
-            return "Python".to_string();
-        } else if shebang.contains("node") {
-            return "JavaScript".to_string();
-        } else if shebang.contains("ruby") {
-            return "Ruby".to_string();
-        } else if shebang.contains("perl") {
-            return "Perl".to_string();
-        } else if shebang.contains("php") {
-            return "PHP".to_string();
-        } else if shebang.contains("bash") || shebang.contains("sh") {
-            return "Shell".to_string();
-        }
+    if let Some(language) = detect_language(path, content) {
+        return language;
     }
-
-    // Check file extension
-    if let Some(extension) = path.extension().and_then(|e| e.to_str()) {
-        match extension.to_lowercase().as_str() {
-            "rs" => return "Rust".to_string(),
-            "py" => return "Python".to_string(),
-            "js" => return "JavaScript".to_string(),
-            "ts" => return "TypeScript".to_string(),
-            "html" | "htm" => return "HTML".to_string(),
-            "css" => return "CSS".to_string(),
-            "c" => return "C".to_string(),
-            "cpp" | "cc" | "cxx" => return "C++".to_string(),
-            "h" => return "C".to_string(),
-            "hpp" => return "C++".to_string(),
-            "s" => return "GAS".to_string(),
-            "java" => return "Java".to_string(),
-            "go" => return "Go".to_string(),
-            "rb" => return "Ruby".to_string(),
-            "php" => return "PHP".to_string(),
-            "pl" => return "Perl".to_string(),
-            "swift" => return "Swift".to_string(),
-            "md" | "markdown" => return "Markdown".to_string(),
-            "json" => return "JSON".to_string(),
-            "xml" => return "XML".to_string(),
-            "yml" | "yaml" => return "YAML".to_string(),
-            "sql" => return "SQL".to_string(),
-            "sh" | "bash" => return "Shell".to_string(),
-            "kt" | "kts" => return "Kotlin".to_string(),
-            "dart" => return "Dart".to_string(),
-            "scala" => return "Scala".to_string(),
-            "cs" => return "C#".to_string(),
-            "fs" => return "F#".to_string(),
-            "r" => return "R".to_string(),
-            "lua" => return "Lua".to_string(),
-            "jl" => return "Julia".to_string(),
-            "ex" | "exs" => return "Elixir".to_string(),
-            "clj" => return "Clojure".to_string(),
-            "hs" => return "Haskell".to_string(),
-            "erl" => return "Erlang".to_string(),
-            "sc" => return "SuperCollider".to_string(),
-            "tex" => return "TeX".to_string(),
-            _ => {}
-        }
+    if is_utf8_text(inspect(content)) {
+        "Text".to_string()
+    } else {
+        "Unknown".to_string()
     }
+}
 
-    // Check file name for special cases
-    let file_name = path
-        .file_name()
-        .and_then(|n| n.to_str())
-        .map(|s| s.to_lowercase())
-        .unwrap_or_default();
-
-    if matches!(
-        file_name.as_str(),
-        "dockerfile" | "containerfile" | "containerfile.core"
-    ) {
-        return "Dockerfile".to_string();
-    } else if file_name == "makefile" {
-        return "Makefile".to_string();
-    } else if file_name == "gemfile" || file_name == "rakefile" {
-        return "Ruby".to_string();
+/// Detect the language of a file from its name, extension and first bytes.
+///
+/// A known extension or file name wins. Otherwise the interpreter of a
+/// shebang line (`#!/usr/bin/env python3`) decides, and a few content hints
+/// are tried last.
+pub fn detect_language(path: &Path, first_bytes: &[u8]) -> Option<String> {
+    let language = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .and_then(|extension| language_for_extension(&extension.to_lowercase()))
+        .or_else(|| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| language_for_file_name(&name.to_lowercase()))
+        })
+        .or_else(|| shebang_interpreter(first_bytes).and_then(language_for_interpreter))
+        .or_else(|| language_from_content(first_bytes))?;
+    Some(language.to_string())
+}
+
+fn language_for_extension(extension: &str) -> Option<&'static str> {
+    let language = match extension {
+        "rs" => "Rust",
+        "py" | "pyi" | "pyw" => "Python",
+        "js" | "mjs" | "cjs" | "jsx" => "JavaScript",
+        "ts" | "mts" | "cts" | "tsx" => "TypeScript",
+        "html" | "htm" => "HTML",
+        "css" => "CSS",
+        "c" => "C",
+        "cpp" | "cc" | "cxx" | "c++" => "C++",
+        "h" => "C",
+        "hpp" | "hh" | "hxx" | "h++" => "C++",
+        "s" => "GAS",
+        "java" => "Java",
+        "go" => "Go",
+        "rb" | "rake" | "gemspec" => "Ruby",
+        "php" | "phtml" => "PHP",
+        "pl" | "pm" => "Perl",
+        "swift" => "Swift",
+        "md" | "markdown" => "Markdown",
+        "json" => "JSON",
+        "xml" => "XML",
+        "yml" | "yaml" => "YAML",
+        "toml" => "TOML",
+        "sql" => "SQL",
+        "sh" | "bash" | "zsh" | "ksh" => "Shell",
+        "ps1" | "psm1" => "PowerShell",
+        "kt" | "kts" => "Kotlin",
+        "dart" => "Dart",
+        "scala" | "sbt" => "Scala",
+        "groovy" | "gradle" => "Groovy",
+        "cs" => "C#",
+        "fs" => "F#",
+        "r" => "R",
+        "lua" => "Lua",
+        "jl" => "Julia",
+        "ex" | "exs" => "Elixir",
+        "clj" => "Clojure",
+        "hs" => "Haskell",
+        "erl" => "Erlang",
+        "sc" => "SuperCollider",
+        "tex" => "TeX",
+        _ => return None,
+    };
+    Some(language)
+}
+
+fn language_for_file_name(file_name: &str) -> Option<&'static str> {
+    let language = match file_name {
+        "dockerfile" | "containerfile" | "containerfile.core" => "Dockerfile",
+        "makefile" | "gnumakefile" => "Makefile",
+        "gemfile" | "rakefile" | "podfile" | "vagrantfile" => "Ruby",
+        "jenkinsfile" => "Groovy",
+        _ => return None,
+    };
+    Some(language)
+}
+
+/// Program named by a `#!` line, looking through `/usr/bin/env` and its
+/// options. Lines like Rust's `#![no_std]` are not shebangs.
+fn shebang_interpreter(content: &[u8]) -> Option<&str> {
+    let line_end = content
+        .iter()
+        .position(|&b| b == b'\n')
+        .unwrap_or(content.len());
+    let line = std::str::from_utf8(&content[..line_end]).ok()?;
+    let command = line.strip_prefix("#!")?.trim();
+    if !command.starts_with('/') {
+        return None;
+    }
+    let mut words = command.split_whitespace();
+    let program = words.next()?.rsplit('/').next()?;
+    if program == "env" {
+        return words.find(|word| !word.starts_with('-') && !word.contains('='));
     }
+    Some(program)
+}
 
-    if is_utf8_text(inspect(content)) {
-        let text_sample = String::from_utf8_lossy(&content[..std::cmp::min(content.len(), 1000)]);
-
-        if text_sample.contains("<?php") {
-            return "PHP".to_string();
-        } else if text_sample.contains("<html") || text_sample.contains("<!DOCTYPE html") {
-            return "HTML".to_string();
-        } else if text_sample.contains("import React") || text_sample.contains("import {") {
-            return "JavaScript/TypeScript".to_string();
-        } else if text_sample.contains("def ") && text_sample.contains(":") {
-            return "Python".to_string();
-        } else if text_sample.contains("package ")
-            && text_sample.contains("import ")
-            && text_sample.contains("{")
-        {
-            return "Go".to_string();
-        }
+fn language_for_interpreter(interpreter: &str) -> Option<&'static str> {
+    // `python3.11` and `python2` are both Python.
+    let name = interpreter.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+    let language = match name {
+        "python" | "pypy" => "Python",
+        "node" | "nodejs" => "JavaScript",
+        "deno" | "bun" | "ts-node" | "tsx" => "TypeScript",
+        "ruby" => "Ruby",
+        "perl" => "Perl",
+        "php" => "PHP",
+        "sh" | "bash" | "zsh" | "ksh" | "dash" | "ash" => "Shell",
+        "pwsh" => "PowerShell",
+        "lua" => "Lua",
+        "Rscript" => "R",
+        "julia" => "Julia",
+        "elixir" => "Elixir",
+        "escript" => "Erlang",
+        "groovy" => "Groovy",
+        "scala" => "Scala",
+        "swift" => "Swift",
+        _ => return None,
+    };
+    Some(language)
+}
 
-        return "Text".to_string();
+fn language_from_content(content: &[u8]) -> Option<&'static str> {
+    if !is_utf8_text(inspect(content)) {
+        return None;
     }
+    let text_sample = String::from_utf8_lossy(&content[..std::cmp::min(content.len(), 1000)]);
 
-    "Unknown".to_string()
+    if text_sample.contains("<?php") {
+        Some("PHP")
+    } else if text_sample.contains("<html") || text_sample.contains("<!DOCTYPE html") {
+        Some("HTML")
+    } else if text_sample.contains("import React") || text_sample.contains("import {") {
+        Some("JavaScript/TypeScript")
+    } else if text_sample.contains("def ") && text_sample.contains(":") {
+        Some("Python")
+    } else if text_sample.contains("package ")
+        && text_sample.contains("import ")
+        && text_sample.contains("{")
+    {
+        Some("Go")
+    } else {
+        None
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::detect_language;
+    use super::{detect_language, programming_language};
     use std::path::Path;
 
     #[test]
     fn detect_language_supports_containerfile_names() {
         assert_eq!(
-            detect_language(Path::new("Containerfile"), b"FROM scratch\n"),
+            programming_language(Path::new("Containerfile"), b"FROM scratch\n"),
             "Dockerfile"
         );
         assert_eq!(
-            detect_language(Path::new("containerfile.core"), b"FROM scratch\n"),
+            programming_language(Path::new("containerfile.core"), b"FROM scratch\n"),
             "Dockerfile"
         );
     }
 
     #[test]
     fn detect_language_maps_c_headers_to_c() {
-        assert_eq!(detect_language(Path::new("zlib.h"), b"/* header */\n"), "C");
+        assert_eq!(
+            programming_language(Path::new("zlib.h"), b"/* header */\n"),
+            "C"
+        );
     }
 
     #[test]
     fn detect_language_maps_uppercase_s_to_gas() {
-        assert_eq!(detect_language(Path::new("gvmat64.S"), b"; asm\n"), "GAS");
+        assert_eq!(
+            programming_language(Path::new("gvmat64.S"), b"; asm\n"),
+            "GAS"
+        );
+    }
+
+    #[test]
+    fn detect_language_covers_common_source_and_data_extensions() {
+        for (file_name, language) in [
+            ("main.rs", "Rust"),
+            ("main.go", "Go"),
+            ("app.py", "Python"),
+            ("index.mjs", "JavaScript"),
+            ("component.tsx", "TypeScript"),
+            ("Main.java", "Java"),
+            ("util.hh", "C++"),
+            ("Program.cs", "C#"),
+            ("tasks.rake", "Ruby"),
+            ("index.php", "PHP"),
+            ("View.swift", "Swift"),
+            ("Main.kt", "Kotlin"),
+            ("build.sbt", "Scala"),
+            ("install.zsh", "Shell"),
+            ("config.yaml", "YAML"),
+            ("Cargo.toml", "TOML"),
+            ("package.json", "JSON"),
+        ] {
+            assert_eq!(
+                detect_language(Path::new(file_name), b"").as_deref(),
+                Some(language),
+                "{file_name}"
+            );
+        }
+    }
+
+    #[test]
+    fn detect_language_uses_shebang_without_known_extension() {
+        assert_eq!(
+            detect_language(Path::new("bin/tool"), b"#!/usr/bin/env python3\nprint(1)\n")
+                .as_deref(),
+            Some("Python")
+        );
+        assert_eq!(
+            detect_language(Path::new("run.cgi"), b"#! /bin/bash -e\necho hi\n").as_deref(),
+            Some("Shell")
+        );
+        assert_eq!(
+            detect_language(Path::new("serve"), b"#!/usr/bin/env -S deno run\n").as_deref(),
+            Some("TypeScript")
+        );
+        assert_eq!(
+            detect_language(Path::new("script.sh"), b"#!/usr/bin/env python\n").as_deref(),
+            Some("Shell")
+        );
+    }
+
+    #[test]
+    fn detect_language_ignores_rust_inner_attributes() {
+        assert_eq!(
+            detect_language(Path::new("lib"), b"#![allow(clippy::bash)]\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn programming_language_falls_back_to_text_and_binary() {
+        assert_eq!(
+            programming_language(Path::new("notes"), b"plain words\n"),
+            "Text"
+        );
+        assert_eq!(
+            programming_language(Path::new("blob.rs"), &[0u8; 64]),
+            "Binary"
+        );
     }
 }
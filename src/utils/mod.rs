@@ -1,8 +1,13 @@
+pub mod comment_header;
 pub mod file;
 pub mod generated;
 pub mod hash;
 pub mod language;
 pub mod magic;
+pub mod preserved_comments;
+pub mod purl;
 pub mod sourcemap;
 pub mod spdx;
+pub mod tabular;
 pub mod text;
+pub mod version_req;
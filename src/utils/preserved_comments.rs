@@ -0,0 +1,141 @@
+//! Detection of license comments marked for preservation through minification.
+//!
+//! Closure Compiler, UglifyJS, Terser and most CSS minifiers keep block
+//! comments that start with `/*!` or carry a `@license` / `@preserve`
+//! annotation, so bundled and minified files often retain their license
+//! headers only in such blocks. This module locates those blocks so the
+//! scanner can note them in `scan_notes`; detection order is left unchanged.
+
+/// One preserved comment block found in a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreservedComment {
+    /// 1-based line where the comment starts.
+    pub start_line: usize,
+    /// 1-based line where the comment ends.
+    pub end_line: usize,
+    /// Marker that makes the comment preserved: `@license`, `@preserve` or `/*!`.
+    pub annotation: &'static str,
+}
+
+impl PreservedComment {
+    /// Human-readable note recorded in `scan_notes` for this comment.
+    pub fn note(&self) -> String {
+        format!(
+            "Preserved license comment ({}) at lines {}-{}",
+            self.annotation, self.start_line, self.end_line
+        )
+    }
+
+    /// Whether the 1-based line range `start..=end` lies within this comment.
+    pub fn contains_lines(&self, start: usize, end: usize) -> bool {
+        self.start_line <= start && end <= self.end_line
+    }
+}
+
+/// Find all `/* ... */` comments annotated with `@license`, `@preserve` or
+/// opened with `/*!`, in source order.
+///
+/// An unterminated comment extends to the end of the text.
+pub fn find_preserved_comments(text: &str) -> Vec<PreservedComment> {
+    let mut comments = Vec::new();
+    let mut offset = 0;
+
+    while let Some(start) = text[offset..].find("/*").map(|index| offset + index) {
+        let body_start = start + 2;
+        let end = text[body_start..]
+            .find("*/")
+            .map_or(text.len(), |index| body_start + index + 2);
+        let body = &text[body_start..end.min(text.len())];
+
+        if let Some(annotation) = preservation_annotation(body) {
+            comments.push(PreservedComment {
+                start_line: line_number_at(text, start),
+                end_line: line_number_at(text, end.saturating_sub(1).max(start)),
+                annotation,
+            });
+        }
+        offset = end.max(body_start);
+    }
+
+    comments
+}
+
+fn preservation_annotation(body: &str) -> Option<&'static str> {
+    if has_annotation(body, "@license") {
+        Some("@license")
+    } else if has_annotation(body, "@preserve") {
+        Some("@preserve")
+    } else if body.starts_with('!') {
+        Some("/*!")
+    } else {
+        None
+    }
+}
+
+/// Match `tag` only as a whole JSDoc tag, so `@licenses` or `@preserveAspect`
+/// do not count.
+fn has_annotation(body: &str, tag: &str) -> bool {
+    body.match_indices(tag).any(|(index, _)| {
+        body[index + tag.len()..]
+            .chars()
+            .next()
+            .is_none_or(|next| !next.is_alphanumeric() && next != '_' && next != '-')
+    })
+}
+
+fn line_number_at(text: &str, byte_offset: usize) -> usize {
+    text.as_bytes()[..byte_offset.min(text.len())]
+        .iter()
+        .filter(|&&byte| byte == b'\n')
+        .count()
+        + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_preserved_comments_recognizes_each_marker() {
+        let text = "/*! @license MIT\n * Copyright Foo\n */\nvar a;\n/** @preserve Foo v1 */\n/*! bar */\n/* plain */\n";
+        let comments = find_preserved_comments(text);
+
+        assert_eq!(
+            comments,
+            vec![
+                PreservedComment {
+                    start_line: 1,
+                    end_line: 3,
+                    annotation: "@license",
+                },
+                PreservedComment {
+                    start_line: 5,
+                    end_line: 5,
+                    annotation: "@preserve",
+                },
+                PreservedComment {
+                    start_line: 6,
+                    end_line: 6,
+                    annotation: "/*!",
+                },
+            ]
+        );
+        assert_eq!(
+            comments[0].note(),
+            "Preserved license comment (@license) at lines 1-3"
+        );
+    }
+
+    #[test]
+    fn test_find_preserved_comments_ignores_longer_tags_and_unannotated_blocks() {
+        let text = "/* @licenses are listed elsewhere */\n/* @preserveAspectRatio */\n";
+        assert!(find_preserved_comments(text).is_empty());
+    }
+
+    #[test]
+    fn test_find_preserved_comments_handles_unterminated_block() {
+        let comments = find_preserved_comments("x();\n/*! @license Apache-2.0\n * trailing");
+        assert_eq!(comments.len(), 1);
+        assert_eq!((comments[0].start_line, comments[0].end_line), (2, 3));
+    }
+}
@@ -0,0 +1,242 @@
+//! Package URL construction shared by the package parsers.
+//!
+//! Each namespaced ecosystem has its own rules for splitting a package
+//! coordinate into purl namespace and name: npm scopes keep their `@`
+//! (percent-encoded as `%40`), Maven group IDs are dotted, Go module paths keep
+//! their case and every path segment but the last, and Docker image references
+//! carry their registry as a `repository_url` qualifier. The npm, Gradle, Go
+//! and Dockerfile parsers build their purls through the helpers here so those
+//! rules live in one place; percent-encoding itself is left to [`PackageUrl`].
+//!
+//! Ecosystems with flat names, or with type-specific qualifiers such as RPM
+//! architectures, still build their [`PackageUrl`] directly. The Maven POM
+//! parser also formats its own purls, because ScanCode leaves unresolved
+//! `${property}` placeholders in them unencoded.
+
+use packageurl::PackageUrl;
+
+/// Build a purl from already-split components.
+///
+/// Blank namespaces and versions are omitted. Returns `None` when the name is
+/// blank or a component is rejected by the purl encoder.
+pub fn build_purl(
+    package_type: &str,
+    namespace: Option<&str>,
+    name: &str,
+    version: Option<&str>,
+) -> Option<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return None;
+    }
+    let mut purl = PackageUrl::new(package_type, name).ok()?;
+    if let Some(namespace) = non_blank(namespace) {
+        purl.with_namespace(namespace).ok()?;
+    }
+    if let Some(version) = non_blank(version) {
+        purl.with_version(version).ok()?;
+    }
+    Some(purl.to_string())
+}
+
+/// Build an npm purl from a package name that may carry a scope.
+///
+/// `@babel/core` becomes `pkg:npm/%40babel/core`; the slash between scope and
+/// name is kept as a path separator rather than encoded as `%2F`.
+pub fn npm_purl(full_name: &str, version: Option<&str>) -> Option<String> {
+    let (namespace, name) = match full_name.split_once('/') {
+        Some((scope, name)) if full_name.starts_with('@') => (Some(scope), name),
+        _ => (None, full_name),
+    };
+    build_purl("npm", namespace, name, version)
+}
+
+/// Build a Maven purl from a group ID, artifact ID and version.
+///
+/// Group IDs written as paths (`org/apache/commons`) are normalized to their
+/// dotted form.
+pub fn maven_purl(
+    group_id: Option<&str>,
+    artifact_id: &str,
+    version: Option<&str>,
+) -> Option<String> {
+    let group_id = non_blank(group_id).map(|group_id| group_id.replace('/', "."));
+    build_purl("maven", group_id.as_deref(), artifact_id, version)
+}
+
+/// Build a Go purl from a module or package import path.
+///
+/// Import paths are case-sensitive, so the path is kept verbatim: the last
+/// segment is the name and everything before it the namespace.
+pub fn golang_purl(module_path: &str, version: Option<&str>) -> Option<String> {
+    let (namespace, name) = split_golang_module_path(module_path.trim().trim_matches('/'));
+    build_purl("golang", namespace, name, version)
+}
+
+/// Split a Go module or package import path into namespace and name.
+///
+/// `github.com/Azure/azure-sdk-for-go/sdk/azcore` splits into
+/// `github.com/Azure/azure-sdk-for-go/sdk` and `azcore`; a path without a `/`
+/// is all name.
+pub fn split_golang_module_path(module_path: &str) -> (Option<&str>, &str) {
+    match module_path.rsplit_once('/') {
+        Some((namespace, name)) => (Some(namespace), name),
+        None => (None, module_path),
+    }
+}
+
+/// Registries that stand for Docker Hub and are left out of Docker purls.
+const DOCKER_HUB_REGISTRIES: &[&str] = &["docker.io", "index.docker.io", "registry-1.docker.io"];
+
+/// Build a Docker purl from an image reference such as
+/// `gcr.io/distroless/static:nonroot` or `nginx@sha256:...`.
+///
+/// A leading registry host (anything with a `.` or `:`, or `localhost`) other
+/// than Docker Hub goes to the `repository_url` qualifier, and Docker Hub's
+/// implicit `library/` namespace is dropped. The tag is the purl version,
+/// falling back to the digest for digest-only references.
+pub fn docker_purl(image_reference: &str) -> Option<String> {
+    let image_reference = image_reference.trim();
+    let (image, digest) = match image_reference.split_once('@') {
+        Some((image, digest)) => (image, Some(digest)),
+        None => (image_reference, None),
+    };
+    let name_start = image.rfind('/').map_or(0, |index| index + 1);
+    let (image, tag) = match image[name_start..].rfind(':') {
+        Some(colon) => (
+            &image[..name_start + colon],
+            Some(&image[name_start + colon + 1..]),
+        ),
+        None => (image, None),
+    };
+
+    let (registry, path) = match image.split_once('/') {
+        Some((first, rest)) if is_registry_host(first) => (Some(first), rest),
+        _ => (None, image),
+    };
+    let registry = registry.filter(|registry| !DOCKER_HUB_REGISTRIES.contains(registry));
+    let path = match registry {
+        Some(_) => path,
+        None => path.strip_prefix("library/").unwrap_or(path),
+    };
+    let (namespace, name) = match path.rsplit_once('/') {
+        Some((namespace, name)) => (Some(namespace), name),
+        None => (None, path),
+    };
+
+    let name = name.trim();
+    if name.is_empty() {
+        return None;
+    }
+    let mut purl = PackageUrl::new("docker", name).ok()?;
+    if let Some(namespace) = non_blank(namespace) {
+        purl.with_namespace(namespace).ok()?;
+    }
+    if let Some(version) = non_blank(tag).or(non_blank(digest)) {
+        purl.with_version(version).ok()?;
+    }
+    if let Some(registry) = registry {
+        purl.add_qualifier("repository_url", registry).ok()?;
+    }
+    Some(purl.to_string())
+}
+
+fn is_registry_host(segment: &str) -> bool {
+    segment.contains('.') || segment.contains(':') || segment == "localhost"
+}
+
+fn non_blank(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_npm_purl_encodes_scope() {
+        assert_eq!(
+            npm_purl("@babel/core", Some("7.0.0")).as_deref(),
+            Some("pkg:npm/%40babel/core@7.0.0")
+        );
+        assert_eq!(npm_purl("lodash", None).as_deref(), Some("pkg:npm/lodash"));
+    }
+
+    #[test]
+    fn test_maven_purl_uses_dotted_group_id() {
+        assert_eq!(
+            maven_purl(Some("org.apache.commons"), "commons-lang3", Some("3.12.0")).as_deref(),
+            Some("pkg:maven/org.apache.commons/commons-lang3@3.12.0")
+        );
+        assert_eq!(
+            maven_purl(Some("org/apache/commons"), "commons-lang3", None).as_deref(),
+            Some("pkg:maven/org.apache.commons/commons-lang3")
+        );
+        assert_eq!(
+            maven_purl(Some(" "), "junit", Some("")).as_deref(),
+            Some("pkg:maven/junit")
+        );
+    }
+
+    #[test]
+    fn test_golang_purl_keeps_case_and_path_segments() {
+        assert_eq!(
+            golang_purl(
+                "github.com/Azure/azure-sdk-for-go/sdk/azcore",
+                Some("v1.9.0")
+            )
+            .as_deref(),
+            Some("pkg:golang/github.com/Azure/azure-sdk-for-go/sdk/azcore@v1.9.0")
+        );
+        assert_eq!(
+            golang_purl("rsc.io/quote", None).as_deref(),
+            Some("pkg:golang/rsc.io/quote")
+        );
+    }
+
+    #[test]
+    fn test_split_golang_module_path() {
+        assert_eq!(
+            split_golang_module_path("github.com/Azure/azure-sdk-for-go/sdk/azcore"),
+            (Some("github.com/Azure/azure-sdk-for-go/sdk"), "azcore")
+        );
+        assert_eq!(split_golang_module_path("example"), (None, "example"));
+    }
+
+    #[test]
+    fn test_docker_purl_splits_registry_namespace_and_version() {
+        assert_eq!(
+            docker_purl("nginx:1.25").as_deref(),
+            Some("pkg:docker/nginx@1.25")
+        );
+        assert_eq!(
+            docker_purl("docker.io/library/ubuntu:22.04").as_deref(),
+            Some("pkg:docker/ubuntu@22.04")
+        );
+        assert_eq!(
+            docker_purl("bitnami/redis").as_deref(),
+            Some("pkg:docker/bitnami/redis")
+        );
+        assert_eq!(
+            docker_purl("gcr.io/distroless/static:nonroot").as_deref(),
+            Some("pkg:docker/distroless/static@nonroot?repository_url=gcr.io")
+        );
+        assert_eq!(
+            docker_purl("localhost/team/app:dev").as_deref(),
+            Some("pkg:docker/team/app@dev?repository_url=localhost")
+        );
+        assert_eq!(
+            docker_purl("alpine:3.19@sha256:abc123").as_deref(),
+            Some("pkg:docker/alpine@3.19")
+        );
+        assert!(
+            docker_purl("alpine@sha256:abc123")
+                .is_some_and(|purl| purl.starts_with("pkg:docker/alpine@sha256"))
+        );
+    }
+
+    #[test]
+    fn test_build_purl_rejects_blank_name() {
+        assert_eq!(build_purl("generic", None, "  ", Some("1.0")), None);
+    }
+}
@@ -201,6 +201,46 @@ fn render_boolean_operand(
     }
 }
 
+/// Extract user-defined `LicenseRef-<id>` identifiers from an SPDX expression.
+///
+/// ScanCode's own `LicenseRef-scancode-*` keys are skipped and the original case is kept, since
+/// it is needed to locate the matching `LICENSES/<id>.txt` file.
+pub fn custom_license_refs(spdx_expression: &str) -> Vec<String> {
+    let mut refs: Vec<String> = Vec::new();
+    for token in spdx_expression.split(|c: char| c.is_whitespace() || c == '(' || c == ')') {
+        let lowered = token.to_ascii_lowercase();
+        if lowered.len() > "licenseref-".len()
+            && lowered.starts_with("licenseref-")
+            && !lowered.starts_with("licenseref-scancode-")
+            && !refs.iter().any(|existing| existing == token)
+        {
+            refs.push(token.to_string());
+        }
+    }
+    refs
+}
+
+/// Extract the license and exception ids of an SPDX expression, as named in
+/// REUSE `LICENSES/<id>.txt` files.
+///
+/// Operators and ScanCode's own `LicenseRef-scancode-*` keys are skipped and the
+/// original case is kept.
+pub fn reuse_license_ids(spdx_expression: &str) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::new();
+    for token in spdx_expression.split(|c: char| c.is_whitespace() || c == '(' || c == ')') {
+        let token = token.trim_end_matches('+');
+        let lowered = token.to_ascii_lowercase();
+        if !token.is_empty()
+            && !matches!(lowered.as_str(), "and" | "or" | "with")
+            && !lowered.starts_with("licenseref-scancode-")
+            && !ids.iter().any(|existing| existing == token)
+        {
+            ids.push(token.to_string());
+        }
+    }
+    ids
+}
+
 fn wrap_compound_expression(expression: &str) -> String {
     if expression.contains(' ') && !(expression.starts_with('(') && expression.ends_with(')')) {
         format!("({})", expression)
@@ -213,6 +253,22 @@ fn wrap_compound_expression(expression: &str) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn reuse_license_ids_lists_licenses_and_exceptions() {
+        assert_eq!(
+            reuse_license_ids(
+                "(MIT OR Apache-2.0) AND GPL-2.0-or-later WITH Classpath-exception-2.0 AND \
+                 LicenseRef-scancode-unknown-spdx AND MIT"
+            ),
+            vec![
+                "MIT",
+                "Apache-2.0",
+                "GPL-2.0-or-later",
+                "Classpath-exception-2.0"
+            ]
+        );
+    }
+
     #[test]
     fn combine_license_expressions_preserves_spdx_case() {
         let result = combine_license_expressions(vec!["MIT".to_string(), "Apache-2.0".to_string()]);
@@ -263,4 +319,16 @@ mod tests {
 
         assert_eq!(result.as_deref(), Some("MIT"));
     }
+
+    #[test]
+    fn test_custom_license_refs_skips_scancode_refs() {
+        assert_eq!(
+            custom_license_refs(
+                "(MIT OR LicenseRef-MyCorp) AND LicenseRef-scancode-proprietary AND LicenseRef-MyCorp"
+            ),
+            vec!["LicenseRef-MyCorp".to_string()]
+        );
+        assert!(custom_license_refs("Apache-2.0 WITH LLVM-exception").is_empty());
+        assert!(custom_license_refs("LicenseRef-").is_empty());
+    }
 }
@@ -0,0 +1,80 @@
+//! Heuristic recognition of tabular data files.
+//!
+//! CSV, TSV and similar exports are text, so they would otherwise run through
+//! full license and copyright detection, which is slow on large files and
+//! almost never finds anything. A file counts as tabular when nearly every
+//! sampled line splits into the same number of fields on one delimiter.
+
+/// Scan note recorded on files whose detection was skipped by `--skip-data-files`.
+pub const SKIPPED_DATA_FILE_NOTE: &str =
+    "License and copyright detection skipped for tabular data file";
+
+const DELIMITERS: [char; 4] = [',', '\t', ';', '|'];
+const SAMPLE_LINES: usize = 200;
+const MIN_LINES: usize = 20;
+/// Share of sampled lines that must carry the dominant delimiter count.
+const MIN_CONSISTENT_RATIO: f64 = 0.9;
+
+/// Whether `text` looks like delimiter-separated tabular data.
+///
+/// Only the first [`SAMPLE_LINES`] non-blank lines are inspected, and short
+/// files are never considered tabular.
+pub fn is_tabular_data(text: &str) -> bool {
+    let lines: Vec<&str> = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .take(SAMPLE_LINES)
+        .collect();
+    if lines.len() < MIN_LINES {
+        return false;
+    }
+
+    DELIMITERS.iter().any(|&delimiter| {
+        let mut counts: Vec<usize> = lines
+            .iter()
+            .map(|line| line.matches(delimiter).count())
+            .collect();
+        counts.sort_unstable();
+        let (dominant_count, occurrences) = most_common(&counts);
+        dominant_count > 0 && occurrences as f64 >= lines.len() as f64 * MIN_CONSISTENT_RATIO
+    })
+}
+
+/// Most frequent value in a sorted slice and how often it occurs.
+fn most_common(sorted: &[usize]) -> (usize, usize) {
+    sorted
+        .chunk_by(|a, b| a == b)
+        .map(|run| (run[0], run.len()))
+        .max_by_key(|&(_, len)| len)
+        .unwrap_or((0, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn csv_rows(rows: usize) -> String {
+        (0..rows)
+            .map(|row| format!("{row},name-{row},{}.5,true\n", row * 3))
+            .collect()
+    }
+
+    #[test]
+    fn test_is_tabular_data_recognizes_csv_and_tsv() {
+        assert!(is_tabular_data(&format!(
+            "id,name,score,active\n{}",
+            csv_rows(50)
+        )));
+        assert!(is_tabular_data(&csv_rows(50).replace(',', "\t")));
+    }
+
+    #[test]
+    fn test_is_tabular_data_rejects_prose_and_short_files() {
+        let prose = "Permission is hereby granted, free of charge, to any person obtaining a copy\n\
+                     of this software and associated documentation files (the \"Software\"), to deal\n\
+                     in the Software without restriction, including without limitation the rights\n"
+            .repeat(10);
+        assert!(!is_tabular_data(&prose));
+        assert!(!is_tabular_data(&csv_rows(5)));
+    }
+}
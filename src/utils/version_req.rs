@@ -0,0 +1,257 @@
+//! Structured parsing of dependency version requirements.
+//!
+//! Requirement strings are kept verbatim in `extracted_requirement`; this
+//! module breaks them into `(operator, version)` constraints for ecosystems
+//! whose requirement syntax is well defined: npm semver ranges, Python PEP 440
+//! specifiers and Cargo semver requirements.
+//!
+//! A requirement parses into alternatives (npm `||`), each of which is a list
+//! of constraints that must all hold. Operators are kept as written in the
+//! source syntax, with the implicit default made explicit: a bare npm version
+//! is `=`, a bare Cargo version is `^` (or `=` for a wildcard such as `1.*`).
+//! Wildcard versions (`1.2.x`, `1.2.*`) keep their wildcard in `version`.
+
+use serde::Serialize;
+
+/// Requirement syntax of a package ecosystem.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VersionReqSyntax {
+    Npm,
+    Pep440,
+    Cargo,
+}
+
+impl VersionReqSyntax {
+    /// Syntax for a purl type, when its requirement semantics are known.
+    pub fn for_purl_type(purl_type: &str) -> Option<Self> {
+        match purl_type {
+            "npm" => Some(Self::Npm),
+            "pypi" => Some(Self::Pep440),
+            "cargo" => Some(Self::Cargo),
+            _ => None,
+        }
+    }
+}
+
+/// A single `operator version` comparison.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct VersionConstraint {
+    pub operator: String,
+    pub version: String,
+}
+
+impl VersionConstraint {
+    fn new(operator: &str, version: &str) -> Self {
+        Self {
+            operator: operator.to_string(),
+            version: version.to_string(),
+        }
+    }
+}
+
+const NPM_OPERATORS: &[&str] = &[">=", "<=", ">", "<", "=", "^", "~"];
+const PEP440_OPERATORS: &[&str] = &["===", "~=", "==", "!=", ">=", "<=", ">", "<"];
+const CARGO_OPERATORS: &[&str] = &[">=", "<=", ">", "<", "=", "^", "~"];
+
+/// Parse `requirement` into alternatives of constraints.
+///
+/// Returns `None` when the requirement is not a version range in `syntax`
+/// (for example an npm git URL or dist-tag, or a Poetry-style `^1.2` in a PEP
+/// 440 context).
+pub fn parse_version_req(
+    requirement: &str,
+    syntax: VersionReqSyntax,
+) -> Option<Vec<Vec<VersionConstraint>>> {
+    let requirement = requirement.trim();
+    match syntax {
+        VersionReqSyntax::Npm => requirement
+            .split("||")
+            .map(parse_npm_range)
+            .collect::<Option<Vec<_>>>(),
+        VersionReqSyntax::Pep440 => {
+            parse_comma_separated(requirement, PEP440_OPERATORS, None).map(|set| vec![set])
+        }
+        VersionReqSyntax::Cargo => {
+            parse_comma_separated(requirement, CARGO_OPERATORS, Some("^")).map(|set| vec![set])
+        }
+    }
+}
+
+fn parse_npm_range(range: &str) -> Option<Vec<VersionConstraint>> {
+    let range = range.trim();
+    if range.is_empty() || range == "*" || range.eq_ignore_ascii_case("x") {
+        return Some(vec![VersionConstraint::new("*", "*")]);
+    }
+
+    if let Some((low, high)) = range.split_once(" - ") {
+        let (low, high) = (low.trim(), high.trim());
+        return (is_version(low) && is_version(high)).then(|| {
+            vec![
+                VersionConstraint::new(">=", low),
+                VersionConstraint::new("<=", high),
+            ]
+        });
+    }
+
+    let mut constraints = Vec::new();
+    let mut pending_operator: Option<&str> = None;
+    for token in range.split_whitespace() {
+        if NPM_OPERATORS.contains(&token) {
+            pending_operator = Some(token);
+            continue;
+        }
+        let (operator, version) = match pending_operator.take() {
+            Some(operator) => (operator, token),
+            None => split_operator(token, NPM_OPERATORS).unwrap_or(("=", token)),
+        };
+        let version = version.trim_start_matches('v');
+        if !is_version(version) {
+            return None;
+        }
+        constraints.push(VersionConstraint::new(operator, version));
+    }
+
+    (pending_operator.is_none() && !constraints.is_empty()).then_some(constraints)
+}
+
+fn parse_comma_separated(
+    requirement: &str,
+    operators: &[&str],
+    default_operator: Option<&str>,
+) -> Option<Vec<VersionConstraint>> {
+    if requirement == "*" {
+        return Some(vec![VersionConstraint::new("*", "*")]);
+    }
+
+    requirement
+        .split(',')
+        .map(|clause| {
+            let clause = clause.trim();
+            let (operator, version) = match split_operator(clause, operators) {
+                Some(split) => split,
+                None if clause.contains('*') => (default_operator.map(|_| "=")?, clause),
+                None => (default_operator?, clause),
+            };
+            let version = version.trim();
+            is_version(version).then(|| VersionConstraint::new(operator, version))
+        })
+        .collect()
+}
+
+/// Split a leading operator off `clause`. `operators` lists longer operators
+/// before their prefixes so `>=` wins over `>`.
+fn split_operator<'a>(
+    clause: &'a str,
+    operators: &[&'static str],
+) -> Option<(&'static str, &'a str)> {
+    operators
+        .iter()
+        .find_map(|operator| Some((*operator, clause.strip_prefix(operator)?.trim_start())))
+}
+
+fn is_version(version: &str) -> bool {
+    version.starts_with(|c: char| c.is_ascii_digit())
+        && version
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+' | '*' | '!' | '_'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constraints(pairs: &[(&str, &str)]) -> Vec<VersionConstraint> {
+        pairs
+            .iter()
+            .map(|(operator, version)| VersionConstraint::new(operator, version))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_npm_ranges() {
+        assert_eq!(
+            parse_version_req("^1.2", VersionReqSyntax::Npm),
+            Some(vec![constraints(&[("^", "1.2")])])
+        );
+        assert_eq!(
+            parse_version_req(">= 1.2.0 <2", VersionReqSyntax::Npm),
+            Some(vec![constraints(&[(">=", "1.2.0"), ("<", "2")])])
+        );
+        assert_eq!(
+            parse_version_req("1.2.x || ~2.0.1", VersionReqSyntax::Npm),
+            Some(vec![
+                constraints(&[("=", "1.2.x")]),
+                constraints(&[("~", "2.0.1")])
+            ])
+        );
+        assert_eq!(
+            parse_version_req("1.0.0 - 1.4.0", VersionReqSyntax::Npm),
+            Some(vec![constraints(&[(">=", "1.0.0"), ("<=", "1.4.0")])])
+        );
+        assert_eq!(
+            parse_version_req("*", VersionReqSyntax::Npm),
+            Some(vec![constraints(&[("*", "*")])])
+        );
+        assert_eq!(parse_version_req("latest", VersionReqSyntax::Npm), None);
+        assert_eq!(
+            parse_version_req("github:user/repo#v1", VersionReqSyntax::Npm),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_pep440_specifiers() {
+        assert_eq!(
+            parse_version_req(">=1.2,<2", VersionReqSyntax::Pep440),
+            Some(vec![constraints(&[(">=", "1.2"), ("<", "2")])])
+        );
+        assert_eq!(
+            parse_version_req("~=1.4.2", VersionReqSyntax::Pep440),
+            Some(vec![constraints(&[("~=", "1.4.2")])])
+        );
+        assert_eq!(
+            parse_version_req("== 1.2.*, != 1.2.3", VersionReqSyntax::Pep440),
+            Some(vec![constraints(&[("==", "1.2.*"), ("!=", "1.2.3")])])
+        );
+        assert_eq!(parse_version_req("^1.2", VersionReqSyntax::Pep440), None);
+        assert_eq!(parse_version_req("1.2", VersionReqSyntax::Pep440), None);
+    }
+
+    #[test]
+    fn test_parse_cargo_requirements() {
+        assert_eq!(
+            parse_version_req("1.2", VersionReqSyntax::Cargo),
+            Some(vec![constraints(&[("^", "1.2")])])
+        );
+        assert_eq!(
+            parse_version_req(">=1.2, <2", VersionReqSyntax::Cargo),
+            Some(vec![constraints(&[(">=", "1.2"), ("<", "2")])])
+        );
+        assert_eq!(
+            parse_version_req("=0.4.3", VersionReqSyntax::Cargo),
+            Some(vec![constraints(&[("=", "0.4.3")])])
+        );
+        assert_eq!(
+            parse_version_req("1.*", VersionReqSyntax::Cargo),
+            Some(vec![constraints(&[("=", "1.*")])])
+        );
+        assert_eq!(parse_version_req("~>1.2", VersionReqSyntax::Cargo), None);
+    }
+
+    #[test]
+    fn test_syntax_for_purl_type() {
+        assert_eq!(
+            VersionReqSyntax::for_purl_type("npm"),
+            Some(VersionReqSyntax::Npm)
+        );
+        assert_eq!(
+            VersionReqSyntax::for_purl_type("pypi"),
+            Some(VersionReqSyntax::Pep440)
+        );
+        assert_eq!(
+            VersionReqSyntax::for_purl_type("cargo"),
+            Some(VersionReqSyntax::Cargo)
+        );
+        assert_eq!(VersionReqSyntax::for_purl_type("hex"), None);
+    }
+}
@@ -0,0 +1,39 @@
+defmodule Demo.MixProject do
+  use Mix.Project
+
+  @version "0.4.2"
+  @source_url "https://github.com/example/demo"
+
+  def project do
+    [
+      app: :demo,
+      version: @version,
+      elixir: "~> 1.14",
+      description: "A demo Phoenix application",
+      source_url: @source_url,
+      package: package(),
+      deps: deps()
+    ]
+  end
+
+  defp package do
+    [
+      licenses: ["Apache-2.0"],
+      links: %{"GitHub" => @source_url}
+    ]
+  end
+
+  defp deps do
+    [
+      {:phoenix, "~> 1.7.10"},
+      # JSON encoding
+      {:jason, "1.4.1"},
+      {:ex_doc, "~> 0.31", only: :dev, runtime: false},
+      {:mox, "~> 1.1", only: [:test]},
+      {:telemetry, "~> 1.0", optional: true},
+      {:gettext, git: "https://github.com/elixir-lang/gettext.git", tag: "v0.24.0"},
+      {:plug_cowboy, github: "elixir-plug/plug_cowboy", branch: "main"},
+      {:credo, "~> 1.7", only: Mix.env()}
+    ]
+  end
+end
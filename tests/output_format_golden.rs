@@ -246,6 +246,7 @@ fn test_json_contract_includes_facets_and_tallies_by_facet() {
         files: vec![core_file],
         license_references: vec![],
         license_rule_references: vec![],
+        unknown_license_texts: vec![],
     };
 
     let mut bytes = Vec::new();
@@ -1181,7 +1182,7 @@ fn extract_spdx_rdf_semantics(xml: &str) -> BTreeMap<String, String> {
         Regex::new(r#"<spdx:specVersion>([^<]+)</spdx:specVersion>"#).expect("spec version regex");
     let comment_re = Regex::new(r#"(?s)<rdfs:comment>(.*?)</rdfs:comment>"#)
         .expect("comment regex should compile");
-    let creation_info_re = Regex::new(r#"<spdx:creationInfo><spdx:CreationInfo><spdx:created>[^<]+</spdx:created></spdx:CreationInfo></spdx:creationInfo>"#)
+    let creation_info_re = Regex::new(r#"<spdx:creationInfo><spdx:CreationInfo><spdx:created>[^<]+</spdx:created><spdx:licenseListVersion>[^<]+</spdx:licenseListVersion></spdx:CreationInfo></spdx:creationInfo>"#)
         .expect("creation info regex should compile");
     let package_license_values_re = Regex::new(
         r#"(?s)<spdx:Package[^>]*>.*?<spdx:licenseConcluded rdf:resource=\"([^\"]+)\"/>.*?<spdx:licenseDeclared rdf:resource=\"([^\"]+)\"/>.*?<spdx:licenseInfoFromFiles rdf:resource=\"([^\"]+)\"/>.*?<spdx:copyrightText>([^<]+)</spdx:copyrightText>.*?<spdx:name>[^<]+</spdx:name>"#,
@@ -1560,12 +1561,13 @@ fn sample_header(files_count: usize, directories_count: usize) -> Header {
             files_count,
             directories_count,
             excluded_count: 0,
-            system_environment: SystemEnvironment {
+            system_environment: Some(SystemEnvironment {
                 operating_system: Some("linux".to_string()),
                 cpu_architecture: "64".to_string(),
                 platform: "linux".to_string(),
                 rust_version: "1.93.0".to_string(),
-            },
+            }),
+            spdx_license_list_version: None,
         },
     }
 }
@@ -1589,6 +1591,7 @@ fn sample_output_with_sections(
         files,
         license_references: vec![],
         license_rule_references: vec![],
+        unknown_license_texts: vec![],
     }
 }
 
@@ -1706,6 +1709,7 @@ fn sample_html_simple_output() -> Output {
                     copyright: "Copyright (c) 2000 ACME, Inc.".to_string(),
                     start_line: 1,
                     end_line: 1,
+                    ..Default::default()
                 }],
                 vec![Holder {
                     holder: "ACME, Inc.".to_string(),
@@ -1816,6 +1820,8 @@ fn sample_cyclonedx_dependency_output() -> Output {
         datafile_path: "scan/package-lock.json".to_string(),
         datasource_id: DatasourceId::NpmPackageLockJson,
         namespace: None,
+        depth: None,
+        dependency_path: Vec::new(),
     };
 
     let fallback_dep = TopLevelDependency {
@@ -1835,6 +1841,8 @@ fn sample_cyclonedx_dependency_output() -> Output {
         datafile_path: "scan/package-lock.json".to_string(),
         datasource_id: DatasourceId::NpmPackageLockJson,
         namespace: None,
+        depth: None,
+        dependency_path: Vec::new(),
     };
 
     sample_output_with_sections(0, 0, vec![], vec![root_dep, fallback_dep], vec![])
@@ -1878,6 +1886,7 @@ fn sample_csv_tree_file(path: &str, name: &str) -> FileInfo {
             copyright: "Copyright (c) 2000 ACME, Inc.".to_string(),
             start_line: 1,
             end_line: 1,
+            ..Default::default()
         }],
         vec![Holder {
             holder: "ACME, Inc.".to_string(),
@@ -1905,5 +1914,6 @@ fn empty_output() -> Output {
         files: vec![],
         license_references: vec![],
         license_rule_references: vec![],
+        unknown_license_texts: vec![],
     }
 }
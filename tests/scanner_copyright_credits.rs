@@ -42,6 +42,7 @@ fn scanner_matches_structured_credits_fixture() {
         max_urls: 50,
         timeout_seconds: 120.0,
         scan_cache_dir: None,
+        ..Default::default()
     };
 
     let collected = collect_paths(&fixture_dir, 0, &patterns);
@@ -75,3 +76,139 @@ fn scanner_matches_structured_credits_fixture() {
     assert!(scanned.copyrights.is_empty());
     assert!(scanned.holders.is_empty());
 }
+
+#[test]
+fn scanner_detects_one_author_per_line_in_authors_list() {
+    let temp_dir = tempfile::TempDir::new().expect("create temp dir");
+    fs::write(
+        temp_dir.path().join("AUTHORS"),
+        "# Names of the people who wrote this project.\n\nAlice Smith <alice@example.com>\nBob Jones\nCarol Nguyen (https://carol.example.org)\n",
+    )
+    .expect("write AUTHORS file");
+
+    let collected = collect_paths(temp_dir.path(), 0, &[]);
+    let result = process_collected(
+        &collected,
+        hidden_progress(),
+        None,
+        LicenseScanOptions::default(),
+        &TextDetectionOptions {
+            listed_authors: true,
+            ..TextDetectionOptions::default()
+        },
+    );
+
+    let scanned = result
+        .files
+        .into_iter()
+        .find(|entry| entry.file_type == FileType::File && entry.path.ends_with("AUTHORS"))
+        .expect("AUTHORS file should be present in scan result");
+
+    let actual: Vec<ExpectedAuthor> = scanned
+        .authors
+        .into_iter()
+        .map(|author| ExpectedAuthor {
+            author: author.author,
+            start_line: author.start_line,
+            end_line: author.end_line,
+        })
+        .collect();
+
+    assert_eq!(
+        actual,
+        vec![
+            ExpectedAuthor {
+                author: "Alice Smith <alice@example.com>".to_string(),
+                start_line: 3,
+                end_line: 3,
+            },
+            ExpectedAuthor {
+                author: "Bob Jones".to_string(),
+                start_line: 4,
+                end_line: 4,
+            },
+            ExpectedAuthor {
+                author: "Carol Nguyen (https://carol.example.org)".to_string(),
+                start_line: 5,
+                end_line: 5,
+            },
+        ]
+    );
+}
+
+#[test]
+fn scanner_keeps_detector_authors_for_authors_golden_fixtures_by_default() {
+    let fixtures = [
+        "testdata/copyright-golden/authors/authors-name-email-list.txt",
+        "testdata/copyright-golden/copyrights/contributors.txt",
+        "testdata/copyright-golden/copyrights/misco2/AUTHORSdottxt.txt",
+        "testdata/summarycode-golden/summary/summary_without_holder/pip-22.0.4/AUTHORS.txt",
+    ];
+
+    for fixture in fixtures {
+        let content = fs::read_to_string(fixture).expect("read fixture");
+        let temp_dir = tempfile::TempDir::new().expect("create temp dir");
+        fs::write(temp_dir.path().join("AUTHORS"), &content).expect("write AUTHORS file");
+
+        let collected = collect_paths(temp_dir.path(), 0, &[]);
+        let result = process_collected(
+            &collected,
+            hidden_progress(),
+            None,
+            LicenseScanOptions::default(),
+            &TextDetectionOptions::default(),
+        );
+        let scanned = result
+            .files
+            .into_iter()
+            .find(|entry| entry.file_type == FileType::File && entry.path.ends_with("AUTHORS"))
+            .expect("AUTHORS file should be present in scan result");
+
+        let actual: Vec<_> = scanned
+            .authors
+            .into_iter()
+            .map(|author| (author.author, author.start_line, author.end_line))
+            .collect();
+        let (_, _, detected) = provenant::copyright::detect_copyrights(&content);
+        let expected: Vec<_> = detected
+            .into_iter()
+            .map(|author| (author.author, author.start_line, author.end_line))
+            .collect();
+        assert_eq!(actual, expected, "{fixture}");
+    }
+}
+
+#[test]
+fn scanner_adds_listed_authors_next_to_detected_authors() {
+    let temp_dir = tempfile::TempDir::new().expect("create temp dir");
+    fs::write(
+        temp_dir.path().join("AUTHORS"),
+        "Written by John Doe <john@example.com>\n\nJane Smith <jane@example.com>\n",
+    )
+    .expect("write AUTHORS file");
+
+    let collected = collect_paths(temp_dir.path(), 0, &[]);
+    let result = process_collected(
+        &collected,
+        hidden_progress(),
+        None,
+        LicenseScanOptions::default(),
+        &TextDetectionOptions {
+            listed_authors: true,
+            ..TextDetectionOptions::default()
+        },
+    );
+    let scanned = result
+        .files
+        .into_iter()
+        .find(|entry| entry.file_type == FileType::File && entry.path.ends_with("AUTHORS"))
+        .expect("AUTHORS file should be present in scan result");
+
+    let lines: Vec<_> = scanned
+        .authors
+        .iter()
+        .map(|author| author.start_line)
+        .collect();
+    assert_eq!(lines, vec![1, 3], "{:?}", scanned.authors);
+    assert_eq!(scanned.authors[1].author, "Jane Smith <jane@example.com>");
+}
@@ -567,6 +567,7 @@ fn test_scanner_detects_emails_and_urls_when_enabled() {
         max_urls: 50,
         timeout_seconds: 120.0,
         scan_cache_dir: None,
+        ..Default::default()
     };
 
     let result = scan(test_path, 10, &patterns, engine, false, Some(&options));
@@ -611,6 +612,7 @@ fn test_scanner_detects_copyrights_in_latin1_text() {
         max_urls: 50,
         timeout_seconds: 120.0,
         scan_cache_dir: None,
+        ..Default::default()
     };
 
     let result = scan(test_path, 10, &patterns, engine, false, Some(&options));
@@ -658,6 +660,7 @@ fn test_scanner_detects_copyrights_in_pdf_text() {
         max_urls: 50,
         timeout_seconds: 120.0,
         scan_cache_dir: None,
+        ..Default::default()
     };
 
     let result = scan(test_path, 10, &patterns, engine, false, Some(&options));
@@ -718,6 +721,7 @@ fn test_scanner_detects_emails_and_urls_in_pdf_text() {
         max_urls: 50,
         timeout_seconds: 120.0,
         scan_cache_dir: None,
+        ..Default::default()
     };
 
     let result = scan(test_path, 10, &patterns, engine, false, Some(&options));
@@ -780,6 +784,7 @@ fn test_scanner_detects_copyrights_in_supported_image_exif_containers() {
         max_urls: 50,
         timeout_seconds: 120.0,
         scan_cache_dir: None,
+        ..Default::default()
     };
 
     let result = scan(test_path, 10, &patterns, engine, false, Some(&options));
@@ -856,6 +861,7 @@ fn test_scanner_detects_emails_and_urls_in_xmp_metadata() {
         max_urls: 50,
         timeout_seconds: 120.0,
         scan_cache_dir: None,
+        ..Default::default()
     };
 
     let result = scan(test_path, 10, &patterns, engine, false, Some(&options));
@@ -934,6 +940,7 @@ fn test_scanner_detects_urls_in_additional_xmp_fields() {
         max_urls: 50,
         timeout_seconds: 120.0,
         scan_cache_dir: None,
+        ..Default::default()
     };
 
     let result = scan(test_path, 10, &patterns, engine, false, Some(&options));
@@ -997,6 +1004,7 @@ fn test_scanner_detects_emails_in_exif_user_comment() {
         max_urls: 50,
         timeout_seconds: 120.0,
         scan_cache_dir: None,
+        ..Default::default()
     };
 
     let result = scan(test_path, 10, &patterns, engine, false, Some(&options));
@@ -1050,6 +1058,7 @@ fn test_scanner_ignores_non_clue_image_metadata() {
         max_urls: 50,
         timeout_seconds: 120.0,
         scan_cache_dir: None,
+        ..Default::default()
     };
 
     let result = scan(test_path, 10, &patterns, engine, false, Some(&options));
@@ -1095,6 +1104,7 @@ fn test_scanner_ignores_xml_namespace_garbage_in_copyright_detection() {
         max_urls: 50,
         timeout_seconds: 120.0,
         scan_cache_dir: None,
+        ..Default::default()
     };
 
     let result = scan(test_path, 10, &patterns, engine, false, Some(&options));
@@ -1152,6 +1162,7 @@ fn test_scanner_detects_copyrights_in_windows_dll_strings() {
         max_urls: 50,
         timeout_seconds: 120.0,
         scan_cache_dir: None,
+        ..Default::default()
     };
 
     let result = scan(test_path, 10, &patterns, engine, false, Some(&options));
@@ -1199,6 +1210,7 @@ fn test_scanner_avoids_false_positive_copyrights_in_executable_strings() {
         max_urls: 50,
         timeout_seconds: 120.0,
         scan_cache_dir: None,
+        ..Default::default()
     };
 
     let result = scan(test_path, 10, &patterns, engine, false, Some(&options));
@@ -1253,6 +1265,7 @@ fn test_scanner_respects_email_url_thresholds() {
         max_urls: 2,
         timeout_seconds: 120.0,
         scan_cache_dir: None,
+        ..Default::default()
     };
 
     let result = scan(test_path, 10, &patterns, engine, false, Some(&options));
@@ -1298,6 +1311,7 @@ fn test_scanner_persists_scan_result_cache_entries() {
         max_urls: 50,
         timeout_seconds: 120.0,
         scan_cache_dir: Some(cache_dir.clone()),
+        ..Default::default()
     };
 
     let first = scan(test_path, 10, &patterns, None, false, Some(&options));
@@ -1328,3 +1342,885 @@ fn test_scanner_persists_scan_result_cache_entries() {
     assert_eq!(second_file.emails.len(), 1);
     assert_eq!(second_file.urls.len(), 1);
 }
+
+#[test]
+fn test_scanner_text_ext_override_runs_license_detection_on_dat_file() {
+    use tempfile::TempDir;
+
+    let Some(engine) = create_license_detection_engine() else {
+        return;
+    };
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let content_path = temp_dir.path().join("records.dat");
+    let mut content = b"SPDX-License-Identifier: MIT\n".to_vec();
+    content.extend([0x01; 16]);
+    content.push(0xff);
+    fs::write(&content_path, &content).expect("Failed to write test file");
+
+    let options = TextDetectionOptions {
+        detect_copyrights: false,
+        text_extensions: vec!["dat".to_string()],
+        ..TextDetectionOptions::default()
+    };
+
+    let result = scan(temp_dir.path(), 0, &[], Some(engine), false, Some(&options));
+
+    let file = result
+        .files
+        .iter()
+        .find(|f| f.file_type == FileType::File && f.path.ends_with("records.dat"))
+        .expect("Should find dat file");
+
+    assert_eq!(file.mime_type.as_deref(), Some("text/plain"));
+    assert_eq!(file.license_expression.as_deref(), Some("MIT"));
+}
+
+#[test]
+fn test_scanner_marks_files_with_copyright_but_no_license() {
+    use tempfile::TempDir;
+
+    let Some(engine) = create_license_detection_engine() else {
+        return;
+    };
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("notice_only.c"),
+        "/* Copyright (c) 2024 Example Corp. */\nint main(void) { return 0; }\n",
+    )
+    .expect("Failed to write test file");
+    fs::write(
+        temp_dir.path().join("licensed.c"),
+        "/* Copyright (c) 2024 Example Corp. */\n/* SPDX-License-Identifier: MIT */\nint x;\n",
+    )
+    .expect("Failed to write test file");
+
+    let options = TextDetectionOptions {
+        mark_copyright_only: true,
+        ..TextDetectionOptions::default()
+    };
+
+    let result = scan(temp_dir.path(), 0, &[], Some(engine), false, Some(&options));
+    let find = |name: &str| {
+        result
+            .files
+            .iter()
+            .find(|f| f.file_type == FileType::File && f.path.ends_with(name))
+            .unwrap_or_else(|| panic!("Should find {name}"))
+    };
+
+    let notice_only = find("notice_only.c");
+    assert!(!notice_only.copyrights.is_empty());
+    assert!(notice_only.license_detections.is_empty());
+    assert!(notice_only.has_copyright_no_license);
+
+    assert!(!find("licensed.c").has_copyright_no_license);
+}
+
+#[test]
+fn test_scanner_leaves_license_ref_tag_unlinked_in_file_findings() {
+    use tempfile::TempDir;
+
+    let Some(engine) = create_license_detection_engine() else {
+        return;
+    };
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let licenses_dir = temp_dir.path().join("LICENSES");
+    fs::create_dir_all(&licenses_dir).expect("Failed to create LICENSES dir");
+    fs::write(
+        licenses_dir.join("LicenseRef-MyCorp.txt"),
+        "MyCorp internal license terms.\n",
+    )
+    .expect("Failed to write custom license");
+    let src_dir = temp_dir.path().join("src");
+    fs::create_dir_all(&src_dir).expect("Failed to create src dir");
+    fs::write(
+        src_dir.join("main.c"),
+        "// SPDX-License-Identifier: LicenseRef-MyCorp\nint main(void) { return 0; }\n",
+    )
+    .expect("Failed to write test file");
+
+    let options = TextDetectionOptions {
+        detect_copyrights: false,
+        ..TextDetectionOptions::default()
+    };
+    let result = scan(temp_dir.path(), 0, &[], Some(engine), false, Some(&options));
+
+    let file = result
+        .files
+        .iter()
+        .find(|f| f.file_type == FileType::File && f.path.ends_with("main.c"))
+        .expect("Should find tagged file");
+    let tag_match = file
+        .license_detections
+        .iter()
+        .flat_map(|detection| detection.matches.iter())
+        .find(|m| m.license_expression_spdx == "LicenseRef-MyCorp")
+        .expect("LicenseRef tag should be matched");
+    // Linking to LICENSES/ happens after the scan, bounded by the scan root, so the
+    // per-file (cached) findings carry no reference to it.
+    assert_eq!(tag_match.referenced_filenames, None);
+    assert!(tag_match.custom_license_files.is_empty());
+}
+
+#[test]
+fn test_scanner_detects_license_header_before_invalid_utf8() {
+    use provenant::utils::file::LOSSY_DECODE_NOTE;
+    use tempfile::TempDir;
+
+    let Some(engine) = create_license_detection_engine() else {
+        return;
+    };
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let mut content = "// SPDX-License-Identifier: MIT\n// Copyright (c) 2024 Zoë Example\n"
+        .as_bytes()
+        .to_vec();
+    content.extend([0xff, 0xfe, 0xc3, 0x28]);
+    content.extend(b"\nint main(void) { return 0; }\n");
+    fs::write(temp_dir.path().join("main.c"), &content).expect("Failed to write test file");
+
+    let result = scan(temp_dir.path(), 0, &[], Some(engine), false, None);
+
+    let file = result
+        .files
+        .iter()
+        .find(|f| f.file_type == FileType::File && f.path.ends_with("main.c"))
+        .expect("Should find corrupt file");
+    assert_eq!(file.license_expression.as_deref(), Some("MIT"));
+    assert_eq!(file.scan_notes, vec![LOSSY_DECODE_NOTE.to_string()]);
+    assert!(file.scan_errors.is_empty());
+}
+
+#[test]
+fn test_scanner_flags_preserved_license_comment_in_minified_js() {
+    use tempfile::TempDir;
+
+    let Some(engine) = create_license_detection_engine() else {
+        return;
+    };
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let content = "/*! @license MIT\n * Copyright (c) 2021 Example Widgets Inc.\n * Released under the MIT license\n */\n!function(e){\"use strict\";e.widget=function(){return 1}}(window);\n";
+    fs::write(temp_dir.path().join("widget.min.js"), content).expect("Failed to write test file");
+
+    let result = scan(temp_dir.path(), 0, &[], Some(engine), false, None);
+
+    let file = result
+        .files
+        .iter()
+        .find(|f| f.file_type == FileType::File && f.path.ends_with("widget.min.js"))
+        .expect("Should find minified file");
+    assert_eq!(file.license_expression.as_deref(), Some("MIT"));
+    let first_detection = file
+        .license_detections
+        .first()
+        .expect("Should detect the preserved license");
+    assert_eq!(first_detection.license_expression, "mit");
+    assert!(
+        first_detection
+            .matches
+            .iter()
+            .all(|m| m.start_line >= 1 && m.end_line <= 4)
+    );
+    assert_eq!(
+        file.scan_notes,
+        vec!["Preserved license comment (@license) at lines 1-4".to_string()]
+    );
+}
+
+#[test]
+fn test_scanner_skip_data_files_skips_detection_for_tabular_files() {
+    use tempfile::TempDir;
+
+    let Some(engine) = create_license_detection_engine() else {
+        return;
+    };
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let mut content =
+        "id,name,license,comment\n1,widget,MIT,Copyright (c) 2021 Example Widgets Inc.\n"
+            .to_string();
+    for row in 2..5000 {
+        content.push_str(&format!("{row},item-{row},none,batch {}\n", row % 17));
+    }
+    fs::write(temp_dir.path().join("records.csv"), &content).expect("Failed to write data file");
+    fs::write(
+        temp_dir.path().join("licensed.csv"),
+        "# SPDX-License-Identifier: MIT\nid,name\n1,widget\n",
+    )
+    .expect("Failed to write licensed file");
+
+    let options = TextDetectionOptions {
+        skip_data_files: true,
+        ..TextDetectionOptions::default()
+    };
+    let skipped = scan(
+        temp_dir.path(),
+        0,
+        &[],
+        Some(engine.clone()),
+        false,
+        Some(&options),
+    );
+    let find = |result: &provenant::scanner::ProcessResult, name: &str| {
+        result
+            .files
+            .iter()
+            .find(|f| f.file_type == FileType::File && f.path.ends_with(name))
+            .unwrap_or_else(|| panic!("Should find {name}"))
+            .clone()
+    };
+
+    let data_file = find(&skipped, "records.csv");
+    assert!(data_file.license_detections.is_empty());
+    assert!(data_file.copyrights.is_empty());
+    assert!(
+        data_file
+            .scan_notes
+            .contains(&provenant::utils::tabular::SKIPPED_DATA_FILE_NOTE.to_string())
+    );
+
+    let default_scan = scan(temp_dir.path(), 0, &[], Some(engine), false, None);
+    assert_eq!(
+        find(&default_scan, "licensed.csv")
+            .license_expression
+            .as_deref(),
+        Some("MIT")
+    );
+    assert!(!find(&default_scan, "records.csv").copyrights.is_empty());
+}
+
+#[test]
+fn test_scanner_emits_scancode_and_spdx_forms_for_gpl_plus_tag() {
+    use tempfile::TempDir;
+
+    let Some(engine) = create_license_detection_engine() else {
+        return;
+    };
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("driver.c"),
+        "// SPDX-License-Identifier: GPL-2.0+\nint init(void) { return 0; }\n",
+    )
+    .expect("Failed to write test file");
+
+    let result = scan(temp_dir.path(), 0, &[], Some(engine), false, None);
+
+    let file = result
+        .files
+        .iter()
+        .find(|f| f.file_type == FileType::File && f.path.ends_with("driver.c"))
+        .expect("Should find tagged file");
+    assert_eq!(file.license_expression.as_deref(), Some("GPL-2.0-or-later"));
+    assert_eq!(file.license_detections.len(), 1);
+    let detection = &file.license_detections[0];
+    assert_eq!(detection.license_expression, "gpl-2.0-plus");
+    assert_eq!(detection.license_expression_spdx, "GPL-2.0-or-later");
+    assert!(detection.matches.iter().all(|m| {
+        m.license_expression == "gpl-2.0-plus" && m.license_expression_spdx == "GPL-2.0-or-later"
+    }));
+}
+
+#[test]
+fn test_scanner_attributes_oci_layer_files_to_layer_digest() {
+    use flate2::Compression;
+    use flate2::write::GzEncoder;
+    use provenant::scanner::{attribute_oci_layers, extract_oci_layers};
+    use tempfile::TempDir;
+
+    fn write_blob(image_dir: &Path, content: &[u8]) -> String {
+        let hex = calculate_sha256(content);
+        let blobs = image_dir.join("blobs").join("sha256");
+        fs::create_dir_all(&blobs).expect("Failed to create blobs dir");
+        fs::write(blobs.join(&hex), content).expect("Failed to write blob");
+        format!("sha256:{hex}")
+    }
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let image_dir = temp_dir.path().join("image");
+
+    let mut layer = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::default()));
+    for (path, content) in [
+        ("etc/os-release", "NAME=\"Tiny\"\n".as_bytes()),
+        (
+            "usr/share/doc/tiny/copyright",
+            "Copyright (c) 2024 Tiny Authors\nSPDX-License-Identifier: MIT\n".as_bytes(),
+        ),
+        ("usr/share/doc/.wh.removed", b"".as_slice()),
+    ] {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        layer
+            .append_data(&mut header, path, content)
+            .expect("Failed to append layer entry");
+    }
+    let layer_bytes = layer
+        .into_inner()
+        .and_then(|encoder| encoder.finish())
+        .expect("Failed to finish layer");
+    let layer_digest = write_blob(&image_dir, &layer_bytes);
+    let config_digest = write_blob(&image_dir, b"{}");
+
+    let manifest = serde_json::json!({
+        "schemaVersion": 2,
+        "mediaType": "application/vnd.oci.image.manifest.v1+json",
+        "config": {
+            "mediaType": "application/vnd.oci.image.config.v1+json",
+            "digest": config_digest,
+            "size": 2
+        },
+        "layers": [{
+            "mediaType": "application/vnd.oci.image.layer.v1.tar+gzip",
+            "digest": layer_digest,
+            "size": layer_bytes.len()
+        }]
+    });
+    let manifest_digest = write_blob(&image_dir, manifest.to_string().as_bytes());
+    let index = serde_json::json!({
+        "schemaVersion": 2,
+        "manifests": [{
+            "mediaType": "application/vnd.oci.image.manifest.v1+json",
+            "digest": manifest_digest
+        }]
+    });
+    fs::write(image_dir.join("index.json"), index.to_string()).expect("Failed to write index");
+
+    let extraction_dir = temp_dir.path().join("extracted");
+    let image_root = extraction_dir.join("image");
+    let layers = extract_oci_layers(&image_dir, &image_root).expect("Failed to extract layers");
+    assert_eq!(layers.len(), 1);
+    assert_eq!(layers[0].digest, layer_digest);
+
+    let options = TextDetectionOptions {
+        detect_copyrights: true,
+        ..TextDetectionOptions::default()
+    };
+    let mut result = scan(&image_root, 0, &[], None, false, Some(&options));
+    attribute_oci_layers(&mut result.files, &layers, &extraction_dir);
+
+    let layer_dir = format!("image/{}", layer_digest.replace(':', "-"));
+    let copyright = result
+        .files
+        .iter()
+        .find(|f| f.path == format!("{layer_dir}/usr/share/doc/tiny/copyright"))
+        .expect("Should find file from layer");
+    assert_eq!(
+        copyright.layer_digest.as_deref(),
+        Some(layer_digest.as_str())
+    );
+    assert!(
+        copyright
+            .holders
+            .iter()
+            .any(|holder| holder.holder == "Tiny Authors"),
+        "{:?}",
+        copyright.holders
+    );
+    assert!(
+        result
+            .files
+            .iter()
+            .any(|f| f.path == format!("{layer_dir}/etc/os-release")
+                && f.layer_digest.as_deref() == Some(layer_digest.as_str()))
+    );
+    assert!(result.files.iter().all(|f| !f.path.contains(".wh.")));
+    assert!(
+        result
+            .files
+            .iter()
+            .find(|f| f.path == "image")
+            .is_some_and(|root| root.layer_digest.is_none())
+    );
+}
+
+#[test]
+fn test_scanner_reports_match_type_when_requested() {
+    use tempfile::TempDir;
+
+    let Some(engine) = create_license_detection_engine() else {
+        return;
+    };
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("main.c"),
+        "// SPDX-License-Identifier: MIT\nint main(void) { return 0; }\n",
+    )
+    .expect("Failed to write test file");
+
+    let collected = collect_paths(temp_dir.path(), 0, &[]);
+    let result = process_collected(
+        &collected,
+        hidden_progress(),
+        Some(engine),
+        LicenseScanOptions {
+            include_match_type: true,
+            ..LicenseScanOptions::default()
+        },
+        &TextDetectionOptions::default(),
+    );
+
+    let file = result
+        .files
+        .iter()
+        .find(|f| f.file_type == FileType::File && f.path.ends_with("main.c"))
+        .expect("Should find scanned file");
+    let matches: Vec<_> = file
+        .license_detections
+        .iter()
+        .flat_map(|detection| detection.matches.iter())
+        .collect();
+    assert!(!matches.is_empty());
+    assert!(
+        matches
+            .iter()
+            .all(|m| m.match_type.as_deref() == Some("tag")),
+        "{matches:?}"
+    );
+}
+
+#[test]
+fn test_scanner_tags_license_facets_when_requested() {
+    use provenant::models::LicenseFacet;
+    use tempfile::TempDir;
+
+    let Some(engine) = create_license_detection_engine() else {
+        return;
+    };
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("package.json"),
+        r#"{"name": "demo", "version": "1.0.0", "license": "MIT"}"#,
+    )
+    .expect("Failed to write manifest");
+    fs::write(
+        temp_dir.path().join("main.c"),
+        "/*\n * Licensed under the Apache License, Version 2.0 (the \"License\");\n * you may not use this file except in compliance with the License.\n * You may obtain a copy of the License at\n *\n *     http://www.apache.org/licenses/LICENSE-2.0\n *\n * Unless required by applicable law or agreed to in writing, software\n * distributed under the License is distributed on an \"AS IS\" BASIS,\n * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.\n * See the License for the specific language governing permissions and\n * limitations under the License.\n */\nint main(void) { return 0; }\n",
+    )
+    .expect("Failed to write source file");
+
+    let collected = collect_paths(temp_dir.path(), 0, &[]);
+    let result = process_collected(
+        &collected,
+        hidden_progress(),
+        Some(engine),
+        LicenseScanOptions {
+            include_license_facets: true,
+            ..LicenseScanOptions::default()
+        },
+        &package_scan_options(),
+    );
+    let file = |name: &str| {
+        result
+            .files
+            .iter()
+            .find(|f| f.file_type == FileType::File && f.path.ends_with(name))
+            .expect("Should find scanned file")
+    };
+
+    let declared: Vec<_> = file("package.json")
+        .package_data
+        .iter()
+        .flat_map(|package_data| &package_data.license_detections)
+        .map(|detection| detection.license_facet)
+        .collect();
+    assert!(!declared.is_empty());
+    assert!(
+        declared
+            .iter()
+            .all(|facet| *facet == Some(LicenseFacet::Declared))
+    );
+
+    let source = file("main.c");
+    assert!(!source.license_detections.is_empty());
+    assert!(
+        source
+            .license_detections
+            .iter()
+            .all(|detection| detection.license_facet == Some(LicenseFacet::Incidental)),
+        "{:?}",
+        source.license_detections
+    );
+}
+
+#[test]
+fn test_scanner_attributes_detections_to_license_components() {
+    use tempfile::TempDir;
+
+    let Some(engine) = create_license_detection_engine() else {
+        return;
+    };
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("LICENSE"),
+        "This product bundles the following components.\n\
+         -------------------- component: left-pad --------------------\n\
+         SPDX-License-Identifier: MIT\n\
+         -------------------- component: guava --------------------\n\
+         SPDX-License-Identifier: Apache-2.0\n",
+    )
+    .expect("Failed to write license file");
+
+    let collected = collect_paths(temp_dir.path(), 0, &[]);
+    let result = process_collected(
+        &collected,
+        hidden_progress(),
+        Some(engine),
+        LicenseScanOptions {
+            split_license_components: true,
+            ..LicenseScanOptions::default()
+        },
+        &TextDetectionOptions::default(),
+    );
+    let file = result
+        .files
+        .iter()
+        .find(|f| f.file_type == FileType::File && f.path.ends_with("LICENSE"))
+        .expect("Should find scanned file");
+
+    let components: Vec<_> = file
+        .license_detections
+        .iter()
+        .map(|detection| {
+            (
+                detection.component.as_deref(),
+                detection.license_expression_spdx.as_str(),
+                detection.matches[0].start_line,
+            )
+        })
+        .collect();
+    assert_eq!(
+        components,
+        vec![
+            (Some("left-pad"), "MIT", 3),
+            (Some("guava"), "Apache-2.0", 5),
+        ]
+    );
+}
+
+#[test]
+fn test_scanner_flags_detections_in_source_comment_header() {
+    use tempfile::TempDir;
+
+    let Some(engine) = create_license_detection_engine() else {
+        return;
+    };
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("main.c"),
+        "/*\n * Copyright (c) 2024 Example Inc.\n * SPDX-License-Identifier: MIT\n */\n\n\
+         #include <stdio.h>\n\n\
+         /* Fixture used by the license notice tests. */\n\
+         static const char *notice =\n\
+         \"This program is free software; you can redistribute it and/or modify \"\n\
+         \"it under the terms of the GNU General Public License as published by \"\n\
+         \"the Free Software Foundation; either version 2 of the License, or \"\n\
+         \"(at your option) any later version.\";\n",
+    )
+    .expect("Failed to write source file");
+
+    let collected = collect_paths(temp_dir.path(), 0, &[]);
+    let result = process_collected(
+        &collected,
+        hidden_progress(),
+        Some(engine),
+        LicenseScanOptions {
+            include_in_header: true,
+            ..LicenseScanOptions::default()
+        },
+        &TextDetectionOptions::default(),
+    );
+    let file = result
+        .files
+        .iter()
+        .find(|f| f.file_type == FileType::File && f.path.ends_with("main.c"))
+        .expect("Should find scanned file");
+
+    let header = file
+        .license_detections
+        .iter()
+        .find(|detection| detection.license_expression_spdx == "MIT")
+        .expect("header MIT detection");
+    assert_eq!(header.in_header, Some(true));
+
+    let body = file
+        .license_detections
+        .iter()
+        .find(|detection| detection.license_expression_spdx.contains("GPL"))
+        .expect("body GPL detection");
+    assert_eq!(body.in_header, Some(false));
+}
+
+#[test]
+fn test_scanner_notes_truncated_license_query() {
+    use tempfile::TempDir;
+
+    let Some(engine) = create_license_detection_engine() else {
+        return;
+    };
+    let engine = Arc::new((*engine).clone().with_max_query_tokens(100));
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("huge.txt"),
+        format!(
+            "SPDX-License-Identifier: Apache-2.0\n{}",
+            "the quick brown fox jumps over the lazy dog\n".repeat(100_000)
+        ),
+    )
+    .expect("Failed to write large file");
+
+    let collected = collect_paths(temp_dir.path(), 0, &[]);
+    let result = process_collected(
+        &collected,
+        hidden_progress(),
+        Some(engine),
+        LicenseScanOptions::default(),
+        &TextDetectionOptions {
+            collect_info: true,
+            ..TextDetectionOptions::default()
+        },
+    );
+    let file = result
+        .files
+        .iter()
+        .find(|f| f.file_type == FileType::File && f.path.ends_with("huge.txt"))
+        .expect("Should find scanned file");
+
+    assert!(file.scan_errors.is_empty(), "{:?}", file.scan_errors);
+    assert!(
+        file.scan_notes
+            .iter()
+            .any(|note| note.contains("first 100 tokens")),
+        "{:?}",
+        file.scan_notes
+    );
+    assert_eq!(file.license_expression.as_deref(), Some("Apache-2.0"));
+    assert!(file.sha1.is_some());
+}
+
+#[test]
+fn test_scanner_flags_public_domain_detections_when_requested() {
+    use tempfile::TempDir;
+
+    let Some(engine) = create_license_detection_engine() else {
+        return;
+    };
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("dedicated.c"),
+        "// SPDX-License-Identifier: CC0-1.0\nint main(void) { return 0; }\n",
+    )
+    .expect("Failed to write test file");
+    fs::write(
+        temp_dir.path().join("licensed.c"),
+        "// SPDX-License-Identifier: MIT\nint main(void) { return 0; }\n",
+    )
+    .expect("Failed to write test file");
+
+    let collected = collect_paths(temp_dir.path(), 0, &[]);
+    let scan = |include_public_domain| {
+        process_collected(
+            &collected,
+            hidden_progress(),
+            Some(engine.clone()),
+            LicenseScanOptions {
+                include_public_domain,
+                ..LicenseScanOptions::default()
+            },
+            &TextDetectionOptions::default(),
+        )
+    };
+    let flags = |result: &provenant::scanner::ProcessResult, name: &str| -> Vec<Option<bool>> {
+        result
+            .files
+            .iter()
+            .find(|f| f.file_type == FileType::File && f.path.ends_with(name))
+            .expect("Should find scanned file")
+            .license_detections
+            .iter()
+            .map(|detection| detection.is_public_domain)
+            .collect()
+    };
+
+    let flagged = scan(true);
+    assert_eq!(flags(&flagged, "dedicated.c"), vec![Some(true)]);
+    assert_eq!(flags(&flagged, "licensed.c"), vec![Some(false)]);
+
+    let unflagged = scan(false);
+    assert_eq!(flags(&unflagged, "dedicated.c"), vec![None]);
+}
+
+#[test]
+fn test_scanner_reports_translated_rule_language_on_matches() {
+    use tempfile::TempDir;
+
+    let rules_dir = TempDir::new().expect("Failed to create rules dir");
+    fs::write(
+        rules_dir.path().join("bar.LICENSE"),
+        "---\nkey: bar\nshort_name: bar\nname: bar\ncategory: Permissive\n---\n\
+         The bar license grants everyone the right to copy and share this software freely.",
+    )
+    .expect("Failed to write license fixture");
+    let translated_dir = TempDir::new().expect("Failed to create translated dir");
+    fs::write(
+        translated_dir.path().join("bar_fr_1.RULE"),
+        "---\nlicense_expression: bar\nis_license_notice: yes\n---\n\
+         Cette bibliothèque est distribuée selon les termes de la licence bar.",
+    )
+    .expect("Failed to write translated rule");
+    let engine = LicenseDetectionEngine::from_directory(rules_dir.path())
+        .expect("engine from fixture")
+        .with_translated_rules(translated_dir.path(), "fr")
+        .expect("merge translated rules");
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("LISEZMOI"),
+        "Cette bibliothèque est distribuée selon les termes de la licence bar.\n",
+    )
+    .expect("Failed to write test file");
+
+    let collected = collect_paths(temp_dir.path(), 0, &[]);
+    let result = process_collected(
+        &collected,
+        hidden_progress(),
+        Some(Arc::new(engine)),
+        LicenseScanOptions::default(),
+        &TextDetectionOptions::default(),
+    );
+
+    let file = result
+        .files
+        .iter()
+        .find(|f| f.file_type == FileType::File && f.path.ends_with("LISEZMOI"))
+        .expect("Should find scanned file");
+    let languages: Vec<_> = file
+        .license_detections
+        .iter()
+        .flat_map(|detection| detection.matches.iter())
+        .map(|m| m.language.as_deref())
+        .collect();
+    assert_eq!(languages, vec![Some("fr")], "{file:?}");
+}
+
+#[test]
+fn test_scanner_keeps_detection_order_around_preserved_license_comment() {
+    use tempfile::TempDir;
+
+    let Some(engine) = create_license_detection_engine() else {
+        return;
+    };
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let content = "// SPDX-License-Identifier: Apache-2.0\n/*! @license\n * Released under the MIT license\n */\nexport const widget = 1;\n";
+    fs::write(temp_dir.path().join("widget.js"), content).expect("Failed to write test file");
+
+    let result = scan(temp_dir.path(), 0, &[], Some(engine), false, None);
+
+    let file = result
+        .files
+        .iter()
+        .find(|f| f.file_type == FileType::File && f.path.ends_with("widget.js"))
+        .expect("Should find source file");
+    let expressions: Vec<_> = file
+        .license_detections
+        .iter()
+        .map(|detection| detection.license_expression.as_str())
+        .collect();
+    assert_eq!(expressions, vec!["apache-2.0", "mit"]);
+    assert_eq!(
+        file.license_expression.as_deref(),
+        Some("Apache-2.0 AND MIT")
+    );
+    assert_eq!(
+        file.scan_notes,
+        vec!["Preserved license comment (@license) at lines 2-4".to_string()]
+    );
+}
+
+#[test]
+fn test_scanner_cache_keeps_preserved_comment_notes_path_specific() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let cache_dir = TempDir::new().expect("Failed to create cache dir");
+    let content = "/*! @license MIT */\nexport const widget = 1;\n";
+    fs::write(temp_dir.path().join("widget.js"), content).expect("Failed to write test file");
+    fs::write(temp_dir.path().join("widget.txt"), content).expect("Failed to write test file");
+
+    let options = TextDetectionOptions {
+        scan_cache_dir: Some(cache_dir.path().to_path_buf()),
+        ..TextDetectionOptions::default()
+    };
+    let notes = |name: &str| {
+        let result = scan(temp_dir.path(), 0, &[], None, false, Some(&options));
+        result
+            .files
+            .iter()
+            .find(|f| f.file_type == FileType::File && f.path.ends_with(name))
+            .expect("Should find scanned file")
+            .scan_notes
+            .clone()
+    };
+
+    // Scan twice so the second pass reads whichever entry the first pass cached.
+    for _ in 0..2 {
+        assert_eq!(
+            notes("widget.js"),
+            vec!["Preserved license comment (@license) at lines 1-1".to_string()]
+        );
+        assert!(notes("widget.txt").is_empty());
+    }
+}
+
+#[test]
+fn test_scanner_skips_code_like_copyrights_only_when_requested() {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+    let content = "int ok = license_copyright(c, d);\n/* Copyright (c) 2020 Foo */\n";
+    fs::write(temp_dir.path().join("main.c"), content).expect("Failed to write test file");
+
+    let scanned_copyrights = |skip_code_copyrights: bool| -> Vec<(String, usize)> {
+        let collected = collect_paths(temp_dir.path(), 0, &[]);
+        let result = process_collected(
+            &collected,
+            hidden_progress(),
+            None,
+            LicenseScanOptions::default(),
+            &TextDetectionOptions {
+                skip_code_copyrights,
+                ..TextDetectionOptions::default()
+            },
+        );
+        result
+            .files
+            .into_iter()
+            .find(|f| f.file_type == FileType::File && f.path.ends_with("main.c"))
+            .expect("Should find main.c")
+            .copyrights
+            .into_iter()
+            .map(|c| (c.copyright, c.start_line))
+            .collect()
+    };
+
+    let (detected, _, _) = provenant::copyright::detect_copyrights(content);
+    let detected: Vec<_> = detected
+        .into_iter()
+        .map(|c| (c.copyright, c.start_line))
+        .collect();
+    assert_eq!(scanned_copyrights(false), detected);
+    assert_eq!(
+        scanned_copyrights(true),
+        vec![("Copyright (c) 2020 Foo".to_string(), 2)]
+    );
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "server-tests")]
+
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::sync::Arc;
+use std::thread;
+
+use provenant::license_detection::LicenseDetectionEngine;
+use provenant::serve::DetectServer;
+
+const MIT_TEXT: &str = "Permission is hereby granted, free of charge, to any person obtaining a copy \
+of this software and associated documentation files (the \"Software\"), to deal \
+in the Software without restriction, including without limitation the rights \
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell \
+copies of the Software, and to permit persons to whom the Software is \
+furnished to do so, subject to the following conditions:\n\n\
+The above copyright notice and this permission notice shall be included in all \
+copies or substantial portions of the Software.\n\n\
+THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR \
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, \
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE \
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER \
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, \
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE \
+SOFTWARE.\n";
+
+fn post(addr: std::net::SocketAddr, path: &str, body: &str) -> (String, serde_json::Value) {
+    let mut stream = TcpStream::connect(addr).expect("connect to detection server");
+    write!(
+        stream,
+        "POST {path} HTTP/1.1\r\nHost: {addr}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+        body.len()
+    )
+    .expect("write request");
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).expect("read response");
+    let (head, payload) = response
+        .split_once("\r\n\r\n")
+        .expect("response should have a header block");
+    let status_line = head.lines().next().unwrap_or_default().to_string();
+    let json = serde_json::from_str(payload).expect("response body should be JSON");
+    (status_line, json)
+}
+
+#[test]
+fn test_serve_detects_mit_text() {
+    let engine = Arc::new(LicenseDetectionEngine::from_embedded().expect("embedded engine"));
+    let server = DetectServer::bind("127.0.0.1:0", engine).expect("bind server");
+    let addr = server.local_addr().expect("local addr");
+    thread::spawn(move || server.serve());
+
+    let body = serde_json::json!({ "text": MIT_TEXT }).to_string();
+    let (status, json) = post(addr, "/detect", &body);
+
+    assert!(status.contains("200"), "unexpected status: {status}");
+    let expressions: Vec<&str> = json["detections"]
+        .as_array()
+        .expect("detections array")
+        .iter()
+        .filter_map(|detection| detection["license_expression"].as_str())
+        .collect();
+    assert_eq!(expressions, vec!["mit"]);
+    let first_match = &json["detections"][0]["matches"][0];
+    assert_eq!(first_match["license_expression"], "mit");
+    assert!(first_match["start_line"].is_u64());
+    assert!(first_match["score"].is_number());
+    assert!(json["license_clues"].is_array());
+
+    let (status, json) = post(addr, "/detect", "{not json");
+    assert!(status.contains("400"), "unexpected status: {status}");
+    assert!(json["error"].is_string());
+}